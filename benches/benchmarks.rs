@@ -111,19 +111,26 @@ fn bench_state_parse(c: &mut Criterion) {
 fn bench_url_parse(c: &mut Criterion) {
     let config = RepoConfig {
         url: "git@github.com:organization/repository-name.git".to_string(),
+        remote: None,
+        name: None,
         path: "packages/repository-name".to_string(),
         default_branch: "main".to_string(),
+        revision: None,
         copyfile: None,
         linkfile: None,
         platform: None,
         reference: false,
         groups: Vec::new(),
         agent: None,
+        depends_on: Vec::new(),
+        clone: None,
+        sparse: Vec::new(),
+        pr: None,
     };
     let workspace = PathBuf::from("/home/user/workspace");
 
     c.bench_function("url_parse_github_ssh", |b| {
-        b.iter(|| RepoInfo::from_config("repo", black_box(&config), black_box(&workspace)))
+        b.iter(|| RepoInfo::from_config("repo", black_box(&config), black_box(&workspace), None))
     });
 }
 
@@ -131,19 +138,26 @@ fn bench_url_parse(c: &mut Criterion) {
 fn bench_url_parse_azure(c: &mut Criterion) {
     let config = RepoConfig {
         url: "https://dev.azure.com/organization/project/_git/repository".to_string(),
+        remote: None,
+        name: None,
         path: "repository".to_string(),
         default_branch: "main".to_string(),
+        revision: None,
         copyfile: None,
         linkfile: None,
         platform: None,
         reference: false,
         groups: Vec::new(),
         agent: None,
+        depends_on: Vec::new(),
+        clone: None,
+        sparse: Vec::new(),
+        pr: None,
     };
     let workspace = PathBuf::from("/home/user/workspace");
 
     c.bench_function("url_parse_azure_https", |b| {
-        b.iter(|| RepoInfo::from_config("repo", black_box(&config), black_box(&workspace)))
+        b.iter(|| RepoInfo::from_config("repo", black_box(&config), black_box(&workspace), None))
     });
 }
 
@@ -891,7 +905,12 @@ workspace:
                 .repos
                 .iter()
                 .filter_map(|(name, config)| {
-                    RepoInfo::from_config(name, config, black_box(&workspace))
+                    RepoInfo::from_config(
+                        name,
+                        config,
+                        black_box(&workspace),
+                        manifest.remotes.as_ref(),
+                    )
                 })
                 .collect();
             black_box(repos)
@@ -23,7 +23,9 @@ pub fn run_add(
     let repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .collect();
 
     let mut total_staged = 0;
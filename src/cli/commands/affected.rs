@@ -0,0 +1,126 @@
+//! Affected command implementation
+//!
+//! Lists repos changed since a ref plus every repo that transitively depends
+//! on them, and provides the filter behind the global `--affected` flag.
+
+use crate::cli::output::Output;
+use crate::core::affected::{compute_affected, AffectedReason};
+use crate::core::manifest::Manifest;
+use crate::core::repo::filter_repos;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+/// JSON output for a single affected repo
+#[derive(Serialize)]
+struct JsonAffectedRepo {
+    name: String,
+    /// "changed", "unknown-ref" or "dependency"
+    reason: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    changed_files: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    depends_on: Option<String>,
+}
+
+/// JSON output for gr affected
+#[derive(Serialize)]
+struct JsonAffected {
+    /// The ref compared against (None = each repo's default branch)
+    since: Option<String>,
+    repos: Vec<JsonAffectedRepo>,
+}
+
+/// Names of the repos affected by changes since `since`
+pub fn affected_repo_names(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    since: Option<&str>,
+) -> BTreeSet<String> {
+    let repos = filter_repos(manifest, workspace_root, None, None, true);
+    compute_affected(manifest, &repos, since)
+        .into_keys()
+        .collect()
+}
+
+/// Run the affected command
+pub fn run_affected(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    since: Option<&str>,
+    json: bool,
+    quiet: bool,
+) -> anyhow::Result<()> {
+    let repos = filter_repos(manifest, workspace_root, None, None, true);
+    let affected = compute_affected(manifest, &repos, since);
+
+    if json {
+        let repos = affected
+            .into_iter()
+            .map(|(name, reason)| match reason {
+                AffectedReason::Changed(files) => JsonAffectedRepo {
+                    name,
+                    reason: "changed",
+                    changed_files: files,
+                    depends_on: None,
+                },
+                AffectedReason::UnknownRef(_) => JsonAffectedRepo {
+                    name,
+                    reason: "unknown-ref",
+                    changed_files: Vec::new(),
+                    depends_on: None,
+                },
+                AffectedReason::DependsOn(dep) => JsonAffectedRepo {
+                    name,
+                    reason: "dependency",
+                    changed_files: Vec::new(),
+                    depends_on: Some(dep),
+                },
+            })
+            .collect();
+        let output = JsonAffected {
+            since: since.map(String::from),
+            repos,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if quiet {
+        for name in affected.keys() {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    Output::header(&format!(
+        "Affected repos since {}",
+        since.unwrap_or("each repo's default branch")
+    ));
+    println!();
+
+    if affected.is_empty() {
+        Output::info("No repos affected");
+        return Ok(());
+    }
+
+    for (name, reason) in &affected {
+        let detail = match reason {
+            AffectedReason::Changed(files) => format!("{} changed file(s)", files.len()),
+            AffectedReason::UnknownRef(since) => {
+                format!("'{}' not found, assuming changed", since)
+            }
+            AffectedReason::DependsOn(dep) => format!("depends on {}", dep),
+        };
+        println!("  {} ({})", Output::repo_name(name), detail);
+    }
+
+    println!();
+    Output::info(&format!(
+        "{} of {} repo(s) affected",
+        affected.len(),
+        repos.len()
+    ));
+
+    Ok(())
+}
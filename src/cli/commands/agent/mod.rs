@@ -16,6 +16,121 @@ pub use verify::run_agent_verify;
 
 use serde::Serialize;
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus};
+
+use crate::cli::output::Output;
+use crate::core::dag::{run_level, RepoDag};
+use crate::core::manifest::{Manifest, RepoAgentConfig};
+use crate::core::repo::{filter_repos, RepoInfo};
+
+/// A per-repo agent command (`agent.build` or `agent.test`)
+struct AgentCommand {
+    /// Manifest key under `agent:`
+    key: &'static str,
+    select: fn(&RepoAgentConfig) -> Option<&str>,
+    running: &'static str,
+    succeeded: &'static str,
+    failed: &'static str,
+}
+
+/// Run an agent command across repos in dependency order.
+///
+/// Repos in the same dependency level run in parallel with their output
+/// captured; a level of one repo streams its output. A failure stops before
+/// the next level starts.
+fn run_agent_command(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repo_filter: Option<&str>,
+    command: &AgentCommand,
+) -> anyhow::Result<()> {
+    let repos = filter_repos(manifest, workspace_root, None, None, false);
+
+    let mut targets: Vec<RepoInfo> = Vec::new();
+    for repo in repos {
+        if let Some(filter) = repo_filter {
+            if repo.name != filter {
+                continue;
+            }
+        }
+
+        if repo.agent.as_ref().and_then(command.select).is_none() {
+            if repo_filter.is_some() {
+                anyhow::bail!(
+                    "Repository '{}' has no agent.{} command defined in the manifest",
+                    repo.name,
+                    command.key
+                );
+            }
+            continue;
+        }
+        targets.push(repo);
+    }
+
+    if targets.is_empty() {
+        if repo_filter.is_some() {
+            anyhow::bail!("Repository not found in manifest");
+        }
+        Output::info(&format!("No repos have agent.{} configured", command.key));
+        return Ok(());
+    }
+
+    let levels = RepoDag::from_manifest(manifest).levels(targets)?;
+    for level in &levels {
+        let mut failures = Vec::new();
+
+        if let [repo] = level.as_slice() {
+            // A lone repo streams its output live
+            announce(repo, command);
+            let status = shell(repo, command).status()?;
+            report(repo, command, status, &mut failures);
+        } else {
+            // Parallel repos are captured so their output doesn't interleave
+            let results = run_level(level, |repo| shell(repo, command).output());
+            for (repo, output) in level.iter().zip(results) {
+                announce(repo, command);
+                let output = output?;
+                print!("{}", String::from_utf8_lossy(&output.stdout));
+                eprint!("{}", String::from_utf8_lossy(&output.stderr));
+                report(repo, command, output.status, &mut failures);
+            }
+        }
+
+        if !failures.is_empty() {
+            anyhow::bail!("{}", failures.join("; "));
+        }
+    }
+
+    Ok(())
+}
+
+/// `sh -c <agent command>` in the repo's directory
+fn shell(repo: &RepoInfo, command: &AgentCommand) -> Command {
+    let cmd = repo.agent.as_ref().and_then(command.select).unwrap_or("");
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(cmd).current_dir(&repo.absolute_path);
+    shell
+}
+
+fn announce(repo: &RepoInfo, command: &AgentCommand) {
+    let cmd = repo.agent.as_ref().and_then(command.select).unwrap_or("");
+    Output::header(&format!("{} {}", command.running, repo.name));
+    Output::info(&format!("$ {}", cmd));
+}
+
+fn report(repo: &RepoInfo, command: &AgentCommand, status: ExitStatus, failures: &mut Vec<String>) {
+    if status.success() {
+        Output::success(&format!("{} {}", repo.name, command.succeeded));
+    } else {
+        failures.push(format!(
+            "{} for '{}' (exit code: {:?})",
+            command.failed,
+            repo.name,
+            status.code()
+        ));
+    }
+}
 
 /// JSON output for gr agent context
 #[derive(Serialize)]
@@ -206,17 +206,24 @@ fn run_benchmark_operation(name: &str) -> Result<()> {
         "url-parse" => {
             let config = RepoConfig {
                 url: "git@github.com:user/repo.git".to_string(),
+                remote: None,
+                name: None,
                 path: "repo".to_string(),
                 default_branch: "main".to_string(),
+                revision: None,
                 copyfile: None,
                 linkfile: None,
                 platform: None,
                 reference: false,
                 groups: Vec::new(),
                 agent: None,
+                depends_on: Vec::new(),
+                clone: None,
+                sparse: Vec::new(),
+                pr: None,
             };
             let workspace = std::path::PathBuf::from("/workspace");
-            let _ = RepoInfo::from_config("repo", &config, &workspace);
+            let _ = RepoInfo::from_config("repo", &config, &workspace, None);
             Ok(())
         }
         _ => Err(anyhow::anyhow!(
@@ -0,0 +1,343 @@
+//! Workspace bisect command implementation
+//!
+//! Bisects over the combined first-parent history of all repos, checking out
+//! a consistent SHA set in every repo at each step. Good and bad points are
+//! dates or commits in the manifest repo (which stand for their commit date).
+
+use crate::cli::output::Output;
+use crate::core::bisect::{bisect_path, BisectNext, BisectState, BisectStep, BisectVerdict};
+use crate::core::manifest::Manifest;
+use crate::core::repo::{filter_repos, get_manifest_repo_info, RepoInfo};
+use crate::git::branch::{checkout_branch, checkout_detached};
+use crate::git::cache::invalidate_status_cache;
+use crate::git::log::first_parent_log;
+use crate::git::status::get_cached_status;
+use crate::git::{branch_exists, get_head_sha, open_repo, path_exists};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Cloned, non-reference repos taking part in the bisect
+fn bisect_repos(workspace_root: &PathBuf, manifest: &Manifest) -> Vec<RepoInfo> {
+    filter_repos(manifest, workspace_root, None, None, false)
+        .into_iter()
+        .filter(|r| path_exists(&r.absolute_path))
+        .collect()
+}
+
+/// Parse a date: RFC 3339, `YYYY-MM-DD HH:MM[:SS]` or `YYYY-MM-DD` (local time)
+fn parse_date(value: &str) -> Option<i64> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(date.timestamp());
+    }
+    let naive = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        })?;
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|d| d.timestamp())
+}
+
+/// Resolve a workspace point to seconds since the Unix epoch
+fn resolve_point(workspace_root: &Path, manifest: &Manifest, point: &str) -> anyhow::Result<i64> {
+    if let Some(timestamp) = parse_date(point) {
+        return Ok(timestamp);
+    }
+    let commit = get_manifest_repo_info(manifest, workspace_root)
+        .and_then(|info| open_repo(&info.absolute_path).ok())
+        .and_then(|repo| {
+            let commit = repo.revparse_single(point).ok()?.peel_to_commit().ok()?;
+            Some(commit.time().seconds())
+        });
+    commit.ok_or_else(|| {
+        anyhow::anyhow!(
+            "'{}' is neither a date nor a commit in the manifest repo",
+            point
+        )
+    })
+}
+
+fn format_time(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|d| d.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+fn load_state(workspace_root: &Path) -> anyhow::Result<BisectState> {
+    BisectState::load(&bisect_path(workspace_root))?
+        .ok_or_else(|| anyhow::anyhow!("No bisect in progress (run 'gr bisect start' first)"))
+}
+
+/// Run `gr bisect start <bad> <good>`
+pub fn run_bisect_start(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    bad: &str,
+    good: &str,
+) -> anyhow::Result<()> {
+    let path = bisect_path(workspace_root);
+    if BisectState::load(&path)?.is_some() {
+        anyhow::bail!("A bisect is already in progress (run 'gr bisect reset' first)");
+    }
+
+    let bad_time = resolve_point(workspace_root, manifest, bad)?;
+    let good_time = resolve_point(workspace_root, manifest, good)?;
+    if good_time >= bad_time {
+        anyhow::bail!("The good point must be older than the bad point");
+    }
+
+    let repos = bisect_repos(workspace_root, manifest);
+    let mut problems = Vec::new();
+    for repo in &repos {
+        let status = get_cached_status(&repo.absolute_path)?;
+        if !status.staged.is_empty() || !status.modified.is_empty() {
+            problems.push(format!("{}: has uncommitted changes", repo.name));
+        }
+    }
+    if !problems.is_empty() {
+        anyhow::bail!("Cannot start bisect:\n  {}", problems.join("\n  "));
+    }
+
+    let mut original = BTreeMap::new();
+    let mut base = BTreeMap::new();
+    let mut timeline = Vec::new();
+    let mut too_new = Vec::new();
+    for repo in &repos {
+        let git_repo = open_repo(&repo.absolute_path)?;
+        let mut history = first_parent_log(&git_repo, good_time)?;
+        let Some(first) = history.pop() else {
+            continue;
+        };
+        // The whole history is newer: there is no state to call good
+        if first.timestamp > good_time {
+            too_new.push(repo.name.clone());
+            continue;
+        }
+
+        let head = git_repo.head()?;
+        let head = match head.shorthand().filter(|_| head.is_branch()) {
+            Some(branch) => branch.to_string(),
+            None => get_head_sha(&git_repo)?,
+        };
+        original.insert(repo.name.clone(), head);
+        base.insert(repo.name.clone(), first.sha);
+
+        // Clamp out-of-order commit dates so each repo only moves forward
+        let mut time = first.timestamp;
+        for entry in history.into_iter().rev() {
+            time = time.max(entry.timestamp);
+            if time > bad_time {
+                break;
+            }
+            timeline.push((
+                time,
+                BisectStep {
+                    repo_name: repo.name.clone(),
+                    sha: entry.sha,
+                    timestamp: entry.timestamp,
+                    subject: entry.subject,
+                },
+            ));
+        }
+    }
+    if !too_new.is_empty() {
+        too_new.sort();
+        anyhow::bail!(
+            "No commit at or before {} in: {} (pick an older good point)",
+            format_time(good_time),
+            too_new.join(", ")
+        );
+    }
+
+    timeline.sort_by(|a, b| (a.0, &a.1.repo_name).cmp(&(b.0, &b.1.repo_name)));
+    let steps: Vec<BisectStep> = timeline.into_iter().map(|(_, step)| step).collect();
+
+    if steps.is_empty() {
+        anyhow::bail!(
+            "No commits in any repo between {} and {}",
+            format_time(good_time),
+            format_time(bad_time)
+        );
+    }
+
+    Output::header(&format!(
+        "Bisecting {} commit(s) across {} repo(s)",
+        steps.len(),
+        base.len()
+    ));
+    let mut state = BisectState::new(original, base, steps);
+    advance(workspace_root, &repos, &mut state)
+}
+
+/// Run `gr bisect good|bad|skip`: mark the checked out state
+pub fn run_bisect_mark(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    verdict: BisectVerdict,
+) -> anyhow::Result<()> {
+    let mut state = load_state(workspace_root)?;
+    let repos = bisect_repos(workspace_root, manifest);
+    let Some(current) = state.current else {
+        anyhow::bail!("Bisect has finished (run 'gr bisect reset')");
+    };
+    state.mark(current, verdict);
+    advance(workspace_root, &repos, &mut state)
+}
+
+/// Run `gr bisect run <cmd>`: test each state with a command
+///
+/// `command` is either one shell command line or a program and its
+/// arguments. Like `git bisect run`: exit code 0 is good, 125 skips, 1-127 is bad and
+/// anything else (e.g. killed by a signal) stops the bisect.
+pub fn run_bisect_run(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    command: &[String],
+) -> anyhow::Result<()> {
+    let mut state = load_state(workspace_root)?;
+    let repos = bisect_repos(workspace_root, manifest);
+    let display = command.join(" ");
+
+    while let Some(current) = state.current {
+        println!();
+        Output::info(&format!("Running: {}", display));
+        // A single argument is a shell command line; several are run as is
+        let mut cmd = match command {
+            [line] => {
+                let mut cmd = Command::new("sh");
+                cmd.arg("-c").arg(line);
+                cmd
+            }
+            [program, args @ ..] => {
+                let mut cmd = Command::new(program);
+                cmd.args(args);
+                cmd
+            }
+            [] => anyhow::bail!("No command given"),
+        };
+        let status = cmd.current_dir(workspace_root).status()?;
+        let verdict = match status.code() {
+            Some(0) => BisectVerdict::Good,
+            Some(125) => BisectVerdict::Skip,
+            Some(code) if (1..128).contains(&code) => BisectVerdict::Bad,
+            _ => anyhow::bail!("Command failed with {}; stopping bisect", status),
+        };
+        state.mark(current, verdict);
+        advance(workspace_root, &repos, &mut state)?;
+    }
+    Ok(())
+}
+
+/// Check out the next state to test, or report the result
+fn advance(
+    workspace_root: &Path,
+    repos: &[RepoInfo],
+    state: &mut BisectState,
+) -> anyhow::Result<()> {
+    let path = bisect_path(workspace_root);
+    match state.next() {
+        BisectNext::Test(k) => {
+            let remaining = state.bad - state.good - 1;
+            println!(
+                "Testing state {}/{} ({} untested, roughly {} step(s) left)",
+                k,
+                state.steps.len(),
+                remaining,
+                usize::BITS - remaining.leading_zeros()
+            );
+            checkout_state(repos, &state.shas_at(k))?;
+            state.current = Some(k);
+            state.save(&path)?;
+        }
+        BisectNext::Found(k) => {
+            state.current = None;
+            state.save(&path)?;
+            let step = &state.steps[k - 1];
+            println!();
+            Output::success(&format!(
+                "First bad commit: {} {} {} ({})",
+                step.repo_name,
+                &step.sha[..7],
+                step.subject,
+                format_time(step.timestamp)
+            ));
+            println!("Workspace at the first bad state:");
+            for (repo, sha) in state.shas_at(k) {
+                println!("  {:<20} {}", repo, &sha[..7]);
+            }
+            Output::info("Run 'gr bisect reset' to return to the original branches.");
+        }
+        BisectNext::Inconclusive { good, bad } => {
+            state.current = None;
+            state.save(&path)?;
+            Output::warning("Only skipped states are left; the first bad commit is one of:");
+            for step in &state.steps[good..bad] {
+                println!(
+                    "  {:<20} {} {}",
+                    step.repo_name,
+                    &step.sha[..7],
+                    step.subject
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run `gr bisect reset`: return every repo to where the bisect started
+pub fn run_bisect_reset(workspace_root: &PathBuf, manifest: &Manifest) -> anyhow::Result<()> {
+    let state = load_state(workspace_root)?;
+    let repos = bisect_repos(workspace_root, manifest);
+
+    let mut failed = 0;
+    for (name, head) in &state.original {
+        let Some(repo) = repos.iter().find(|r| &r.name == name) else {
+            Output::warning(&format!("{}: not in workspace, skipping", name));
+            continue;
+        };
+        let git_repo = open_repo(&repo.absolute_path)?;
+        let result = if branch_exists(&git_repo, head) {
+            checkout_branch(&git_repo, head)
+        } else {
+            checkout_detached(&git_repo, head)
+        };
+        invalidate_status_cache(&repo.absolute_path);
+        match result {
+            Ok(()) => Output::success(&format!("{}: back on {}", name, head)),
+            Err(e) => {
+                failed += 1;
+                Output::error(&format!("{}: {}", name, e));
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!(
+            "Failed to reset {} repo(s); fix them and run 'gr bisect reset' again",
+            failed
+        );
+    }
+    std::fs::remove_file(bisect_path(workspace_root))?;
+    Ok(())
+}
+
+/// Check out `shas` (detached) in every repo
+fn checkout_state(repos: &[RepoInfo], shas: &BTreeMap<String, String>) -> anyhow::Result<()> {
+    for repo in repos {
+        let Some(sha) = shas.get(&repo.name) else {
+            continue;
+        };
+        let git_repo = open_repo(&repo.absolute_path)?;
+        let result = checkout_detached(&git_repo, sha);
+        invalidate_status_cache(&repo.absolute_path);
+        result.map_err(|e| anyhow::anyhow!("{}: checkout of {} failed: {}", repo.name, sha, e))?;
+    }
+    Ok(())
+}
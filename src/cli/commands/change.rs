@@ -0,0 +1,215 @@
+//! Cross-repo change commands (`gr show`, `gr revert`)
+//!
+//! Both find every commit carrying a `Gitgrip-Change-Id:` trailer, in any
+//! repo and on any branch.
+
+use crate::cli::commands::commit::create_commit;
+use crate::cli::output::Output;
+use crate::core::manifest::Manifest;
+use crate::core::repo::{filter_repos, get_manifest_repo_info, RepoInfo};
+use crate::git::cache::invalidate_status_cache;
+use crate::git::change::{
+    change_id_of, generate_change_id, is_valid_change_id, reset_hard, revert_no_commit,
+    with_change_id,
+};
+use crate::git::log::{get_log, show_commit, LogEntry, LogFilter};
+use crate::git::status::get_cached_status;
+use crate::git::{get_head_sha, open_repo, path_exists};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+/// Commits of a change in one repo, newest first
+struct RepoChange {
+    repo: RepoInfo,
+    commits: Vec<LogEntry>,
+}
+
+/// JSON-serializable commit for --json output
+#[derive(serde::Serialize)]
+struct JsonChangeCommit {
+    repo: String,
+    sha: String,
+    author: String,
+    subject: String,
+}
+
+/// Find the commits of a change in every repo, within `range` (default: all
+/// refs)
+fn find_change(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    id: &str,
+    range: Option<&str>,
+) -> anyhow::Result<Vec<RepoChange>> {
+    if !is_valid_change_id(id) {
+        anyhow::bail!("Invalid change ID '{}'", id);
+    }
+
+    let mut repos = filter_repos(manifest, workspace_root, None, None, true);
+    if let Some(manifest_repo) = get_manifest_repo_info(manifest, workspace_root) {
+        repos.push(manifest_repo);
+    }
+
+    let filter = LogFilter {
+        change_id: Some(id),
+        ..Default::default()
+    };
+    let mut changes = Vec::new();
+    let mut ids = BTreeSet::new();
+    for repo in repos {
+        if !path_exists(&repo.absolute_path) {
+            continue;
+        }
+        let git_repo = open_repo(&repo.absolute_path)?;
+        let commits = get_log(&git_repo, range, &filter)?;
+        for commit in &commits {
+            let change_id = git_repo
+                .find_commit(git2::Oid::from_str(&commit.sha)?)?
+                .message()
+                .and_then(change_id_of);
+            ids.extend(change_id);
+        }
+        if !commits.is_empty() {
+            changes.push(RepoChange { repo, commits });
+        }
+    }
+
+    if changes.is_empty() {
+        anyhow::bail!("No commits found for change {}", id);
+    }
+    // A prefix may match several changes
+    if ids.len() > 1 {
+        anyhow::bail!(
+            "Change ID prefix {} is ambiguous:\n  {}",
+            id,
+            ids.into_iter().collect::<Vec<_>>().join("\n  ")
+        );
+    }
+    Ok(changes)
+}
+
+/// Run `gr show <change-id>`: show every commit of a change
+pub fn run_show(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    id: &str,
+    stat: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    let changes = find_change(workspace_root, manifest, id, None)?;
+
+    if json {
+        let commits: Vec<JsonChangeCommit> = changes
+            .iter()
+            .flat_map(|change| {
+                change.commits.iter().map(|c| JsonChangeCommit {
+                    repo: change.repo.name.clone(),
+                    sha: c.sha.clone(),
+                    author: c.author.clone(),
+                    subject: c.subject.clone(),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&commits)?);
+        return Ok(());
+    }
+
+    let git_repos = changes
+        .iter()
+        .map(|c| open_repo(&c.repo.absolute_path))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut first = true;
+    for (change, git_repo) in changes.iter().zip(&git_repos) {
+        for commit in &change.commits {
+            if !first {
+                println!();
+            }
+            first = false;
+            Output::header(&format!("{}: {}", change.repo.name, &commit.sha[..8]));
+            println!("{}", show_commit(git_repo, &commit.sha, stat)?);
+        }
+    }
+    Ok(())
+}
+
+/// Run `gr revert <change-id>`: revert the commits of a change on each repo's
+/// current branch
+///
+/// All or nothing: if reverting fails in one repo, repos already reverted are
+/// reset to where they were.
+pub fn run_revert(workspace_root: &PathBuf, manifest: &Manifest, id: &str) -> anyhow::Result<()> {
+    let changes = find_change(workspace_root, manifest, id, Some("HEAD"))?;
+
+    // Reverting needs a clean index and worktree in every repo
+    let mut problems = Vec::new();
+    for change in &changes {
+        let status = get_cached_status(&change.repo.absolute_path)?;
+        if !status.staged.is_empty() || !status.modified.is_empty() {
+            problems.push(format!("{}: has uncommitted changes", change.repo.name));
+        }
+    }
+    if !problems.is_empty() {
+        anyhow::bail!("Cannot revert {}:\n  {}", id, problems.join("\n  "));
+    }
+
+    Output::header(&format!(
+        "Reverting change {} in {} repo(s)...",
+        id,
+        changes.len()
+    ));
+    println!();
+
+    let revert_id = (changes.len() > 1).then(|| generate_change_id(id));
+    let mut reverted: Vec<(&RepoInfo, String)> = Vec::new();
+    for change in &changes {
+        let repo = &change.repo;
+        let git_repo = open_repo(&repo.absolute_path)?;
+        let original_head = get_head_sha(&git_repo)?;
+
+        let shas: Vec<String> = change.commits.iter().map(|c| c.sha.clone()).collect();
+        let subject = &change.commits[0].subject;
+        let mut message = format!(
+            "Revert \"{}\"\n\nThis reverts change {} (commit {}).",
+            subject,
+            id,
+            shas.join(", ")
+        );
+        if let Some(revert_id) = &revert_id {
+            message = with_change_id(&message, revert_id);
+        }
+
+        let result = revert_no_commit(&repo.absolute_path, &shas)
+            .map_err(anyhow::Error::from)
+            .and_then(|_| create_commit(&git_repo, &message, false));
+        invalidate_status_cache(&repo.absolute_path);
+        match result {
+            Ok(sha) => {
+                Output::success(&format!("{}: reverted ({})", repo.name, &sha[..7]));
+                reverted.push((repo, original_head));
+            }
+            Err(e) => {
+                Output::error(&format!("{}: {}", repo.name, e));
+                let _ = reset_hard(&repo.absolute_path, &original_head);
+                for (repo, head) in &reverted {
+                    if let Err(e) = reset_hard(&repo.absolute_path, head) {
+                        Output::error(&format!("{}: failed to roll back - {}", repo.name, e));
+                    }
+                    invalidate_status_cache(&repo.absolute_path);
+                }
+                anyhow::bail!("Revert aborted; no repo was changed");
+            }
+        }
+    }
+
+    println!();
+    match revert_id {
+        Some(revert_id) => Output::success(&format!(
+            "Reverted {} in {} repo(s) as change {}",
+            id,
+            reverted.len(),
+            revert_id
+        )),
+        None => Output::success(&format!("Reverted {}", id)),
+    }
+    Ok(())
+}
@@ -3,10 +3,13 @@
 //! Provides `gr ci run`, `gr ci list`, and `gr ci status` for workspace CI/CD.
 
 use crate::cli::output::Output;
-use crate::core::manifest::Manifest;
+use crate::core::dag::{run_level, RepoDag};
+use crate::core::manifest::{CiStep, Manifest};
+use crate::core::repo::{filter_repos, RepoInfo};
+use crate::git::path_exists;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::Instant;
 
@@ -32,10 +35,14 @@ pub struct PipelineResult {
 }
 
 /// Run a CI pipeline
+///
+/// With `affected_only` (`--affected`), `manifest` only holds the affected
+/// repos, and steps that are not `per_repo` are skipped when none remain.
 pub fn run_ci_run(
     workspace_root: &PathBuf,
     manifest: &Manifest,
     pipeline_name: &str,
+    affected_only: bool,
     json: bool,
 ) -> anyhow::Result<()> {
     let ci_config = manifest
@@ -78,49 +85,68 @@ pub fn run_ci_run(
     let mut pipeline_success = true;
 
     for step in &pipeline.steps {
-        if !json {
-            let spinner = Output::spinner(&format!("Running: {}...", step.name));
+        if affected_only && !step.per_repo && manifest.repos.is_empty() {
+            if !json {
+                Output::info(&format!("{}: skipped (no affected repos)", step.name));
+            }
+            continue;
+        }
 
-            let result = run_step(workspace_root, step, manifest);
-            let step_result = result;
+        let spinner = (!json).then(|| Output::spinner(&format!("Running: {}...", step.name)));
 
-            if step_result.success {
-                spinner.finish_with_message(format!(
-                    "{}: passed ({}ms)",
-                    step.name, step_result.duration_ms
-                ));
-            } else {
-                spinner.finish_with_message(format!(
+        let results = if step.per_repo {
+            run_step_per_repo(workspace_root, step, manifest)?
+        } else {
+            let cwd = match &step.cwd {
+                Some(dir) => workspace_root.join(dir),
+                None => workspace_root.clone(),
+            };
+            vec![run_step(&step.name, step, manifest, &cwd, None)]
+        };
+        let failed: Vec<&StepResult> = results.iter().filter(|r| !r.success).collect();
+
+        if let Some(spinner) = spinner {
+            let duration_ms: u64 = results.iter().map(|r| r.duration_ms).sum();
+            match (failed.first(), step.per_repo) {
+                (None, false) => spinner
+                    .finish_with_message(format!("{}: passed ({}ms)", step.name, duration_ms)),
+                (None, true) => spinner.finish_with_message(format!(
+                    "{}: passed in {} repo(s) ({}ms)",
+                    step.name,
+                    results.len(),
+                    duration_ms
+                )),
+                (Some(result), false) => spinner.finish_with_message(format!(
                     "{}: FAILED (exit {})",
                     step.name,
-                    step_result.exit_code.unwrap_or(-1)
-                ));
-
-                if !step_result.output.is_empty() {
-                    eprintln!("{}", step_result.output);
-                }
+                    result.exit_code.unwrap_or(-1)
+                )),
+                (Some(_), true) => spinner.finish_with_message(format!(
+                    "{}: FAILED in {}",
+                    step.name,
+                    failed
+                        .iter()
+                        .map(|r| r.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            }
 
-                if !step.continue_on_error {
-                    pipeline_success = false;
-                    step_results.push(step_result);
-                    break;
+            for result in &failed {
+                if !result.output.is_empty() {
+                    eprintln!("{}", result.output);
                 }
-                pipeline_success = false;
             }
+        }
 
-            step_results.push(step_result);
-        } else {
-            let step_result = run_step(workspace_root, step, manifest);
-            let failed = !step_result.success;
-            step_results.push(step_result);
+        let step_failed = !failed.is_empty();
+        step_results.extend(results);
 
-            if failed && !step.continue_on_error {
-                pipeline_success = false;
+        if step_failed {
+            pipeline_success = false;
+            if !step.continue_on_error {
                 break;
             }
-            if failed {
-                pipeline_success = false;
-            }
         }
     }
 
@@ -274,22 +300,58 @@ pub fn run_ci_status(workspace_root: &PathBuf, json: bool) -> anyhow::Result<()>
     Ok(())
 }
 
-/// Run a single CI step
-fn run_step(
+/// Run a per-repo CI step in every non-reference repo, in dependency order.
+///
+/// Repos within a dependency level run in parallel. Unless the step has
+/// `continue_on_error`, a failure stops before the next level.
+fn run_step_per_repo(
     workspace_root: &PathBuf,
-    step: &crate::core::manifest::CiStep,
+    step: &CiStep,
     manifest: &Manifest,
+) -> anyhow::Result<Vec<StepResult>> {
+    let repos = filter_repos(manifest, workspace_root, None, None, false)
+        .into_iter()
+        .filter(|r| path_exists(&r.absolute_path))
+        .collect();
+
+    let mut results = Vec::new();
+    for level in RepoDag::from_manifest(manifest).levels(repos)? {
+        let level_results = run_level(&level, |repo| {
+            let cwd = match &step.cwd {
+                Some(dir) => repo.absolute_path.join(dir),
+                None => repo.absolute_path.clone(),
+            };
+            let name = format!("{} ({})", step.name, repo.name);
+            run_step(&name, step, manifest, &cwd, Some(repo))
+        });
+
+        let failed = level_results.iter().any(|r| !r.success);
+        results.extend(level_results);
+        if failed && !step.continue_on_error {
+            break;
+        }
+    }
+
+    Ok(results)
+}
+
+/// Run a single CI step command in `cwd`
+fn run_step(
+    name: &str,
+    step: &CiStep,
+    manifest: &Manifest,
+    cwd: &Path,
+    repo: Option<&RepoInfo>,
 ) -> StepResult {
     let start = Instant::now();
 
-    // Resolve working directory
-    let cwd = match &step.cwd {
-        Some(dir) => workspace_root.join(dir),
-        None => workspace_root.clone(),
-    };
-
     let mut cmd = Command::new("sh");
-    cmd.arg("-c").arg(&step.command).current_dir(&cwd);
+    cmd.arg("-c").arg(&step.command).current_dir(cwd);
+
+    if let Some(repo) = repo {
+        cmd.env("REPO_NAME", &repo.name)
+            .env("REPO_PATH", &repo.absolute_path);
+    }
 
     // Set workspace env vars
     if let Some(ref workspace) = manifest.workspace {
@@ -319,7 +381,7 @@ fn run_step(
             };
 
             StepResult {
-                name: step.name.clone(),
+                name: name.to_string(),
                 command: step.command.clone(),
                 success: output.status.success(),
                 exit_code: output.status.code(),
@@ -328,7 +390,7 @@ fn run_step(
             }
         }
         Err(e) => StepResult {
-            name: step.name.clone(),
+            name: name.to_string(),
             command: step.command.clone(),
             success: false,
             exit_code: None,
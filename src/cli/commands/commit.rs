@@ -5,6 +5,7 @@ use crate::core::manifest::Manifest;
 use crate::core::manifest_paths;
 use crate::core::repo::RepoInfo;
 use crate::git::cache::invalidate_status_cache;
+use crate::git::change::{change_id_of, generate_change_id, head_message, with_change_id};
 use crate::git::{get_workdir, open_repo, path_exists};
 use crate::util::log_cmd;
 use git2::Repository;
@@ -27,9 +28,30 @@ pub fn run_commit(
     let repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .collect();
 
+    // Link commits in more than one repo with a shared change ID
+    let manifest_dir = manifest_paths::resolve_manifest_repo_dir(workspace_root)
+        .filter(|dir| dir.join(".git").exists());
+    let staged_paths: Vec<&PathBuf> = repos
+        .iter()
+        .map(|r| &r.absolute_path)
+        .chain(manifest_dir.as_ref())
+        .filter(|path| {
+            path_exists(path)
+                && open_repo(path).is_ok_and(|r| has_staged_changes(&r).unwrap_or(false))
+        })
+        .collect();
+    let change_id = resolve_change_id(&staged_paths, message, amend);
+    let message = match &change_id {
+        Some(id) => with_change_id(message, id),
+        None => message.to_string(),
+    };
+    let message = message.as_str();
+
     let mut success_count = 0;
     let mut skip_count = 0;
 
@@ -143,10 +165,13 @@ pub fn run_commit(
             success: bool,
             committed: Vec<JsonCommit>,
             skipped: Vec<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            change_id: Option<String>,
         }
 
         let result = JsonCommitResult {
             success: !json_committed.is_empty(),
+            change_id: change_id.filter(|_| json_committed.len() > 1),
             committed: json_committed,
             skipped: json_skipped,
         };
@@ -163,6 +188,9 @@ pub fn run_commit(
                     String::new()
                 }
             );
+            if let Some(id) = change_id.filter(|_| success_count > 1) {
+                println!("Change ID: {}", id);
+            }
         } else {
             println!("No changes to commit.");
         }
@@ -171,6 +199,25 @@ pub fn run_commit(
     Ok(())
 }
 
+/// Change ID for a commit in the given repos
+///
+/// Amending keeps the ID already on any of the amended commits; otherwise a
+/// new ID is generated when more than one repo is committed.
+fn resolve_change_id(staged_paths: &[&PathBuf], message: &str, amend: bool) -> Option<String> {
+    if let Some(id) = change_id_of(message) {
+        return Some(id);
+    }
+    if amend {
+        let existing = staged_paths
+            .iter()
+            .find_map(|path| head_message(path).ok().as_deref().and_then(change_id_of));
+        if existing.is_some() {
+            return existing;
+        }
+    }
+    (staged_paths.len() > 1).then(|| generate_change_id(message))
+}
+
 /// Check if a repository has staged changes using git CLI
 fn has_staged_changes(repo: &Repository) -> anyhow::Result<bool> {
     let repo_path = get_workdir(repo);
@@ -187,7 +234,11 @@ fn has_staged_changes(repo: &Repository) -> anyhow::Result<bool> {
 }
 
 /// Create a commit in the repository using git CLI
-fn create_commit(repo: &Repository, message: &str, amend: bool) -> anyhow::Result<String> {
+pub(crate) fn create_commit(
+    repo: &Repository,
+    message: &str,
+    amend: bool,
+) -> anyhow::Result<String> {
     let repo_path = get_workdir(repo);
 
     let mut args = vec!["commit", "-m", message];
@@ -3,6 +3,7 @@
 use crate::cli::output::Output;
 use crate::core::manifest::Manifest;
 use crate::core::repo::{get_manifest_repo_info, RepoInfo};
+use crate::git::sparse::skip_worktree_paths;
 use crate::git::{open_repo, path_exists};
 use git2::{DiffOptions, Repository};
 use std::path::PathBuf;
@@ -17,7 +18,9 @@ pub fn run_diff(
     let mut repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .collect();
 
     // Include manifest repo in diff operations
@@ -118,7 +121,23 @@ fn get_diff(repo: &Repository, staged: bool) -> anyhow::Result<String> {
         repo.diff_index_to_workdir(None, Some(&mut opts))?
     };
 
-    diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| {
+    // libgit2 reports paths outside a sparse checkout as deleted
+    let skipped = if staged {
+        Default::default()
+    } else {
+        skip_worktree_paths(repo)
+    };
+
+    diff.print(git2::DiffFormat::Patch, |delta, _hunk, line| {
+        let skip = delta
+            .old_file()
+            .path()
+            .and_then(|p| p.to_str())
+            .is_some_and(|p| skipped.contains(p));
+        if skip {
+            return true;
+        }
+
         let prefix = match line.origin() {
             '+' => "+",
             '-' => "-",
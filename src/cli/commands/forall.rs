@@ -10,11 +10,17 @@
 //! - Direct git commands (git status, git branch, etc.)
 //! - Piped commands (git status | grep modified)
 //! - Redirected commands (git log > file.txt)
+//!
+//! Worktree queries (status, unstaged diff, `ls-files -m`) in sparse-checkout
+//! repos are passed to the git CLI: libgit2 would report every path outside
+//! the sparse checkout as deleted.
 
 use crate::cli::output::Output;
+use crate::core::dag::RepoDag;
 use crate::core::manifest::Manifest;
 use crate::core::repo::{filter_repos, RepoInfo};
 use crate::git::path_exists;
+use crate::git::sparse::{is_sparse, skip_worktree_paths};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
@@ -59,6 +65,18 @@ enum GitCommand {
     ConfigGet { key: String },
 }
 
+impl GitCommand {
+    /// Whether the command compares the worktree against the index
+    fn reads_worktree(&self) -> bool {
+        matches!(
+            self,
+            GitCommand::Status { .. }
+                | GitCommand::Diff { staged: false, .. }
+                | GitCommand::LsFiles { modified: true }
+        )
+    }
+}
+
 /// Parsed command with optional pipe or redirect
 #[derive(Debug)]
 enum ParsedCommand {
@@ -79,6 +97,18 @@ enum ParsedCommand {
     Shell(String),
 }
 
+impl ParsedCommand {
+    /// The intercepted git command, if any
+    fn git_command(&self) -> Option<&GitCommand> {
+        match self {
+            ParsedCommand::Git(git_cmd)
+            | ParsedCommand::Piped { git_cmd, .. }
+            | ParsedCommand::Redirect { git_cmd, .. } => Some(git_cmd),
+            ParsedCommand::Shell(_) => None,
+        }
+    }
+}
+
 /// Parse a command string, handling pipes and redirects
 fn parse_command(command: &str) -> ParsedCommand {
     let trimmed = command.trim();
@@ -754,6 +784,7 @@ fn execute_redirected_command(
 }
 
 /// Run the forall command
+#[allow(clippy::too_many_arguments)]
 pub fn run_forall(
     workspace_root: &PathBuf,
     manifest: &Manifest,
@@ -762,9 +793,18 @@ pub fn run_forall(
     changed_only: bool,
     no_intercept: bool,
     group_filter: Option<&[String]>,
+    ordered: bool,
 ) -> anyhow::Result<()> {
     let repos: Vec<RepoInfo> = filter_repos(manifest, workspace_root, None, group_filter, true);
 
+    // With --ordered, run dependencies first; each level only starts once the
+    // previous one has finished
+    let levels = if ordered {
+        RepoDag::from_manifest(manifest).levels(repos)?
+    } else {
+        vec![repos]
+    };
+
     // Parse the command (handles pipes, redirects, git interception)
     let parsed = if no_intercept {
         ParsedCommand::Shell(command.to_string())
@@ -773,9 +813,9 @@ pub fn run_forall(
     };
 
     if parallel {
-        run_parallel(&repos, command, changed_only, &parsed)?;
+        run_parallel(&levels, command, changed_only, &parsed)?;
     } else {
-        run_sequential(&repos, command, changed_only, &parsed)?;
+        run_sequential(&levels.concat(), command, changed_only, &parsed)?;
     }
 
     Ok(())
@@ -788,7 +828,13 @@ fn execute_parsed_command(
     parsed: &ParsedCommand,
     repo: &RepoInfo,
 ) -> Result<String, String> {
+    let sparse_fallback = parsed.git_command().is_some_and(GitCommand::reads_worktree)
+        && crate::git::open_repo(repo_path)
+            .map(|r| is_sparse(&r))
+            .unwrap_or(false);
+
     match parsed {
+        _ if sparse_fallback => execute_shell_command(repo_path, command, repo),
         ParsedCommand::Git(git_cmd) => execute_git_command(repo_path, git_cmd),
         ParsedCommand::Piped { git_cmd, pipe_to } => {
             execute_piped_command(repo_path, git_cmd, pipe_to)
@@ -798,32 +844,38 @@ fn execute_parsed_command(
             file,
             append,
         } => execute_redirected_command(repo_path, git_cmd, file, *append),
-        ParsedCommand::Shell(_) => {
-            // Run as shell command
-            let output = Command::new("sh")
-                .arg("-c")
-                .arg(command)
-                .current_dir(repo_path)
-                .env("REPO_NAME", &repo.name)
-                .env("REPO_PATH", repo_path)
-                .env("REPO_URL", &repo.url)
-                .env("REPO_BRANCH", &repo.default_branch)
-                .output()
-                .map_err(|e| e.to_string())?;
+        ParsedCommand::Shell(_) => execute_shell_command(repo_path, command, repo),
+    }
+}
 
-            if output.status.success() {
-                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-                let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-                Ok(format!("{}{}", stdout, stderr))
-            } else {
-                Err(format!(
-                    "Exit code: {:?}\n{}{}",
-                    output.status.code(),
-                    String::from_utf8_lossy(&output.stdout),
-                    String::from_utf8_lossy(&output.stderr)
-                ))
-            }
-        }
+/// Run a command through the shell
+fn execute_shell_command(
+    repo_path: &PathBuf,
+    command: &str,
+    repo: &RepoInfo,
+) -> Result<String, String> {
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .current_dir(repo_path)
+        .env("REPO_NAME", &repo.name)
+        .env("REPO_PATH", repo_path)
+        .env("REPO_URL", &repo.url)
+        .env("REPO_BRANCH", &repo.default_branch)
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        Ok(format!("{}{}", stdout, stderr))
+    } else {
+        Err(format!(
+            "Exit code: {:?}\n{}{}",
+            output.status.code(),
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        ))
     }
 }
 
@@ -925,11 +977,41 @@ impl From<&ParsedCommand> for CloneableParsedCommand {
 }
 
 fn run_parallel(
-    repos: &[RepoInfo],
+    levels: &[Vec<RepoInfo>],
     command: &str,
     changed_only: bool,
     parsed: &ParsedCommand,
 ) -> anyhow::Result<()> {
+    let mut success_count = 0;
+    let mut error_count = 0;
+
+    for level in levels {
+        let (succeeded, failed) = run_parallel_level(level, command, changed_only, parsed)?;
+        success_count += succeeded;
+        error_count += failed;
+    }
+
+    if error_count == 0 {
+        Output::success(&format!("Command completed in {} repo(s)", success_count));
+    } else {
+        Output::warning(&format!(
+            "{} succeeded, {} failed",
+            success_count, error_count
+        ));
+    }
+
+    Ok(())
+}
+
+/// Run the command concurrently in a set of repos, printing each result.
+///
+/// Returns the number of repos that succeeded and failed.
+fn run_parallel_level(
+    repos: &[RepoInfo],
+    command: &str,
+    changed_only: bool,
+    parsed: &ParsedCommand,
+) -> anyhow::Result<(usize, usize)> {
     use std::sync::{Arc, Mutex};
     use std::thread;
 
@@ -1033,16 +1115,7 @@ fn run_parallel(
         println!();
     }
 
-    if error_count == 0 {
-        Output::success(&format!("Command completed in {} repo(s)", success_count));
-    } else {
-        Output::warning(&format!(
-            "{} succeeded, {} failed",
-            success_count, error_count
-        ));
-    }
-
-    Ok(())
+    Ok((success_count, error_count))
 }
 
 /// Check if a repository has uncommitted changes
@@ -1050,7 +1123,11 @@ fn has_changes(repo_path: &PathBuf) -> anyhow::Result<bool> {
     match crate::git::open_repo(repo_path) {
         Ok(repo) => {
             let statuses = repo.statuses(None)?;
-            Ok(!statuses.is_empty())
+            // Paths outside a sparse checkout show up as deleted
+            let skipped = skip_worktree_paths(&repo);
+            Ok(statuses
+                .iter()
+                .any(|entry| entry.path().map_or(true, |p| !skipped.contains(p))))
         }
         Err(_) => Ok(false),
     }
@@ -1090,6 +1167,36 @@ mod tests {
         repo_path
     }
 
+    #[test]
+    fn test_sparse_repo_paths_not_reported_as_deleted() {
+        let temp = TempDir::new().unwrap();
+        let repo_path = setup_test_repo(&temp);
+        std::fs::create_dir_all(repo_path.join("docs")).unwrap();
+        std::fs::write(repo_path.join("docs/guide.md"), "# Guide").unwrap();
+        for args in [&["add", "docs"][..], &["commit", "-m", "Add docs"][..]] {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(&repo_path)
+                .output()
+                .unwrap();
+            assert!(output.status.success());
+        }
+
+        let repo = Repository::open(&repo_path).unwrap();
+        crate::git::sparse::set_sparse_checkout(&repo, &["src".to_string()]).unwrap();
+        assert!(!repo_path.join("docs").exists());
+        assert!(!has_changes(&repo_path).unwrap());
+
+        let config: crate::core::manifest::RepoConfig =
+            serde_yaml::from_str("url: git@github.com:org/repo.git\npath: repo\n").unwrap();
+        let info =
+            RepoInfo::from_config("repo", &config, &temp.path().to_path_buf(), None).unwrap();
+        let command = "git status --porcelain";
+        let output =
+            execute_parsed_command(&repo_path, command, &parse_command(command), &info).unwrap();
+        assert_eq!(output, "");
+    }
+
     #[test]
     fn test_has_changes_clean_repo() {
         let temp = TempDir::new().unwrap();
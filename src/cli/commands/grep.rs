@@ -1,12 +1,14 @@
 //! Grep command implementation
 //!
 //! Runs `git grep` across all repositories, aggregating results
-//! with repo-name prefixes for easy identification.
+//! with repo-name prefixes for easy identification. In sparse-checkout
+//! repos only the checked-out directories are searched.
 
 use crate::cli::output::Output;
 use crate::core::manifest::Manifest;
 use crate::core::repo::{filter_repos, RepoInfo};
-use crate::git::path_exists;
+use crate::git::sparse::is_sparse;
+use crate::git::{open_repo, path_exists};
 use crate::util::log_cmd;
 use std::path::PathBuf;
 use std::process::Command;
@@ -29,6 +31,19 @@ pub fn run_grep(
         run_grep_sequential(&repos, pattern, ignore_case, pathspec)?;
     }
 
+    let sparse: Vec<&str> = repos
+        .iter()
+        .filter(|repo| path_exists(&repo.absolute_path))
+        .filter(|repo| open_repo(&repo.absolute_path).is_ok_and(|r| is_sparse(&r)))
+        .map(|repo| repo.name.as_str())
+        .collect();
+    if !sparse.is_empty() {
+        Output::info(&format!(
+            "Searched only the sparse checkout of: {}",
+            sparse.join(", ")
+        ));
+    }
+
     Ok(())
 }
 
@@ -4,9 +4,9 @@
 
 use crate::cli::output::Output;
 use crate::core::manifest::Manifest;
+use crate::core::manifest_editor::ManifestEditor;
 use crate::core::manifest_paths;
 use crate::core::repo::RepoInfo;
-use serde_yaml::{self, Value};
 use std::collections::BTreeMap;
 use std::path::PathBuf;
 
@@ -18,7 +18,9 @@ pub fn run_group_list(workspace_root: &PathBuf, manifest: &Manifest) -> anyhow::
     let repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .collect();
 
     // Collect groups -> repos mapping
@@ -76,16 +78,7 @@ pub fn run_group_add(
     repos: &[String],
 ) -> anyhow::Result<()> {
     let manifest_path = find_manifest_path(workspace_root)?;
-
-    // Load the raw YAML to preserve formatting
-    let content = std::fs::read_to_string(&manifest_path)?;
-    let mut manifest: Value = serde_yaml::from_str(&content)?;
-
-    let repos_section = manifest
-        .get_mut("repos")
-        .ok_or_else(|| anyhow::anyhow!("No 'repos' section found in manifest"))?
-        .as_mapping_mut()
-        .ok_or_else(|| anyhow::anyhow!("'repos' is not a mapping"))?;
+    let mut editor = ManifestEditor::load(&manifest_path)?;
 
     // Load the fully resolved manifest to check for repos from gripspaces
     let resolved_manifest = Manifest::load(&manifest_path)?;
@@ -105,44 +98,18 @@ pub fn run_group_add(
             continue;
         }
 
-        // Check if repo exists in the local manifest section
-        let repo_key = Value::String(repo_name.clone());
-        let repo_entry = repos_section.entry(repo_key.clone()).or_insert_with(|| {
-            // Repo exists in gripspace but not locally - create a minimal local entry
-            // with just the groups field
-            let mut new_repo = serde_yaml::Mapping::new();
-            new_repo.insert(Value::String("groups".to_string()), Value::Sequence(vec![]));
-            Value::Mapping(new_repo)
-        });
-
-        let repo_map = repo_entry
-            .as_mapping_mut()
-            .ok_or_else(|| anyhow::anyhow!("Repository '{}' is not a mapping", repo_name))?;
-
-        // Get or create groups array
-        let groups_key = Value::String("groups".to_string());
-        let groups = repo_map
-            .entry(groups_key.clone())
-            .or_insert_with(|| Value::Sequence(vec![]));
-
-        let groups_seq = groups
-            .as_sequence_mut()
-            .ok_or_else(|| anyhow::anyhow!("'groups' is not an array in '{}'", repo_name))?;
-
-        let group_value = Value::String(group.to_string());
-        if groups_seq.contains(&group_value) {
-            Output::info(&format!("{}: already in group '{}'", repo_name, group));
-            already_count += 1;
-        } else {
-            groups_seq.push(group_value);
+        // Repos that only exist in a gripspace get a minimal local entry
+        // holding just the groups field
+        if editor.add_to_list(&["repos", repo_name, "groups"], group)? {
             Output::success(&format!("{}: added to group '{}'", repo_name, group));
             added_count += 1;
+        } else {
+            Output::info(&format!("{}: already in group '{}'", repo_name, group));
+            already_count += 1;
         }
     }
 
-    // Write back
-    let yaml = serde_yaml::to_string(&manifest)?;
-    std::fs::write(&manifest_path, &yaml)?;
+    let yaml = editor.save(&manifest_path)?;
     manifest_paths::sync_legacy_mirror_if_present(workspace_root, &manifest_path, &yaml)?;
 
     println!();
@@ -175,16 +142,7 @@ pub fn run_group_remove(
     repos: &[String],
 ) -> anyhow::Result<()> {
     let manifest_path = find_manifest_path(workspace_root)?;
-
-    // Load the raw YAML to preserve formatting
-    let content = std::fs::read_to_string(&manifest_path)?;
-    let mut manifest: Value = serde_yaml::from_str(&content)?;
-
-    let repos_section = manifest
-        .get_mut("repos")
-        .ok_or_else(|| anyhow::anyhow!("No 'repos' section found in manifest"))?
-        .as_mapping_mut()
-        .ok_or_else(|| anyhow::anyhow!("'repos' is not a mapping"))?;
+    let mut editor = ManifestEditor::load(&manifest_path)?;
 
     // Load the fully resolved manifest to check for repos from gripspaces
     let resolved_manifest = Manifest::load(&manifest_path)?;
@@ -204,52 +162,27 @@ pub fn run_group_remove(
             continue;
         }
 
-        let repo_key = Value::String(repo_name.clone());
-        if let Some(repo) = repos_section.get_mut(&repo_key) {
-            let repo_map = repo
-                .as_mapping_mut()
-                .ok_or_else(|| anyhow::anyhow!("Repository '{}' is not a mapping", repo_name))?;
-
-            let groups_key = Value::String("groups".to_string());
-            if let Some(groups) = repo_map.get_mut(&groups_key) {
-                if let Some(groups_seq) = groups.as_sequence_mut() {
-                    let group_value = Value::String(group.to_string());
-                    let original_len = groups_seq.len();
-                    groups_seq.retain(|v| v != &group_value);
-
-                    if groups_seq.len() < original_len {
-                        Output::success(&format!("{}: removed from group '{}'", repo_name, group));
-                        removed_count += 1;
-
-                        // Remove empty groups array
-                        if groups_seq.is_empty() {
-                            repo_map.remove(&groups_key);
-                        }
-                    } else {
-                        Output::info(&format!("{}: not in group '{}'", repo_name, group));
-                        not_in_count += 1;
-                    }
-                } else {
-                    Output::info(&format!("{}: not in group '{}'", repo_name, group));
-                    not_in_count += 1;
-                }
-            } else {
-                Output::info(&format!("{}: has no groups", repo_name));
-                not_in_count += 1;
-            }
-        } else {
+        if !editor.contains(&["repos", repo_name]) {
             // Repo exists in gripspace but not locally - can't remove from local manifest
             Output::info(&format!(
                 "{}: not in local manifest (from gripspace) - group membership managed in source gripspace",
                 repo_name
             ));
             not_in_count += 1;
+        } else if !editor.contains(&["repos", repo_name, "groups"]) {
+            Output::info(&format!("{}: has no groups", repo_name));
+            not_in_count += 1;
+        } else if editor.remove_from_list(&["repos", repo_name, "groups"], group)? {
+            // An emptied groups list is removed along with the last entry
+            Output::success(&format!("{}: removed from group '{}'", repo_name, group));
+            removed_count += 1;
+        } else {
+            Output::info(&format!("{}: not in group '{}'", repo_name, group));
+            not_in_count += 1;
         }
     }
 
-    // Write back
-    let yaml = serde_yaml::to_string(&manifest)?;
-    std::fs::write(&manifest_path, &yaml)?;
+    let yaml = editor.save(&manifest_path)?;
     manifest_paths::sync_legacy_mirror_if_present(workspace_root, &manifest_path, &yaml)?;
 
     println!();
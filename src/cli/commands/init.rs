@@ -634,14 +634,21 @@ fn generate_manifest(repos: &[DiscoveredRepo]) -> Manifest {
             repo.name.clone(),
             RepoConfig {
                 url,
+                remote: None,
+                name: None,
                 path: repo.path.clone(),
                 default_branch: repo.default_branch.clone(),
+                revision: None,
                 copyfile: None,
                 linkfile: None,
                 platform: None,
                 reference: false,
                 groups: Vec::new(),
                 agent: None,
+                depends_on: Vec::new(),
+                clone: None,
+                sparse: Vec::new(),
+                pr: None,
             },
         );
     }
@@ -649,6 +656,7 @@ fn generate_manifest(repos: &[DiscoveredRepo]) -> Manifest {
     Manifest {
         version: 1,
         gripspaces: None,
+        remotes: None,
         manifest: None,
         repos: repo_configs,
         settings: Default::default(),
@@ -934,6 +942,8 @@ pub fn suggest_manifest_url(platform: PlatformType, owner: &str, name: &str) ->
             format!("git@ssh.dev.azure.com:v3/{}/{}.git", owner, name)
         }
         PlatformType::Bitbucket => format!("git@bitbucket.org:{}/{}.git", owner, name),
+        PlatformType::Gitea => format!("git@codeberg.org:{}/{}.git", owner, name),
+        PlatformType::Local => format!("file://{}/{}.git", owner, name),
     }
 }
 
@@ -957,6 +967,8 @@ pub fn suggest_manifest_https_url(platform: PlatformType, owner: &str, name: &st
             }
         }
         PlatformType::Bitbucket => format!("https://bitbucket.org/{}/{}.git", owner, name),
+        PlatformType::Gitea => format!("https://codeberg.org/{}/{}.git", owner, name),
+        PlatformType::Local => format!("file://{}/{}.git", owner, name),
     }
 }
 
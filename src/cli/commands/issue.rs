@@ -0,0 +1,450 @@
+//! Issue command implementation
+//!
+//! Creates, lists and updates issues on each repo's hosting platform. A
+//! tracking issue is one issue per selected repo, each body listing the
+//! others and carrying a hidden linked-issues comment so `--linked` can
+//! comment on or close the whole set.
+
+use crate::cli::output::Output;
+use crate::core::manifest::Manifest;
+use crate::core::repo::{filter_repos, get_manifest_repo_info, RepoInfo};
+use crate::platform::traits::LinkedIssueRef;
+use crate::platform::{get_platform_adapter, Issue, IssueState};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Issue with the repo it belongs to, for JSON output
+#[derive(Serialize)]
+struct RepoIssueJson<'a> {
+    repo: &'a str,
+    #[serde(flatten)]
+    issue: &'a Issue,
+}
+
+/// Repos selected by `--repo`/`--group` (the manifest repo is `manifest`)
+fn select_repos(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repos: Option<&[String]>,
+    groups: Option<&[String]>,
+) -> anyhow::Result<Vec<RepoInfo>> {
+    let mut selected = filter_repos(manifest, workspace_root, repos, groups, repos.is_some());
+    // Stable order, so a tracking issue lists the same set the same way everywhere
+    selected.sort_by(|a, b| a.name.cmp(&b.name));
+    if let Some(names) = repos {
+        if names.iter().any(|n| n == "manifest") {
+            selected.extend(get_manifest_repo_info(manifest, workspace_root));
+        }
+        for name in names {
+            if !selected.iter().any(|r| &r.name == name) {
+                anyhow::bail!("Unknown repo: {}", name);
+            }
+        }
+    }
+    Ok(selected)
+}
+
+/// The single repo named by `--repo`
+fn find_repo(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    name: &str,
+) -> anyhow::Result<RepoInfo> {
+    let names = [name.to_string()];
+    let mut repos = select_repos(workspace_root, manifest, Some(&names), None)?;
+    Ok(repos.remove(0))
+}
+
+/// Run `gr issue create`
+#[allow(clippy::too_many_arguments)]
+pub async fn run_issue_create(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    title: &str,
+    body: Option<&str>,
+    labels: &[String],
+    repos: Option<&[String]>,
+    groups: Option<&[String]>,
+    tracking: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    if repos.is_none() && groups.is_none() {
+        anyhow::bail!("Specify the repos to open issues in with --repo or --group");
+    }
+    let selected = select_repos(workspace_root, manifest, repos, groups)?;
+    if selected.is_empty() {
+        anyhow::bail!("No repos match the given --repo/--group");
+    }
+
+    let mut created: Vec<(RepoInfo, u64, String)> = Vec::new();
+    let mut failed = 0;
+    for repo in &selected {
+        let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+        match platform
+            .create_issue(&repo.owner, &repo.repo, title, body, labels)
+            .await
+        {
+            Ok(issue) => {
+                if !json {
+                    Output::success(&format!(
+                        "{}: created issue #{} - {}",
+                        repo.name, issue.number, issue.url
+                    ));
+                }
+                created.push((repo.clone(), issue.number, issue.url));
+            }
+            Err(e) => {
+                failed += 1;
+                Output::error(&format!("{}: {}", repo.name, e));
+            }
+        }
+    }
+
+    if tracking && created.len() > 1 {
+        link_issues(&created, body).await;
+    }
+
+    if json {
+        #[derive(Serialize)]
+        struct CreatedIssueJson<'a> {
+            repo: &'a str,
+            number: u64,
+            url: &'a str,
+        }
+        let issues: Vec<CreatedIssueJson> = created
+            .iter()
+            .map(|(repo, number, url)| CreatedIssueJson {
+                repo: &repo.name,
+                number: *number,
+                url,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&issues)?);
+    }
+
+    if failed > 0 {
+        anyhow::bail!("Failed to create issues in {} repo(s)", failed);
+    }
+    Ok(())
+}
+
+/// Rewrite every issue of a tracking set to list and link the others
+async fn link_issues(created: &[(RepoInfo, u64, String)], body: Option<&str>) {
+    let links: Vec<LinkedIssueRef> = created
+        .iter()
+        .map(|(repo, number, _)| LinkedIssueRef {
+            repo_name: repo.name.clone(),
+            number: *number,
+        })
+        .collect();
+
+    let mut list = format!("Tracking issue across {} repos:\n", created.len());
+    for (repo, number, url) in created {
+        list.push_str(&format!("- {}#{}: {}\n", repo.name, number, url));
+    }
+
+    for (repo, number, _) in created {
+        let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+        let mut linked_body = match body {
+            Some(body) if !body.is_empty() => format!("{}\n\n---\n", body),
+            _ => String::new(),
+        };
+        linked_body.push_str(&list);
+        linked_body.push('\n');
+        linked_body.push_str(&platform.generate_linked_issue_comment(&links));
+
+        if let Err(e) = platform
+            .update_issue(&repo.owner, &repo.repo, *number, None, Some(&linked_body))
+            .await
+        {
+            Output::warning(&format!(
+                "{}: could not link issue #{}: {}",
+                repo.name, number, e
+            ));
+        }
+    }
+}
+
+/// Run `gr issue list`
+pub async fn run_issue_list(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    state: IssueState,
+    labels: &[String],
+    repos: Option<&[String]>,
+    groups: Option<&[String]>,
+    json: bool,
+) -> anyhow::Result<()> {
+    let selected = select_repos(workspace_root, manifest, repos, groups)?;
+
+    let mut all: Vec<(String, Issue)> = Vec::new();
+    for repo in &selected {
+        let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+        match platform
+            .list_issues(&repo.owner, &repo.repo, state, labels)
+            .await
+        {
+            Ok(issues) => {
+                all.extend(issues.into_iter().map(|issue| (repo.name.clone(), issue)));
+            }
+            Err(e) => Output::warning(&format!("{}: {}", repo.name, e)),
+        }
+    }
+
+    if json {
+        let issues: Vec<RepoIssueJson> = all
+            .iter()
+            .map(|(repo, issue)| RepoIssueJson { repo, issue })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&issues)?);
+        return Ok(());
+    }
+
+    if all.is_empty() {
+        println!("No {} issues.", state);
+        return Ok(());
+    }
+    Output::header(&format!("{} {} issue(s)", all.len(), state));
+    let mut current_repo = None;
+    for (repo, issue) in &all {
+        if current_repo != Some(repo) {
+            println!();
+            println!("{}", repo);
+            current_repo = Some(repo);
+        }
+        let labels = if issue.labels.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", issue.labels.join(", "))
+        };
+        println!("  #{:<6} {}{}", issue.number, issue.title, labels);
+    }
+    Ok(())
+}
+
+/// Run `gr issue view`
+pub async fn run_issue_view(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repo_name: &str,
+    number: u64,
+    json: bool,
+) -> anyhow::Result<()> {
+    let repo = find_repo(workspace_root, manifest, repo_name)?;
+    let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+    let issue = platform.get_issue(&repo.owner, &repo.repo, number).await?;
+
+    if json {
+        let issue = RepoIssueJson {
+            repo: &repo.name,
+            issue: &issue,
+        };
+        println!("{}", serde_json::to_string_pretty(&issue)?);
+        return Ok(());
+    }
+
+    Output::header(&format!("{}#{}: {}", repo.name, issue.number, issue.title));
+    Output::kv("State", &issue.state.to_string());
+    if !issue.labels.is_empty() {
+        Output::kv("Labels", &issue.labels.join(", "));
+    }
+    Output::kv("URL", &issue.url);
+    let linked = platform.parse_linked_issue_comment(&issue.body);
+    if !linked.is_empty() {
+        let refs: Vec<String> = linked
+            .iter()
+            .map(|l| format!("{}#{}", l.repo_name, l.number))
+            .collect();
+        Output::kv("Linked", &refs.join(", "));
+    }
+    if !issue.body.is_empty() {
+        println!();
+        println!("{}", issue.body);
+    }
+    Ok(())
+}
+
+/// What to do to an issue (and, with `--linked`, to its tracking set)
+enum IssueAction<'a> {
+    Comment(&'a str),
+    Close(Option<&'a str>),
+}
+
+/// Run `gr issue comment`
+pub async fn run_issue_comment(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repo_name: &str,
+    number: u64,
+    message: &str,
+    linked: bool,
+) -> anyhow::Result<()> {
+    apply_to_issues(
+        workspace_root,
+        manifest,
+        repo_name,
+        number,
+        linked,
+        IssueAction::Comment(message),
+    )
+    .await
+}
+
+/// Run `gr issue close`
+pub async fn run_issue_close(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repo_name: &str,
+    number: u64,
+    message: Option<&str>,
+    linked: bool,
+) -> anyhow::Result<()> {
+    apply_to_issues(
+        workspace_root,
+        manifest,
+        repo_name,
+        number,
+        linked,
+        IssueAction::Close(message),
+    )
+    .await
+}
+
+async fn apply_to_issues(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repo_name: &str,
+    number: u64,
+    linked: bool,
+    action: IssueAction<'_>,
+) -> anyhow::Result<()> {
+    let repo = find_repo(workspace_root, manifest, repo_name)?;
+    let mut targets = vec![(repo, number)];
+
+    if linked {
+        let (repo, _) = &targets[0];
+        let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+        let issue = platform.get_issue(&repo.owner, &repo.repo, number).await?;
+        let links = platform.parse_linked_issue_comment(&issue.body);
+        if links.is_empty() {
+            Output::warning(&format!(
+                "{}#{} is not part of a tracking issue",
+                repo_name, number
+            ));
+        }
+        for link in links {
+            if link.repo_name == repo_name {
+                continue;
+            }
+            match find_repo(workspace_root, manifest, &link.repo_name) {
+                Ok(repo) => targets.push((repo, link.number)),
+                Err(_) => Output::warning(&format!(
+                    "{}#{}: repo not in manifest, skipping",
+                    link.repo_name, link.number
+                )),
+            }
+        }
+    }
+
+    let mut failed = 0;
+    for (repo, number) in &targets {
+        let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+        let result = match action {
+            IssueAction::Comment(message) => platform
+                .create_issue_comment(&repo.owner, &repo.repo, *number, message)
+                .await
+                .map(|()| "commented on"),
+            IssueAction::Close(message) => {
+                let commented = match message {
+                    Some(message) => {
+                        platform
+                            .create_issue_comment(&repo.owner, &repo.repo, *number, message)
+                            .await
+                    }
+                    None => Ok(()),
+                };
+                match commented {
+                    Ok(()) => platform
+                        .close_issue(&repo.owner, &repo.repo, *number)
+                        .await
+                        .map(|()| "closed"),
+                    Err(e) => Err(e),
+                }
+            }
+        };
+        match result {
+            Ok(verb) => Output::success(&format!("{}: {} issue #{}", repo.name, verb, number)),
+            Err(e) => {
+                failed += 1;
+                Output::error(&format!("{}#{}: {}", repo.name, number, e));
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("Failed to update {} issue(s)", failed);
+    }
+    Ok(())
+}
+
+/// Run `gr issue edit`
+pub async fn run_issue_edit(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repo_name: &str,
+    number: u64,
+    title: Option<&str>,
+    body: Option<&str>,
+) -> anyhow::Result<()> {
+    if title.is_none() && body.is_none() {
+        anyhow::bail!("Nothing to edit (pass --title and/or --body)");
+    }
+    let repo = find_repo(workspace_root, manifest, repo_name)?;
+    let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+
+    // Keep the tracking links when replacing the body
+    let body = match body {
+        Some(body) => {
+            let issue = platform.get_issue(&repo.owner, &repo.repo, number).await?;
+            let links = platform.parse_linked_issue_comment(&issue.body);
+            if links.is_empty() {
+                Some(body.to_string())
+            } else {
+                Some(format!(
+                    "{}\n\n{}",
+                    body,
+                    platform.generate_linked_issue_comment(&links)
+                ))
+            }
+        }
+        None => None,
+    };
+
+    platform
+        .update_issue(&repo.owner, &repo.repo, number, title, body.as_deref())
+        .await?;
+    Output::success(&format!("{}: updated issue #{}", repo.name, number));
+    Ok(())
+}
+
+/// Run `gr issue label`
+pub async fn run_issue_label(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repo_name: &str,
+    number: u64,
+    labels: &[String],
+) -> anyhow::Result<()> {
+    let repo = find_repo(workspace_root, manifest, repo_name)?;
+    let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+    platform
+        .add_issue_labels(&repo.owner, &repo.repo, number, labels)
+        .await?;
+    Output::success(&format!(
+        "{}: labelled issue #{} with {}",
+        repo.name,
+        number,
+        labels.join(", ")
+    ));
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//! Log command implementation
+//!
+//! Merges the commit history of every repo into one timeline, newest first.
+
+use crate::cli::output::Output;
+use crate::core::manifest::Manifest;
+use crate::core::repo::{filter_repos, get_manifest_repo_info, RepoInfo};
+use crate::git::branch::get_commits_between;
+use crate::git::log::{get_log, get_log_of, LogEntry, LogFilter};
+use crate::git::{get_current_branch, open_repo, path_exists};
+use chrono::{DateTime, Local, Utc};
+use colored::Colorize;
+use git2::Repository;
+use std::path::PathBuf;
+
+/// Options for `gr log`
+#[derive(Debug, Clone, Default)]
+pub struct LogOptions<'a> {
+    /// `git log` filters applied in every repo
+    pub filter: LogFilter<'a>,
+    /// Only commits on the current branch that are not on the repo's default branch
+    pub branch_only: bool,
+}
+
+/// JSON-serializable commit for --json output
+#[derive(serde::Serialize)]
+struct JsonLogEntry {
+    repo: String,
+    sha: String,
+    author: String,
+    email: String,
+    date: String,
+    subject: String,
+}
+
+/// Run the log command
+pub fn run_log(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    options: &LogOptions,
+    repos_filter: Option<&[String]>,
+    group_filter: Option<&[String]>,
+    json: bool,
+) -> anyhow::Result<()> {
+    let mut repos = filter_repos(manifest, workspace_root, repos_filter, group_filter, true);
+    if repos_filter.is_none() && group_filter.is_none() {
+        if let Some(manifest_repo) = get_manifest_repo_info(manifest, workspace_root) {
+            repos.push(manifest_repo);
+        }
+    }
+
+    let mut entries: Vec<(String, LogEntry)> = Vec::new();
+    for repo in &repos {
+        if !path_exists(&repo.absolute_path) {
+            continue;
+        }
+        match repo_log(repo, options) {
+            Ok(log) => entries.extend(log.into_iter().map(|e| (repo.name.clone(), e))),
+            Err(e) => Output::warning(&format!("{}: {}", repo.name, e)),
+        }
+    }
+
+    // Stable sort keeps each repo's own order for commits with the same date
+    entries.sort_by_key(|(_, e)| std::cmp::Reverse(e.timestamp));
+    if let Some(max) = options.filter.max_count {
+        entries.truncate(max);
+    }
+
+    if json {
+        let json_entries: Vec<JsonLogEntry> = entries
+            .into_iter()
+            .map(|(repo, e)| JsonLogEntry {
+                repo,
+                date: DateTime::<Utc>::from_timestamp(e.timestamp, 0)
+                    .map(|d| d.to_rfc3339())
+                    .unwrap_or_default(),
+                sha: e.sha,
+                author: e.author,
+                email: e.email,
+                subject: e.subject,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&json_entries)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("No commits.");
+        return Ok(());
+    }
+
+    let width = entries.iter().map(|(r, _)| r.len()).max().unwrap_or(0);
+    for (repo, entry) in &entries {
+        let date = DateTime::<Utc>::from_timestamp(entry.timestamp, 0)
+            .map(|d| d.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+        println!(
+            "{} {} {} {} {}",
+            entry.sha[..entry.sha.len().min(8)].yellow(),
+            date.dimmed(),
+            Output::repo_name(&format!("{:<width$}", repo)),
+            entry.subject,
+            format!("({})", entry.author).dimmed()
+        );
+    }
+    Ok(())
+}
+
+fn repo_log(repo: &RepoInfo, options: &LogOptions) -> anyhow::Result<Vec<LogEntry>> {
+    let git_repo = open_repo(&repo.absolute_path)?;
+    if !options.branch_only {
+        return Ok(get_log(&git_repo, None, &options.filter)?);
+    }
+
+    if get_current_branch(&git_repo)? == repo.default_branch {
+        return Ok(Vec::new());
+    }
+    let Some(base) = default_branch_ref(&git_repo, &repo.default_branch) else {
+        return Ok(Vec::new());
+    };
+    let shas = get_commits_between(&git_repo, &base, None)?;
+    Ok(get_log_of(&git_repo, &shas, &options.filter)?)
+}
+
+/// `origin/<default>`, else the local default branch
+fn default_branch_ref(repo: &Repository, default_branch: &str) -> Option<String> {
+    [
+        format!("refs/remotes/origin/{}", default_branch),
+        format!("refs/heads/{}", default_branch),
+    ]
+    .into_iter()
+    .find(|r| repo.find_reference(r).is_ok())
+}
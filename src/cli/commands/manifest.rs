@@ -1,36 +1,87 @@
-//! Manifest operations (import, sync)
+//! Manifest operations (import, export, sync, lock, diff, fmt)
 //!
-//! Handles conversion between git-repo XML manifests and gitgrip YAML manifests.
+//! Handles conversion between gitgrip YAML manifests and other workspace formats
+//! (git-repo XML, `.gitmodules`, vcstool, meta),
+//! pinning the workspace to exact commits via `gripspace.lock`, and comparing
+//! manifest revisions.
 
 use crate::cli::output::Output;
+use crate::core::gripspace::list_gripspaces;
+use crate::core::lockfile::{lockfile_path, LockedGripspace, LockedRepo, Lockfile};
+use crate::core::manifest::Manifest;
+use crate::core::manifest_diff::ManifestDiff;
+use crate::core::manifest_editor::format_manifest;
+use crate::core::manifest_paths;
+use crate::core::repo::RepoInfo;
 use crate::core::repo_manifest::XmlManifest;
+use crate::core::workspace_import::{import_workspace, ImportFormat};
+use crate::git::{get_head_sha, open_repo, path_exists};
 use std::path::Path;
 
-/// Import a git-repo XML manifest and convert to gitgrip YAML
+/// Import a workspace file and convert to gitgrip YAML.
+///
+/// The format (git-repo XML, `.gitmodules`, vcstool `.repos` or meta `.meta`)
+/// is detected from the file name, falling back to the content.
 pub fn run_manifest_import(path: &str, output_path: Option<&str>) -> anyhow::Result<()> {
-    let xml_path = Path::new(path);
-    if !xml_path.exists() {
-        anyhow::bail!("XML manifest not found: {}", path);
+    let source_path = Path::new(path);
+    if !source_path.exists() {
+        anyhow::bail!("Workspace file not found: {}", path);
     }
 
-    Output::header("Importing git-repo manifest...");
+    let content = std::fs::read_to_string(source_path)?;
+    let format = ImportFormat::detect(source_path, &content).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Could not detect the format of {}. Supported: git-repo XML, .gitmodules, vcstool .repos, meta .meta",
+            path
+        )
+    })?;
+
+    Output::header(&format!("Importing {} manifest...", format));
     println!();
 
-    let xml_manifest = XmlManifest::parse_file(xml_path)?;
-    let result = xml_manifest.to_manifest()?;
+    let manifest = if format == ImportFormat::RepoXml {
+        let xml_manifest = XmlManifest::parse_file(source_path)?;
+        let result = xml_manifest.to_manifest()?;
 
-    // Print summary
-    Output::info(&format!(
-        "{} total projects, {} Gerrit (skipped), {} non-Gerrit (imported)",
-        result.total_projects, result.gerrit_skipped, result.non_gerrit_imported
-    ));
-
-    for (platform, count) in &result.platform_counts {
-        Output::info(&format!("  {}: {} repos", platform, count));
-    }
+        Output::info(&format!(
+            "{} total projects, {} Gerrit (skipped), {} non-Gerrit (imported)",
+            result.total_projects, result.gerrit_skipped, result.non_gerrit_imported
+        ));
+        for (platform, count) in &result.platform_counts {
+            Output::info(&format!("  {}: {} repos", platform, count));
+        }
+        result.manifest
+    } else {
+        let result = import_workspace(source_path, &content, format)?;
+
+        Output::info(&format!(
+            "{} total entries, {} imported, {} skipped",
+            result.total_entries,
+            result.imported,
+            result.skipped.len()
+        ));
+        for (platform, count) in &result.platform_counts {
+            Output::info(&format!("  {}: {} repos", platform, count));
+        }
+        for (name, reason) in &result.skipped {
+            Output::warning(&format!("Skipped {}: {}", name, reason));
+        }
+        if !result.unsupported_fields.is_empty() {
+            let fields: Vec<&str> = result
+                .unsupported_fields
+                .iter()
+                .map(String::as_str)
+                .collect();
+            Output::warning(&format!(
+                "Unsupported fields ignored: {}",
+                fields.join(", ")
+            ));
+        }
+        result.manifest
+    };
 
     // Serialize to YAML
-    let yaml = serde_yaml::to_string(&result.manifest)?;
+    let yaml = serde_yaml::to_string(&manifest)?;
 
     // Write output
     let dest = output_path.unwrap_or("gripspace.yml");
@@ -42,6 +93,47 @@ pub fn run_manifest_import(path: &str, output_path: Option<&str>) -> anyhow::Res
     Ok(())
 }
 
+/// Export the workspace manifest in another format (currently git-repo XML)
+///
+/// Pins are looked up in the local clones to tell tags from branches; pins of
+/// repos that are not cloned are exported as they are.
+pub fn run_manifest_export(
+    workspace_root: &Path,
+    manifest: &Manifest,
+    format: &str,
+    output_path: Option<&str>,
+) -> anyhow::Result<()> {
+    let workspace_root = workspace_root.to_path_buf();
+    let is_tag = |key: &str, pin: &str| {
+        manifest
+            .repos
+            .get(key)
+            .and_then(|config| {
+                RepoInfo::from_config(key, config, &workspace_root, manifest.remotes.as_ref())
+            })
+            .and_then(|repo| open_repo(&repo.absolute_path).ok())
+            .is_some_and(|repo| repo.find_reference(&format!("refs/tags/{}", pin)).is_ok())
+    };
+    let content = match format {
+        "repo-xml" | "xml" => XmlManifest::from_manifest(manifest, is_tag).to_xml(),
+        _ => anyhow::bail!("Unknown format: {}. Use repo-xml.", format),
+    };
+
+    match output_path {
+        Some(dest) => {
+            std::fs::write(dest, &content)?;
+            Output::success(&format!(
+                "Exported {} repo(s): {}",
+                manifest.repos.len(),
+                dest
+            ));
+        }
+        None => print!("{}", content),
+    }
+
+    Ok(())
+}
+
 /// Re-sync gitgrip YAML from .repo/ manifest XML
 pub fn run_manifest_sync(workspace_root: &std::path::PathBuf) -> anyhow::Result<()> {
     // Find the XML manifest
@@ -76,6 +168,283 @@ pub fn run_manifest_sync(workspace_root: &std::path::PathBuf) -> anyhow::Result<
     Ok(())
 }
 
+/// Record the current commit of every repo and gripspace in `gripspace.lock`
+pub fn run_manifest_lock(
+    workspace_root: &Path,
+    manifest: &Manifest,
+    quiet: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    let lockfile = build_lockfile(workspace_root, manifest)?;
+    let path = lockfile_path(workspace_root);
+    lockfile.save(&path)?;
+
+    if json {
+        #[derive(serde::Serialize)]
+        struct JsonLockResult<'a> {
+            path: String,
+            #[serde(flatten)]
+            lockfile: &'a Lockfile,
+        }
+
+        let result = JsonLockResult {
+            path: path.display().to_string(),
+            lockfile: &lockfile,
+        };
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    if !quiet {
+        Output::header("Locking workspace...");
+        println!();
+        for (name, repo) in &lockfile.repos {
+            Output::info(&format!("{}: {}", name, short_sha(&repo.sha)));
+        }
+        for (name, gripspace) in &lockfile.gripspaces {
+            Output::info(&format!(
+                "gripspace '{}': {}",
+                name,
+                short_sha(&gripspace.sha)
+            ));
+        }
+        println!();
+        Output::success(&format!(
+            "Locked {} repo(s) and {} gripspace(s): {}",
+            lockfile.repos.len(),
+            lockfile.gripspaces.len(),
+            path.display()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Build a lockfile from the current HEAD of every repo and gripspace
+pub fn build_lockfile(workspace_root: &Path, manifest: &Manifest) -> anyhow::Result<Lockfile> {
+    let mut lockfile = Lockfile::default();
+
+    for (name, config) in &manifest.repos {
+        let repo_path = workspace_root.join(&config.path);
+        if !path_exists(&repo_path) {
+            anyhow::bail!("Repo '{}' is not cloned. Run 'gr sync' first.", name);
+        }
+        let git_repo = open_repo(&repo_path)?;
+        let sha = get_head_sha(&git_repo)
+            .map_err(|e| anyhow::anyhow!("Repo '{}': cannot resolve HEAD: {}", name, e))?;
+        lockfile.repos.insert(
+            name.clone(),
+            LockedRepo {
+                url: config
+                    .resolve_url(name, manifest.remotes.as_ref())
+                    .unwrap_or_else(|| config.url.clone()),
+                path: config.path.clone(),
+                sha,
+            },
+        );
+    }
+
+    if let Some(ref gripspaces) = manifest.gripspaces {
+        let spaces_dir = manifest_paths::spaces_dir(workspace_root);
+        for (dir_name, config) in list_gripspaces(gripspaces, &spaces_dir) {
+            let git_repo = open_repo(spaces_dir.join(&dir_name))?;
+            let sha = get_head_sha(&git_repo).map_err(|e| {
+                anyhow::anyhow!("Gripspace '{}': cannot resolve HEAD: {}", dir_name, e)
+            })?;
+            lockfile.gripspaces.insert(
+                dir_name,
+                LockedGripspace {
+                    url: config.url,
+                    sha,
+                },
+            );
+        }
+    }
+
+    Ok(lockfile)
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
+/// Compare two versions of the gripspace manifest.
+///
+/// Each side is either a path to a manifest file or a revision in the manifest
+/// repo. `rev_a` defaults to `HEAD` and `rev_b` to the working copy, so a bare
+/// `gr manifest diff` shows uncommitted manifest edits.
+pub fn run_manifest_diff(
+    workspace_root: &Path,
+    rev_a: Option<&str>,
+    rev_b: Option<&str>,
+    json: bool,
+) -> anyhow::Result<()> {
+    let (label_a, old) = load_manifest_version(workspace_root, Some(rev_a.unwrap_or("HEAD")))?;
+    let (label_b, new) = load_manifest_version(workspace_root, rev_b)?;
+    let diff = ManifestDiff::between(&old, &new);
+
+    if json {
+        #[derive(serde::Serialize)]
+        struct JsonManifestDiff<'a> {
+            from: String,
+            to: String,
+            #[serde(flatten)]
+            diff: &'a ManifestDiff,
+        }
+
+        let result = JsonManifestDiff {
+            from: label_a,
+            to: label_b,
+            diff: &diff,
+        };
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
+    Output::header(&format!("Manifest diff {} -> {}", label_a, label_b));
+    println!();
+
+    if diff.is_empty() {
+        Output::info("No manifest changes");
+        return Ok(());
+    }
+
+    for repo in &diff.added_repos {
+        println!("  + {} ({})", Output::repo_name(&repo.name), repo.url);
+    }
+    for name in &diff.removed_repos {
+        println!("  - {}", Output::repo_name(name));
+    }
+    for repo in &diff.changed_repos {
+        println!("  ~ {}", Output::repo_name(&repo.name));
+        for field in &repo.fields {
+            println!(
+                "      {}: {} -> {}",
+                field.field,
+                field.old.as_deref().unwrap_or("(none)"),
+                field.new.as_deref().unwrap_or("(none)")
+            );
+        }
+        if !repo.groups_added.is_empty() {
+            println!("      groups added: {}", repo.groups_added.join(", "));
+        }
+        if !repo.groups_removed.is_empty() {
+            println!("      groups removed: {}", repo.groups_removed.join(", "));
+        }
+    }
+
+    for (title, changes) in [("Scripts", &diff.scripts), ("Env", &diff.env)] {
+        if changes.is_empty() {
+            continue;
+        }
+        println!();
+        println!("  {}:", title);
+        for key in &changes.added {
+            println!("    + {}", key);
+        }
+        for key in &changes.removed {
+            println!("    - {}", key);
+        }
+        for key in &changes.changed {
+            println!("    ~ {}", key);
+        }
+    }
+
+    for (title, changes) in [("Hooks", &diff.hooks), ("Linkfiles", &diff.linkfiles)] {
+        if changes.is_empty() {
+            continue;
+        }
+        println!();
+        println!("  {}:", title);
+        for change in changes {
+            println!("    {}:", change.owner);
+            for entry in &change.added {
+                println!("      + {}", entry);
+            }
+            for entry in &change.removed {
+                println!("      - {}", entry);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Load one side of a manifest diff: the working copy (None), a file path, or
+/// a revision in the manifest repo. Returns a display label and the manifest.
+fn load_manifest_version(
+    workspace_root: &Path,
+    spec: Option<&str>,
+) -> anyhow::Result<(String, Manifest)> {
+    let Some(spec) = spec else {
+        let path = manifest_paths::resolve_gripspace_manifest_path(workspace_root)
+            .ok_or_else(|| anyhow::anyhow!("No gripspace manifest found in workspace"))?;
+        let content = std::fs::read_to_string(&path)?;
+        return Ok(("working copy".to_string(), Manifest::parse_raw(&content)?));
+    };
+
+    let file = Path::new(spec);
+    if file.is_file() {
+        let content = std::fs::read_to_string(file)?;
+        return Ok((spec.to_string(), Manifest::parse_raw(&content)?));
+    }
+
+    let repo_dir = manifest_paths::resolve_manifest_repo_dir(workspace_root).ok_or_else(|| {
+        anyhow::anyhow!(
+            "'{}' is not a file and the manifest directory is not a git repo",
+            spec
+        )
+    })?;
+    let repo = open_repo(&repo_dir)?;
+    let tree = repo
+        .revparse_single(spec)
+        .and_then(|object| object.peel_to_tree())
+        .map_err(|e| anyhow::anyhow!("Cannot resolve '{}' in manifest repo: {}", spec, e))?;
+
+    let entry = std::iter::once(manifest_paths::PRIMARY_FILE_NAME)
+        .chain(manifest_paths::LEGACY_FILE_NAMES)
+        .find_map(|name| tree.get_name(name))
+        .ok_or_else(|| anyhow::anyhow!("No manifest file found at '{}'", spec))?;
+    let blob = entry.to_object(&repo)?.peel_to_blob()?;
+    let content = std::str::from_utf8(blob.content())
+        .map_err(|e| anyhow::anyhow!("Manifest at '{}' is not UTF-8: {}", spec, e))?;
+
+    Ok((spec.to_string(), Manifest::parse_raw(content)?))
+}
+
+/// Normalize key order and indentation of the workspace manifest, keeping comments.
+///
+/// With `check`, nothing is written and an error is returned if the manifest
+/// is not already formatted.
+pub fn run_manifest_fmt(workspace_root: &Path, check: bool, quiet: bool) -> anyhow::Result<()> {
+    let manifest_path = manifest_paths::resolve_gripspace_manifest_path(workspace_root)
+        .ok_or_else(|| anyhow::anyhow!("No gripspace manifest found in workspace"))?;
+    let content = std::fs::read_to_string(&manifest_path)?;
+    let formatted = format_manifest(&content)?;
+
+    if formatted == content {
+        if !quiet {
+            Output::success(&format!(
+                "Manifest already formatted: {}",
+                manifest_path.display()
+            ));
+        }
+        return Ok(());
+    }
+
+    if check {
+        anyhow::bail!(
+            "Manifest is not formatted: {}. Run 'gr manifest fmt' to fix.",
+            manifest_path.display()
+        );
+    }
+
+    std::fs::write(&manifest_path, &formatted)?;
+    manifest_paths::sync_legacy_mirror_if_present(workspace_root, &manifest_path, &formatted)?;
+    Output::success(&format!("Formatted {}", manifest_path.display()));
+    Ok(())
+}
+
 /// Show manifest schema specification
 pub fn run_manifest_schema(format: &str) -> anyhow::Result<()> {
     let schema = include_str!("../../../docs/manifest-schema.yaml");
@@ -117,6 +486,7 @@ It is typically located at `.gitgrip/spaces/main/gripspace.yml`.
 |-------|------|----------|-------------|
 | `version` | integer | Yes | Schema version (currently `1`) |
 | `gripspaces` | array | No | Gripspace includes for manifest inheritance |
+| `remotes` | object | No | Named remotes repos can reference instead of a full URL |
 | `manifest` | object | No | Self-tracking manifest repo config |
 | `repos` | object | Yes | Repository definitions |
 | `settings` | object | No | Global workspace settings |
@@ -130,6 +500,19 @@ Include repos, scripts, env, hooks, and linkfiles from other gripspace repos.
 |-------|------|----------|-------------|
 | `url` | string | Yes | Git URL for the gripspace repository |
 | `rev` | string | No | Branch, tag, or commit to pin (default: remote HEAD) |
+| `exclude_repos` | array | No | Inherited repos to leave out |
+| `include_groups` | array | No | Only inherit repos in one of these groups |
+| `overrides` | object | No | Per-repo `path`, `default_branch`, `revision`, and added `groups` |
+
+## Remotes
+
+Each remote under `remotes` supports:
+
+| Field | Type | Required | Description |
+|-------|------|----------|-------------|
+| `fetch` | string | Yes | Fetch base URL; repo URLs are `<fetch>/<name>` |
+| `push` | string | No | Push base URL (default: fetch base) |
+| `platform` | object | No | Platform for repos on this remote (default: auto) |
 
 ### Composefile (in manifest section)
 
@@ -149,9 +532,16 @@ Each repository under `repos` supports:
 
 | Field | Type | Default | Description |
 |-------|------|---------|-------------|
-| `url` | string | - | Git URL (SSH or HTTPS) |
+| `url` | string | - | Git URL (SSH or HTTPS); omit when `remote` is set |
+| `remote` | string | - | Named remote to resolve the URL against |
+| `name` | string | repo key | Project name relative to the remote's fetch base |
 | `path` | string | - | Local path relative to workspace |
 | `default_branch` | string | `main` | Default branch name |
+| `revision` | string | - | Pin to a branch, tag, or commit SHA |
+| `depends_on` | array | `[]` | Repos processed before this one (build, test, CI, merge, release) |
+| `clone` | object | - | Shallow/partial clone options: `depth`, `filter`, `single_branch` |
+| `sparse` | array | `[]` | Directories to check out (cone-mode sparse checkout) |
+| `pr` | object | - | Overrides of `settings.pr` for this repo's PRs |
 | `groups` | array | `[]` | Groups for selective operations |
 | `reference` | boolean | `false` | Read-only reference repo |
 | `copyfile` | array | - | Files to copy to workspace |
@@ -171,6 +561,11 @@ Each repository under `repos` supports:
 |-------|------|---------|-------------|
 | `pr_prefix` | string | `[cross-repo]` | Prefix for PR titles |
 | `merge_strategy` | string | `all-or-nothing` | `all-or-nothing` or `independent` |
+| `pr.reviewers` | array | - | Users to request reviews from on `gr pr create` |
+| `pr.team_reviewers` | array | - | Teams to request reviews from |
+| `pr.labels` | array | - | Labels to add |
+| `pr.assignees` | array | - | Users to assign |
+| `pr.milestone` | string | - | Milestone title |
 
 ## Platform Types
 
@@ -178,6 +573,8 @@ Each repository under `repos` supports:
 - `gitlab` - GitLab.com or self-hosted
 - `azure-devops` - Azure DevOps or Azure DevOps Server
 - `bitbucket` - Bitbucket Cloud or Server
+- `gitea` - Gitea or Forgejo (alias `forgejo`); self-hosted instances default to the repo URL's host
+- `local` - Bare repos on disk; PRs, reviews and checks are JSON files beside them
 
 ## Workspace Agent Config
 
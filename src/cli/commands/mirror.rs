@@ -0,0 +1,100 @@
+//! Mirror command implementation
+//!
+//! Maintains shared bare mirrors of the manifest's repos in the user-level
+//! mirror directory. Once a repo has a mirror, `gr sync` refreshes it before
+//! syncing and new clones borrow objects from it.
+
+use crate::cli::output::Output;
+use crate::core::manifest::Manifest;
+use crate::core::repo::filter_repos;
+use crate::git::gc::{dir_size, format_bytes};
+use crate::git::mirror::{ensure_mirror, mirror_path, mirror_root, uses_mirror, MIRROR_DIR_ENV};
+use crate::git::{open_repo, path_exists};
+use std::path::PathBuf;
+
+/// Run `gr mirror sync`: create or refresh the mirror of every repo
+pub fn run_mirror_sync(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    group_filter: Option<&[String]>,
+    quiet: bool,
+) -> anyhow::Result<()> {
+    let root = require_mirror_root()?;
+    let repos = filter_repos(manifest, workspace_root, None, group_filter, true);
+
+    Output::header(&format!(
+        "Syncing {} mirror(s) in {}...",
+        repos.len(),
+        root.display()
+    ));
+    println!();
+
+    let mut failed = 0;
+    for repo in &repos {
+        let path = mirror_path(&root, &repo.url);
+        let spinner = Output::spinner(&format!("Mirroring {}...", repo.name));
+        match ensure_mirror(&repo.url, &path) {
+            Ok(true) => spinner.finish_with_message(format!("{}: mirror created", repo.name)),
+            Ok(false) if quiet => spinner.finish_and_clear(),
+            Ok(false) => spinner.finish_with_message(format!("{}: mirror updated", repo.name)),
+            Err(e) => {
+                failed += 1;
+                spinner.finish_with_message(format!("{}: error - {}", repo.name, e));
+            }
+        }
+    }
+
+    println!();
+    if failed > 0 {
+        anyhow::bail!("{} of {} mirror(s) failed to sync", failed, repos.len());
+    }
+    Output::success(&format!("{} mirror(s) up to date.", repos.len()));
+    Ok(())
+}
+
+/// Run `gr mirror list`: show each repo's mirror, its size and whether the
+/// workspace clone borrows objects from it
+pub fn run_mirror_list(workspace_root: &PathBuf, manifest: &Manifest) -> anyhow::Result<()> {
+    let root = require_mirror_root()?;
+    let repos = filter_repos(manifest, workspace_root, None, None, true);
+
+    Output::header(&format!("Mirrors in {}", root.display()));
+    println!();
+
+    let mut total: u64 = 0;
+    for repo in &repos {
+        let path = mirror_path(&root, &repo.url);
+        if !path.join("HEAD").is_file() {
+            println!("  {}: no mirror", Output::repo_name(&repo.name));
+            continue;
+        }
+
+        let size = dir_size(&path);
+        total += size;
+        let linked = path_exists(&repo.absolute_path)
+            && open_repo(&repo.absolute_path).is_ok_and(|r| uses_mirror(&r, &path));
+        println!(
+            "  {}: {}{}",
+            Output::repo_name(&repo.name),
+            format_bytes(size),
+            if linked {
+                " (shared with workspace)"
+            } else {
+                ""
+            }
+        );
+    }
+
+    println!();
+    Output::info(&format!("Total mirror size: {}", format_bytes(total)));
+    Ok(())
+}
+
+fn require_mirror_root() -> anyhow::Result<PathBuf> {
+    mirror_root().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Cannot determine the mirror directory: set {} or HOME",
+            MIRROR_DIR_ENV
+        )
+    })
+}
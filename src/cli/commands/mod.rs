@@ -3,9 +3,12 @@
 //! Each command is implemented in its own module.
 
 pub mod add;
+pub mod affected;
 pub mod agent;
 pub mod bench;
+pub mod bisect;
 pub mod branch;
+pub mod change;
 pub mod checkout;
 pub mod cherry_pick;
 pub mod ci;
@@ -17,8 +20,12 @@ pub mod gc;
 pub mod grep;
 pub mod group;
 pub mod init;
+pub mod issue;
 pub mod link;
+pub mod log;
 pub mod manifest;
+pub mod mirror;
+pub mod op;
 pub mod pr;
 pub mod prune;
 pub mod pull;
@@ -27,7 +34,9 @@ pub mod rebase;
 pub mod release;
 pub mod repo;
 pub mod run;
+pub mod stash;
 pub mod status;
 pub mod sync;
+pub mod tag;
 pub mod tree;
 pub mod verify;
@@ -0,0 +1,268 @@
+//! Operation log commands (`gr op log`, `gr undo`)
+//!
+//! Mutating commands snapshot the refs of every repo before and after they
+//! run ([`PendingOperation`]); `gr undo` moves the refs and `HEAD`s back.
+
+use crate::cli::output::Output;
+use crate::core::manifest::Manifest;
+use crate::core::oplog::{
+    load_operations, oplog_path, record_operation, save_operations, RepoRefChange,
+};
+use crate::core::repo::{filter_repos, get_manifest_repo_info, RepoInfo};
+use crate::git::cache::invalidate_status_cache;
+use crate::git::refs::{restore_refs, snapshot_refs, RefSnapshot};
+use crate::git::status::get_cached_status;
+use crate::git::{open_repo, path_exists};
+use chrono::Local;
+use std::path::{Path, PathBuf};
+
+/// Every cloned repo in the workspace, including the manifest repo
+fn workspace_repos(workspace_root: &Path, manifest: &Manifest) -> Vec<RepoInfo> {
+    let workspace_root = workspace_root.to_path_buf();
+    let mut repos = filter_repos(manifest, &workspace_root, None, None, true);
+    if let Some(manifest_repo) = get_manifest_repo_info(manifest, &workspace_root) {
+        repos.push(manifest_repo);
+    }
+    repos.retain(|r| path_exists(&r.absolute_path));
+    repos
+}
+
+fn snapshot(path: &Path) -> Option<RefSnapshot> {
+    open_repo(path)
+        .ok()
+        .and_then(|repo| snapshot_refs(&repo).ok())
+}
+
+/// Ref state captured before a mutating command runs
+pub struct PendingOperation {
+    path: PathBuf,
+    command: String,
+    before: Vec<(String, PathBuf, RefSnapshot)>,
+}
+
+impl PendingOperation {
+    /// Snapshot the refs of every repo before running `command`
+    pub fn begin(workspace_root: &Path, manifest: &Manifest, command: &str) -> Self {
+        let before = workspace_repos(workspace_root, manifest)
+            .into_iter()
+            .filter_map(|r| {
+                let snapshot = snapshot(&r.absolute_path)?;
+                Some((r.name, r.absolute_path, snapshot))
+            })
+            .collect();
+        Self {
+            path: oplog_path(workspace_root),
+            command: command.to_string(),
+            before,
+        }
+    }
+
+    /// Record the repos whose refs changed since [`PendingOperation::begin`]
+    ///
+    /// Called whether or not the command succeeded, since a failed command
+    /// may still have moved refs. Failing to record only warns.
+    pub fn finish(self) {
+        let repos: Vec<RepoRefChange> = self
+            .before
+            .into_iter()
+            .filter_map(|(repo_name, path, before)| {
+                let after = snapshot(&path)?;
+                (after != before).then_some(RepoRefChange {
+                    repo_name,
+                    before,
+                    after,
+                })
+            })
+            .collect();
+        if repos.is_empty() {
+            return;
+        }
+        if let Err(e) = record_operation(&self.path, &self.command, repos) {
+            Output::warning(&format!("Failed to record operation: {}", e));
+        }
+    }
+}
+
+/// JSON-serializable operation for --json output
+#[derive(serde::Serialize)]
+struct JsonOperation {
+    id: u64,
+    command: String,
+    timestamp: String,
+    repos: Vec<String>,
+    undone: bool,
+}
+
+/// Run `gr op log`: list recorded operations, newest first
+pub fn run_op_log(workspace_root: &Path, json: bool) -> anyhow::Result<()> {
+    let operations = load_operations(&oplog_path(workspace_root))?;
+
+    if json {
+        let ops: Vec<JsonOperation> = operations
+            .iter()
+            .rev()
+            .map(|op| JsonOperation {
+                id: op.id,
+                command: op.command.clone(),
+                timestamp: op.timestamp.to_rfc3339(),
+                repos: op.repos.iter().map(|r| r.repo_name.clone()).collect(),
+                undone: op.undone,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&ops)?);
+        return Ok(());
+    }
+
+    if operations.is_empty() {
+        println!("No operations recorded.");
+        return Ok(());
+    }
+
+    for op in operations.iter().rev() {
+        let repos: Vec<&str> = op.repos.iter().map(|r| r.repo_name.as_str()).collect();
+        println!(
+            "#{:<4} {}  gr {}{}",
+            op.id,
+            op.timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S"),
+            op.command,
+            if op.undone { "  (undone)" } else { "" }
+        );
+        println!("       {}", repos.join(", "));
+    }
+    Ok(())
+}
+
+/// Run `gr undo [n]`: restore the refs from before the last `n` operations
+///
+/// Operations are undone newest first. Before touching an operation's repos,
+/// every one of them must still have the refs the operation left behind and,
+/// where `HEAD` moves, no uncommitted changes to tracked files. An operation
+/// is only marked undone once all of its repos are restored; a retry skips
+/// the repos an earlier attempt already restored.
+pub fn run_undo(workspace_root: &Path, manifest: &Manifest, count: usize) -> anyhow::Result<()> {
+    let path = oplog_path(workspace_root);
+    let mut operations = load_operations(&path)?;
+    let targets: Vec<usize> = (0..operations.len())
+        .rev()
+        .filter(|&i| !operations[i].undone)
+        .take(count)
+        .collect();
+    if targets.is_empty() {
+        anyhow::bail!("Nothing to undo");
+    }
+
+    let repos = workspace_repos(workspace_root, manifest);
+    for index in targets {
+        let op = &operations[index];
+
+        let mut problems = Vec::new();
+        let mut plan = Vec::new();
+        for change in &op.repos {
+            let Some(repo) = repos.iter().find(|r| r.name == change.repo_name) else {
+                problems.push(format!("{}: not in workspace", change.repo_name));
+                continue;
+            };
+            let current = snapshot(&repo.absolute_path).unwrap_or_default();
+            // Already put back by an earlier, partly failed undo
+            if is_restored(&current, &change.before, &change.after) {
+                continue;
+            }
+            let moved = change
+                .before
+                .changed_branches(&change.after)
+                .into_iter()
+                .any(|b| current.branches.get(b) != change.after.branches.get(b));
+            if moved
+                || (change.before.head_differs(&change.after)
+                    && current.head_differs(&change.after))
+            {
+                problems.push(format!("{}: refs have changed since", repo.name));
+                continue;
+            }
+            if change.after.switches_checkout(&change.before) {
+                let status = get_cached_status(&repo.absolute_path)?;
+                if !status.staged.is_empty() || !status.modified.is_empty() {
+                    problems.push(format!("{}: has uncommitted changes", repo.name));
+                    continue;
+                }
+            }
+            plan.push((repo, change));
+        }
+        if !problems.is_empty() {
+            anyhow::bail!(
+                "Cannot undo #{} (gr {}):\n  {}",
+                op.id,
+                op.command,
+                problems.join("\n  ")
+            );
+        }
+
+        Output::header(&format!("Undoing #{}: gr {}", op.id, op.command));
+        let mut failed = 0;
+        for (repo, change) in plan {
+            let result = restore_refs(
+                &repo.absolute_path,
+                &change.after,
+                &change.before,
+                is_commit(&op.command),
+            );
+            invalidate_status_cache(&repo.absolute_path);
+            match result {
+                Ok(()) => Output::success(&format!(
+                    "{}: restored {}",
+                    repo.name,
+                    describe_head(&change.before)
+                )),
+                Err(e) => {
+                    failed += 1;
+                    Output::error(&format!("{}: {}", repo.name, e));
+                }
+            }
+        }
+
+        // Leave a partial undo unrecorded so that it can be retried
+        if failed > 0 {
+            anyhow::bail!("Failed to restore {} repo(s)", failed);
+        }
+        operations[index].undone = true;
+        save_operations(&path, &operations)?;
+    }
+    Ok(())
+}
+
+/// Whether a recorded command line is `gr commit`, whose undo keeps the
+/// committed changes staged
+fn is_commit(command: &str) -> bool {
+    let mut args = command.split_whitespace();
+    while let Some(arg) = args.next() {
+        match arg {
+            "--git-backend" => {
+                args.next();
+            }
+            _ if arg.starts_with('-') => {}
+            _ => return arg == "commit",
+        }
+    }
+    false
+}
+
+/// Whether `current` already has the refs from before the operation
+fn is_restored(current: &RefSnapshot, before: &RefSnapshot, after: &RefSnapshot) -> bool {
+    !current.head_differs(before)
+        && before
+            .changed_branches(after)
+            .into_iter()
+            .all(|b| current.branches.get(b) == before.branches.get(b))
+}
+
+fn describe_head(snapshot: &RefSnapshot) -> String {
+    let sha = snapshot.head_sha.as_deref().map(|s| &s[..7.min(s.len())]);
+    match (&snapshot.head, sha) {
+        (Some(branch), Some(sha)) => format!("{} at {}", branch, sha),
+        (Some(branch), None) => branch.clone(),
+        (None, Some(sha)) => format!("detached at {}", sha),
+        (None, None) => "HEAD".to_string(),
+    }
+}
@@ -0,0 +1,219 @@
+//! PR comments command implementation
+//!
+//! Aggregates conversation comments and review threads from the PRs of the
+//! current branch in every repo, and posts one comment to all of them.
+
+use crate::cli::output::Output;
+use crate::core::manifest::Manifest;
+use crate::core::repo::{get_manifest_repo_info, RepoInfo};
+use crate::git::{get_current_branch, open_repo, path_exists};
+use crate::platform::{get_platform_adapter, PRComment, ReviewThread};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Open PRs for the current branch: (repo, PR number, URL)
+async fn branch_prs(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repo_filter: Option<&[String]>,
+) -> Vec<(RepoInfo, u64, String)> {
+    let mut repos: Vec<RepoInfo> = manifest
+        .repos
+        .iter()
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
+        .filter(|r| !r.reference)
+        .collect();
+    repos.extend(get_manifest_repo_info(manifest, workspace_root));
+
+    let mut prs = Vec::new();
+    for repo in repos {
+        if repo_filter.is_some_and(|names| !names.contains(&repo.name)) {
+            continue;
+        }
+        if !path_exists(&repo.absolute_path) {
+            continue;
+        }
+        let Ok(branch) = open_repo(&repo.absolute_path).and_then(|r| get_current_branch(&r)) else {
+            continue;
+        };
+        if branch == repo.default_branch {
+            continue;
+        }
+
+        let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+        match platform
+            .find_pr_by_branch(&repo.owner, &repo.repo, &branch)
+            .await
+        {
+            Ok(Some(pr)) => prs.push((repo, pr.number, pr.url)),
+            Ok(None) => {}
+            Err(e) => Output::error(&format!("{}: {}", repo.name, e)),
+        }
+    }
+    prs
+}
+
+/// Run `gr pr comments`
+pub async fn run_pr_comments(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    unresolved_only: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct PRConversation {
+        repo: String,
+        pr_number: u64,
+        url: String,
+        comments: Vec<PRComment>,
+        threads: Vec<ReviewThread>,
+        unresolved_threads: usize,
+    }
+
+    let mut conversations = Vec::new();
+    for (repo, number, url) in branch_prs(workspace_root, manifest, None).await {
+        let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+        let comments = match platform
+            .get_pull_request_comments(&repo.owner, &repo.repo, number)
+            .await
+        {
+            Ok(comments) => comments,
+            Err(e) => {
+                Output::warning(&format!("{} #{}: {}", repo.name, number, e));
+                Vec::new()
+            }
+        };
+        let mut threads = match platform
+            .get_review_threads(&repo.owner, &repo.repo, number)
+            .await
+        {
+            Ok(threads) => threads,
+            Err(e) => {
+                Output::warning(&format!("{} #{}: {}", repo.name, number, e));
+                Vec::new()
+            }
+        };
+        let unresolved_threads = threads.iter().filter(|t| !t.resolved).count();
+        if unresolved_only {
+            threads.retain(|t| !t.resolved);
+        }
+
+        conversations.push(PRConversation {
+            repo: repo.name,
+            pr_number: number,
+            url,
+            comments: if unresolved_only {
+                Vec::new()
+            } else {
+                comments
+            },
+            threads,
+            unresolved_threads,
+        });
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&conversations)?);
+        return Ok(());
+    }
+
+    if conversations.is_empty() {
+        println!("No open PRs for the current branch.");
+        return Ok(());
+    }
+
+    for conversation in &conversations {
+        Output::header(&format!(
+            "{} #{} ({} unresolved)",
+            conversation.repo, conversation.pr_number, conversation.unresolved_threads
+        ));
+        if conversation.comments.is_empty() && conversation.threads.is_empty() {
+            println!("  No comments");
+            continue;
+        }
+        for comment in &conversation.comments {
+            print_comment(comment, "  ");
+        }
+        for thread in &conversation.threads {
+            let location = match (&thread.path, thread.line) {
+                (Some(path), Some(line)) => format!("{}:{}", path, line),
+                (Some(path), None) => path.clone(),
+                _ => "(general)".to_string(),
+            };
+            let state = if thread.resolved {
+                "resolved"
+            } else {
+                "unresolved"
+            };
+            println!("  {} [{}]", location, state);
+            for comment in &thread.comments {
+                print_comment(comment, "    ");
+            }
+        }
+    }
+
+    let total: usize = conversations.iter().map(|c| c.unresolved_threads).sum();
+    println!();
+    if total == 0 {
+        Output::success("No unresolved conversations");
+    } else {
+        Output::warning(&format!(
+            "{} unresolved conversation(s) across {} PR(s)",
+            total,
+            conversations
+                .iter()
+                .filter(|c| c.unresolved_threads > 0)
+                .count()
+        ));
+    }
+    Ok(())
+}
+
+fn print_comment(comment: &PRComment, indent: &str) {
+    let when = comment
+        .created_at
+        .as_deref()
+        .map(|t| format!(" ({})", t.get(..10).unwrap_or(t)))
+        .unwrap_or_default();
+    println!("{}{}{}:", indent, comment.author, when);
+    for line in comment.body.lines() {
+        println!("{}  {}", indent, line);
+    }
+}
+
+/// Run `gr pr comment -m <message>`: comment on every PR of the current branch
+pub async fn run_pr_comment(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    message: &str,
+    repos: Option<&[String]>,
+) -> anyhow::Result<()> {
+    let prs = branch_prs(workspace_root, manifest, repos).await;
+    if prs.is_empty() {
+        println!("No open PRs for the current branch.");
+        return Ok(());
+    }
+
+    let mut failed = 0;
+    for (repo, number, _) in &prs {
+        let platform = get_platform_adapter(repo.platform_type, repo.platform_base_url.as_deref());
+        match platform
+            .create_pull_request_comment(&repo.owner, &repo.repo, *number, message)
+            .await
+        {
+            Ok(()) => Output::success(&format!("{}: commented on PR #{}", repo.name, number)),
+            Err(e) => {
+                failed += 1;
+                Output::error(&format!("{} #{}: {}", repo.name, number, e));
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("Failed to comment on {} PR(s)", failed);
+    }
+    Ok(())
+}
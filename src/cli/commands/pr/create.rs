@@ -1,12 +1,12 @@
 //! PR create command implementation
 
 use crate::cli::output::Output;
-use crate::core::manifest::{Manifest, PlatformType};
+use crate::core::manifest::{Manifest, PlatformType, PrDefaults};
 use crate::core::repo::{get_manifest_repo_info, RepoInfo};
 use crate::core::state::StateFile;
 use crate::git::status::has_uncommitted_changes;
 use crate::git::{get_current_branch, open_repo, path_exists};
-use crate::platform::get_platform_adapter;
+use crate::platform::{get_platform_adapter, PRCreateOptions};
 use git2::Repository;
 use std::path::PathBuf;
 
@@ -17,6 +17,7 @@ pub async fn run_pr_create(
     manifest: &Manifest,
     title: Option<&str>,
     body: Option<&str>,
+    metadata: &PrDefaults,
     draft: bool,
     push_first: bool,
     dry_run: bool,
@@ -35,7 +36,9 @@ pub async fn run_pr_create(
     let repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .filter(|r| !r.reference) // Skip reference repos
         .collect();
 
@@ -150,6 +153,10 @@ pub async fn run_pr_create(
         Output::subheader("Repositories that would create PRs:");
         for repo in &repos_with_changes {
             println!("  - {} ({}/{})", repo.name, repo.owner, repo.repo);
+            let options = pr_options(manifest, &repo.name, metadata);
+            if options.has_metadata() {
+                println!("      {}", describe_metadata(&options));
+            }
         }
         println!();
         Output::warning("Run without --dry-run to actually create the PRs.");
@@ -181,6 +188,21 @@ pub async fn run_pr_create(
                     "{}: created PR #{} - {}",
                     repo.name, pr.number, pr.url
                 ));
+
+                // The PR exists either way; reviewers and labels are best effort
+                let options = pr_options(manifest, &repo.name, metadata);
+                if options.has_metadata() {
+                    if let Err(e) = platform
+                        .set_pull_request_metadata(&repo.owner, &repo.repo, pr.number, &options)
+                        .await
+                    {
+                        Output::warning(&format!(
+                            "{}: could not set reviewers/labels on PR #{} - {}",
+                            repo.name, pr.number, e
+                        ));
+                    }
+                }
+
                 created_prs.push((repo.name.clone(), pr.number, pr.url.clone()));
             }
             Err(e) => {
@@ -249,6 +271,51 @@ pub async fn run_pr_create(
     Ok(())
 }
 
+/// Reviewers and metadata for `repo_name`'s PR: `settings.pr`, replaced field
+/// by field by the repo's `pr`, plus anything given on the command line
+fn pr_options(manifest: &Manifest, repo_name: &str, cli: &PrDefaults) -> PRCreateOptions {
+    let repo_overrides = manifest.repos.get(repo_name).and_then(|r| r.pr.as_ref());
+    let config = manifest.settings.pr.merged_with(repo_overrides);
+
+    let combine = |configured: &Option<Vec<String>>, extra: &Option<Vec<String>>| {
+        let mut names = configured.clone().unwrap_or_default();
+        for name in extra.iter().flatten() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        names
+    };
+
+    PRCreateOptions {
+        reviewers: combine(&config.reviewers, &cli.reviewers),
+        team_reviewers: combine(&config.team_reviewers, &cli.team_reviewers),
+        labels: combine(&config.labels, &cli.labels),
+        assignees: combine(&config.assignees, &cli.assignees),
+        milestone: cli.milestone.clone().or(config.milestone),
+        ..Default::default()
+    }
+}
+
+/// One-line summary of a PR's reviewers, labels, assignees and milestone
+fn describe_metadata(options: &PRCreateOptions) -> String {
+    let mut parts = Vec::new();
+    for (label, names) in [
+        ("reviewers", &options.reviewers),
+        ("teams", &options.team_reviewers),
+        ("labels", &options.labels),
+        ("assignees", &options.assignees),
+    ] {
+        if !names.is_empty() {
+            parts.push(format!("{}: {}", label, names.join(", ")));
+        }
+    }
+    if let Some(ref milestone) = options.milestone {
+        parts.push(format!("milestone: {}", milestone));
+    }
+    parts.join("; ")
+}
+
 /// Check if a branch has commits ahead of another branch
 pub(crate) fn has_commits_ahead(
     repo: &Repository,
@@ -341,6 +408,10 @@ pub fn get_token_for_platform(platform: &PlatformType) -> Option<String> {
         PlatformType::GitLab => std::env::var("GITLAB_TOKEN").ok(),
         PlatformType::AzureDevOps => std::env::var("AZURE_DEVOPS_TOKEN").ok(),
         PlatformType::Bitbucket => std::env::var("BITBUCKET_TOKEN").ok(),
+        PlatformType::Gitea => std::env::var("GITEA_TOKEN")
+            .ok()
+            .or_else(|| std::env::var("FORGEJO_TOKEN").ok()),
+        PlatformType::Local => Some(String::new()),
     }
 }
 
@@ -2,6 +2,7 @@
 
 use super::create::has_commits_ahead;
 use crate::cli::output::Output;
+use crate::core::dag::RepoDag;
 use crate::core::manifest::Manifest;
 use crate::core::repo::{get_manifest_repo_info, RepoInfo};
 use crate::git::{get_current_branch, open_repo, path_exists};
@@ -31,9 +32,13 @@ pub async fn run_pr_merge(
     let repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .filter(|r| !r.reference) // Skip reference repos
         .collect();
+    // Merge dependencies before the repos that consume them
+    let repos = RepoDag::from_manifest(manifest).sort(repos)?;
 
     let merge_method = match method {
         Some("squash") => MergeMethod::Squash,
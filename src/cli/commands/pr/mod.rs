@@ -3,12 +3,14 @@
 //! Subcommands for pull request operations.
 
 mod checks;
+mod comments;
 mod create;
 mod diff;
 mod merge;
 mod status;
 
 pub use checks::run_pr_checks;
+pub use comments::{run_pr_comment, run_pr_comments};
 pub use create::run_pr_create;
 pub use diff::run_pr_diff;
 pub use merge::run_pr_merge;
@@ -5,6 +5,7 @@ use crate::core::manifest::Manifest;
 use crate::core::repo::RepoInfo;
 use crate::git::{get_current_branch, open_repo, path_exists};
 use crate::platform::get_platform_adapter;
+use crate::platform::traits::is_comments_not_supported;
 use std::path::PathBuf;
 
 /// Run the PR status command
@@ -21,7 +22,9 @@ pub async fn run_pr_status(
     let repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .filter(|r| !r.reference) // Skip reference repos
         .collect();
 
@@ -34,6 +37,8 @@ pub async fn run_pr_status(
         approved: bool,
         checks_pass: bool,
         mergeable: bool,
+        /// `None` if the review threads could not be fetched
+        unresolved_threads: Option<usize>,
         url: Option<String>,
     }
 
@@ -90,6 +95,17 @@ pub async fn run_pr_status(
                     Err(_) => false,
                 };
 
+                let unresolved_threads = match platform
+                    .get_review_threads(&repo.owner, &repo.repo, pr.number)
+                    .await
+                {
+                    Ok(threads) => Some(threads.iter().filter(|t| !t.resolved).count()),
+                    // Platforms without review threads have nothing to resolve
+                    Err(e) if is_comments_not_supported(&e) => Some(0),
+                    // Unknown, shown as `?` and never counted as ready
+                    Err(_) => None,
+                };
+
                 statuses.push(PRStatusInfo {
                     repo: repo.name.clone(),
                     branch: branch.clone(),
@@ -98,6 +114,7 @@ pub async fn run_pr_status(
                     approved,
                     checks_pass,
                     mergeable,
+                    unresolved_threads,
                     url: Some(pr.url.clone()),
                 });
             }
@@ -110,6 +127,7 @@ pub async fn run_pr_status(
                     approved: false,
                     checks_pass: false,
                     mergeable: false,
+                    unresolved_threads: Some(0),
                     url: None,
                 });
             }
@@ -139,6 +157,7 @@ pub async fn run_pr_status(
         "Approved",
         "Checks",
         "Mergeable",
+        "Unresolved",
     ]);
 
     for status in &statuses {
@@ -149,6 +168,11 @@ pub async fn run_pr_status(
         let approved = if status.approved { "✓" } else { "✗" };
         let checks = if status.checks_pass { "✓" } else { "✗" };
         let mergeable = if status.mergeable { "✓" } else { "✗" };
+        let unresolved = match (status.pr_number, status.unresolved_threads) {
+            (None, _) => "-".to_string(),
+            (Some(_), Some(count)) => count.to_string(),
+            (Some(_), None) => "?".to_string(),
+        };
 
         table.add_row(vec![
             &status.repo,
@@ -157,6 +181,7 @@ pub async fn run_pr_status(
             approved,
             checks,
             mergeable,
+            &unresolved,
         ]);
     }
 
@@ -167,7 +192,13 @@ pub async fn run_pr_status(
     let with_prs = statuses.iter().filter(|s| s.pr_number.is_some()).count();
     let ready = statuses
         .iter()
-        .filter(|s| s.pr_number.is_some() && s.approved && s.checks_pass && s.mergeable)
+        .filter(|s| {
+            s.pr_number.is_some()
+                && s.approved
+                && s.checks_pass
+                && s.mergeable
+                && s.unresolved_threads == Some(0)
+        })
         .count();
 
     if ready == with_prs && with_prs > 0 {
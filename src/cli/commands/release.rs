@@ -10,6 +10,7 @@ use chrono::Local;
 use serde::Serialize;
 
 use crate::cli::output::Output;
+use crate::core::dag::{run_level, RepoDag};
 use crate::core::manifest::Manifest;
 use crate::core::repo::{filter_repos, RepoInfo};
 use crate::platform::{get_platform_adapter, ReleaseResult};
@@ -433,42 +434,61 @@ pub async fn run_release(opts: ReleaseOptions<'_>) -> anyhow::Result<()> {
         Output::info("Step 3: Building");
     }
 
-    let mut built_any = false;
-    for repo in &repos {
-        let build_cmd = repo.agent.as_ref().and_then(|a| a.build.as_deref());
-        let Some(cmd) = build_cmd else {
-            continue;
-        };
+    // Build in dependency order; independent repos build in parallel
+    let buildable: Vec<RepoInfo> = repos
+        .iter()
+        .filter(|r| r.agent.as_ref().and_then(|a| a.build.as_deref()).is_some())
+        .cloned()
+        .collect();
+    let built_any = !buildable.is_empty();
+    let build_cmd = |repo: &RepoInfo| {
+        repo.agent
+            .as_ref()
+            .and_then(|a| a.build.clone())
+            .unwrap_or_default()
+    };
 
+    for level in RepoDag::from_manifest(opts.manifest).levels(buildable)? {
         if opts.dry_run {
             if !opts.json && !opts.quiet {
-                Output::info(&format!("  Would run in {}: {}", repo.name, cmd));
+                for repo in &level {
+                    Output::info(&format!(
+                        "  Would run in {}: {}",
+                        repo.name,
+                        build_cmd(repo)
+                    ));
+                }
             }
-            built_any = true;
             continue;
         }
 
         if !opts.json && !opts.quiet {
-            Output::info(&format!("  Building {} ({})", repo.name, cmd));
+            for repo in &level {
+                Output::info(&format!("  Building {} ({})", repo.name, build_cmd(repo)));
+            }
         }
 
-        let status = Command::new("sh")
-            .arg("-c")
-            .arg(cmd)
-            .current_dir(&repo.absolute_path)
-            .status()?;
+        let results = run_level(&level, |repo| {
+            Command::new("sh")
+                .arg("-c")
+                .arg(build_cmd(repo))
+                .current_dir(&repo.absolute_path)
+                .status()
+        });
 
-        if !status.success() {
-            anyhow::bail!(
-                "Build failed for '{}' (exit code: {:?})",
-                repo.name,
-                status.code()
-            );
-        }
+        for (repo, status) in level.iter().zip(results) {
+            let status = status?;
+            if !status.success() {
+                anyhow::bail!(
+                    "Build failed for '{}' (exit code: {:?})",
+                    repo.name,
+                    status.code()
+                );
+            }
 
-        built_any = true;
-        if !opts.json && !opts.quiet {
-            Output::success(&format!("  {} built successfully", repo.name));
+            if !opts.json && !opts.quiet {
+                Output::success(&format!("  {} built successfully", repo.name));
+            }
         }
     }
 
@@ -589,6 +609,7 @@ pub async fn run_release(opts: ReleaseOptions<'_>) -> anyhow::Result<()> {
                 opts.manifest,
                 Some(&format!("chore: release {}", version_tag)),
                 opts.notes,
+                &Default::default(),
                 false, // draft
                 false, // push (already pushed)
                 false, // dry_run
@@ -648,17 +669,21 @@ pub async fn run_release(opts: ReleaseOptions<'_>) -> anyhow::Result<()> {
             )?;
 
             // Sync
-            crate::cli::commands::sync::run_sync(
-                opts.workspace_root,
-                opts.manifest,
-                false, // force
-                opts.quiet,
-                None,  // group
-                false, // sequential
-                false, // reset_refs
-                opts.json,
-                false, // no_hooks
-            )
+            crate::cli::commands::sync::run_sync(crate::cli::commands::sync::SyncOptions {
+                workspace_root: opts.workspace_root,
+                manifest: opts.manifest,
+                force: false,
+                quiet: opts.quiet,
+                group_filter: None,
+                sequential: false,
+                reset_refs: false,
+                json: opts.json,
+                no_hooks: false,
+                locked: false,
+                depth: None,
+                filter: None,
+                unshallow: false,
+            })
             .await?;
         }
     }
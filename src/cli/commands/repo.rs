@@ -4,6 +4,7 @@
 
 use crate::cli::output::{Output, Table};
 use crate::core::manifest::Manifest;
+use crate::core::manifest_editor::ManifestEditor;
 use crate::core::manifest_paths;
 use crate::core::repo::RepoInfo;
 use crate::git::path_exists;
@@ -17,7 +18,9 @@ pub fn run_repo_list(workspace_root: &PathBuf, manifest: &Manifest) -> anyhow::R
     let repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .collect();
 
     let mut table = Table::new(vec!["Name", "Path", "Branch", "Status"]);
@@ -64,52 +67,18 @@ pub fn run_repo_add(
 
     let branch = default_branch.unwrap_or("main").to_string();
 
-    // Load manifest
     let manifest_path = manifest_paths::resolve_manifest_path_for_update(workspace_root)
         .ok_or_else(|| anyhow::anyhow!("No workspace manifest found to update"))?;
-    let content = std::fs::read_to_string(&manifest_path)?;
-
-    // Simple YAML append (for a proper implementation, use serde_yaml to read/write)
-    let new_repo_yaml = format!(
-        r#"
-  {}:
-    url: {}
-    path: {}
-    default_branch: {}"#,
-        repo_name, url, repo_path, branch
-    );
-
-    // Check if repos section exists and append
-    let updated_content = if content.contains("repos:") {
-        // Find where to insert - after repos: and before next top-level key
-        let mut lines: Vec<&str> = content.lines().collect();
-        let mut after_repos = false;
-        let mut insert_index = lines.len();
-
-        for (i, line) in lines.iter().enumerate() {
-            if line.starts_with("repos:") {
-                after_repos = true;
-                continue;
-            }
-
-            // If we're after repos: section and hit a new top-level key, insert here
-            if after_repos
-                && (line.starts_with("settings:")
-                    || line.starts_with("workspace:")
-                    || line.starts_with("manifest:"))
-            {
-                insert_index = i;
-                break;
-            }
-        }
+    let mut editor = ManifestEditor::load(&manifest_path)?;
+    if editor.contains(&["repos", &repo_name]) {
+        anyhow::bail!("Repository '{}' already exists in manifest", repo_name);
+    }
 
-        lines.insert(insert_index, &new_repo_yaml);
-        lines.join("\n")
-    } else {
-        format!("{}repos:{}", content, new_repo_yaml)
-    };
+    editor.set(&["repos", &repo_name, "url"], url)?;
+    editor.set(&["repos", &repo_name, "path"], &repo_path)?;
+    editor.set(&["repos", &repo_name, "default_branch"], &branch)?;
 
-    std::fs::write(&manifest_path, &updated_content)?;
+    let updated_content = editor.save(&manifest_path)?;
     manifest_paths::sync_legacy_mirror_if_present(
         workspace_root,
         &manifest_path,
@@ -153,37 +122,9 @@ pub fn run_repo_remove(
         }
     }
 
-    // Remove from manifest (simple string replacement)
-    // For a proper implementation, use serde_yaml to read/write
-    let repo_pattern = format!("  {}:", name);
-    let lines: Vec<&str> = content.lines().collect();
-    let mut new_lines: Vec<&str> = Vec::new();
-    let mut skip_until_next_repo = false;
-
-    for line in lines {
-        if line.starts_with(&repo_pattern) {
-            skip_until_next_repo = true;
-            continue;
-        }
-
-        if skip_until_next_repo {
-            // Check if this is a new repo entry or top-level key
-            if line.starts_with("  ") && !line.starts_with("    ") && line.contains(':') {
-                skip_until_next_repo = false;
-            } else if !line.starts_with("  ") && !line.starts_with("    ") {
-                skip_until_next_repo = false;
-            } else {
-                continue;
-            }
-        }
-
-        if !skip_until_next_repo {
-            new_lines.push(line);
-        }
-    }
-
-    let updated_content = new_lines.join("\n");
-    std::fs::write(&manifest_path, &updated_content)?;
+    let mut editor = ManifestEditor::parse(&content)?;
+    editor.remove(&["repos", name]);
+    let updated_content = editor.save(&manifest_path)?;
     manifest_paths::sync_legacy_mirror_if_present(
         workspace_root,
         &manifest_path,
@@ -276,41 +217,25 @@ mod tests {
 
 #[cfg(test)]
 mod yaml_insertion_tests {
-    use super::*;
+    use crate::core::manifest_editor::ManifestEditor;
 
-    /// Helper function to extract the insertion logic for testing
+    /// Insert a `  name:\n    key: value` entry the way run_repo_add does
     fn test_insert_yaml(content: &str, new_entry: &str) -> String {
-        test_helper_insert(content, new_entry)
-    }
-
-    /// Replicates the insertion logic from run_repo_add for testing
-    fn test_helper_insert(content: &str, new_entry: &str) -> String {
-        if content.contains("repos:") {
-            let mut lines: Vec<&str> = content.lines().collect();
-            let mut after_repos = false;
-            let mut insert_index = lines.len();
-
-            for (i, line) in lines.iter().enumerate() {
-                if line.starts_with("repos:") {
-                    after_repos = true;
-                    continue;
-                }
-
-                if after_repos
-                    && (line.starts_with("settings:")
-                        || line.starts_with("workspace:")
-                        || line.starts_with("manifest:"))
-                {
-                    insert_index = i;
-                    break;
-                }
+        let entry: serde_yaml::Mapping = serde_yaml::from_str(new_entry).unwrap();
+        let mut editor = ManifestEditor::parse(content).unwrap();
+
+        for (name, fields) in entry {
+            let name = name.as_str().unwrap();
+            for (key, value) in fields.as_mapping().unwrap() {
+                editor
+                    .set(
+                        &["repos", name, key.as_str().unwrap()],
+                        value.as_str().unwrap(),
+                    )
+                    .unwrap();
             }
-
-            lines.insert(insert_index, new_entry);
-            lines.join("\n")
-        } else {
-            format!("{}repos:{}", content, new_entry)
         }
+        editor.render()
     }
 
     fn normalize(s: &str) -> String {
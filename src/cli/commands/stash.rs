@@ -0,0 +1,288 @@
+//! Stash command implementation
+//!
+//! Stashes every dirty repo under one named workspace stash recorded in
+//! `.gitgrip/state.json`, and restores them together. Pop is all-or-nothing:
+//! if any repo cannot be restored, the repos already restored are reset and
+//! the stash is kept.
+
+use crate::cli::output::Output;
+use crate::core::manifest::Manifest;
+use crate::core::repo::{filter_repos, get_manifest_repo_info, RepoInfo};
+use crate::core::state::{StashedRepo, StateFile, WorkspaceStash};
+use crate::git::cache::invalidate_status_cache;
+use crate::git::stash::{
+    discard_changes, find_stash, stash_apply, stash_drop, stash_pop, stash_push,
+};
+use crate::git::status::get_cached_status;
+use chrono::Utc;
+use std::path::{Path, PathBuf};
+
+fn state_path(workspace_root: &Path) -> PathBuf {
+    workspace_root.join(".gitgrip").join("state.json")
+}
+
+/// Workspace repos plus the manifest repo, cloned or not
+fn workspace_repos(workspace_root: &PathBuf, manifest: &Manifest) -> Vec<RepoInfo> {
+    let mut repos = filter_repos(manifest, workspace_root, None, None, false);
+    if let Some(manifest_repo) = get_manifest_repo_info(manifest, workspace_root) {
+        repos.push(manifest_repo);
+    }
+    repos
+}
+
+/// Run `gr stash push`: stash every dirty repo under one workspace stash
+pub fn run_stash_push(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    message: Option<&str>,
+) -> anyhow::Result<()> {
+    let path = state_path(workspace_root);
+    let mut state = StateFile::load(&path)?;
+    let name = state.next_stash_name();
+    let git_message = match message {
+        Some(message) => format!("gr stash {}: {}", name, message),
+        None => format!("gr stash {}", name),
+    };
+
+    let mut dirty = Vec::new();
+    for repo in workspace_repos(workspace_root, manifest) {
+        if !repo.exists() {
+            continue;
+        }
+        let status = get_cached_status(&repo.absolute_path)?;
+        if !status.is_clean {
+            dirty.push((repo, status.current_branch));
+        }
+    }
+
+    if dirty.is_empty() {
+        Output::info("No local changes to stash.");
+        return Ok(());
+    }
+
+    Output::header(&format!("Stashing {} repo(s) as {}...", dirty.len(), name));
+    println!();
+
+    let mut stashed: Vec<(RepoInfo, StashedRepo)> = Vec::new();
+    for (repo, branch) in dirty {
+        let result = stash_push(&repo.absolute_path, &git_message);
+        invalidate_status_cache(&repo.absolute_path);
+        match result {
+            Ok(Some(commit)) => {
+                Output::success(&format!("{}: stashed", repo.name));
+                let entry = StashedRepo {
+                    repo_name: repo.name.clone(),
+                    commit,
+                    branch,
+                };
+                stashed.push((repo, entry));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                Output::error(&format!("{}: stash failed - {}", repo.name, e));
+                // Put back what was already stashed so nothing is left half-stashed
+                for (repo, _) in stashed.iter().rev() {
+                    if let Err(e) = stash_pop(&repo.absolute_path) {
+                        Output::error(&format!("{}: failed to restore - {}", repo.name, e));
+                    }
+                    invalidate_status_cache(&repo.absolute_path);
+                }
+                anyhow::bail!("Stash aborted; no workspace stash was recorded");
+            }
+        }
+    }
+
+    if stashed.is_empty() {
+        Output::info("No local changes to stash.");
+        return Ok(());
+    }
+
+    let count = stashed.len();
+    state.stashes.push(WorkspaceStash {
+        name: name.clone(),
+        message: message.map(str::to_string),
+        created_at: Utc::now(),
+        repos: stashed.into_iter().map(|(_, entry)| entry).collect(),
+    });
+    state.save(&path)?;
+
+    println!();
+    Output::success(&format!("Saved {} ({} repo(s))", name, count));
+    Ok(())
+}
+
+/// Run `gr stash list`
+pub fn run_stash_list(workspace_root: &Path, json: bool) -> anyhow::Result<()> {
+    let state = StateFile::load(state_path(workspace_root))?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&state.stashes)?);
+        return Ok(());
+    }
+
+    if state.stashes.is_empty() {
+        Output::info("No workspace stashes.");
+        return Ok(());
+    }
+
+    for stash in state.stashes.iter().rev() {
+        let repos: Vec<&str> = stash.repos.iter().map(|r| r.repo_name.as_str()).collect();
+        println!(
+            "{}: {}{} [{}]",
+            stash.name,
+            stash.created_at.format("%Y-%m-%d %H:%M"),
+            stash
+                .message
+                .as_deref()
+                .map(|m| format!(" - {}", m))
+                .unwrap_or_default(),
+            repos.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Run `gr stash pop`: restore a workspace stash (default: the most recent)
+/// in every repo, or in none
+pub fn run_stash_pop(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    name: Option<&str>,
+) -> anyhow::Result<()> {
+    let path = state_path(workspace_root);
+    let mut state = StateFile::load(&path)?;
+    let stash = find_workspace_stash(&state, name)?.clone();
+    let targets = resolve_stash_repos(workspace_root, manifest, &stash)?;
+
+    // Check every repo before touching any of them
+    let mut problems = Vec::new();
+    for (repo, entry) in &targets {
+        if !repo.exists() {
+            problems.push(format!("{}: not cloned", repo.name));
+            continue;
+        }
+        if find_stash(&repo.absolute_path, &entry.commit)?.is_none() {
+            problems.push(format!("{}: git stash entry is missing", repo.name));
+            continue;
+        }
+        let status = get_cached_status(&repo.absolute_path)?;
+        if !status.is_clean {
+            problems.push(format!("{}: has uncommitted changes", repo.name));
+        }
+        if status.current_branch != entry.branch {
+            Output::warning(&format!(
+                "{}: stashed on {}, restoring onto {}",
+                repo.name, entry.branch, status.current_branch
+            ));
+        }
+    }
+    if !problems.is_empty() {
+        anyhow::bail!("Cannot pop {}:\n  {}", stash.name, problems.join("\n  "));
+    }
+
+    Output::header(&format!(
+        "Restoring {} in {} repo(s)...",
+        stash.name,
+        targets.len()
+    ));
+    println!();
+
+    let mut applied: Vec<&RepoInfo> = Vec::new();
+    for (repo, entry) in &targets {
+        let result = stash_apply(&repo.absolute_path, &entry.commit);
+        invalidate_status_cache(&repo.absolute_path);
+        if let Err(e) = result {
+            Output::error(&format!("{}: {}", repo.name, e));
+            // Every target was clean, so resetting undoes the partial restore
+            applied.push(repo);
+            for repo in applied {
+                if let Err(e) = discard_changes(&repo.absolute_path) {
+                    Output::error(&format!("{}: failed to roll back - {}", repo.name, e));
+                }
+                invalidate_status_cache(&repo.absolute_path);
+            }
+            anyhow::bail!(
+                "Pop aborted; {} was kept and no repo was changed",
+                stash.name
+            );
+        }
+        applied.push(repo);
+        Output::success(&format!("{}: restored", repo.name));
+    }
+
+    for (repo, entry) in &targets {
+        if let Err(e) = stash_drop(&repo.absolute_path, &entry.commit) {
+            Output::warning(&format!("{}: {}", repo.name, e));
+        }
+    }
+    state.remove_stash(&stash.name);
+    state.save(&path)?;
+
+    println!();
+    Output::success(&format!("Restored {}", stash.name));
+    Ok(())
+}
+
+/// Run `gr stash drop`: discard a workspace stash (default: the most recent)
+pub fn run_stash_drop(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    name: Option<&str>,
+) -> anyhow::Result<()> {
+    let path = state_path(workspace_root);
+    let mut state = StateFile::load(&path)?;
+    let stash = find_workspace_stash(&state, name)?.clone();
+    let repos = workspace_repos(workspace_root, manifest);
+
+    for entry in &stash.repos {
+        let repo = repos.iter().find(|r| r.name == entry.repo_name);
+        match repo {
+            Some(repo) if repo.exists() => {
+                if let Err(e) = stash_drop(&repo.absolute_path, &entry.commit) {
+                    Output::warning(&format!("{}: {}", repo.name, e));
+                }
+            }
+            _ => Output::warning(&format!("{}: not in workspace, skipping", entry.repo_name)),
+        }
+    }
+    state.remove_stash(&stash.name);
+    state.save(&path)?;
+
+    Output::success(&format!("Dropped {}", stash.name));
+    Ok(())
+}
+
+fn find_workspace_stash<'a>(
+    state: &'a StateFile,
+    name: Option<&str>,
+) -> anyhow::Result<&'a WorkspaceStash> {
+    state.find_stash(name).ok_or_else(|| match name {
+        Some(name) => anyhow::anyhow!("No workspace stash named '{}'", name),
+        None => anyhow::anyhow!("No workspace stashes"),
+    })
+}
+
+fn resolve_stash_repos<'a>(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    stash: &'a WorkspaceStash,
+) -> anyhow::Result<Vec<(RepoInfo, &'a StashedRepo)>> {
+    let repos = workspace_repos(workspace_root, manifest);
+    stash
+        .repos
+        .iter()
+        .map(|entry| {
+            repos
+                .iter()
+                .find(|r| r.name == entry.repo_name)
+                .map(|repo| (repo.clone(), entry))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Cannot pop {}: repo '{}' is no longer in the manifest",
+                        stash.name,
+                        entry.repo_name
+                    )
+                })
+        })
+        .collect()
+}
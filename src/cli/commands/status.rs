@@ -5,8 +5,9 @@ use crate::core::gripspace::{get_gripspace_rev, gripspace_name};
 use crate::core::manifest::Manifest;
 use crate::core::manifest_paths;
 use crate::core::repo::{filter_repos, RepoInfo};
-use crate::git::path_exists;
+use crate::git::sparse::{is_sparse, sparse_matches, sparse_patterns};
 use crate::git::status::{get_repo_status, RepoStatus};
+use crate::git::{get_head_sha, open_repo, path_exists, resolve_revision};
 use std::path::PathBuf;
 
 /// JSON-serializable repo status for --json output
@@ -22,6 +23,15 @@ struct JsonRepoStatus {
     behind: usize,
     reference: bool,
     groups: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    revision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pin_drifted: Option<bool>,
+    /// Directories currently checked out, for sparse repos
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sparse: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sparse_drifted: Option<bool>,
 }
 
 /// Run the status command
@@ -48,28 +58,46 @@ pub fn run_status(
         .iter()
         .map(|repo| (get_repo_status(repo), repo))
         .collect();
+    let drifted: Vec<bool> = statuses
+        .iter()
+        .map(|(_, repo)| pin_drifted(repo) == Some(true))
+        .collect();
 
     // Count stats
     let total = statuses.len();
     let cloned = statuses.iter().filter(|(s, _)| s.exists).count();
     let with_changes = statuses.iter().filter(|(s, _)| !s.clean).count();
     let ahead_count = statuses.iter().filter(|(s, _)| s.ahead_main > 0).count();
+    let drifted_count = drifted.iter().filter(|d| **d).count();
 
-    // In quiet mode, only show repos with changes or not on default branch
-    let filtered_statuses: Vec<&(RepoStatus, &RepoInfo)> = if quiet {
+    // In quiet mode, only show repos with changes, not on default branch, or off their pin
+    let filtered_statuses: Vec<(&(RepoStatus, &RepoInfo), bool)> = if quiet {
         statuses
             .iter()
-            .filter(|(s, repo)| !s.clean || !s.exists || s.branch != repo.default_branch)
+            .zip(drifted.iter().copied())
+            .filter(|((s, repo), drifted)| {
+                !s.clean || !s.exists || s.branch != repo.default_branch || *drifted
+            })
             .collect()
     } else {
-        statuses.iter().collect()
+        statuses.iter().zip(drifted.iter().copied()).collect()
     };
 
     // Display table
     let mut table = Table::new(vec!["Repo", "Branch", "Status", "vs main"]);
 
-    for (status, repo) in &filtered_statuses {
-        let status_str = format_status(status, verbose);
+    for ((status, repo), drifted) in &filtered_statuses {
+        let mut status_str = format_status(status, verbose);
+        if *drifted {
+            if let Some(ref revision) = repo.revision {
+                status_str.push_str(&format!(" (drifted from pin '{}')", revision));
+            }
+        }
+        match sparse_status(repo) {
+            Some((_, false)) => status_str.push_str(" (sparse)"),
+            Some((_, true)) => status_str.push_str(" (sparse checkout differs from manifest)"),
+            None => {}
+        }
         let main_str = format_main_comparison(status, &repo.default_branch);
         // Add [ref] suffix for reference repos
         let repo_display = if repo.reference {
@@ -103,9 +131,11 @@ pub fn run_status(
             let manifest_repo_info = RepoInfo {
                 name: "manifest".to_string(),
                 url: String::new(),
+                push_url: None,
                 path: rel_path,
                 absolute_path: manifests_dir.clone(),
                 default_branch: "main".to_string(),
+                revision: None,
                 owner: String::new(),
                 repo: "manifests".to_string(),
                 platform_type: crate::core::manifest::PlatformType::GitHub,
@@ -114,6 +144,8 @@ pub fn run_status(
                 reference: false,
                 groups: Vec::new(),
                 agent: None,
+                clone: None,
+                sparse: Vec::new(),
             };
 
             let status = get_repo_status(&manifest_repo_info);
@@ -182,8 +214,8 @@ pub fn run_status(
     if quiet {
         // Machine-readable summary line
         println!(
-            "SUMMARY: repos={} cloned={} changes={} ahead={}",
-            total, cloned, with_changes, ahead_count
+            "SUMMARY: repos={} cloned={} changes={} ahead={} drifted={}",
+            total, cloned, with_changes, ahead_count, drifted_count
         );
     } else {
         let ahead_suffix = if ahead_count > 0 {
@@ -191,9 +223,14 @@ pub fn run_status(
         } else {
             String::new()
         };
+        let drifted_suffix = if drifted_count > 0 {
+            format!(" | {} drifted from pin", drifted_count)
+        } else {
+            String::new()
+        };
         println!(
-            "  {}/{} cloned | {} with changes{}",
-            cloned, total, with_changes, ahead_suffix
+            "  {}/{} cloned | {} with changes{}{}",
+            cloned, total, with_changes, ahead_suffix, drifted_suffix
         );
     }
 
@@ -212,6 +249,11 @@ fn run_status_json(
         .iter()
         .map(|repo| {
             let status = get_repo_status(repo);
+            let pin_drifted = pin_drifted(repo);
+            let (sparse, sparse_drifted) = match sparse_status(repo) {
+                Some((dirs, drifted)) => (dirs, Some(drifted)),
+                None => (Vec::new(), None),
+            };
             JsonRepoStatus {
                 name: status.name,
                 branch: status.branch,
@@ -223,6 +265,10 @@ fn run_status_json(
                 behind: status.behind_main,
                 reference: repo.reference,
                 groups: repo.groups.clone(),
+                revision: repo.revision.clone(),
+                pin_drifted,
+                sparse,
+                sparse_drifted,
             }
         })
         .collect();
@@ -231,6 +277,40 @@ fn run_status_json(
     Ok(())
 }
 
+/// Check whether a pinned repo's HEAD has moved away from its `revision`.
+///
+/// Returns `None` when the repo has no pin, is not cloned, or the pin cannot
+/// be resolved locally.
+fn pin_drifted(repo: &RepoInfo) -> Option<bool> {
+    let revision = repo.revision.as_deref()?;
+    if !path_exists(&repo.absolute_path) {
+        return None;
+    }
+    let git_repo = open_repo(&repo.absolute_path).ok()?;
+    let head = get_head_sha(&git_repo).ok()?;
+    let target = resolve_revision(&git_repo, revision).ok()?;
+    Some(head != target)
+}
+
+/// Sparse checkout state: the directories checked out and whether they differ
+/// from the manifest's `sparse` list.
+///
+/// Returns `None` when the repo is not cloned, or is neither sparse on disk
+/// nor in the manifest. Status itself comes from the git CLI, which already
+/// ignores paths outside the sparse checkout.
+fn sparse_status(repo: &RepoInfo) -> Option<(Vec<String>, bool)> {
+    if !path_exists(&repo.absolute_path) {
+        return None;
+    }
+    let git_repo = open_repo(&repo.absolute_path).ok()?;
+    if !is_sparse(&git_repo) && repo.sparse.is_empty() {
+        return None;
+    }
+    let current = sparse_patterns(&git_repo).ok()?;
+    let drifted = !sparse_matches(&git_repo, &repo.sparse).ok()?;
+    Some((current, drifted))
+}
+
 /// Format the vs main comparison column
 fn format_main_comparison(status: &RepoStatus, default_branch: &str) -> String {
     // On default branch - no comparison needed
@@ -3,22 +3,31 @@
 use crate::cli::commands::link::apply_links;
 use crate::cli::output::Output;
 use crate::core::gripspace::{
-    ensure_gripspace, gripspace_name, resolve_all_gripspaces, update_gripspace,
+    ensure_gripspace, gripspace_name, resolve_all_gripspaces_locked, update_gripspace,
 };
 use crate::core::griptree::GriptreeConfig;
-use crate::core::manifest::{HookCondition, Manifest};
+use crate::core::lockfile::Lockfile;
+use crate::core::manifest::{CloneConfig, GripspaceConfig, HookCondition, Manifest};
 use crate::core::manifest_paths;
 use crate::core::repo::{filter_repos, get_manifest_repo_info, RepoInfo};
 use crate::files::process_composefiles;
-use crate::git::branch::{checkout_branch_at_upstream, checkout_detached, has_commits_ahead};
+use crate::git::branch::{
+    checkout_branch_at_upstream, checkout_detached, has_commits_ahead, remote_branch_exists,
+};
+use crate::git::mirror::{fetch_from_mirror, find_mirror, update_mirror};
 use crate::git::remote::{
     fetch_remote, pull_latest_from_upstream, reset_hard, safe_pull_latest, set_branch_upstream_ref,
+    set_remote_push_url, unshallow,
 };
+use crate::git::sparse::{disable_sparse_checkout, set_sparse_checkout, sparse_matches};
 use crate::git::status::has_uncommitted_changes;
-use crate::git::{clone_repo, get_current_branch, open_repo, path_exists};
+use crate::git::{
+    clone_repo_with_options, get_current_branch, get_head_sha, open_repo, path_exists,
+    resolve_revision,
+};
 use git2::Repository;
 use indicatif::ProgressBar;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use tokio::task::JoinSet;
@@ -52,21 +61,90 @@ struct HookResult {
     error: Option<String>,
 }
 
+/// Options for the sync command
+pub struct SyncOptions<'a> {
+    pub workspace_root: &'a PathBuf,
+    pub manifest: &'a Manifest,
+    pub force: bool,
+    pub quiet: bool,
+    pub group_filter: Option<&'a [String]>,
+    pub sequential: bool,
+    pub reset_refs: bool,
+    pub json: bool,
+    pub no_hooks: bool,
+    /// Check out the exact commits recorded in gripspace.lock
+    pub locked: bool,
+    /// Clone new repos with this history depth (overrides `clone.depth`)
+    pub depth: Option<u32>,
+    /// Partial clone filter for new repos (overrides `clone.filter`)
+    pub filter: Option<&'a str>,
+    /// Fetch full history for shallow clones, and clone new repos in full
+    pub unshallow: bool,
+}
+
+/// Clone overrides from `gr sync --depth/--filter/--unshallow`
+#[derive(Debug, Clone, Default)]
+struct CloneOverrides {
+    depth: Option<u32>,
+    filter: Option<String>,
+    unshallow: bool,
+}
+
+impl CloneOverrides {
+    /// Effective clone options for a repo: flags win over the manifest's `clone`
+    fn clone_config(&self, repo: &RepoInfo) -> CloneConfig {
+        let mut config = repo.clone.clone().unwrap_or_default();
+        if self.depth.is_some() {
+            config.depth = self.depth;
+        }
+        if self.filter.is_some() {
+            config.filter = self.filter.clone();
+        }
+        if self.unshallow {
+            config.depth = None;
+        }
+        config
+    }
+}
+
 /// Run the sync command
-#[allow(clippy::too_many_arguments)]
-pub async fn run_sync(
-    workspace_root: &PathBuf,
-    manifest: &Manifest,
-    force: bool,
-    quiet: bool,
-    group_filter: Option<&[String]>,
-    sequential: bool,
-    reset_refs: bool,
-    json: bool,
-    no_hooks: bool,
-) -> anyhow::Result<()> {
+pub async fn run_sync(opts: SyncOptions<'_>) -> anyhow::Result<()> {
+    let SyncOptions {
+        workspace_root,
+        manifest,
+        force,
+        quiet,
+        group_filter,
+        sequential,
+        reset_refs,
+        json,
+        no_hooks,
+        locked,
+        depth,
+        filter,
+        unshallow,
+    } = opts;
+    let clone_overrides = CloneOverrides {
+        depth,
+        filter: filter.map(String::from),
+        unshallow,
+    };
+
+    if let Some(filter) = filter {
+        if filter.is_empty() || filter.starts_with('-') || filter.contains(char::is_whitespace) {
+            anyhow::bail!("Invalid --filter '{}'", filter);
+        }
+    }
+
+    // Load the lockfile up front so a missing lock fails before anything changes
+    let lockfile = if locked {
+        Some(Lockfile::load_from_workspace(workspace_root)?)
+    } else {
+        None
+    };
+
     // Re-load and resolve gripspaces before syncing repos
-    let manifest = sync_gripspaces(workspace_root, manifest, quiet)?;
+    let manifest = sync_gripspaces(workspace_root, manifest, quiet, lockfile.as_ref())?;
     let manifest = &manifest;
 
     let mut repos: Vec<RepoInfo> = filter_repos(manifest, workspace_root, None, group_filter, true);
@@ -77,6 +155,10 @@ pub async fn run_sync(
     }
     let griptree_config = GriptreeConfig::load_from_workspace(workspace_root)?;
     let griptree_branch = griptree_config.as_ref().map(|cfg| cfg.branch.clone());
+    let lock_targets = lockfile
+        .as_ref()
+        .map(|l| lock_targets(manifest, l))
+        .unwrap_or_default();
 
     if !json {
         Output::header(&format!("Syncing {} repositories...", repos.len()));
@@ -91,6 +173,8 @@ pub async fn run_sync(
             griptree_config.as_ref(),
             griptree_branch.as_deref(),
             reset_refs,
+            &lock_targets,
+            &clone_overrides,
         )?
     } else {
         sync_parallel(
@@ -100,6 +184,8 @@ pub async fn run_sync(
             griptree_config.clone(),
             griptree_branch.clone(),
             reset_refs,
+            &lock_targets,
+            &clone_overrides,
         )
         .await?
     };
@@ -115,6 +201,8 @@ pub async fn run_sync(
     for result in &results {
         let action = if result.was_cloned {
             "cloned"
+        } else if result.success && locked {
+            "locked"
         } else if result.success {
             "pulled"
         } else {
@@ -249,10 +337,14 @@ pub async fn run_sync(
 }
 
 /// Sync gripspaces: update existing or clone new ones, then resolve merged manifest.
+///
+/// With a lockfile, each gripspace is pinned to its locked SHA instead of
+/// pulling its configured `rev`.
 fn sync_gripspaces(
     workspace_root: &PathBuf,
     manifest: &Manifest,
     quiet: bool,
+    lockfile: Option<&Lockfile>,
 ) -> anyhow::Result<Manifest> {
     let gripspaces = match &manifest.gripspaces {
         Some(gs) if !gs.is_empty() => gs,
@@ -267,6 +359,7 @@ fn sync_gripspaces(
     }
 
     for gs_config in gripspaces {
+        let gs_config = &pin_gripspace(gs_config, lockfile);
         let name = gripspace_name(&gs_config.url);
         // Use resolve_space_name to find the actual directory (handles reserved names)
         let dir_name = match crate::core::gripspace::resolve_space_name(&gs_config.url, &spaces_dir)
@@ -319,7 +412,7 @@ fn sync_gripspaces(
         manifest.clone()
     };
 
-    if let Err(e) = resolve_all_gripspaces(&mut resolved, &spaces_dir) {
+    if let Err(e) = resolve_all_gripspaces_locked(&mut resolved, &spaces_dir, lockfile) {
         Output::warning(&format!("Gripspace resolution failed: {}", e));
         return Ok(manifest.clone());
     }
@@ -336,7 +429,13 @@ Using the pre-sync manifest; check gripspace manifests/includes.",
     Ok(resolved)
 }
 
+/// Override a gripspace's `rev` with its locked SHA, if the lockfile has one.
+fn pin_gripspace(config: &GripspaceConfig, lockfile: Option<&Lockfile>) -> GripspaceConfig {
+    lockfile.map_or_else(|| config.clone(), |l| l.pin_gripspace(config))
+}
+
 /// Sync repos sequentially (original behavior)
+#[allow(clippy::too_many_arguments)]
 fn sync_sequential(
     repos: &[RepoInfo],
     force: bool,
@@ -344,6 +443,8 @@ fn sync_sequential(
     griptree_config: Option<&GriptreeConfig>,
     griptree_branch: Option<&str>,
     reset_refs: bool,
+    lock_targets: &HashMap<String, LockTarget>,
+    clone_overrides: &CloneOverrides,
 ) -> anyhow::Result<Vec<SyncResult>> {
     let mut results = Vec::new();
 
@@ -356,6 +457,8 @@ fn sync_sequential(
             griptree_config,
             griptree_branch,
             reset_refs,
+            lock_targets.get(&repo.name).cloned(),
+            clone_overrides,
         )?;
         results.push(result);
     }
@@ -365,6 +468,7 @@ fn sync_sequential(
 
 /// Sync repos in parallel using tokio
 #[allow(clippy::unnecessary_to_owned)] // We need to clone for move into spawn_blocking
+#[allow(clippy::too_many_arguments)]
 async fn sync_parallel(
     repos: &[RepoInfo],
     force: bool,
@@ -372,6 +476,8 @@ async fn sync_parallel(
     griptree_config: Option<GriptreeConfig>,
     griptree_branch: Option<String>,
     reset_refs: bool,
+    lock_targets: &HashMap<String, LockTarget>,
+    clone_overrides: &CloneOverrides,
 ) -> anyhow::Result<Vec<SyncResult>> {
     let results: Arc<Mutex<Vec<SyncResult>>> = Arc::new(Mutex::new(Vec::new()));
     let mut join_set: JoinSet<anyhow::Result<()>> = JoinSet::new();
@@ -383,6 +489,8 @@ async fn sync_parallel(
         let results = Arc::clone(&results);
         let griptree_config = griptree_config.clone();
         let griptree_branch = griptree_branch.clone();
+        let lock_target = lock_targets.get(&repo.name).cloned();
+        let clone_overrides = clone_overrides.clone();
 
         join_set.spawn_blocking(move || {
             let result = sync_single_repo(
@@ -393,6 +501,8 @@ async fn sync_parallel(
                 griptree_config.as_ref(),
                 griptree_branch.as_deref(),
                 reset_refs,
+                lock_target,
+                &clone_overrides,
             )?;
             results.lock().expect("mutex poisoned").push(result);
             Ok(())
@@ -426,6 +536,251 @@ async fn sync_parallel(
     Ok(results)
 }
 
+/// What a repo should be synced to in `--locked` mode.
+#[derive(Debug, Clone, PartialEq)]
+enum LockTarget {
+    /// Check out this exact commit
+    Sha(String),
+    /// The repo has no entry in the lockfile
+    Missing,
+}
+
+/// Build the lock target for every manifest repo.
+///
+/// The manifest repo itself is never locked: it holds the lockfile.
+fn lock_targets(manifest: &Manifest, lockfile: &Lockfile) -> HashMap<String, LockTarget> {
+    manifest
+        .repos
+        .keys()
+        .map(|name| {
+            let target = match lockfile.repo_sha(name) {
+                Some(sha) => LockTarget::Sha(sha.to_string()),
+                None => LockTarget::Missing,
+            };
+            (name.clone(), target)
+        })
+        .collect()
+}
+
+/// Check out the locked commit in detached HEAD mode.
+///
+/// Fetches first when the commit is not available locally. Refuses to
+/// discard uncommitted changes unless `force` is set.
+fn sync_locked(
+    repo: &RepoInfo,
+    git_repo: &Repository,
+    sha: &str,
+    force: bool,
+    spinner: Option<&ProgressBar>,
+    quiet: bool,
+) -> SyncResult {
+    let short = &sha[..sha.len().min(7)];
+    let finish = |success: bool, message: String, had_changes: bool| {
+        if let Some(s) = spinner {
+            if !quiet || !success {
+                s.finish_with_message(format!("{}: {}", repo.name, message));
+            } else {
+                s.finish_and_clear();
+            }
+        }
+        SyncResult {
+            name: repo.name.clone(),
+            success,
+            message,
+            was_cloned: false,
+            had_changes,
+        }
+    };
+
+    if get_head_sha(git_repo).ok().as_deref() == Some(sha) {
+        return finish(true, format!("up to date (locked at {})", short), false);
+    }
+
+    if !force {
+        if let Ok(true) = has_uncommitted_changes(git_repo) {
+            return finish(
+                false,
+                "has uncommitted changes; commit, stash, or use --force".to_string(),
+                false,
+            );
+        }
+    }
+
+    let oid_available = || {
+        git2::Oid::from_str(sha)
+            .ok()
+            .and_then(|oid| git_repo.find_commit(oid).ok())
+            .is_some()
+    };
+    if !oid_available() {
+        if let Err(e) = fetch_remote(git_repo, "origin") {
+            return finish(false, format!("error - {}", e), false);
+        }
+        // A shallow clone may not reach back to the locked commit
+        if !oid_available() && git_repo.is_shallow() {
+            if let Err(e) = unshallow(git_repo, "origin") {
+                return finish(false, format!("error - {}", e), false);
+            }
+        }
+    }
+
+    match checkout_detached(git_repo, sha) {
+        Ok(()) => finish(true, format!("locked ({})", short), true),
+        Err(e) => finish(
+            false,
+            format!("error - cannot check out locked commit {}: {}", short, e),
+            false,
+        ),
+    }
+}
+
+/// Sync a repo that pins a `revision` in the manifest.
+///
+/// Branch pins are checked out on a local tracking branch and pulled; tag and
+/// SHA pins are checked out in detached HEAD mode. Repos on a feature branch
+/// are left alone, and uncommitted changes are never discarded unless `force`
+/// is set.
+fn sync_pinned(
+    repo: &RepoInfo,
+    git_repo: &Repository,
+    revision: &str,
+    force: bool,
+    spinner: Option<&ProgressBar>,
+    quiet: bool,
+) -> SyncResult {
+    let finish = |success: bool, message: String, had_changes: bool| {
+        if let Some(s) = spinner {
+            if !quiet || !success {
+                s.finish_with_message(format!("{}: {}", repo.name, message));
+            } else {
+                s.finish_and_clear();
+            }
+        }
+        SyncResult {
+            name: repo.name.clone(),
+            success,
+            message,
+            was_cloned: false,
+            had_changes,
+        }
+    };
+
+    if let Err(e) = fetch_remote(git_repo, "origin") {
+        return finish(false, format!("error - {}", e), false);
+    }
+
+    let current_branch = get_current_branch(git_repo).ok();
+    let detached = current_branch
+        .as_deref()
+        .map(|b| b.starts_with("(HEAD detached"))
+        .unwrap_or(true);
+    let on_default = current_branch.as_deref() == Some(repo.default_branch.as_str());
+
+    if remote_branch_exists(git_repo, revision, "origin") {
+        if current_branch.as_deref() == Some(revision) {
+            return match safe_pull_latest(git_repo, revision, "origin") {
+                Ok(pull) if pull.pulled => finish(
+                    true,
+                    pull.message.unwrap_or_else(|| "pulled".to_string()),
+                    true,
+                ),
+                Ok(pull) => finish(
+                    true,
+                    pull.message
+                        .unwrap_or_else(|| format!("up to date (pinned to {})", revision)),
+                    false,
+                ),
+                Err(e) => finish(false, format!("error - {}", e), false),
+            };
+        }
+
+        if !detached && !on_default {
+            return finish(
+                true,
+                format!(
+                    "skipped - on branch '{}' (pinned to {})",
+                    current_branch.unwrap_or_default(),
+                    revision
+                ),
+                false,
+            );
+        }
+
+        if !force {
+            if let Ok(true) = has_uncommitted_changes(git_repo) {
+                return finish(
+                    false,
+                    "has uncommitted changes; commit, stash, or use --force".to_string(),
+                    false,
+                );
+            }
+        }
+
+        return match checkout_branch_at_upstream(
+            git_repo,
+            revision,
+            &format!("origin/{}", revision),
+        ) {
+            Ok(()) => finish(
+                true,
+                format!("checked out pinned branch {}", revision),
+                true,
+            ),
+            Err(e) => finish(false, format!("error - {}", e), false),
+        };
+    }
+
+    // A shallow clone may not reach back to the pinned tag or SHA
+    let resolved = match resolve_revision(git_repo, revision) {
+        Err(_) if git_repo.is_shallow() => {
+            unshallow(git_repo, "origin").and_then(|()| resolve_revision(git_repo, revision))
+        }
+        resolved => resolved,
+    };
+    let target = match resolved {
+        Ok(sha) => sha,
+        Err(e) => return finish(false, format!("error - {}", e), false),
+    };
+
+    if get_head_sha(git_repo).ok().as_deref() == Some(target.as_str()) {
+        return finish(true, format!("up to date (pinned at {})", revision), false);
+    }
+
+    if !detached && !on_default {
+        return finish(
+            true,
+            format!(
+                "skipped - on branch '{}' (pinned at {})",
+                current_branch.unwrap_or_default(),
+                revision
+            ),
+            false,
+        );
+    }
+
+    if !force {
+        if let Ok(true) = has_uncommitted_changes(git_repo) {
+            return finish(
+                false,
+                "has uncommitted changes; commit, stash, or use --force".to_string(),
+                false,
+            );
+        }
+    }
+
+    match checkout_detached(git_repo, &target) {
+        Ok(()) => finish(true, format!("pinned at {}", revision), true),
+        Err(e) => finish(
+            false,
+            format!(
+                "error - cannot check out pinned revision {}: {}",
+                revision, e
+            ),
+            false,
+        ),
+    }
+}
+
 fn sync_griptree_upstream(
     repo: &RepoInfo,
     git_repo: &Repository,
@@ -693,7 +1048,51 @@ fn is_worktree_branch_lock_error(message: &str) -> bool {
         || message.contains("already used by worktree")
 }
 
+/// Bring a repo's sparse checkout in line with the manifest's `sparse` list.
+///
+/// Dropping `sparse` from the manifest restores the full checkout.
+fn sync_sparse_checkout(
+    repo: &RepoInfo,
+    git_repo: &Repository,
+    quiet: bool,
+) -> Result<(), crate::git::GitError> {
+    if sparse_matches(git_repo, &repo.sparse)? {
+        return Ok(());
+    }
+    if repo.sparse.is_empty() {
+        disable_sparse_checkout(git_repo)?;
+        if !quiet {
+            Output::info(&format!("{}: sparse checkout disabled", repo.name));
+        }
+    } else {
+        set_sparse_checkout(git_repo, &repo.sparse)?;
+        if !quiet {
+            Output::info(&format!(
+                "{}: sparse checkout set to {}",
+                repo.name,
+                repo.sparse.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Refresh the repo's shared object mirror, if it has one (see `gr mirror`).
+///
+/// A failed refresh is not fatal: the repo still syncs from its remote.
+fn refresh_mirror(repo: &RepoInfo) -> Option<PathBuf> {
+    let mirror = find_mirror(&repo.url)?;
+    match update_mirror(&mirror) {
+        Ok(()) => Some(mirror),
+        Err(e) => {
+            Output::warning(&format!("{}: mirror refresh failed: {}", repo.name, e));
+            None
+        }
+    }
+}
+
 /// Sync a single repository
+#[allow(clippy::too_many_arguments)]
 fn sync_single_repo(
     repo: &RepoInfo,
     force: bool,
@@ -702,6 +1101,8 @@ fn sync_single_repo(
     griptree_config: Option<&GriptreeConfig>,
     griptree_branch: Option<&str>,
     reset_refs: bool,
+    lock_target: Option<LockTarget>,
+    clone_overrides: &CloneOverrides,
 ) -> anyhow::Result<SyncResult> {
     let spinner = if show_spinner {
         Some(Output::spinner(&format!("Pulling {}...", repo.name)))
@@ -709,14 +1110,73 @@ fn sync_single_repo(
         None
     };
 
+    if let Some(LockTarget::Missing) = lock_target {
+        let msg = "error - not in gripspace.lock (run 'gr manifest lock')".to_string();
+        if let Some(s) = spinner {
+            s.finish_with_message(format!("{}: {}", repo.name, msg));
+        }
+        return Ok(SyncResult {
+            name: repo.name.clone(),
+            success: false,
+            message: msg,
+            was_cloned: false,
+            had_changes: false,
+        });
+    }
+    let locked_sha = match lock_target {
+        Some(LockTarget::Sha(sha)) => Some(sha),
+        _ => None,
+    };
+
+    // Refresh the shared mirror first; clones and fetches then read from disk
+    let mirror = refresh_mirror(repo);
+
     if !path_exists(&repo.absolute_path) {
         // Clone the repo
         if let Some(ref s) = spinner {
             s.set_message(format!("Cloning {}...", repo.name));
         }
 
-        match clone_repo(&repo.url, &repo.absolute_path, Some(&repo.default_branch)) {
-            Ok(_) => {
+        match clone_repo_with_options(
+            &repo.url,
+            &repo.absolute_path,
+            Some(&repo.default_branch),
+            &clone_overrides.clone_config(repo),
+            &repo.sparse,
+        ) {
+            Ok(cloned) => {
+                if let Some(ref push_url) = repo.push_url {
+                    set_remote_push_url(&cloned, "origin", push_url)?;
+                }
+
+                if let Some(ref sha) = locked_sha {
+                    let git_repo = open_repo(&repo.absolute_path)?;
+                    let mut result = sync_locked(repo, &git_repo, sha, force, None, quiet);
+                    if result.success {
+                        result.message = format!("cloned, {}", result.message);
+                        result.was_cloned = true;
+                        result.had_changes = true;
+                    }
+                    if let Some(s) = spinner {
+                        s.finish_with_message(format!("{}: {}", repo.name, result.message));
+                    }
+                    return Ok(result);
+                }
+
+                if let Some(ref revision) = repo.revision {
+                    let git_repo = open_repo(&repo.absolute_path)?;
+                    let mut result = sync_pinned(repo, &git_repo, revision, force, None, quiet);
+                    if result.success {
+                        result.message = format!("cloned, {}", result.message);
+                        result.was_cloned = true;
+                        result.had_changes = true;
+                    }
+                    if let Some(s) = spinner {
+                        s.finish_with_message(format!("{}: {}", repo.name, result.message));
+                    }
+                    return Ok(result);
+                }
+
                 // Check actual branch after clone
                 let clone_msg = if let Ok(git_repo) = open_repo(&repo.absolute_path) {
                     if let Ok(actual_branch) = get_current_branch(&git_repo) {
@@ -766,6 +1226,67 @@ fn sync_single_repo(
     // Pull existing repo
     match open_repo(&repo.absolute_path) {
         Ok(git_repo) => {
+            if clone_overrides.unshallow && git_repo.is_shallow() {
+                if let Some(ref s) = spinner {
+                    s.set_message(format!("Unshallowing {}...", repo.name));
+                }
+                if let Err(e) = unshallow(&git_repo, "origin") {
+                    let msg = format!("error - unshallow failed: {}", e);
+                    if let Some(s) = spinner {
+                        s.finish_with_message(format!("{}: {}", repo.name, msg));
+                    }
+                    return Ok(SyncResult {
+                        name: repo.name.clone(),
+                        success: false,
+                        message: msg,
+                        was_cloned: false,
+                        had_changes: false,
+                    });
+                }
+            }
+
+            if let Some(ref push_url) = repo.push_url {
+                let current = git_repo
+                    .find_remote("origin")
+                    .ok()
+                    .and_then(|r| r.pushurl().map(str::to_string));
+                if current.as_deref() != Some(push_url.as_str()) {
+                    if let Err(e) = set_remote_push_url(&git_repo, "origin", push_url) {
+                        Output::warning(&format!("{}: failed to set push URL: {}", repo.name, e));
+                    }
+                }
+            }
+
+            if let Some(ref mirror) = mirror {
+                if let Err(e) = fetch_from_mirror(&git_repo, mirror) {
+                    Output::warning(&format!("{}: fetch from mirror failed: {}", repo.name, e));
+                }
+            }
+
+            if let Err(e) = sync_sparse_checkout(repo, &git_repo, quiet) {
+                let msg = format!("error - sparse checkout: {}", e);
+                if let Some(s) = spinner {
+                    s.finish_with_message(format!("{}: {}", repo.name, msg));
+                }
+                return Ok(SyncResult {
+                    name: repo.name.clone(),
+                    success: false,
+                    message: msg,
+                    was_cloned: false,
+                    had_changes: false,
+                });
+            }
+
+            if let Some(ref sha) = locked_sha {
+                let result = sync_locked(repo, &git_repo, sha, force, spinner.as_ref(), quiet);
+                return Ok(result);
+            }
+
+            if let Some(ref revision) = repo.revision {
+                let result = sync_pinned(repo, &git_repo, revision, force, spinner.as_ref(), quiet);
+                return Ok(result);
+            }
+
             if repo.reference && reset_refs {
                 let result =
                     sync_reference_reset(repo, &git_repo, griptree_config, spinner.as_ref(), quiet);
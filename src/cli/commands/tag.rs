@@ -0,0 +1,219 @@
+//! Tag command implementation
+//!
+//! Creates, lists and deletes tags across repos, e.g. to mark a whole
+//! platform snapshot for QA.
+
+use crate::cli::output::Output;
+use crate::core::manifest::Manifest;
+use crate::core::repo::{filter_repos, get_manifest_repo_info, RepoInfo};
+use crate::git::path_exists;
+use crate::git::tag::{create_tag, delete_remote_tag, delete_tag, list_tags, push_tag, tag_exists};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Cloned repos selected by `--repo`/`--group`; the manifest repo is
+/// included when no filter is given
+fn selected_repos(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repos_filter: Option<&[String]>,
+    group_filter: Option<&[String]>,
+) -> Vec<RepoInfo> {
+    let mut repos = filter_repos(manifest, workspace_root, repos_filter, group_filter, false);
+    if repos_filter.is_none() && group_filter.is_none() {
+        if let Some(manifest_repo) = get_manifest_repo_info(manifest, workspace_root) {
+            repos.push(manifest_repo);
+        }
+    }
+    repos.retain(|r| {
+        let cloned = path_exists(&r.absolute_path);
+        if !cloned {
+            Output::warning(&format!("{}: not cloned, skipping", r.name));
+        }
+        cloned
+    });
+    repos
+}
+
+/// Run `gr tag <name>`: create an annotated tag at `HEAD` in every repo
+///
+/// Nothing is tagged if the tag already exists in any repo; if creating it
+/// fails part way, the tags already created are deleted again.
+#[allow(clippy::too_many_arguments)]
+pub fn run_tag_create(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    name: &str,
+    message: Option<&str>,
+    sign: bool,
+    push: bool,
+    repos_filter: Option<&[String]>,
+    group_filter: Option<&[String]>,
+) -> anyhow::Result<()> {
+    let repos = selected_repos(workspace_root, manifest, repos_filter, group_filter);
+
+    let existing: Vec<&str> = repos
+        .iter()
+        .filter(|r| tag_exists(&r.absolute_path, name))
+        .map(|r| r.name.as_str())
+        .collect();
+    if !existing.is_empty() {
+        anyhow::bail!("Tag '{}' already exists in: {}", name, existing.join(", "));
+    }
+
+    Output::header(&format!("Tagging {} repo(s) as {}...", repos.len(), name));
+    println!();
+
+    let message = message.unwrap_or(name);
+    let mut created: Vec<&RepoInfo> = Vec::new();
+    for repo in &repos {
+        if let Err(e) = create_tag(&repo.absolute_path, name, message, sign) {
+            Output::error(&format!("{}: {}", repo.name, e));
+            for repo in &created {
+                if let Err(e) = delete_tag(&repo.absolute_path, name) {
+                    Output::error(&format!("{}: failed to roll back - {}", repo.name, e));
+                }
+            }
+            anyhow::bail!("Tagging aborted; no repo was tagged");
+        }
+        created.push(repo);
+        Output::success(&format!("{}: tagged", repo.name));
+    }
+
+    if push {
+        let mut failed = 0;
+        for repo in &created {
+            let spinner = Output::spinner(&format!("Pushing {} in {}...", name, repo.name));
+            match push_tag(&repo.absolute_path, "origin", name) {
+                Ok(()) => spinner.finish_with_message(format!("{}: pushed", repo.name)),
+                Err(e) => {
+                    failed += 1;
+                    spinner.finish_with_message(format!("{}: push failed - {}", repo.name, e));
+                }
+            }
+        }
+        if failed > 0 {
+            anyhow::bail!("Failed to push tag '{}' in {} repo(s)", name, failed);
+        }
+    }
+
+    println!();
+    Output::success(&format!("Tagged {} repo(s) as {}", created.len(), name));
+    Ok(())
+}
+
+/// Run `gr tag -d <name>`: delete a tag in every repo that has it
+pub fn run_tag_delete(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    name: &str,
+    push: bool,
+    repos_filter: Option<&[String]>,
+    group_filter: Option<&[String]>,
+) -> anyhow::Result<()> {
+    let repos = selected_repos(workspace_root, manifest, repos_filter, group_filter);
+
+    let mut deleted = 0;
+    let mut failed = 0;
+    for repo in &repos {
+        if !tag_exists(&repo.absolute_path, name) {
+            continue;
+        }
+        match delete_tag(&repo.absolute_path, name) {
+            Ok(()) => {
+                deleted += 1;
+                Output::success(&format!("{}: deleted", repo.name));
+            }
+            Err(e) => {
+                failed += 1;
+                Output::error(&format!("{}: {}", repo.name, e));
+                continue;
+            }
+        }
+        if push {
+            if let Err(e) = delete_remote_tag(&repo.absolute_path, "origin", name) {
+                failed += 1;
+                Output::error(&format!("{}: remote delete failed - {}", repo.name, e));
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("Failed to delete tag '{}' in {} repo(s)", name, failed);
+    }
+    if deleted == 0 {
+        Output::info(&format!("Tag '{}' not found in any repo.", name));
+    } else {
+        Output::success(&format!("Deleted {} from {} repo(s)", name, deleted));
+    }
+    Ok(())
+}
+
+/// JSON-serializable tag for --json output
+#[derive(serde::Serialize)]
+struct JsonTag {
+    name: String,
+    repos: Vec<String>,
+    missing: Vec<String>,
+}
+
+/// Run `gr tag` without a name: list tags and the repos missing them
+pub fn run_tag_list(
+    workspace_root: &PathBuf,
+    manifest: &Manifest,
+    repos_filter: Option<&[String]>,
+    group_filter: Option<&[String]>,
+    json: bool,
+) -> anyhow::Result<()> {
+    let repos = selected_repos(workspace_root, manifest, repos_filter, group_filter);
+
+    let mut tags: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for repo in &repos {
+        match list_tags(&repo.absolute_path) {
+            Ok(names) => {
+                for tag in names {
+                    tags.entry(tag).or_default().push(repo.name.clone());
+                }
+            }
+            Err(e) => Output::warning(&format!("{}: {}", repo.name, e)),
+        }
+    }
+
+    let entries: Vec<JsonTag> = tags
+        .into_iter()
+        .map(|(name, present)| JsonTag {
+            missing: repos
+                .iter()
+                .filter(|r| !present.contains(&r.name))
+                .map(|r| r.name.clone())
+                .collect(),
+            name,
+            repos: present,
+        })
+        .collect();
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("No tags.");
+        return Ok(());
+    }
+
+    for tag in &entries {
+        if tag.missing.is_empty() {
+            println!("  {} (all repos)", tag.name);
+        } else {
+            println!(
+                "  {} ({}/{} repos, missing in: {})",
+                tag.name,
+                tag.repos.len(),
+                repos.len(),
+                tag.missing.join(", ")
+            );
+        }
+    }
+    Ok(())
+}
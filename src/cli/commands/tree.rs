@@ -12,13 +12,12 @@ use crate::git::branch::{
     branch_exists, checkout_branch, delete_local_branch, remote_branch_exists,
 };
 use crate::git::remote::{delete_remote_branch, get_upstream_branch, set_branch_upstream_ref};
+use crate::git::stash::{stash_pop, stash_push};
 use crate::git::status::get_cached_status;
 use crate::git::{get_current_branch, open_repo, path_exists};
-use crate::util::log_cmd;
 use chrono::Utc;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::process::Command;
 
 /// Griptrees list file structure
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -114,7 +113,9 @@ pub fn run_tree_add(
     let repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .collect();
 
     let mut success_count = 0;
@@ -474,9 +475,9 @@ pub async fn run_tree_return(
             if !dirty_repos.contains(&repo.name) || !repo.exists() {
                 continue;
             }
-            match stash_repo(&repo.absolute_path, "gr tree return") {
-                Ok(true) => stashed_repos.push(repo.absolute_path.clone()),
-                Ok(false) => {}
+            match stash_push(&repo.absolute_path, "gr tree return") {
+                Ok(Some(_)) => stashed_repos.push(repo.absolute_path.clone()),
+                Ok(None) => {}
                 Err(e) => Output::error(&format!("{}: stash failed - {}", repo.name, e)),
             }
         }
@@ -514,17 +515,21 @@ pub async fn run_tree_return(
 
     if !no_sync {
         println!();
-        let _ = crate::cli::commands::sync::run_sync(
+        let _ = crate::cli::commands::sync::run_sync(crate::cli::commands::sync::SyncOptions {
             workspace_root,
             manifest,
-            false,
-            false,
-            None,
-            false,
-            false,
-            false,
-            false,
-        )
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
         .await;
     }
 
@@ -591,7 +596,7 @@ pub async fn run_tree_return(
     if autostash && !stashed_repos.is_empty() {
         println!();
         for repo_path in &stashed_repos {
-            if let Err(e) = stash_pop_repo(repo_path) {
+            if let Err(e) = stash_pop(repo_path) {
                 Output::warning(&format!(
                     "{}: stash pop failed - {}",
                     repo_path.display(),
@@ -984,36 +989,3 @@ fn sync_repo_with_upstream(repo_path: &PathBuf, default_branch: &str) -> anyhow:
 
     Ok(())
 }
-
-fn stash_repo(repo_path: &PathBuf, message: &str) -> anyhow::Result<bool> {
-    let mut cmd = Command::new("git");
-    cmd.args(["stash", "push", "-u", "-m", message])
-        .current_dir(repo_path);
-    log_cmd(&cmd);
-    let output = cmd.output()?;
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-
-    if !output.status.success() {
-        return Err(anyhow::anyhow!("git stash failed: {}", stderr.trim()));
-    }
-
-    let combined = format!("{}{}", stdout, stderr);
-    if combined.contains("No local changes to save") {
-        return Ok(false);
-    }
-
-    Ok(true)
-}
-
-fn stash_pop_repo(repo_path: &PathBuf) -> anyhow::Result<()> {
-    let mut cmd = Command::new("git");
-    cmd.args(["stash", "pop"]).current_dir(repo_path);
-    log_cmd(&cmd);
-    let output = cmd.output()?;
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!("git stash pop failed: {}", stderr.trim()));
-    }
-    Ok(())
-}
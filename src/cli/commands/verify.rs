@@ -5,11 +5,13 @@
 //! and puts pass/fail in the JSON body.
 
 use crate::cli::output::Output;
+use crate::core::lockfile::Lockfile;
 use crate::core::manifest::Manifest;
+use crate::core::manifest_paths;
 use crate::core::repo::{filter_repos, RepoInfo};
-use crate::git::path_exists;
 use crate::git::status::get_repo_status;
-use std::path::PathBuf;
+use crate::git::{get_head_sha, open_repo, path_exists};
+use std::path::{Path, PathBuf};
 
 /// JSON-serializable verification result
 #[derive(serde::Serialize)]
@@ -37,11 +39,13 @@ pub struct VerifyOptions<'a> {
     pub links: bool,
     pub on_branch: Option<&'a str>,
     pub synced: bool,
+    pub locked: bool,
 }
 
 /// Run the verify command
 pub fn run_verify(opts: VerifyOptions) -> anyhow::Result<()> {
-    let has_any_check = opts.clean || opts.links || opts.on_branch.is_some() || opts.synced;
+    let has_any_check =
+        opts.clean || opts.links || opts.on_branch.is_some() || opts.synced || opts.locked;
 
     if !has_any_check {
         if opts.json {
@@ -51,7 +55,7 @@ pub fn run_verify(opts: VerifyOptions) -> anyhow::Result<()> {
                     name: "no-checks".to_string(),
                     pass: false,
                     details: vec![serde_json::json!({
-                        "error": "No verification flags provided. Use --clean, --links, --on-branch, or --synced."
+                        "error": "No verification flags provided. Use --clean, --links, --on-branch, --synced, or --locked."
                     })],
                 }],
             };
@@ -59,7 +63,7 @@ pub fn run_verify(opts: VerifyOptions) -> anyhow::Result<()> {
             return Ok(());
         }
         anyhow::bail!(
-            "No verification flags provided. Use --clean, --links, --on-branch, or --synced."
+            "No verification flags provided. Use --clean, --links, --on-branch, --synced, or --locked."
         );
     }
 
@@ -89,6 +93,10 @@ pub fn run_verify(opts: VerifyOptions) -> anyhow::Result<()> {
         all_checks.push(check_synced(&repos));
     }
 
+    if opts.locked {
+        all_checks.push(check_locked(opts.workspace_root, &repos));
+    }
+
     let all_pass = all_checks.iter().all(|c| c.pass);
 
     if opts.json {
@@ -176,7 +184,9 @@ fn check_links(workspace_root: &PathBuf, manifest: &Manifest) -> CheckResult {
     let repos: Vec<RepoInfo> = manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .collect();
 
     for (name, config) in &manifest.repos {
@@ -302,6 +312,71 @@ fn check_synced(repos: &[RepoInfo]) -> CheckResult {
     }
 }
 
+/// Check that all repos and gripspaces are at the commits recorded in gripspace.lock
+fn check_locked(workspace_root: &Path, repos: &[RepoInfo]) -> CheckResult {
+    let mut details = Vec::new();
+
+    let lockfile = match Lockfile::load_from_workspace(workspace_root) {
+        Ok(lockfile) => lockfile,
+        Err(e) => {
+            return CheckResult {
+                name: "locked".to_string(),
+                pass: false,
+                details: vec![serde_json::json!({ "error": e.to_string() })],
+            };
+        }
+    };
+
+    for repo in repos {
+        let Some(expected) = lockfile.repo_sha(&repo.name) else {
+            details.push(serde_json::json!({
+                "repo": repo.name,
+                "status": "not in lockfile"
+            }));
+            continue;
+        };
+
+        if !path_exists(&repo.absolute_path) {
+            details.push(serde_json::json!({
+                "repo": repo.name,
+                "status": "not cloned"
+            }));
+            continue;
+        }
+
+        let actual = open_repo(&repo.absolute_path)
+            .ok()
+            .and_then(|r| get_head_sha(&r).ok());
+        if actual.as_deref() != Some(expected) {
+            details.push(serde_json::json!({
+                "repo": repo.name,
+                "expected": expected,
+                "actual": actual.unwrap_or_else(|| "unknown".to_string())
+            }));
+        }
+    }
+
+    let spaces_dir = manifest_paths::spaces_dir(workspace_root);
+    for (name, gripspace) in &lockfile.gripspaces {
+        let actual = open_repo(spaces_dir.join(name))
+            .ok()
+            .and_then(|r| get_head_sha(&r).ok());
+        if actual.as_deref() != Some(gripspace.sha.as_str()) {
+            details.push(serde_json::json!({
+                "gripspace": name,
+                "expected": gripspace.sha,
+                "actual": actual.unwrap_or_else(|| "missing".to_string())
+            }));
+        }
+    }
+
+    CheckResult {
+        name: "locked".to_string(),
+        pass: details.is_empty(),
+        details,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,9 +386,11 @@ mod tests {
         RepoInfo {
             name: name.to_string(),
             url: format!("file://{}", path.display()),
+            push_url: None,
             path: name.to_string(),
             absolute_path: path.to_path_buf(),
             default_branch: "main".to_string(),
+            revision: None,
             owner: "local".to_string(),
             repo: name.to_string(),
             platform_type: PlatformType::GitHub,
@@ -322,6 +399,8 @@ mod tests {
             reference: false,
             groups: Vec::new(),
             agent: None,
+            clone: None,
+            sparse: Vec::new(),
         }
     }
 
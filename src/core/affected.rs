@@ -0,0 +1,451 @@
+//! Affected-repo analysis
+//!
+//! Works out which repos are affected by changes since a git ref: repos with
+//! changed files, plus every repo that transitively depends on one of them.
+//! Dependencies come from `depends_on` in the manifest and are also inferred
+//! from build files (`Cargo.toml`, `package.json`, `go.mod`) that reference
+//! another manifest repo by package name, local path, or git URL.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::path::{Component, Path, PathBuf};
+
+use crate::core::dag::RepoDag;
+use crate::core::manifest::Manifest;
+use crate::core::repo::RepoInfo;
+use crate::git::status::get_changed_files_since;
+use crate::git::{open_repo, path_exists};
+
+/// Why a repo is affected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AffectedReason {
+    /// The repo has changed files since the ref
+    Changed(Vec<String>),
+    /// The ref could not be resolved in the repo, so it is assumed changed
+    UnknownRef(String),
+    /// The repo depends on an affected repo
+    DependsOn(String),
+}
+
+/// References a repo's build files make to packages and other repos
+#[derive(Debug, Default)]
+struct PackageRefs {
+    /// Package/module names the repo publishes
+    provides: BTreeSet<String>,
+    /// Package/module names the repo depends on
+    requires: BTreeSet<String>,
+    /// Local path dependencies (absolute, normalized)
+    paths: Vec<PathBuf>,
+    /// Git URL dependencies (normalized)
+    git_urls: BTreeSet<String>,
+}
+
+/// Infer inter-repo dependencies from build files.
+///
+/// Returns a map of repo name -> names of repos it depends on.
+pub fn infer_dependencies(repos: &[RepoInfo]) -> BTreeMap<String, BTreeSet<String>> {
+    let refs: Vec<(&RepoInfo, PackageRefs)> = repos
+        .iter()
+        .map(|repo| (repo, read_package_refs(&repo.absolute_path)))
+        .collect();
+
+    let mut deps: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (repo, repo_refs) in &refs {
+        for (other, other_refs) in &refs {
+            if repo.name == other.name {
+                continue;
+            }
+            let other_root = normalize_path(&other.absolute_path);
+            let other_url = normalize_url(&other.url);
+
+            let by_name = repo_refs
+                .requires
+                .iter()
+                .any(|r| other_refs.provides.contains(r) || *r == other_url);
+            let by_path = repo_refs.paths.iter().any(|p| p.starts_with(&other_root));
+            let by_url = repo_refs.git_urls.contains(&other_url);
+
+            if by_name || by_path || by_url {
+                deps.entry(repo.name.clone())
+                    .or_default()
+                    .insert(other.name.clone());
+            }
+        }
+    }
+    deps
+}
+
+/// Compute the repos affected by changes since `since`.
+///
+/// With `since` unset, each repo is compared against its own default branch.
+/// Repos that are not cloned are never "changed" but can still be affected
+/// through their dependencies. The result is keyed by repo name.
+pub fn compute_affected(
+    manifest: &Manifest,
+    repos: &[RepoInfo],
+    since: Option<&str>,
+) -> BTreeMap<String, AffectedReason> {
+    let mut affected = BTreeMap::new();
+
+    for repo in repos {
+        if !path_exists(&repo.absolute_path) {
+            continue;
+        }
+        let Ok(git_repo) = open_repo(&repo.absolute_path) else {
+            continue;
+        };
+        let since_ref = since.unwrap_or(&repo.default_branch);
+        match get_changed_files_since(&git_repo, since_ref) {
+            Ok(files) if files.is_empty() => {}
+            Ok(files) => {
+                affected.insert(repo.name.clone(), AffectedReason::Changed(files));
+            }
+            Err(_) => {
+                affected.insert(
+                    repo.name.clone(),
+                    AffectedReason::UnknownRef(since_ref.to_string()),
+                );
+            }
+        }
+    }
+
+    // Reverse edges: repo -> repos that depend on it
+    let dag = RepoDag::from_manifest(manifest);
+    let mut dependents: HashMap<String, BTreeSet<String>> = HashMap::new();
+    for repo in repos {
+        for dep in dag.dependencies(&repo.name) {
+            dependents
+                .entry(dep.clone())
+                .or_default()
+                .insert(repo.name.clone());
+        }
+    }
+    for (repo, deps) in infer_dependencies(repos) {
+        for dep in deps {
+            dependents.entry(dep).or_default().insert(repo.clone());
+        }
+    }
+
+    let mut queue: VecDeque<String> = affected.keys().cloned().collect();
+    while let Some(name) = queue.pop_front() {
+        for dependent in dependents.get(&name).into_iter().flatten() {
+            if !affected.contains_key(dependent) {
+                affected.insert(dependent.clone(), AffectedReason::DependsOn(name.clone()));
+                queue.push_back(dependent.clone());
+            }
+        }
+    }
+
+    affected
+}
+
+// ── Build file parsing ──────────────────────────────────────────────────────
+
+fn read_package_refs(repo_root: &Path) -> PackageRefs {
+    let mut refs = PackageRefs::default();
+    if let Ok(content) = std::fs::read_to_string(repo_root.join("Cargo.toml")) {
+        parse_cargo_toml(&content, repo_root, &mut refs);
+    }
+    if let Ok(content) = std::fs::read_to_string(repo_root.join("package.json")) {
+        parse_package_json(&content, repo_root, &mut refs);
+    }
+    if let Ok(content) = std::fs::read_to_string(repo_root.join("go.mod")) {
+        parse_go_mod(&content, repo_root, &mut refs);
+    }
+    refs
+}
+
+/// Line-based scan of a Cargo.toml for the package name and dependencies
+fn parse_cargo_toml(content: &str, repo_root: &Path, refs: &mut PackageRefs) {
+    enum Section {
+        Package,
+        Dependencies,
+        /// `[dependencies.<name>]` style table
+        Dependency,
+        Other,
+    }
+    let mut section = Section::Other;
+
+    for raw in content.lines() {
+        let line = raw.split(" #").next().unwrap_or("").trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            let header = line.trim_matches(|c| c == '[' || c == ']').trim();
+            section = if header == "package" {
+                Section::Package
+            } else if header.ends_with("dependencies") {
+                Section::Dependencies
+            } else if let Some((table, name)) = header.rsplit_once('.') {
+                if table.ends_with("dependencies") {
+                    refs.requires.insert(unquote(name));
+                    Section::Dependency
+                } else {
+                    Section::Other
+                }
+            } else {
+                Section::Other
+            };
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = unquote(key.trim());
+        let value = value.trim();
+
+        match section {
+            Section::Package if key == "name" => {
+                refs.provides.insert(unquote(value));
+            }
+            Section::Dependencies => {
+                let name = inline_field(value, "package").unwrap_or(key);
+                refs.requires.insert(name);
+                if let Some(path) = inline_field(value, "path") {
+                    refs.paths.push(normalize_path(&repo_root.join(path)));
+                }
+                if let Some(url) = inline_field(value, "git") {
+                    refs.git_urls.insert(normalize_url(&url));
+                }
+            }
+            Section::Dependency => match key.as_str() {
+                "package" => {
+                    refs.requires.insert(unquote(value));
+                }
+                "path" => refs
+                    .paths
+                    .push(normalize_path(&repo_root.join(unquote(value)))),
+                "git" => {
+                    refs.git_urls.insert(normalize_url(&unquote(value)));
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+fn parse_package_json(content: &str, repo_root: &Path, refs: &mut PackageRefs) {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(content) else {
+        return;
+    };
+    if let Some(name) = json.get("name").and_then(|n| n.as_str()) {
+        refs.provides.insert(name.to_string());
+    }
+
+    for field in [
+        "dependencies",
+        "devDependencies",
+        "peerDependencies",
+        "optionalDependencies",
+    ] {
+        let Some(deps) = json.get(field).and_then(|d| d.as_object()) else {
+            continue;
+        };
+        for (name, spec) in deps {
+            refs.requires.insert(name.clone());
+            let Some(spec) = spec.as_str() else {
+                continue;
+            };
+            if let Some(path) = spec
+                .strip_prefix("file:")
+                .or_else(|| spec.strip_prefix("link:"))
+            {
+                refs.paths.push(normalize_path(&repo_root.join(path)));
+            } else if let Some(repo) = spec.strip_prefix("github:") {
+                refs.git_urls
+                    .insert(normalize_url(&format!("github.com/{}", repo)));
+            } else if spec.starts_with("git") || spec.contains("://") {
+                refs.git_urls.insert(normalize_url(spec));
+            }
+        }
+    }
+}
+
+fn parse_go_mod(content: &str, repo_root: &Path, refs: &mut PackageRefs) {
+    let mut in_require = false;
+    for raw in content.lines() {
+        let line = raw.split("//").next().unwrap_or("").trim();
+        if in_require {
+            if line == ")" {
+                in_require = false;
+            } else if let Some(module) = line.split_whitespace().next() {
+                refs.requires.insert(module.to_string());
+            }
+        } else if let Some(module) = line.strip_prefix("module ") {
+            refs.provides.insert(unquote(module.trim()));
+        } else if line == "require (" {
+            in_require = true;
+        } else if let Some(rest) = line.strip_prefix("require ") {
+            if let Some(module) = rest.split_whitespace().next() {
+                refs.requires.insert(module.to_string());
+            }
+        } else if let Some((_, target)) = line.split_once("=>") {
+            let target = target.split_whitespace().next().unwrap_or("");
+            if target.starts_with('.') || target.starts_with('/') {
+                refs.paths.push(normalize_path(&repo_root.join(target)));
+            }
+        }
+    }
+}
+
+// ── Helpers ─────────────────────────────────────────────────────────────────
+
+fn unquote(value: &str) -> String {
+    value
+        .trim()
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string()
+}
+
+/// Extract a string field from an inline TOML table, e.g. `path` from
+/// `{ path = "../lib", version = "1" }`
+fn inline_field(value: &str, field: &str) -> Option<String> {
+    let body = value.trim().strip_prefix('{')?.strip_suffix('}')?;
+    body.split(',').find_map(|part| {
+        let (key, val) = part.split_once('=')?;
+        (key.trim() == field).then(|| unquote(val))
+    })
+}
+
+/// Normalize a path lexically (resolving `.` and `..` without touching the filesystem)
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Normalize a git URL to `host/owner/repo` for comparison
+fn normalize_url(url: &str) -> String {
+    let url = url.trim().trim_start_matches("git+");
+    let url = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let url = url.split_once('@').map(|(_, rest)| rest).unwrap_or(url);
+    let url = url.split('#').next().unwrap_or(url);
+    url.replacen(':', "/", 1)
+        .trim_end_matches('/')
+        .trim_end_matches(".git")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn repo(temp: &TempDir, name: &str, url: &str, files: &[(&str, &str)]) -> RepoInfo {
+        let path = temp.path().join(name);
+        std::fs::create_dir_all(&path).unwrap();
+        for (file, content) in files {
+            std::fs::write(path.join(file), content).unwrap();
+        }
+        let config: crate::core::manifest::RepoConfig =
+            serde_yaml::from_str(&format!("url: {}\npath: {}\n", url, name)).unwrap();
+        RepoInfo::from_config(name, &config, &temp.path().to_path_buf(), None).unwrap()
+    }
+
+    #[test]
+    fn test_infer_cargo_dependencies() {
+        let temp = TempDir::new().unwrap();
+        let repos = vec![
+            repo(
+                &temp,
+                "core",
+                "git@github.com:org/core.git",
+                &[("Cargo.toml", "[package]\nname = \"org-core\"\n")],
+            ),
+            repo(
+                &temp,
+                "util",
+                "git@github.com:org/util.git",
+                &[("Cargo.toml", "[package]\nname = \"util\"\n")],
+            ),
+            repo(
+                &temp,
+                "api",
+                "git@github.com:org/api.git",
+                &[(
+                    "Cargo.toml",
+                    "[package]\nname = \"api\"\n\n[dependencies]\norg-core = { path = \"../core\" }\nserde = \"1\"\n\n[dev-dependencies.helpers]\ngit = \"https://github.com/org/util\"\n",
+                )],
+            ),
+        ];
+
+        let deps = infer_dependencies(&repos);
+        assert_eq!(
+            deps["api"],
+            BTreeSet::from(["core".to_string(), "util".to_string()])
+        );
+        assert!(!deps.contains_key("core"));
+    }
+
+    #[test]
+    fn test_infer_package_json_and_go_mod_dependencies() {
+        let temp = TempDir::new().unwrap();
+        let repos = vec![
+            repo(
+                &temp,
+                "ui-kit",
+                "https://github.com/org/ui-kit.git",
+                &[("package.json", r#"{"name": "@org/ui-kit"}"#)],
+            ),
+            repo(
+                &temp,
+                "web",
+                "https://github.com/org/web.git",
+                &[(
+                    "package.json",
+                    r#"{"name": "web", "dependencies": {"@org/ui-kit": "^2.0.0", "react": "18"}}"#,
+                )],
+            ),
+            repo(&temp, "gosdk", "https://github.com/org/gosdk.git", &[]),
+            repo(
+                &temp,
+                "svc",
+                "https://github.com/org/svc.git",
+                &[(
+                    "go.mod",
+                    "module github.com/org/svc\n\nrequire (\n\tgithub.com/org/gosdk v1.2.0\n)\n",
+                )],
+            ),
+        ];
+
+        let deps = infer_dependencies(&repos);
+        assert_eq!(deps["web"], BTreeSet::from(["ui-kit".to_string()]));
+        assert_eq!(deps["svc"], BTreeSet::from(["gosdk".to_string()]));
+    }
+
+    #[test]
+    fn test_normalize_url() {
+        assert_eq!(
+            normalize_url("git@github.com:Org/Repo.git"),
+            "github.com/org/repo"
+        );
+        assert_eq!(
+            normalize_url("git+https://github.com/org/repo#main"),
+            "github.com/org/repo"
+        );
+        assert_eq!(
+            normalize_url("ssh://git@gitlab.com/group/repo.git"),
+            "gitlab.com/group/repo"
+        );
+    }
+
+    #[test]
+    fn test_inline_field() {
+        assert_eq!(
+            inline_field(r#"{ path = "../lib", version = "1" }"#, "path").as_deref(),
+            Some("../lib")
+        );
+        assert_eq!(inline_field(r#""1.0""#, "path"), None);
+    }
+}
@@ -0,0 +1,206 @@
+//! Workspace bisect state
+//!
+//! A workspace bisect (.gitgrip/bisect.json) searches the combined history
+//! of all repos. The history is a timeline of steps, each advancing one repo
+//! by one first-parent commit; state `k` is the good point with the first
+//! `k` steps applied, so every state is a consistent set of SHAs.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::core::state::StateError;
+
+/// One commit in the combined timeline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BisectStep {
+    /// Repository name (from manifest)
+    pub repo_name: String,
+    /// Commit the repo advances to
+    pub sha: String,
+    /// Committer date, seconds since the Unix epoch
+    pub timestamp: i64,
+    /// First line of the commit message
+    pub subject: String,
+}
+
+/// Result of testing a workspace state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BisectVerdict {
+    Good,
+    Bad,
+    Skip,
+}
+
+/// What to do next
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BisectNext {
+    /// Test state `k`
+    Test(usize),
+    /// State `k` is the first bad one; `steps[k - 1]` introduced the problem
+    Found(usize),
+    /// Every state between the last good and first bad one was skipped
+    Inconclusive { good: usize, bad: usize },
+}
+
+/// A workspace bisect in progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BisectState {
+    /// Map: repo name -> branch (or detached SHA) to return to on reset
+    pub original: BTreeMap<String, String>,
+    /// Map: repo name -> SHA at the good point (state 0)
+    pub base: BTreeMap<String, String>,
+    /// Combined timeline; state `steps.len()` is the bad point
+    pub steps: Vec<BisectStep>,
+    /// Newest state known to be good
+    pub good: usize,
+    /// Oldest state known to be bad
+    pub bad: usize,
+    /// States that could not be tested
+    #[serde(default)]
+    pub skipped: Vec<usize>,
+    /// State currently checked out, if still bisecting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<usize>,
+}
+
+/// Path of the bisect state in a workspace
+pub fn bisect_path(workspace_root: &Path) -> PathBuf {
+    workspace_root.join(".gitgrip").join("bisect.json")
+}
+
+impl BisectState {
+    /// Start a bisect with state 0 good and the last state bad
+    pub fn new(
+        original: BTreeMap<String, String>,
+        base: BTreeMap<String, String>,
+        steps: Vec<BisectStep>,
+    ) -> Self {
+        let bad = steps.len();
+        Self {
+            original,
+            base,
+            steps,
+            good: 0,
+            bad,
+            skipped: Vec::new(),
+            current: None,
+        }
+    }
+
+    /// Load the bisect in progress, if any
+    pub fn load(path: &Path) -> Result<Option<Self>, StateError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// Save the bisect state
+    pub fn save(&self, path: &Path) -> Result<(), StateError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// SHA of every repo in state `k`
+    pub fn shas_at(&self, k: usize) -> BTreeMap<String, String> {
+        let mut shas = self.base.clone();
+        for step in &self.steps[..k] {
+            shas.insert(step.repo_name.clone(), step.sha.clone());
+        }
+        shas
+    }
+
+    /// Record the verdict for state `k`
+    pub fn mark(&mut self, k: usize, verdict: BisectVerdict) {
+        match verdict {
+            BisectVerdict::Good => self.good = self.good.max(k),
+            BisectVerdict::Bad => self.bad = self.bad.min(k),
+            BisectVerdict::Skip => {
+                if !self.skipped.contains(&k) {
+                    self.skipped.push(k);
+                }
+            }
+        }
+    }
+
+    /// The untested state closest to the middle of the remaining range
+    pub fn next(&self) -> BisectNext {
+        if self.bad <= self.good + 1 {
+            return BisectNext::Found(self.bad);
+        }
+        let mid = (self.good + self.bad) / 2;
+        (self.good + 1..self.bad)
+            .filter(|k| !self.skipped.contains(k))
+            .min_by_key(|k| k.abs_diff(mid))
+            .map(BisectNext::Test)
+            .unwrap_or(BisectNext::Inconclusive {
+                good: self.good,
+                bad: self.bad,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(steps: usize) -> BisectState {
+        let steps = (0..steps)
+            .map(|i| BisectStep {
+                repo_name: if i % 2 == 0 { "app" } else { "lib" }.to_string(),
+                sha: format!("sha{}", i + 1),
+                timestamp: i as i64,
+                subject: format!("Step {}", i + 1),
+            })
+            .collect();
+        let base = BTreeMap::from([
+            ("app".to_string(), "sha0".to_string()),
+            ("lib".to_string(), "sha0".to_string()),
+        ]);
+        BisectState::new(BTreeMap::new(), base, steps)
+    }
+
+    #[test]
+    fn test_shas_at() {
+        let s = state(3);
+        assert_eq!(s.shas_at(0)["app"], "sha0");
+        let shas = s.shas_at(2);
+        assert_eq!(shas["app"], "sha1");
+        assert_eq!(shas["lib"], "sha2");
+        assert_eq!(s.shas_at(3)["app"], "sha3");
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let mut s = state(8);
+        // First bad state is 5
+        while let BisectNext::Test(k) = s.next() {
+            let verdict = if k >= 5 {
+                BisectVerdict::Bad
+            } else {
+                BisectVerdict::Good
+            };
+            s.mark(k, verdict);
+        }
+        assert_eq!(s.next(), BisectNext::Found(5));
+    }
+
+    #[test]
+    fn test_skip() {
+        let mut s = state(2);
+        assert_eq!(s.next(), BisectNext::Test(1));
+        s.mark(1, BisectVerdict::Skip);
+        assert_eq!(s.next(), BisectNext::Inconclusive { good: 0, bad: 2 });
+
+        let mut s = state(4);
+        s.mark(2, BisectVerdict::Skip);
+        assert_eq!(s.next(), BisectNext::Test(1));
+    }
+}
@@ -0,0 +1,279 @@
+//! Repository dependency graph
+//!
+//! Built from `depends_on` in the manifest. Commands that act on many repos
+//! (agent build/test, ci run, pr merge, release, forall --ordered) process the
+//! graph level by level: every repo in a level depends only on repos in earlier
+//! levels, so the repos within one level can run in parallel.
+
+use std::collections::{BTreeMap, HashMap};
+use thiserror::Error;
+
+use crate::core::manifest::Manifest;
+use crate::core::repo::RepoInfo;
+
+/// Errors from building the dependency graph
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum DagError {
+    #[error("Dependency cycle: {}", .0.join(" -> "))]
+    Cycle(Vec<String>),
+}
+
+/// Dependency graph of the manifest's repos
+#[derive(Debug, Clone, Default)]
+pub struct RepoDag {
+    /// Map: repo name -> repos it depends on (only repos present in the graph)
+    deps: BTreeMap<String, Vec<String>>,
+}
+
+impl RepoDag {
+    /// Build the graph from the manifest's `depends_on` entries.
+    ///
+    /// Dependencies on repos that are not in the manifest are ignored here;
+    /// `Manifest::validate` reports them.
+    pub fn from_manifest(manifest: &Manifest) -> Self {
+        let deps = manifest
+            .repos
+            .iter()
+            .map(|(name, config)| {
+                let mut deps: Vec<String> = config
+                    .depends_on
+                    .iter()
+                    .filter(|d| manifest.repos.contains_key(d.as_str()))
+                    .cloned()
+                    .collect();
+                deps.sort();
+                deps.dedup();
+                (name.clone(), deps)
+            })
+            .collect();
+        Self { deps }
+    }
+
+    /// Direct dependencies of a repo
+    pub fn dependencies(&self, name: &str) -> &[String] {
+        self.deps.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Find a dependency cycle, returned as a path that starts and ends on the same repo
+    pub fn find_cycle(&self) -> Option<Vec<String>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            Visiting,
+            Done,
+        }
+
+        fn visit<'a>(
+            dag: &'a RepoDag,
+            name: &'a str,
+            marks: &mut HashMap<&'a str, Mark>,
+            stack: &mut Vec<&'a str>,
+        ) -> Option<Vec<String>> {
+            match marks.get(name) {
+                Some(Mark::Done) => return None,
+                Some(Mark::Visiting) => {
+                    let start = stack.iter().position(|n| *n == name).unwrap_or(0);
+                    let mut cycle: Vec<String> =
+                        stack[start..].iter().map(|n| n.to_string()).collect();
+                    cycle.push(name.to_string());
+                    return Some(cycle);
+                }
+                None => {}
+            }
+
+            marks.insert(name, Mark::Visiting);
+            stack.push(name);
+            for dep in dag.dependencies(name) {
+                if let Some(cycle) = visit(dag, dep, marks, stack) {
+                    return Some(cycle);
+                }
+            }
+            stack.pop();
+            marks.insert(name, Mark::Done);
+            None
+        }
+
+        let mut marks = HashMap::new();
+        let mut stack = Vec::new();
+        self.deps
+            .keys()
+            .find_map(|name| visit(self, name, &mut marks, &mut stack))
+    }
+
+    /// Depth of every repo: 0 for repos without dependencies, otherwise one more
+    /// than the deepest dependency.
+    fn depths(&self) -> Result<HashMap<&str, usize>, DagError> {
+        if let Some(cycle) = self.find_cycle() {
+            return Err(DagError::Cycle(cycle));
+        }
+
+        fn depth<'a>(dag: &'a RepoDag, name: &'a str, memo: &mut HashMap<&'a str, usize>) -> usize {
+            if let Some(&d) = memo.get(name) {
+                return d;
+            }
+            let d = dag
+                .dependencies(name)
+                .iter()
+                .map(|dep| depth(dag, dep, memo) + 1)
+                .max()
+                .unwrap_or(0);
+            memo.insert(name, d);
+            d
+        }
+
+        let mut memo = HashMap::new();
+        for name in self.deps.keys() {
+            depth(self, name, &mut memo);
+        }
+        Ok(memo)
+    }
+
+    /// Group repos into dependency levels.
+    ///
+    /// Ordering is kept transitively even when an intermediate repo is not in
+    /// `repos` (e.g. filtered out by group). Repos not in the graph, such as the
+    /// manifest repo, go in the last level. Within a level repos are sorted by name.
+    pub fn levels(&self, repos: Vec<RepoInfo>) -> Result<Vec<Vec<RepoInfo>>, DagError> {
+        let depths = self.depths()?;
+        let last = depths.values().copied().max().unwrap_or(0) + 1;
+
+        let mut by_depth: BTreeMap<usize, Vec<RepoInfo>> = BTreeMap::new();
+        for repo in repos {
+            let depth = depths.get(repo.name.as_str()).copied().unwrap_or(last);
+            by_depth.entry(depth).or_default().push(repo);
+        }
+
+        Ok(by_depth
+            .into_values()
+            .map(|mut level| {
+                level.sort_by(|a, b| a.name.cmp(&b.name));
+                level
+            })
+            .collect())
+    }
+
+    /// Sort repos so that every repo comes after its dependencies
+    pub fn sort(&self, repos: Vec<RepoInfo>) -> Result<Vec<RepoInfo>, DagError> {
+        Ok(self.levels(repos)?.into_iter().flatten().collect())
+    }
+}
+
+/// Run `f` for every repo in a level concurrently; results are in level order
+pub fn run_level<T, F>(level: &[RepoInfo], f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&RepoInfo) -> T + Sync,
+{
+    if level.len() == 1 {
+        return vec![f(&level[0])];
+    }
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = level
+            .iter()
+            .map(|repo| {
+                let f = &f;
+                scope.spawn(move || f(repo))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().expect("worker thread panicked"))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn manifest(deps: &[(&str, &[&str])]) -> Manifest {
+        let repos: String = deps
+            .iter()
+            .map(|(name, on)| {
+                format!(
+                    "  {name}:\n    url: git@github.com:org/{name}.git\n    path: {name}\n    depends_on: [{}]\n",
+                    on.join(", ")
+                )
+            })
+            .collect();
+        Manifest::parse_raw(&format!("repos:\n{}", repos)).unwrap()
+    }
+
+    fn repo_infos(manifest: &Manifest) -> Vec<RepoInfo> {
+        crate::core::repo::filter_repos(manifest, &PathBuf::from("/ws"), None, None, true)
+    }
+
+    fn names(levels: &[Vec<RepoInfo>]) -> Vec<Vec<&str>> {
+        levels
+            .iter()
+            .map(|level| level.iter().map(|r| r.name.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_levels_follow_dependencies() {
+        let m = manifest(&[
+            ("app", &["api", "ui"]),
+            ("api", &["core"]),
+            ("ui", &["core"]),
+            ("core", &[]),
+            ("docs", &[]),
+        ]);
+        let dag = RepoDag::from_manifest(&m);
+        let levels = dag.levels(repo_infos(&m)).unwrap();
+
+        assert_eq!(
+            names(&levels),
+            vec![vec!["core", "docs"], vec!["api", "ui"], vec!["app"]]
+        );
+    }
+
+    #[test]
+    fn test_levels_keep_transitive_order_for_subset() {
+        let m = manifest(&[("app", &["api"]), ("api", &["core"]), ("core", &[])]);
+        let dag = RepoDag::from_manifest(&m);
+        let subset: Vec<RepoInfo> = repo_infos(&m)
+            .into_iter()
+            .filter(|r| r.name != "api")
+            .collect();
+
+        let sorted = dag.sort(subset).unwrap();
+        let order: Vec<&str> = sorted.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(order, vec!["core", "app"]);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let m = manifest(&[("a", &["b"]), ("b", &["c"]), ("c", &["a"]), ("d", &[])]);
+        let dag = RepoDag::from_manifest(&m);
+
+        let cycle = dag.find_cycle().unwrap();
+        assert_eq!(cycle.first(), cycle.last());
+        assert_eq!(cycle.len(), 4);
+        assert!(matches!(
+            dag.levels(repo_infos(&m)),
+            Err(DagError::Cycle(_))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_dependencies_are_ignored() {
+        let m = manifest(&[("app", &["missing"])]);
+        let dag = RepoDag::from_manifest(&m);
+        assert!(dag.dependencies("app").is_empty());
+        assert!(dag.find_cycle().is_none());
+    }
+
+    #[test]
+    fn test_run_level_preserves_order() {
+        let m = manifest(&[("a", &[]), ("b", &[]), ("c", &[])]);
+        let level = RepoDag::from_manifest(&m)
+            .levels(repo_infos(&m))
+            .unwrap()
+            .remove(0);
+
+        let results = run_level(&level, |repo| repo.name.to_uppercase());
+        assert_eq!(results, vec!["A", "B", "C"]);
+    }
+}
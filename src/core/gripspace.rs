@@ -1,20 +1,28 @@
 //! Gripspace include resolution
 //!
 //! Gripspaces allow composable manifest inheritance. A workspace manifest can
-//! include one or more gripspace repositories, inheriting their repos, scripts,
-//! env vars, hooks, and linkfiles. Local values always win on conflict.
+//! include one or more gripspace repositories, inheriting their repos, remotes,
+//! scripts, env vars, hooks, and linkfiles. Local values always win on conflict.
 //!
 //! ## Merge ordering
 //!
 //! Resolution is depth-first. For hooks (post_sync, post_checkout), the ordering
 //! is: deepest gripspace hooks first, then their parent's hooks, then the local
-//! workspace hooks last. Maps (repos, scripts, env) use `entry().or_insert()` so
+//! workspace hooks last. Maps (repos, remotes, scripts, env) use `entry().or_insert()` so
 //! the first definition wins — local definitions take priority because they are
 //! inserted after gripspace values, overriding by key.
+//!
+//! ## Filtering inherited repos
+//!
+//! An include can trim and adjust what it inherits: `overrides` change a repo's
+//! path, branch, revision or groups, `exclude_repos` drops repos by name, and
+//! `include_groups` keeps only repos in the listed groups. These apply to
+//! everything the include contributes, including its own nested includes.
 
+use crate::core::lockfile::Lockfile;
 use crate::core::manifest::{
-    GripspaceConfig, HookCommand, Manifest, ManifestError, WorkspaceAgentConfig, WorkspaceConfig,
-    WorkspaceHooks,
+    GripspaceConfig, HookCommand, Manifest, ManifestError, RepoConfig, WorkspaceAgentConfig,
+    WorkspaceConfig, WorkspaceHooks,
 };
 use crate::core::manifest_paths;
 use crate::git::clone_repo;
@@ -338,6 +346,17 @@ fn checkout_rev(path: &Path, rev: &str) -> Result<(), ManifestError> {
 pub fn resolve_all_gripspaces(
     manifest: &mut Manifest,
     spaces_dir: &Path,
+) -> Result<(), ManifestError> {
+    resolve_all_gripspaces_locked(manifest, spaces_dir, None)
+}
+
+/// Like [`resolve_all_gripspaces`], but every gripspace the lockfile knows,
+/// nested includes too, is checked out at its locked SHA before its
+/// manifest is read.
+pub fn resolve_all_gripspaces_locked(
+    manifest: &mut Manifest,
+    spaces_dir: &Path,
+    lockfile: Option<&Lockfile>,
 ) -> Result<(), ManifestError> {
     let gripspaces = match manifest.gripspaces.take() {
         Some(gs) if !gs.is_empty() => gs,
@@ -347,6 +366,7 @@ pub fn resolve_all_gripspaces(
     let mut active_stack = HashSet::new();
     let mut resolved = HashSet::new();
     let mut merged_repos = HashMap::new();
+    let mut merged_remotes = HashMap::new();
     let mut merged_scripts = HashMap::new();
     let mut merged_env = HashMap::new();
     let mut merged_hooks_post_sync: Vec<HookCommand> = Vec::new();
@@ -360,10 +380,12 @@ pub fn resolve_all_gripspaces(
         resolve_gripspace_recursive(
             gs_config,
             spaces_dir,
+            lockfile,
             &mut active_stack,
             &mut resolved,
             0,
             &mut merged_repos,
+            &mut merged_remotes,
             &mut merged_scripts,
             &mut merged_env,
             &mut merged_hooks_post_sync,
@@ -381,6 +403,14 @@ pub fn resolve_all_gripspaces(
         manifest.repos.entry(name).or_insert(config);
     }
 
+    // Remotes: gripspace remotes first, then local overrides
+    if !merged_remotes.is_empty() {
+        let remotes = manifest.remotes.get_or_insert_with(HashMap::new);
+        for (name, remote) in merged_remotes {
+            remotes.entry(name).or_insert(remote);
+        }
+    }
+
     // Workspace: merge scripts, env, hooks
     let workspace = manifest
         .workspace
@@ -504,10 +534,12 @@ pub fn resolve_all_gripspaces(
 fn resolve_gripspace_recursive(
     config: &GripspaceConfig,
     spaces_dir: &Path,
+    lockfile: Option<&Lockfile>,
     active_stack: &mut HashSet<String>,
     resolved: &mut HashSet<String>,
     depth: usize,
     merged_repos: &mut HashMap<String, crate::core::manifest::RepoConfig>,
+    merged_remotes: &mut HashMap<String, crate::core::manifest::RemoteConfig>,
     merged_scripts: &mut HashMap<String, crate::core::manifest::WorkspaceScript>,
     merged_env: &mut HashMap<String, String>,
     merged_hooks_post_sync: &mut Vec<HookCommand>,
@@ -523,6 +555,8 @@ fn resolve_gripspace_recursive(
         )));
     }
 
+    let pinned = lockfile.map(|l| l.pin_gripspace(config));
+    let config = pinned.as_ref().unwrap_or(config);
     let name = gripspace_name(&config.url);
 
     validate_space_name(&name)?;
@@ -568,19 +602,28 @@ fn resolve_gripspace_recursive(
         ))
     })?;
 
+    // Repos from this gripspace and its includes, collected separately so the
+    // include's exclusions and overrides apply to the whole subtree
+    let mut space_repos = HashMap::new();
+
     // Recursively resolve nested gripspaces first — ensure they are cloned
     if let Some(ref nested_gripspaces) = gs_manifest.gripspaces {
         for nested_config in nested_gripspaces {
+            let pinned = lockfile.map(|l| l.pin_gripspace(nested_config));
+            let nested_config = pinned.as_ref().unwrap_or(nested_config);
+
             // Clone the nested gripspace if it doesn't exist yet
             ensure_gripspace(spaces_dir, nested_config)?;
 
             resolve_gripspace_recursive(
                 nested_config,
                 spaces_dir,
+                lockfile,
                 active_stack,
                 resolved,
                 depth + 1,
-                merged_repos,
+                &mut space_repos,
+                merged_remotes,
                 merged_scripts,
                 merged_env,
                 merged_hooks_post_sync,
@@ -594,9 +637,19 @@ fn resolve_gripspace_recursive(
 
     // Merge repos (first-encountered wins among gripspaces; local always wins last in resolve_all_gripspaces)
     for (repo_name, repo_config) in gs_manifest.repos {
+        space_repos.entry(repo_name).or_insert(repo_config);
+    }
+    apply_repo_filters(config, &name, &mut space_repos)?;
+    for (repo_name, repo_config) in space_repos {
         merged_repos.entry(repo_name).or_insert(repo_config);
     }
 
+    if let Some(remotes) = gs_manifest.remotes {
+        for (remote_name, remote) in remotes {
+            merged_remotes.entry(remote_name).or_insert(remote);
+        }
+    }
+
     // Merge workspace config
     if let Some(ref workspace) = gs_manifest.workspace {
         if let Some(ref scripts) = workspace.scripts {
@@ -673,6 +726,109 @@ fn resolve_gripspace_recursive(
     Ok(())
 }
 
+/// Apply a gripspace include's `overrides`, `include_groups` and
+/// `exclude_repos` to the repos it contributes. Overrides run first so they can
+/// add a repo to an included group.
+fn apply_repo_filters(
+    config: &GripspaceConfig,
+    name: &str,
+    repos: &mut HashMap<String, RepoConfig>,
+) -> Result<(), ManifestError> {
+    let unknown = |kind: &str, repo: &str| {
+        ManifestError::GripspaceError(format!(
+            "Gripspace '{}' {} unknown repo '{}'",
+            name, kind, repo
+        ))
+    };
+
+    let mut overridden: Vec<&String> = config.overrides.keys().collect();
+    overridden.sort();
+    for repo_name in overridden {
+        let over = &config.overrides[repo_name];
+        let repo = repos
+            .get_mut(repo_name)
+            .ok_or_else(|| unknown("overrides", repo_name))?;
+        if let Some(ref path) = over.path {
+            repo.path.clone_from(path);
+        }
+        if let Some(ref branch) = over.default_branch {
+            repo.default_branch.clone_from(branch);
+        }
+        if let Some(ref revision) = over.revision {
+            repo.revision = Some(revision.clone());
+        }
+        for group in &over.groups {
+            if !repo.groups.contains(group) {
+                repo.groups.push(group.clone());
+            }
+        }
+    }
+
+    for repo_name in &config.exclude_repos {
+        if repos.remove(repo_name).is_none() {
+            return Err(unknown("excludes", repo_name));
+        }
+    }
+
+    if !config.include_groups.is_empty() {
+        repos.retain(|_, repo| {
+            repo.groups
+                .iter()
+                .any(|g| config.include_groups.contains(g))
+        });
+    }
+
+    Ok(())
+}
+
+/// List every gripspace reachable from `gripspaces`, including nested includes.
+///
+/// Only walks gripspaces that are already cloned under `spaces_dir`; nothing is
+/// cloned or updated. Returns `(directory name, config)` pairs in depth-first order.
+pub fn list_gripspaces(
+    gripspaces: &[GripspaceConfig],
+    spaces_dir: &Path,
+) -> Vec<(String, GripspaceConfig)> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    list_gripspaces_recursive(gripspaces, spaces_dir, 0, &mut seen, &mut out);
+    out
+}
+
+fn list_gripspaces_recursive(
+    gripspaces: &[GripspaceConfig],
+    spaces_dir: &Path,
+    depth: usize,
+    seen: &mut HashSet<String>,
+    out: &mut Vec<(String, GripspaceConfig)>,
+) {
+    if depth >= MAX_GRIPSPACE_DEPTH {
+        return;
+    }
+
+    for config in gripspaces {
+        if !seen.insert(gripspace_identity(config)) {
+            continue;
+        }
+        let Ok(dir_name) = resolve_space_name(&config.url, spaces_dir) else {
+            continue;
+        };
+        let gripspace_path = spaces_dir.join(&dir_name);
+        if !gripspace_path.exists() {
+            continue;
+        }
+        out.push((dir_name, config.clone()));
+
+        let nested = manifest_paths::resolve_manifest_file_in_dir(&gripspace_path)
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| Manifest::parse_raw(&content).ok())
+            .and_then(|m| m.gripspaces);
+        if let Some(nested) = nested {
+            list_gripspaces_recursive(&nested, spaces_dir, depth + 1, seen, out);
+        }
+    }
+}
+
 /// Get the current revision (branch or SHA) of a gripspace.
 pub fn get_gripspace_rev(gripspace_path: &Path) -> Option<String> {
     let output = Command::new("git")
@@ -754,6 +910,7 @@ mod tests {
         let mut manifest = Manifest {
             version: 1,
             gripspaces: None,
+            remotes: None,
             manifest: None,
             repos: HashMap::new(),
             settings: Default::default(),
@@ -770,6 +927,7 @@ mod tests {
         let mut manifest = Manifest {
             version: 1,
             gripspaces: Some(vec![]),
+            remotes: None,
             manifest: None,
             repos: HashMap::new(),
             settings: Default::default(),
@@ -795,7 +953,11 @@ mod tests {
             gripspaces: Some(vec![GripspaceConfig {
                 url: "https://github.com/user/test-gripspace.git".to_string(),
                 rev: None,
+                exclude_repos: Vec::new(),
+                include_groups: Vec::new(),
+                overrides: HashMap::new(),
             }]),
+            remotes: None,
             manifest: None,
             repos: HashMap::new(),
             settings: Default::default(),
@@ -834,7 +996,11 @@ repos:
             gripspaces: Some(vec![GripspaceConfig {
                 url: "https://github.com/user/base-gripspace.git".to_string(),
                 rev: None,
+                exclude_repos: Vec::new(),
+                include_groups: Vec::new(),
+                overrides: HashMap::new(),
             }]),
+            remotes: None,
             manifest: None,
             repos: {
                 let mut m = HashMap::new();
@@ -842,14 +1008,21 @@ repos:
                     "local-repo".to_string(),
                     crate::core::manifest::RepoConfig {
                         url: "https://github.com/user/local.git".to_string(),
+                        remote: None,
+                        name: None,
                         path: "./local".to_string(),
                         default_branch: "main".to_string(),
+                        revision: None,
                         copyfile: None,
                         linkfile: None,
                         platform: None,
                         reference: false,
                         groups: Vec::new(),
                         agent: None,
+                        depends_on: Vec::new(),
+                        clone: None,
+                        sparse: Vec::new(),
+                        pr: None,
                     },
                 );
                 m
@@ -867,6 +1040,154 @@ repos:
         assert!(manifest.repos.contains_key("local-repo"));
     }
 
+    #[test]
+    fn test_resolve_merges_remotes() {
+        let temp = tempfile::tempdir().unwrap();
+        let gripspaces_dir = temp.path();
+
+        let gs_dir = gripspaces_dir.join("base-gripspace");
+        std::fs::create_dir_all(&gs_dir).unwrap();
+        init_git_with_origin(&gs_dir, "https://github.com/user/base-gripspace.git");
+        std::fs::write(
+            gs_dir.join("manifest.yaml"),
+            r#"
+version: 1
+remotes:
+  shared:
+    fetch: https://gitlab.example.com/platform
+  origin:
+    fetch: https://github.com/gripspace-org
+repos:
+  shared-repo:
+    remote: shared
+    name: tools/shared
+    path: ./shared
+"#,
+        )
+        .unwrap();
+
+        let mut manifest = Manifest::parse(
+            r#"
+gripspaces:
+  - url: https://github.com/user/base-gripspace.git
+remotes:
+  origin:
+    fetch: https://github.com/local-org
+repos:
+  local-repo:
+    remote: origin
+    path: ./local
+"#,
+        )
+        .unwrap();
+
+        resolve_all_gripspaces(&mut manifest, gripspaces_dir).unwrap();
+
+        let remotes = manifest.remotes.as_ref().unwrap();
+        assert_eq!(remotes.len(), 2);
+        // Local remote wins over the gripspace's remote of the same name
+        assert_eq!(remotes["origin"].fetch, "https://github.com/local-org");
+        assert_eq!(
+            manifest.repos["shared-repo"].resolve_url("shared-repo", Some(remotes)),
+            Some("https://gitlab.example.com/platform/tools/shared".to_string())
+        );
+        manifest.validate().unwrap();
+    }
+
+    fn write_filter_gripspace(gripspaces_dir: &Path) {
+        let gs_dir = gripspaces_dir.join("company");
+        std::fs::create_dir_all(&gs_dir).unwrap();
+        init_git_with_origin(&gs_dir, "https://github.com/org/company.git");
+        std::fs::write(
+            gs_dir.join("gripspace.yml"),
+            r#"
+version: 1
+repos:
+  ios:
+    url: https://github.com/org/ios.git
+    path: ./ios
+    groups: [mobile]
+  android:
+    url: https://github.com/org/android.git
+    path: ./android
+    groups: [mobile]
+  design-system:
+    url: https://github.com/org/design-system.git
+    path: ./design-system
+  billing:
+    url: https://github.com/org/billing.git
+    path: ./billing
+    groups: [backend]
+"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_resolve_applies_include_filters() {
+        let temp = tempfile::tempdir().unwrap();
+        write_filter_gripspace(temp.path());
+
+        let mut manifest = Manifest::parse(
+            r#"
+gripspaces:
+  - url: https://github.com/org/company.git
+    include_groups: [mobile]
+    exclude_repos: [android]
+    overrides:
+      design-system:
+        path: ./libs/design
+        default_branch: develop
+        groups: [mobile]
+      ios:
+        revision: v2.0.0
+repos:
+  local-repo:
+    url: https://github.com/org/local.git
+    path: ./local
+"#,
+        )
+        .unwrap();
+
+        resolve_all_gripspaces(&mut manifest, temp.path()).unwrap();
+
+        let mut names: Vec<&String> = manifest.repos.keys().collect();
+        names.sort();
+        assert_eq!(names, vec!["design-system", "ios", "local-repo"]);
+
+        let design = &manifest.repos["design-system"];
+        assert_eq!(design.path, "./libs/design");
+        assert_eq!(design.default_branch, "develop");
+        assert_eq!(design.groups, vec!["mobile"]);
+        assert_eq!(manifest.repos["ios"].revision.as_deref(), Some("v2.0.0"));
+
+        // Filters survive the round trip so re-resolution on sync applies them again
+        let gs = &manifest.gripspaces.as_ref().unwrap()[0];
+        assert_eq!(gs.exclude_repos, vec!["android"]);
+    }
+
+    #[test]
+    fn test_resolve_filter_unknown_repo_fails() {
+        let temp = tempfile::tempdir().unwrap();
+        write_filter_gripspace(temp.path());
+
+        for filter in [
+            "    exclude_repos: [no-such-repo]",
+            "    overrides:\n      no-such-repo:\n        path: ./x",
+        ] {
+            let mut manifest = Manifest::parse(&format!(
+                "gripspaces:\n  - url: https://github.com/org/company.git\n{}\nrepos:\n  local-repo:\n    url: https://github.com/org/local.git\n    path: ./local\n",
+                filter
+            ))
+            .unwrap();
+
+            let err = resolve_all_gripspaces(&mut manifest, temp.path())
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("unknown repo 'no-such-repo'"), "{}", err);
+        }
+    }
+
     #[test]
     fn test_resolve_local_repo_wins() {
         let temp = tempfile::tempdir().unwrap();
@@ -893,7 +1214,11 @@ repos:
             gripspaces: Some(vec![GripspaceConfig {
                 url: "https://github.com/user/base-gripspace.git".to_string(),
                 rev: None,
+                exclude_repos: Vec::new(),
+                include_groups: Vec::new(),
+                overrides: HashMap::new(),
             }]),
+            remotes: None,
             manifest: None,
             repos: {
                 let mut m = HashMap::new();
@@ -901,14 +1226,21 @@ repos:
                     "my-repo".to_string(),
                     crate::core::manifest::RepoConfig {
                         url: "https://github.com/user/local-version.git".to_string(),
+                        remote: None,
+                        name: None,
                         path: "./my-repo-local".to_string(),
                         default_branch: "main".to_string(),
+                        revision: None,
                         copyfile: None,
                         linkfile: None,
                         platform: None,
                         reference: false,
                         groups: Vec::new(),
                         agent: None,
+                        depends_on: Vec::new(),
+                        clone: None,
+                        sparse: Vec::new(),
+                        pr: None,
                     },
                 );
                 m
@@ -940,6 +1272,9 @@ repos:
         let config = GripspaceConfig {
             url: ".git".to_string(),
             rev: None,
+            exclude_repos: Vec::new(),
+            include_groups: Vec::new(),
+            overrides: HashMap::new(),
         };
         let result = ensure_gripspace(temp.path(), &config);
         assert!(result.is_err());
@@ -977,7 +1312,11 @@ repos:
             gripspaces: Some(vec![GripspaceConfig {
                 url: "https://github.com/user/self-ref.git".to_string(),
                 rev: None,
+                exclude_repos: Vec::new(),
+                include_groups: Vec::new(),
+                overrides: HashMap::new(),
             }]),
+            remotes: None,
             manifest: None,
             repos: HashMap::new(),
             settings: Default::default(),
@@ -1034,7 +1373,11 @@ repos:
             gripspaces: Some(vec![GripspaceConfig {
                 url: "https://github.com/user/gs-0.git".to_string(),
                 rev: None,
+                exclude_repos: Vec::new(),
+                include_groups: Vec::new(),
+                overrides: HashMap::new(),
             }]),
+            remotes: None,
             manifest: None,
             repos: HashMap::new(),
             settings: Default::default(),
@@ -1080,7 +1423,11 @@ workspace:
             gripspaces: Some(vec![GripspaceConfig {
                 url: "https://github.com/user/base-gripspace.git".to_string(),
                 rev: None,
+                exclude_repos: Vec::new(),
+                include_groups: Vec::new(),
+                overrides: HashMap::new(),
             }]),
+            remotes: None,
             manifest: None,
             repos: HashMap::new(),
             settings: Default::default(),
@@ -1203,7 +1550,11 @@ repos:
             gripspaces: Some(vec![GripspaceConfig {
                 url: "https://github.com/org/a.git".to_string(),
                 rev: None,
+                exclude_repos: Vec::new(),
+                include_groups: Vec::new(),
+                overrides: HashMap::new(),
             }]),
+            remotes: None,
             manifest: None,
             repos: HashMap::new(),
             settings: Default::default(),
@@ -1285,7 +1636,11 @@ repos:
             gripspaces: Some(vec![GripspaceConfig {
                 url: "https://github.com/root/root-space.git".to_string(),
                 rev: None,
+                exclude_repos: Vec::new(),
+                include_groups: Vec::new(),
+                overrides: HashMap::new(),
             }]),
+            remotes: None,
             manifest: None,
             repos: HashMap::new(),
             settings: Default::default(),
@@ -1469,6 +1824,9 @@ repos:
         let config = GripspaceConfig {
             url: "https://github.com/user/test-repo.git".to_string(),
             rev: Some("--orphan".to_string()),
+            exclude_repos: Vec::new(),
+            include_groups: Vec::new(),
+            overrides: HashMap::new(),
         };
         let result = ensure_gripspace(temp.path(), &config);
         assert!(result.is_err());
@@ -1499,6 +1857,9 @@ repos:
         let config = GripspaceConfig {
             url: "https://github.com/user/test-repo.git".to_string(),
             rev: Some("main ; rm -rf /".to_string()),
+            exclude_repos: Vec::new(),
+            include_groups: Vec::new(),
+            overrides: HashMap::new(),
         };
         let result = ensure_gripspace(temp.path(), &config);
         assert!(result.is_err());
@@ -1529,9 +1890,114 @@ repos:
         let config = GripspaceConfig {
             url: "https://github.com/user/test-repo.git".to_string(),
             rev: Some("".to_string()),
+            exclude_repos: Vec::new(),
+            include_groups: Vec::new(),
+            overrides: HashMap::new(),
         };
         let result = ensure_gripspace(temp.path(), &config);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid rev"));
     }
+
+    #[test]
+    fn test_list_gripspaces_includes_nested() {
+        let temp = tempfile::tempdir().unwrap();
+        let spaces_dir = temp.path();
+
+        for name in ["a", "b"] {
+            std::fs::create_dir_all(spaces_dir.join(name)).unwrap();
+        }
+        init_git_with_origin(&spaces_dir.join("a"), "https://github.com/org/a.git");
+        init_git_with_origin(&spaces_dir.join("b"), "https://github.com/org/b.git");
+
+        std::fs::write(
+            spaces_dir.join("a").join("gripspace.yml"),
+            r#"
+version: 1
+gripspaces:
+  - url: https://github.com/org/b.git
+  - url: https://github.com/org/not-cloned.git
+repos: {}
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            spaces_dir.join("b").join("gripspace.yml"),
+            "version: 1\nrepos: {}\n",
+        )
+        .unwrap();
+
+        let listed = list_gripspaces(
+            &[GripspaceConfig {
+                url: "https://github.com/org/a.git".to_string(),
+                rev: None,
+                exclude_repos: Vec::new(),
+                include_groups: Vec::new(),
+                overrides: HashMap::new(),
+            }],
+            spaces_dir,
+        );
+        let names: Vec<&str> = listed.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_resolve_locked_pins_nested_gripspace_before_reading_it() {
+        let temp = tempfile::tempdir().unwrap();
+        let spaces_dir = temp.path();
+        let git = |dir: &Path, args: &[&str]| {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {:?} failed", args);
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+
+        let a = spaces_dir.join("a");
+        let b = spaces_dir.join("b");
+        std::fs::create_dir_all(&a).unwrap();
+        std::fs::create_dir_all(&b).unwrap();
+        init_git_with_origin(&a, "https://github.com/org/a.git");
+        init_git_with_origin(&b, "https://github.com/org/b.git");
+        std::fs::write(
+            a.join("gripspace.yml"),
+            "version: 1\ngripspaces:\n  - url: https://github.com/org/b.git\n    rev: main\nrepos: {}\n",
+        )
+        .unwrap();
+
+        git(&b, &["checkout", "-q", "-b", "main"]);
+        git(&b, &["config", "user.email", "test@example.com"]);
+        git(&b, &["config", "user.name", "Test User"]);
+        let manifest_for = |repo: &str| {
+            format!(
+                "version: 1\nrepos:\n  {}:\n    url: https://github.com/org/{}.git\n    path: ./{}\n",
+                repo, repo, repo
+            )
+        };
+        std::fs::write(b.join("gripspace.yml"), manifest_for("old-repo")).unwrap();
+        git(&b, &["add", "."]);
+        git(&b, &["commit", "-q", "-m", "Old"]);
+        let locked_sha = git(&b, &["rev-parse", "HEAD"]);
+        std::fs::write(b.join("gripspace.yml"), manifest_for("new-repo")).unwrap();
+        git(&b, &["commit", "-q", "-am", "New"]);
+
+        let mut lockfile = Lockfile::default();
+        lockfile.gripspaces.insert(
+            "b".to_string(),
+            crate::core::lockfile::LockedGripspace {
+                url: "https://github.com/org/b.git".to_string(),
+                sha: locked_sha,
+            },
+        );
+        let mut manifest = Manifest::parse_raw(
+            "version: 1\ngripspaces:\n  - url: https://github.com/org/a.git\nrepos: {}\n",
+        )
+        .unwrap();
+
+        resolve_all_gripspaces_locked(&mut manifest, spaces_dir, Some(&lockfile)).unwrap();
+        assert!(manifest.repos.contains_key("old-repo"));
+        assert!(!manifest.repos.contains_key("new-repo"));
+    }
 }
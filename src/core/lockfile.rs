@@ -0,0 +1,235 @@
+//! Workspace lockfile
+//!
+//! The lockfile (`gripspace.lock`) sits beside the workspace manifest and records
+//! the exact commit SHA of every repo and gripspace, so a workspace can be
+//! reproduced with `gr sync --locked`. Entries are kept in sorted maps so the
+//! file diffs cleanly when committed to the manifest repo.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use crate::core::manifest::GripspaceConfig;
+use crate::core::manifest_paths;
+
+/// Current lockfile format version
+pub const LOCKFILE_VERSION: u32 = 1;
+
+/// Errors that can occur when loading or saving a lockfile
+#[derive(Error, Debug)]
+pub enum LockfileError {
+    #[error("Failed to read lockfile: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to parse lockfile YAML: {0}")]
+    ParseError(#[from] serde_yaml::Error),
+
+    #[error("Lockfile not found: {0} (run 'gr manifest lock' first)")]
+    NotFound(String),
+
+    #[error("Unsupported lockfile version: {0}")]
+    UnsupportedVersion(u32),
+}
+
+/// A locked repository entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedRepo {
+    /// Git URL the repo was locked from
+    pub url: String,
+    /// Path relative to workspace root
+    pub path: String,
+    /// Resolved commit SHA
+    pub sha: String,
+}
+
+/// A locked gripspace entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedGripspace {
+    /// Git URL of the gripspace repository
+    pub url: String,
+    /// Resolved commit SHA
+    pub sha: String,
+}
+
+/// The lockfile structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockfile {
+    /// Lockfile format version
+    pub version: u32,
+    /// Map: repo name -> locked repo
+    #[serde(default)]
+    pub repos: BTreeMap<String, LockedRepo>,
+    /// Map: gripspace directory name -> locked gripspace
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub gripspaces: BTreeMap<String, LockedGripspace>,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Self {
+            version: LOCKFILE_VERSION,
+            repos: BTreeMap::new(),
+            gripspaces: BTreeMap::new(),
+        }
+    }
+}
+
+impl Lockfile {
+    /// Load a lockfile from disk
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, LockfileError> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(LockfileError::NotFound(path.display().to_string()));
+        }
+        let content = std::fs::read_to_string(path)?;
+        Self::parse(&content)
+    }
+
+    /// Parse a lockfile from a YAML string
+    pub fn parse(yaml: &str) -> Result<Self, LockfileError> {
+        let lockfile: Lockfile = serde_yaml::from_str(yaml)?;
+        if lockfile.version != LOCKFILE_VERSION {
+            return Err(LockfileError::UnsupportedVersion(lockfile.version));
+        }
+        Ok(lockfile)
+    }
+
+    /// Save the lockfile to disk
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), LockfileError> {
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_yaml()?)?;
+        Ok(())
+    }
+
+    /// Serialize the lockfile to YAML
+    pub fn to_yaml(&self) -> Result<String, LockfileError> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+
+    /// Load the lockfile that sits beside the workspace manifest
+    pub fn load_from_workspace(workspace_root: &Path) -> Result<Self, LockfileError> {
+        Self::load(lockfile_path(workspace_root))
+    }
+
+    /// Get the locked SHA for a repo
+    pub fn repo_sha(&self, name: &str) -> Option<&str> {
+        self.repos.get(name).map(|r| r.sha.as_str())
+    }
+
+    /// Get the locked SHA for a gripspace by URL
+    pub fn gripspace_sha(&self, url: &str) -> Option<&str> {
+        self.gripspaces
+            .values()
+            .find(|g| g.url == url)
+            .map(|g| g.sha.as_str())
+    }
+
+    /// `config` with its `rev` replaced by the locked SHA, if there is one
+    pub fn pin_gripspace(&self, config: &GripspaceConfig) -> GripspaceConfig {
+        match self.gripspace_sha(&config.url) {
+            Some(sha) => GripspaceConfig {
+                rev: Some(sha.to_string()),
+                ..config.clone()
+            },
+            None => config.clone(),
+        }
+    }
+}
+
+/// Path of the lockfile for a workspace (beside the manifest)
+pub fn lockfile_path(workspace_root: &Path) -> PathBuf {
+    manifest_paths::resolve_manifest_content_dir(workspace_root)
+        .join(manifest_paths::LOCK_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample() -> Lockfile {
+        let mut lockfile = Lockfile::default();
+        lockfile.repos.insert(
+            "frontend".to_string(),
+            LockedRepo {
+                url: "git@github.com:org/frontend.git".to_string(),
+                path: "frontend".to_string(),
+                sha: "1111111111111111111111111111111111111111".to_string(),
+            },
+        );
+        lockfile.repos.insert(
+            "backend".to_string(),
+            LockedRepo {
+                url: "git@github.com:org/backend.git".to_string(),
+                path: "backend".to_string(),
+                sha: "2222222222222222222222222222222222222222".to_string(),
+            },
+        );
+        lockfile.gripspaces.insert(
+            "base".to_string(),
+            LockedGripspace {
+                url: "https://github.com/org/base.git".to_string(),
+                sha: "3333333333333333333333333333333333333333".to_string(),
+            },
+        );
+        lockfile
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let lockfile = sample();
+        let yaml = lockfile.to_yaml().unwrap();
+        let parsed = Lockfile::parse(&yaml).unwrap();
+        assert_eq!(parsed, lockfile);
+    }
+
+    #[test]
+    fn test_repos_serialized_in_sorted_order() {
+        let yaml = sample().to_yaml().unwrap();
+        let backend = yaml.find("backend:").unwrap();
+        let frontend = yaml.find("frontend:").unwrap();
+        assert!(backend < frontend);
+    }
+
+    #[test]
+    fn test_lookup_helpers() {
+        let lockfile = sample();
+        assert_eq!(
+            lockfile.repo_sha("frontend"),
+            Some("1111111111111111111111111111111111111111")
+        );
+        assert_eq!(lockfile.repo_sha("missing"), None);
+        assert_eq!(
+            lockfile.gripspace_sha("https://github.com/org/base.git"),
+            Some("3333333333333333333333333333333333333333")
+        );
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let yaml = "version: 99\nrepos: {}\n";
+        assert!(matches!(
+            Lockfile::parse(yaml),
+            Err(LockfileError::UnsupportedVersion(99))
+        ));
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let tmp = TempDir::new().unwrap();
+        let result = Lockfile::load(tmp.path().join("gripspace.lock"));
+        assert!(matches!(result, Err(LockfileError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("nested").join("gripspace.lock");
+        let lockfile = sample();
+        lockfile.save(&path).unwrap();
+        assert_eq!(Lockfile::load(&path).unwrap(), lockfile);
+    }
+}
@@ -7,6 +7,8 @@ use std::collections::HashMap;
 use std::path::Path;
 use thiserror::Error;
 
+use crate::core::dag::{DagError, RepoDag};
+
 /// Errors that can occur when loading or validating a manifest
 #[derive(Error, Debug)]
 pub enum ManifestError {
@@ -39,6 +41,11 @@ pub enum PlatformType {
     AzureDevOps,
     #[serde(rename = "bitbucket")]
     Bitbucket,
+    #[serde(rename = "gitea", alias = "forgejo")]
+    Gitea,
+    /// Bare repos on disk, with platform data stored beside them
+    #[serde(rename = "local")]
+    Local,
 }
 
 impl std::fmt::Display for PlatformType {
@@ -48,6 +55,8 @@ impl std::fmt::Display for PlatformType {
             PlatformType::GitLab => write!(f, "gitlab"),
             PlatformType::AzureDevOps => write!(f, "azure-devops"),
             PlatformType::Bitbucket => write!(f, "bitbucket"),
+            PlatformType::Gitea => write!(f, "gitea"),
+            PlatformType::Local => write!(f, "local"),
         }
     }
 }
@@ -62,6 +71,45 @@ pub struct PlatformConfig {
     pub base_url: Option<String>,
 }
 
+/// Named remote that repos can resolve their URL against
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteConfig {
+    /// Fetch base URL (e.g., "git@github.com:org" or "https://gitlab.example.com/group")
+    pub fetch: String,
+    /// Optional push base URL (defaults to the fetch base)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push: Option<String>,
+    /// Platform for repos on this remote (auto-detected from the URL if omitted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<PlatformConfig>,
+}
+
+impl RemoteConfig {
+    /// Fetch URL for a project on this remote
+    pub fn fetch_url(&self, project: &str) -> String {
+        join_remote_url(&self.fetch, project)
+    }
+
+    /// Push URL for a project on this remote, if a separate push base is set
+    pub fn push_url(&self, project: &str) -> Option<String> {
+        self.push
+            .as_deref()
+            .map(|base| join_remote_url(base, project))
+    }
+}
+
+/// Join a remote base URL and a relative project name.
+///
+/// SCP-style bases ending in `:` (e.g., "git@host:") are joined without a slash.
+pub fn join_remote_url(base: &str, project: &str) -> String {
+    let project = project.trim_start_matches('/');
+    if base.ends_with(':') {
+        format!("{}{}", base, project)
+    } else {
+        format!("{}/{}", base.trim_end_matches('/'), project)
+    }
+}
+
 /// File copy configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CopyFileConfig {
@@ -88,6 +136,32 @@ pub struct GripspaceConfig {
     /// Optional revision (branch, tag, or commit SHA) to pin
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rev: Option<String>,
+    /// Inherited repos to leave out
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_repos: Vec<String>,
+    /// Only inherit repos in at least one of these groups (empty = all)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include_groups: Vec<String>,
+    /// Per-repo changes applied to inherited repos
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub overrides: HashMap<String, RepoOverride>,
+}
+
+/// Changes to a repo inherited from a gripspace
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepoOverride {
+    /// Local path relative to workspace root
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Default branch name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    /// Revision (branch, tag, or commit SHA) to pin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    /// Groups to add to the repo
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
 }
 
 /// A part of a composed file
@@ -112,6 +186,73 @@ pub struct ComposeFileConfig {
     pub separator: Option<String>,
 }
 
+/// Clone options for large repositories (shallow, partial, single-branch)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloneConfig {
+    /// Truncate history to this many commits (`git clone --depth`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<u32>,
+    /// Partial clone filter spec, e.g. `blob:none` or `tree:0` (`git clone --filter`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Only fetch the default branch (`git clone --single-branch`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub single_branch: bool,
+}
+
+impl CloneConfig {
+    /// True when the clone is a plain full clone
+    pub fn is_full(&self) -> bool {
+        self.depth.is_none() && self.filter.is_none() && !self.single_branch
+    }
+}
+
+/// Reviewers and metadata applied to PRs opened by `gr pr create`
+///
+/// Set workspace-wide in `settings.pr`; a repo's `pr` replaces any field it sets.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrDefaults {
+    /// Users to request reviews from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewers: Option<Vec<String>>,
+    /// Teams to request reviews from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_reviewers: Option<Vec<String>>,
+    /// Labels to add
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    /// Users to assign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<Vec<String>>,
+    /// Milestone title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<String>,
+}
+
+impl PrDefaults {
+    /// True when no field is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// These defaults with every field set in `overrides` replaced
+    pub fn merged_with(&self, overrides: Option<&PrDefaults>) -> PrDefaults {
+        let Some(overrides) = overrides else {
+            return self.clone();
+        };
+        PrDefaults {
+            reviewers: overrides.reviewers.clone().or(self.reviewers.clone()),
+            team_reviewers: overrides
+                .team_reviewers
+                .clone()
+                .or(self.team_reviewers.clone()),
+            labels: overrides.labels.clone().or(self.labels.clone()),
+            assignees: overrides.assignees.clone().or(self.assignees.clone()),
+            milestone: overrides.milestone.clone().or(self.milestone.clone()),
+        }
+    }
+}
+
 /// Agent context for a repository — build/test/lint commands for AI agents
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RepoAgentConfig {
@@ -161,13 +302,24 @@ pub struct WorkspaceAgentConfig {
 /// Repository configuration in the manifest
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RepoConfig {
-    /// Git URL (SSH or HTTPS)
+    /// Git URL (SSH or HTTPS); may be omitted when `remote` is set
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub url: String,
+    /// Named remote (from `remotes`) to resolve the URL against
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    /// Project name relative to the remote's fetch base (defaults to the repo key)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /// Local path relative to manifest root
     pub path: String,
     /// Default branch (e.g., "main", "master")
     #[serde(default = "default_branch")]
     pub default_branch: String,
+    /// Pinned revision (tag, branch or SHA) that sync checks out instead of
+    /// pulling `default_branch`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
     /// Optional file copies
     #[serde(skip_serializing_if = "Option::is_none")]
     pub copyfile: Option<Vec<CopyFileConfig>>,
@@ -186,12 +338,68 @@ pub struct RepoConfig {
     /// Agent context metadata (build/test/lint commands for AI agents)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agent: Option<RepoAgentConfig>,
+    /// Repos that must be processed before this one (build, test, merge, release)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// Shallow/partial clone options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clone: Option<CloneConfig>,
+    /// Directories to check out (cone-mode sparse checkout); empty = full checkout
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sparse: Vec<String>,
+    /// Overrides of `settings.pr` for PRs opened in this repo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pr: Option<PrDefaults>,
 }
 
 fn default_branch() -> String {
     "main".to_string()
 }
 
+impl RepoConfig {
+    /// Project name on the remote (explicit `name`, or the repo key)
+    pub fn project_name<'a>(&'a self, key: &'a str) -> &'a str {
+        self.name.as_deref().unwrap_or(key)
+    }
+
+    /// The named remote this repo resolves against, if any
+    pub fn remote_config<'a>(
+        &self,
+        remotes: Option<&'a HashMap<String, RemoteConfig>>,
+    ) -> Option<&'a RemoteConfig> {
+        let remote = self.remote.as_deref()?;
+        remotes?.get(remote)
+    }
+
+    /// Resolve the fetch URL: an explicit `url`, or the remote's fetch base plus the project name.
+    ///
+    /// Returns `None` when the repo references a remote that is not defined.
+    pub fn resolve_url(
+        &self,
+        key: &str,
+        remotes: Option<&HashMap<String, RemoteConfig>>,
+    ) -> Option<String> {
+        if !self.url.is_empty() {
+            return Some(self.url.clone());
+        }
+        self.remote_config(remotes)
+            .map(|remote| remote.fetch_url(self.project_name(key)))
+    }
+
+    /// Resolve the push URL from the remote's push base, if one is set
+    pub fn resolve_push_url(
+        &self,
+        key: &str,
+        remotes: Option<&HashMap<String, RemoteConfig>>,
+    ) -> Option<String> {
+        if !self.url.is_empty() {
+            return None;
+        }
+        self.remote_config(remotes)
+            .and_then(|remote| remote.push_url(self.project_name(key)))
+    }
+}
+
 /// Manifest repository self-tracking configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManifestRepoConfig {
@@ -234,6 +442,9 @@ pub struct ManifestSettings {
     /// Merge strategy for linked PRs
     #[serde(default)]
     pub merge_strategy: MergeStrategy,
+    /// Reviewers, labels, assignees and milestone for new PRs
+    #[serde(default, skip_serializing_if = "PrDefaults::is_empty")]
+    pub pr: PrDefaults,
 }
 
 fn default_pr_prefix() -> String {
@@ -245,6 +456,7 @@ impl Default for ManifestSettings {
         Self {
             pr_prefix: default_pr_prefix(),
             merge_strategy: MergeStrategy::default(),
+            pr: PrDefaults::default(),
         }
     }
 }
@@ -335,6 +547,10 @@ pub struct CiStep {
     /// Continue pipeline even if this step fails
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub continue_on_error: bool,
+    /// Run the command in every non-reference repo (in `depends_on` order)
+    /// instead of once at the workspace root; `cwd` is then relative to each repo
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub per_repo: bool,
 }
 
 /// A CI pipeline definition
@@ -410,6 +626,9 @@ pub struct Manifest {
     /// Gripspace includes (composable manifest inheritance)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gripspaces: Option<Vec<GripspaceConfig>>,
+    /// Named remotes that repos can reference instead of a full URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remotes: Option<HashMap<String, RemoteConfig>>,
     /// Self-tracking manifest config (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub manifest: Option<ManifestRepoConfig>,
@@ -459,11 +678,16 @@ impl Manifest {
             ));
         }
 
+        self.validate_remotes()?;
+
         // Validate each repo config
         for (name, repo) in &self.repos {
             self.validate_repo_config(name, repo)?;
         }
 
+        // Dependencies may point at repos contributed by gripspaces
+        self.validate_dependencies(self.gripspaces.is_some())?;
+
         // Validate manifest repo config if present
         if let Some(ref manifest_config) = self.manifest {
             self.validate_file_configs(
@@ -476,16 +700,7 @@ impl Manifest {
             }
         }
 
-        // Validate gripspace configs
-        if let Some(ref gripspaces) = self.gripspaces {
-            for gs in gripspaces {
-                if gs.url.is_empty() {
-                    return Err(ManifestError::ValidationError(
-                        "Gripspace has empty URL".to_string(),
-                    ));
-                }
-            }
-        }
+        self.validate_gripspace_configs()?;
 
         // Validate workspace scripts
         if let Some(ref workspace) = self.workspace {
@@ -498,10 +713,13 @@ impl Manifest {
     /// Validate a gripspace manifest (allows empty repos since gripspaces may only contribute
     /// scripts, hooks, env, or file configs).
     pub fn validate_as_gripspace(&self) -> Result<(), ManifestError> {
+        self.validate_remotes()?;
+
         // Validate each repo config (if any)
         for (name, repo) in &self.repos {
             self.validate_repo_config(name, repo)?;
         }
+        self.validate_dependencies(true)?;
 
         // Validate manifest config if present
         if let Some(ref manifest_config) = self.manifest {
@@ -515,16 +733,7 @@ impl Manifest {
             }
         }
 
-        // Validate gripspace configs
-        if let Some(ref gripspaces) = self.gripspaces {
-            for gs in gripspaces {
-                if gs.url.is_empty() {
-                    return Err(ManifestError::ValidationError(
-                        "Gripspace has empty URL".to_string(),
-                    ));
-                }
-            }
-        }
+        self.validate_gripspace_configs()?;
 
         // Validate workspace scripts
         if let Some(ref workspace) = self.workspace {
@@ -534,13 +743,110 @@ impl Manifest {
         Ok(())
     }
 
+    fn validate_gripspace_configs(&self) -> Result<(), ManifestError> {
+        for gs in self.gripspaces.iter().flatten() {
+            if gs.url.is_empty() {
+                return Err(ManifestError::ValidationError(
+                    "Gripspace has empty URL".to_string(),
+                ));
+            }
+
+            for (name, over) in &gs.overrides {
+                if let Some(ref path) = over.path {
+                    if path.is_empty() || path_escapes_boundary(path) {
+                        return Err(ManifestError::PathTraversal(format!(
+                            "Override for '{}' in gripspace '{}' has invalid path: {}",
+                            name, gs.url, path
+                        )));
+                    }
+                }
+                if let Some(ref revision) = over.revision {
+                    if !is_valid_revision(revision) {
+                        return Err(ManifestError::ValidationError(format!(
+                            "Override for '{}' in gripspace '{}' has invalid revision '{}'",
+                            name, gs.url, revision
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_remotes(&self) -> Result<(), ManifestError> {
+        for (name, remote) in self.remotes.iter().flatten() {
+            if remote.fetch.is_empty() {
+                return Err(ManifestError::ValidationError(format!(
+                    "Remote '{}' must have a fetch URL",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate `depends_on`: no self-dependencies or cycles, and (unless
+    /// `allow_external`) no references to repos outside this manifest.
+    fn validate_dependencies(&self, allow_external: bool) -> Result<(), ManifestError> {
+        let mut names: Vec<&String> = self.repos.keys().collect();
+        names.sort();
+        for name in names {
+            for dep in &self.repos[name].depends_on {
+                if dep == name {
+                    return Err(ManifestError::ValidationError(format!(
+                        "Repository '{}' depends on itself",
+                        name
+                    )));
+                }
+                if !allow_external && !self.repos.contains_key(dep) {
+                    return Err(ManifestError::ValidationError(format!(
+                        "Repository '{}' depends on unknown repository '{}'",
+                        name, dep
+                    )));
+                }
+            }
+        }
+
+        if let Some(cycle) = RepoDag::from_manifest(self).find_cycle() {
+            return Err(ManifestError::ValidationError(
+                DagError::Cycle(cycle).to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
     fn validate_repo_config(&self, name: &str, repo: &RepoConfig) -> Result<(), ManifestError> {
-        // URL must be non-empty
-        if repo.url.is_empty() {
-            return Err(ManifestError::ValidationError(format!(
-                "Repository '{}' must have a URL",
-                name
-            )));
+        // Must have exactly one of url or remote
+        match (repo.url.is_empty(), repo.remote.as_deref()) {
+            (true, None) => {
+                return Err(ManifestError::ValidationError(format!(
+                    "Repository '{}' must have a URL or a remote",
+                    name
+                )));
+            }
+            (false, Some(_)) => {
+                return Err(ManifestError::ValidationError(format!(
+                    "Repository '{}' sets both 'url' and 'remote'; use one",
+                    name
+                )));
+            }
+            (true, Some(remote)) => {
+                if repo.remote_config(self.remotes.as_ref()).is_none() {
+                    return Err(ManifestError::ValidationError(format!(
+                        "Repository '{}' references unknown remote '{}'",
+                        name, remote
+                    )));
+                }
+            }
+            (false, None) => {
+                if repo.name.is_some() {
+                    return Err(ManifestError::ValidationError(format!(
+                        "Repository '{}' sets 'name' without a 'remote'",
+                        name
+                    )));
+                }
+            }
         }
 
         // Path must be non-empty
@@ -559,6 +865,53 @@ impl Manifest {
             )));
         }
 
+        // Revision must be usable as a git revision argument
+        if let Some(ref revision) = repo.revision {
+            if !is_valid_revision(revision) {
+                return Err(ManifestError::ValidationError(format!(
+                    "Repository '{}' has invalid revision '{}': must not be empty, start with '-', or contain whitespace",
+                    name, revision
+                )));
+            }
+        }
+
+        if let Some(ref clone) = repo.clone {
+            if clone.depth == Some(0) {
+                return Err(ManifestError::ValidationError(format!(
+                    "Repository '{}' has invalid clone depth 0: must be at least 1",
+                    name
+                )));
+            }
+            if let Some(ref filter) = clone.filter {
+                if !is_valid_revision(filter) {
+                    return Err(ManifestError::ValidationError(format!(
+                        "Repository '{}' has invalid clone filter '{}': must not be empty, start with '-', or contain whitespace",
+                        name, filter
+                    )));
+                }
+            }
+        }
+
+        // Sparse entries are cone-mode directories inside the repo
+        for dir in &repo.sparse {
+            let trimmed = dir.trim_matches('/');
+            if trimmed.is_empty()
+                || dir.starts_with('-')
+                || dir.contains(['*', '?', '[', '!', '\\'])
+            {
+                return Err(ManifestError::ValidationError(format!(
+                    "Repository '{}' has invalid sparse directory '{}': must be a directory path, not a pattern",
+                    name, dir
+                )));
+            }
+            if path_escapes_boundary(trimmed) {
+                return Err(ManifestError::PathTraversal(format!(
+                    "Repository '{}' sparse directory escapes the repo: {}",
+                    name, dir
+                )));
+            }
+        }
+
         // Validate copyfile/linkfile configs
         self.validate_file_configs(name, &repo.copyfile, &repo.linkfile)?;
 
@@ -726,6 +1079,13 @@ fn is_windows_absolute(path: &str) -> bool {
         || path.starts_with("\\\\")
 }
 
+/// Check that a revision is usable as a git revision argument
+fn is_valid_revision(revision: &str) -> bool {
+    !revision.is_empty()
+        && !revision.starts_with('-')
+        && !revision.chars().any(|c| c.is_whitespace())
+}
+
 /// Check if a path escapes the workspace boundary
 fn path_escapes_boundary(path: &str) -> bool {
     // Normalize path separators
@@ -831,6 +1191,355 @@ repos:
         assert!(matches!(result, Err(ManifestError::PathTraversal(_))));
     }
 
+    #[test]
+    fn test_parse_repo_revision() {
+        let yaml = r#"
+repos:
+  pinned:
+    url: git@github.com:user/repo.git
+    path: pinned
+    revision: v1.2.0
+  unpinned:
+    url: git@github.com:user/other.git
+    path: unpinned
+"#;
+        let manifest = Manifest::parse(yaml).unwrap();
+        assert_eq!(manifest.repos["pinned"].revision.as_deref(), Some("v1.2.0"));
+        assert_eq!(manifest.repos["unpinned"].revision, None);
+    }
+
+    #[test]
+    fn test_invalid_revision_fails() {
+        for revision in ["\"\"", "--force", "\"v1 0\""] {
+            let yaml = format!(
+                "repos:\n  pinned:\n    url: git@github.com:user/repo.git\n    path: pinned\n    revision: {}\n",
+                revision
+            );
+            let result = Manifest::parse(&yaml);
+            assert!(
+                matches!(result, Err(ManifestError::ValidationError(_))),
+                "revision {} should be rejected",
+                revision
+            );
+        }
+    }
+
+    #[test]
+    fn test_gripspace_override_validation() {
+        let base = "gripspaces:\n  - url: https://github.com/org/company.git\n    overrides:\n      ios:\n";
+        let repos = "repos:\n  app:\n    url: git@github.com:user/app.git\n    path: app\n";
+
+        let yaml = format!("{}        path: ../outside\n{}", base, repos);
+        assert!(matches!(
+            Manifest::parse(&yaml),
+            Err(ManifestError::PathTraversal(_))
+        ));
+
+        let yaml = format!("{}        revision: --force\n{}", base, repos);
+        assert!(matches!(
+            Manifest::parse(&yaml),
+            Err(ManifestError::ValidationError(_))
+        ));
+
+        let yaml = format!(
+            "{}        path: mobile/ios\n        groups: [mobile]\n{}",
+            base, repos
+        );
+        let manifest = Manifest::parse(&yaml).unwrap();
+        let over = &manifest.gripspaces.unwrap()[0].overrides["ios"];
+        assert_eq!(over.path.as_deref(), Some("mobile/ios"));
+        assert_eq!(over.groups, vec!["mobile"]);
+    }
+
+    #[test]
+    fn test_parse_depends_on() {
+        let yaml = r#"
+repos:
+  core:
+    url: git@github.com:user/core.git
+    path: core
+  app:
+    url: git@github.com:user/app.git
+    path: app
+    depends_on: [core]
+"#;
+        let manifest = Manifest::parse(yaml).unwrap();
+        assert_eq!(manifest.repos["app"].depends_on, vec!["core"]);
+        assert!(manifest.repos["core"].depends_on.is_empty());
+    }
+
+    #[test]
+    fn test_depends_on_unknown_repo_fails() {
+        let yaml = r#"
+repos:
+  app:
+    url: git@github.com:user/app.git
+    path: app
+    depends_on: [core]
+"#;
+        let err = Manifest::parse(yaml).unwrap_err();
+        assert!(err.to_string().contains("unknown repository 'core'"));
+    }
+
+    #[test]
+    fn test_depends_on_cycle_fails() {
+        let yaml = r#"
+repos:
+  a:
+    url: git@github.com:user/a.git
+    path: a
+    depends_on: [b]
+  b:
+    url: git@github.com:user/b.git
+    path: b
+    depends_on: [a]
+"#;
+        let err = Manifest::parse(yaml).unwrap_err();
+        assert!(
+            err.to_string().contains("Dependency cycle"),
+            "unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_depends_on_self_fails() {
+        let yaml = r#"
+repos:
+  a:
+    url: git@github.com:user/a.git
+    path: a
+    depends_on: [a]
+"#;
+        let err = Manifest::parse(yaml).unwrap_err();
+        assert!(err.to_string().contains("depends on itself"));
+    }
+
+    #[test]
+    fn test_parse_sparse() {
+        let yaml = r#"
+repos:
+  platform:
+    url: git@github.com:user/platform.git
+    path: platform
+    sparse:
+      - services/api
+      - docs/
+"#;
+        let manifest = Manifest::parse(yaml).unwrap();
+        assert_eq!(
+            manifest.repos["platform"].sparse,
+            vec!["services/api", "docs/"]
+        );
+
+        for (bad, traversal) in [("'*.rs'", false), ("--cone", false), ("../other", true)] {
+            let yaml = format!(
+                "repos:\n  platform:\n    url: git@github.com:user/platform.git\n    path: platform\n    sparse: [{}]\n",
+                bad
+            );
+            let err = Manifest::parse(&yaml).unwrap_err();
+            assert_eq!(
+                matches!(err, ManifestError::PathTraversal(_)),
+                traversal,
+                "{}: {}",
+                bad,
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_pr_defaults_with_repo_override() {
+        let yaml = r#"
+settings:
+  pr:
+    reviewers: [alice, bob]
+    labels: [cross-repo]
+    milestone: v2
+repos:
+  app:
+    url: git@github.com:user/app.git
+    path: app
+  lib:
+    url: git@github.com:user/lib.git
+    path: lib
+    pr:
+      reviewers: [carol]
+      assignees: [dave]
+"#;
+        let manifest = Manifest::parse(yaml).unwrap();
+        let defaults = &manifest.settings.pr;
+
+        let app = defaults.merged_with(manifest.repos["app"].pr.as_ref());
+        assert_eq!(app, *defaults);
+
+        let lib = defaults.merged_with(manifest.repos["lib"].pr.as_ref());
+        assert_eq!(lib.reviewers, Some(vec!["carol".to_string()]));
+        assert_eq!(lib.labels, Some(vec!["cross-repo".to_string()]));
+        assert_eq!(lib.assignees, Some(vec!["dave".to_string()]));
+        assert_eq!(lib.milestone.as_deref(), Some("v2"));
+        assert!(lib.team_reviewers.is_none());
+    }
+
+    #[test]
+    fn test_parse_clone_config() {
+        let yaml = r#"
+repos:
+  big:
+    url: git@github.com:user/big.git
+    path: big
+    clone:
+      depth: 1
+      filter: blob:none
+      single_branch: true
+  small:
+    url: git@github.com:user/small.git
+    path: small
+"#;
+        let manifest = Manifest::parse(yaml).unwrap();
+        let clone = manifest.repos["big"].clone.as_ref().unwrap();
+        assert_eq!(clone.depth, Some(1));
+        assert_eq!(clone.filter.as_deref(), Some("blob:none"));
+        assert!(clone.single_branch);
+        assert!(!clone.is_full());
+        assert!(manifest.repos["small"].clone.is_none());
+
+        for bad in ["depth: 0", "filter: --upload-pack=x"] {
+            let yaml = format!(
+                "repos:\n  big:\n    url: git@github.com:user/big.git\n    path: big\n    clone:\n      {}\n",
+                bad
+            );
+            assert!(matches!(
+                Manifest::parse(&yaml),
+                Err(ManifestError::ValidationError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_remotes() {
+        let yaml = r#"
+remotes:
+  origin:
+    fetch: git@github.com:myorg
+  internal:
+    fetch: https://gitlab.example.com/group/
+    push: git@gitlab.example.com:group
+    platform:
+      type: gitlab
+      base_url: https://gitlab.example.com
+repos:
+  frontend:
+    remote: origin
+    path: frontend
+  backend:
+    remote: internal
+    name: services/backend
+    path: backend
+  legacy:
+    url: https://github.com/other/legacy.git
+    path: legacy
+"#;
+        let manifest = Manifest::parse(yaml).unwrap();
+        let remotes = manifest.remotes.as_ref();
+
+        let frontend = &manifest.repos["frontend"];
+        assert_eq!(
+            frontend.resolve_url("frontend", remotes).as_deref(),
+            Some("git@github.com:myorg/frontend")
+        );
+        assert_eq!(frontend.resolve_push_url("frontend", remotes), None);
+
+        let backend = &manifest.repos["backend"];
+        assert_eq!(
+            backend.resolve_url("backend", remotes).as_deref(),
+            Some("https://gitlab.example.com/group/services/backend")
+        );
+        assert_eq!(
+            backend.resolve_push_url("backend", remotes).as_deref(),
+            Some("git@gitlab.example.com:group/services/backend")
+        );
+
+        let legacy = &manifest.repos["legacy"];
+        assert_eq!(
+            legacy.resolve_url("legacy", remotes).as_deref(),
+            Some("https://github.com/other/legacy.git")
+        );
+    }
+
+    #[test]
+    fn test_join_remote_url() {
+        assert_eq!(
+            join_remote_url("https://github.com/org/", "repo"),
+            "https://github.com/org/repo"
+        );
+        assert_eq!(
+            join_remote_url("git@github.com:org", "repo.git"),
+            "git@github.com:org/repo.git"
+        );
+        assert_eq!(
+            join_remote_url("git@github.com:", "org/repo"),
+            "git@github.com:org/repo"
+        );
+    }
+
+    #[test]
+    fn test_repo_unknown_remote_fails() {
+        let yaml = r#"
+remotes:
+  origin:
+    fetch: git@github.com:myorg
+repos:
+  app:
+    remote: upstream
+    path: app
+"#;
+        let err = Manifest::parse(yaml).unwrap_err().to_string();
+        assert!(err.contains("unknown remote 'upstream'"), "{}", err);
+    }
+
+    #[test]
+    fn test_repo_url_and_remote_conflict_fails() {
+        let yaml = r#"
+remotes:
+  origin:
+    fetch: git@github.com:myorg
+repos:
+  app:
+    url: git@github.com:myorg/app.git
+    remote: origin
+    path: app
+"#;
+        let result = Manifest::parse(yaml);
+        assert!(matches!(result, Err(ManifestError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_repo_without_url_or_remote_fails() {
+        let yaml = r#"
+repos:
+  app:
+    path: app
+"#;
+        let result = Manifest::parse(yaml);
+        assert!(matches!(result, Err(ManifestError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_remote_empty_fetch_fails() {
+        let yaml = r#"
+remotes:
+  origin:
+    fetch: ""
+repos:
+  app:
+    remote: origin
+    path: app
+"#;
+        let result = Manifest::parse(yaml);
+        assert!(matches!(result, Err(ManifestError::ValidationError(_))));
+    }
+
     #[test]
     fn test_script_with_both_command_and_steps_fails() {
         let yaml = r#"
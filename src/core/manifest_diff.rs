@@ -0,0 +1,405 @@
+//! Manifest diffing
+//!
+//! Compares two versions of a gripspace manifest: repos added or removed,
+//! URL/path/branch changes, group membership, and workspace scripts, hooks,
+//! env and linkfiles. Only the manifest file itself is compared; repos merged
+//! in from gripspaces are not resolved.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::core::manifest::{HookCommand, LinkFileConfig, Manifest, RepoConfig};
+
+/// A changed scalar field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<String>,
+}
+
+/// An added repo
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AddedRepo {
+    pub name: String,
+    pub url: String,
+    pub path: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
+}
+
+/// Changes to a repo present in both versions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RepoChange {
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldChange>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub groups_added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub groups_removed: Vec<String>,
+}
+
+/// Added, removed and changed keys of a named collection (scripts, env)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct KeyedChanges {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changed: Vec<String>,
+}
+
+impl KeyedChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Added and removed entries of a list (hook commands, linkfiles), keyed by owner
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListChange {
+    /// Hook name (e.g. "post-sync") or linkfile owner (repo name or "manifest")
+    pub owner: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+}
+
+/// Differences between two manifests
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ManifestDiff {
+    pub added_repos: Vec<AddedRepo>,
+    pub removed_repos: Vec<String>,
+    pub changed_repos: Vec<RepoChange>,
+    pub scripts: KeyedChanges,
+    pub env: KeyedChanges,
+    pub hooks: Vec<ListChange>,
+    pub linkfiles: Vec<ListChange>,
+}
+
+impl ManifestDiff {
+    /// Compare `old` against `new`
+    pub fn between(old: &Manifest, new: &Manifest) -> Self {
+        let mut diff = ManifestDiff::default();
+
+        for name in sorted_keys(&new.repos) {
+            let new_repo = &new.repos[name];
+            match old.repos.get(name) {
+                None => diff.added_repos.push(AddedRepo {
+                    name: name.clone(),
+                    url: effective_url(name, new_repo, new),
+                    path: new_repo.path.clone(),
+                    groups: new_repo.groups.clone(),
+                }),
+                Some(old_repo) => {
+                    let change = diff_repo(name, old_repo, old, new_repo, new);
+                    if !change.fields.is_empty()
+                        || !change.groups_added.is_empty()
+                        || !change.groups_removed.is_empty()
+                    {
+                        diff.changed_repos.push(change);
+                    }
+                }
+            }
+        }
+        diff.removed_repos = sorted_keys(&old.repos)
+            .into_iter()
+            .filter(|name| !new.repos.contains_key(*name))
+            .cloned()
+            .collect();
+
+        let workspace = |m: &Manifest| m.workspace.clone().unwrap_or_default();
+        let (old_ws, new_ws) = (workspace(old), workspace(new));
+
+        diff.scripts = diff_keyed(
+            &serialized_map(old_ws.scripts.as_ref()),
+            &serialized_map(new_ws.scripts.as_ref()),
+        );
+        diff.env = diff_keyed(
+            &serialized_map(old_ws.env.as_ref()),
+            &serialized_map(new_ws.env.as_ref()),
+        );
+
+        let (old_hooks, new_hooks) = (
+            old_ws.hooks.unwrap_or_default(),
+            new_ws.hooks.unwrap_or_default(),
+        );
+        for (hook, old_cmds, new_cmds) in [
+            ("post-sync", old_hooks.post_sync, new_hooks.post_sync),
+            (
+                "post-checkout",
+                old_hooks.post_checkout,
+                new_hooks.post_checkout,
+            ),
+        ] {
+            let describe = |cmds: Option<Vec<HookCommand>>| -> Vec<String> {
+                cmds.unwrap_or_default()
+                    .into_iter()
+                    .map(|h| h.command)
+                    .collect()
+            };
+            push_list_change(
+                &mut diff.hooks,
+                hook,
+                describe(old_cmds),
+                describe(new_cmds),
+            );
+        }
+
+        let (old_links, new_links) = (linkfiles_by_owner(old), linkfiles_by_owner(new));
+        let owners: BTreeSet<&String> = old_links.keys().chain(new_links.keys()).collect();
+        for owner in owners {
+            push_list_change(
+                &mut diff.linkfiles,
+                owner,
+                old_links.get(owner).cloned().unwrap_or_default(),
+                new_links.get(owner).cloned().unwrap_or_default(),
+            );
+        }
+
+        diff
+    }
+
+    /// True when the manifests are equivalent for everything this diff covers
+    pub fn is_empty(&self) -> bool {
+        self.added_repos.is_empty()
+            && self.removed_repos.is_empty()
+            && self.changed_repos.is_empty()
+            && self.scripts.is_empty()
+            && self.env.is_empty()
+            && self.hooks.is_empty()
+            && self.linkfiles.is_empty()
+    }
+}
+
+fn sorted_keys<V>(map: &HashMap<String, V>) -> Vec<&String> {
+    let mut keys: Vec<&String> = map.keys().collect();
+    keys.sort();
+    keys
+}
+
+/// The repo's URL with named remotes resolved
+fn effective_url(name: &str, repo: &RepoConfig, manifest: &Manifest) -> String {
+    repo.resolve_url(name, manifest.remotes.as_ref())
+        .unwrap_or_else(|| repo.url.clone())
+}
+
+fn diff_repo(
+    name: &str,
+    old: &RepoConfig,
+    old_manifest: &Manifest,
+    new: &RepoConfig,
+    new_manifest: &Manifest,
+) -> RepoChange {
+    let mut fields = Vec::new();
+    let mut compare = |field: &str, old: Option<String>, new: Option<String>| {
+        if old != new {
+            fields.push(FieldChange {
+                field: field.to_string(),
+                old,
+                new,
+            });
+        }
+    };
+    compare(
+        "url",
+        Some(effective_url(name, old, old_manifest)),
+        Some(effective_url(name, new, new_manifest)),
+    );
+    compare("path", Some(old.path.clone()), Some(new.path.clone()));
+    compare(
+        "default_branch",
+        Some(old.default_branch.clone()),
+        Some(new.default_branch.clone()),
+    );
+    compare("revision", old.revision.clone(), new.revision.clone());
+
+    let old_groups: BTreeSet<&String> = old.groups.iter().collect();
+    let new_groups: BTreeSet<&String> = new.groups.iter().collect();
+
+    RepoChange {
+        name: name.to_string(),
+        fields,
+        groups_added: new_groups
+            .difference(&old_groups)
+            .map(|g| g.to_string())
+            .collect(),
+        groups_removed: old_groups
+            .difference(&new_groups)
+            .map(|g| g.to_string())
+            .collect(),
+    }
+}
+
+/// Serialize each value so entries can be compared without `PartialEq` impls
+fn serialized_map<V: Serialize>(map: Option<&HashMap<String, V>>) -> BTreeMap<String, String> {
+    map.into_iter()
+        .flatten()
+        .map(|(k, v)| (k.clone(), serde_yaml::to_string(v).unwrap_or_default()))
+        .collect()
+}
+
+fn diff_keyed(old: &BTreeMap<String, String>, new: &BTreeMap<String, String>) -> KeyedChanges {
+    let mut changes = KeyedChanges::default();
+    for (key, value) in new {
+        match old.get(key) {
+            None => changes.added.push(key.clone()),
+            Some(old_value) if old_value != value => changes.changed.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+    changes.removed = old
+        .keys()
+        .filter(|k| !new.contains_key(*k))
+        .cloned()
+        .collect();
+    changes
+}
+
+fn push_list_change(
+    changes: &mut Vec<ListChange>,
+    owner: &str,
+    old: Vec<String>,
+    new: Vec<String>,
+) {
+    let added: Vec<String> = new.iter().filter(|e| !old.contains(e)).cloned().collect();
+    let removed: Vec<String> = old.iter().filter(|e| !new.contains(e)).cloned().collect();
+    if !added.is_empty() || !removed.is_empty() {
+        changes.push(ListChange {
+            owner: owner.to_string(),
+            added,
+            removed,
+        });
+    }
+}
+
+/// Linkfile entries ("src -> dest") keyed by repo name, with "manifest" for the manifest repo
+fn linkfiles_by_owner(manifest: &Manifest) -> BTreeMap<String, Vec<String>> {
+    let describe = |links: &Option<Vec<LinkFileConfig>>| -> Vec<String> {
+        links
+            .iter()
+            .flatten()
+            .map(|l| format!("{} -> {}", l.src, l.dest))
+            .collect()
+    };
+
+    let mut out = BTreeMap::new();
+    if let Some(ref config) = manifest.manifest {
+        out.insert("manifest".to_string(), describe(&config.linkfile));
+    }
+    for (name, repo) in &manifest.repos {
+        out.insert(name.clone(), describe(&repo.linkfile));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = r#"
+repos:
+  app:
+    url: git@github.com:org/app.git
+    path: app
+    groups: [web]
+    linkfile:
+      - src: config.yml
+        dest: config.yml
+  lib:
+    url: git@github.com:org/lib.git
+    path: lib
+workspace:
+  env:
+    STAGE: dev
+  scripts:
+    build:
+      command: make
+  hooks:
+    post-sync:
+      - command: echo synced
+"#;
+
+    #[test]
+    fn test_identical_manifests_have_no_diff() {
+        let m = Manifest::parse_raw(BASE).unwrap();
+        assert!(ManifestDiff::between(&m, &m).is_empty());
+    }
+
+    #[test]
+    fn test_repo_changes() {
+        let old = Manifest::parse_raw(BASE).unwrap();
+        let new = Manifest::parse_raw(
+            &BASE
+                .replace(
+                    "  lib:\n    url: git@github.com:org/lib.git\n    path: lib\n",
+                    "  api:\n    url: git@github.com:org/api.git\n    path: api\n",
+                )
+                .replace(
+                    "path: app\n    groups: [web]",
+                    "path: apps/app\n    groups: [core]\n    default_branch: develop",
+                ),
+        )
+        .unwrap();
+
+        let diff = ManifestDiff::between(&old, &new);
+        assert_eq!(diff.added_repos.len(), 1);
+        assert_eq!(diff.added_repos[0].name, "api");
+        assert_eq!(diff.added_repos[0].url, "git@github.com:org/api.git");
+        assert_eq!(diff.removed_repos, vec!["lib"]);
+
+        let app = &diff.changed_repos[0];
+        assert_eq!(app.name, "app");
+        let fields: Vec<&str> = app.fields.iter().map(|f| f.field.as_str()).collect();
+        assert_eq!(fields, vec!["path", "default_branch"]);
+        assert_eq!(app.fields[0].new.as_deref(), Some("apps/app"));
+        assert_eq!(app.groups_added, vec!["core"]);
+        assert_eq!(app.groups_removed, vec!["web"]);
+    }
+
+    #[test]
+    fn test_url_change_through_named_remote() {
+        let old = Manifest::parse_raw(
+            "remotes:\n  origin:\n    fetch: git@github.com:old\nrepos:\n  app:\n    remote: origin\n    path: app\n",
+        )
+        .unwrap();
+        let new = Manifest::parse_raw(
+            "remotes:\n  origin:\n    fetch: git@github.com:new\nrepos:\n  app:\n    remote: origin\n    path: app\n",
+        )
+        .unwrap();
+
+        let diff = ManifestDiff::between(&old, &new);
+        let url = &diff.changed_repos[0].fields[0];
+        assert_eq!(url.field, "url");
+        assert_eq!(url.old.as_deref(), Some("git@github.com:old/app"));
+        assert_eq!(url.new.as_deref(), Some("git@github.com:new/app"));
+    }
+
+    #[test]
+    fn test_workspace_changes() {
+        let old = Manifest::parse_raw(BASE).unwrap();
+        let new = Manifest::parse_raw(
+            &BASE
+                .replace("STAGE: dev", "STAGE: prod\n    REGION: eu")
+                .replace("command: make", "command: make all")
+                .replace("echo synced", "gr link")
+                .replace("dest: config.yml", "dest: app.yml"),
+        )
+        .unwrap();
+
+        let diff = ManifestDiff::between(&old, &new);
+        assert_eq!(diff.env.added, vec!["REGION"]);
+        assert_eq!(diff.env.changed, vec!["STAGE"]);
+        assert_eq!(diff.scripts.changed, vec!["build"]);
+        assert_eq!(diff.hooks[0].owner, "post-sync");
+        assert_eq!(diff.hooks[0].added, vec!["gr link"]);
+        assert_eq!(diff.hooks[0].removed, vec!["echo synced"]);
+        assert_eq!(diff.linkfiles[0].owner, "app");
+        assert_eq!(diff.linkfiles[0].added, vec!["config.yml -> app.yml"]);
+    }
+}
@@ -0,0 +1,1113 @@
+//! Comment-preserving manifest editing
+//!
+//! Mutating commands edit `gripspace.yml` through [`ManifestEditor`] instead of
+//! re-serializing it. The document is split into a tree of indentation blocks,
+//! each owning the comment and blank lines above it, so an edit only rewrites
+//! the lines it touches. [`format_manifest`] uses the same tree to normalize
+//! key order and indentation while keeping every comment.
+
+use std::path::Path;
+use thiserror::Error;
+
+/// Errors that can occur when editing a manifest
+#[derive(Error, Debug)]
+pub enum ManifestEditError {
+    #[error("Failed to read manifest file: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to parse manifest YAML: {0}")]
+    ParseError(#[from] serde_yaml::Error),
+
+    #[error("Cannot edit '{path}': {reason}")]
+    Unsupported { path: String, reason: String },
+
+    #[error("Edit produced an invalid manifest: {0}")]
+    InvalidResult(String),
+}
+
+/// Canonical order of top-level manifest keys (matches `Manifest`)
+const TOP_LEVEL_KEYS: &[&str] = &[
+    "version",
+    "gripspaces",
+    "remotes",
+    "manifest",
+    "repos",
+    "settings",
+    "workspace",
+];
+
+/// Canonical order of repo keys (matches `RepoConfig`)
+const REPO_KEYS: &[&str] = &[
+    "url",
+    "remote",
+    "name",
+    "path",
+    "default_branch",
+    "revision",
+    "copyfile",
+    "linkfile",
+    "platform",
+    "reference",
+    "groups",
+    "agent",
+    "depends_on",
+    "clone",
+    "sparse",
+];
+
+/// Canonical order of `manifest:` keys (matches `ManifestRepoConfig`)
+const MANIFEST_REPO_KEYS: &[&str] = &[
+    "url",
+    "default_branch",
+    "copyfile",
+    "linkfile",
+    "composefile",
+    "platform",
+];
+
+/// Canonical order of remote keys (matches `RemoteConfig`)
+const REMOTE_KEYS: &[&str] = &["fetch", "push", "platform"];
+
+/// Indentation used by `format` and for new blocks when none can be inferred
+const INDENT: usize = 2;
+
+/// A line and every more-indented line below it
+#[derive(Debug, Clone, Default)]
+struct Block {
+    /// Comment and blank lines directly above the block, verbatim
+    leading: Vec<String>,
+    indent: usize,
+    /// The first line without its indentation
+    text: String,
+    children: Vec<Block>,
+    /// Children are block scalar content and must keep their exact layout
+    verbatim: bool,
+    /// Comment and blank lines kept after the last child (left behind by removals)
+    trailing: Vec<String>,
+}
+
+impl Block {
+    fn new(indent: usize, text: String) -> Self {
+        Block {
+            indent,
+            text,
+            ..Default::default()
+        }
+    }
+
+    fn key(&self) -> Option<String> {
+        split_key(&self.text).map(|(key, _)| key)
+    }
+
+    /// The inline value after `key:`, without any trailing comment
+    fn value(&self) -> String {
+        split_key(&self.text)
+            .map(|(_, rest)| split_comment(rest).0.trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Replace the inline value, keeping the key and any trailing comment
+    fn set_value(&mut self, value: &str) {
+        let Some(colon) = key_end(&self.text) else {
+            return;
+        };
+        let (head, rest) = self.text.split_at(colon + 1);
+        let comment = split_comment(rest).1;
+        let mut text = head.to_string();
+        if !value.is_empty() {
+            text.push(' ');
+            text.push_str(value);
+        }
+        if let Some(comment) = comment {
+            text.push(' ');
+            text.push_str(comment);
+        }
+        self.text = text;
+    }
+
+    fn render(&self, out: &mut Vec<String>) {
+        out.extend(self.leading.iter().cloned());
+        out.push(format!("{}{}", " ".repeat(self.indent), self.text));
+        for child in &self.children {
+            child.render(out);
+        }
+        out.extend(self.trailing.iter().cloned());
+    }
+}
+
+/// Comment-preserving editor for a gripspace manifest
+#[derive(Debug, Clone)]
+pub struct ManifestEditor {
+    blocks: Vec<Block>,
+    /// Comment and blank lines after the last block
+    trailing: Vec<String>,
+    final_newline: bool,
+    /// Indentation step used when adding nested blocks
+    indent_step: usize,
+}
+
+impl ManifestEditor {
+    /// Parse manifest YAML for editing
+    pub fn parse(content: &str) -> Result<Self, ManifestEditError> {
+        serde_yaml::from_str::<serde_yaml::Value>(content)?;
+
+        let lines: Vec<&str> = content.lines().collect();
+        let mut parser = Parser { lines, pos: 0 };
+        let blocks = parser.parse_blocks(0, false);
+        let trailing = parser.lines[parser.pos..]
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+
+        Ok(ManifestEditor {
+            indent_step: infer_indent_step(&blocks).unwrap_or(INDENT),
+            blocks,
+            trailing,
+            final_newline: content.is_empty() || content.ends_with('\n'),
+        })
+    }
+
+    /// Load a manifest file for editing
+    pub fn load(path: &Path) -> Result<Self, ManifestEditError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Render the edited document
+    pub fn render(&self) -> String {
+        let mut lines = Vec::new();
+        for block in &self.blocks {
+            block.render(&mut lines);
+        }
+        lines.extend(self.trailing.iter().cloned());
+
+        let mut out = lines.join("\n");
+        if self.final_newline && !out.is_empty() {
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Validate and write the document, returning the written content
+    pub fn save(&self, path: &Path) -> Result<String, ManifestEditError> {
+        let content = self.render();
+        serde_yaml::from_str::<serde_yaml::Value>(&content)
+            .map_err(|e| ManifestEditError::InvalidResult(e.to_string()))?;
+        std::fs::write(path, &content)?;
+        Ok(content)
+    }
+
+    /// Whether a key exists at `path` (e.g. `["repos", "app", "groups"]`)
+    pub fn contains(&self, path: &[&str]) -> bool {
+        let mut blocks = &self.blocks;
+        for key in path {
+            match blocks.iter().find(|b| b.key().as_deref() == Some(key)) {
+                Some(block) => blocks = &block.children,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Set a scalar value, creating missing parent mappings
+    pub fn set(&mut self, path: &[&str], value: &str) -> Result<(), ManifestEditError> {
+        let (last, parents) = path.split_last().expect("path must not be empty");
+        let step = self.indent_step;
+        let (container, indent) = self.container_mut(parents, true)?;
+        let value = yaml_scalar(value);
+
+        match container
+            .iter_mut()
+            .find(|b| b.key().as_deref() == Some(last))
+        {
+            Some(block) if block.children.is_empty() || block.verbatim => {
+                block.children.clear();
+                block.verbatim = false;
+                block.set_value(&value);
+            }
+            Some(_) => {
+                return Err(unsupported(path, "value is a mapping or list"));
+            }
+            None => {
+                let indent = child_indent(container, indent, step);
+                push_block(
+                    container,
+                    Block::new(indent, format!("{}: {}", last, value)),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the key at `path` along with the comments directly above it.
+    ///
+    /// Returns false if the key does not exist.
+    pub fn remove(&mut self, path: &[&str]) -> bool {
+        let (last, parents) = path.split_last().expect("path must not be empty");
+        if parents.is_empty() {
+            return remove_child(&mut self.blocks, &mut self.trailing, last);
+        }
+
+        let Some(parent) = self.block_mut(parents) else {
+            return false;
+        };
+        if !remove_child(&mut parent.children, &mut parent.trailing, last) {
+            return false;
+        }
+        // An emptied mapping would become null, which the manifest rejects
+        if parent.children.is_empty() {
+            parent.set_value("{}");
+        }
+        true
+    }
+
+    /// Add `value` to the list at `path`, creating it if missing.
+    ///
+    /// Returns false if the value is already present.
+    pub fn add_to_list(&mut self, path: &[&str], value: &str) -> Result<bool, ManifestEditError> {
+        let (last, parents) = path.split_last().expect("path must not be empty");
+        let step = self.indent_step;
+        let (container, indent) = self.container_mut(parents, true)?;
+
+        let Some(block) = container
+            .iter_mut()
+            .find(|b| b.key().as_deref() == Some(last))
+        else {
+            let indent = child_indent(container, indent, step);
+            let text = format!("{}: [{}]", last, flow_scalar(value));
+            push_block(container, Block::new(indent, text));
+            return Ok(true);
+        };
+
+        let current = block.value();
+        if let Some(mut items) = parse_flow_list(&current) {
+            if items.iter().any(|i| i == value) {
+                return Ok(false);
+            }
+            items.push(value.to_string());
+            block.set_value(&render_flow_list(&items));
+            return Ok(true);
+        }
+
+        if !current.is_empty() || block.verbatim {
+            return Err(unsupported(path, "value is not a list"));
+        }
+        if block.children.is_empty() {
+            block.set_value(&format!("[{}]", flow_scalar(value)));
+            return Ok(true);
+        }
+        if block
+            .children
+            .iter()
+            .any(|c| seq_item(&c.text).map(scalar_value).as_deref() == Some(value))
+        {
+            return Ok(false);
+        }
+        let item_indent = block.children[0].indent;
+        block
+            .children
+            .push(Block::new(item_indent, format!("- {}", yaml_scalar(value))));
+        Ok(true)
+    }
+
+    /// Remove `value` from the list at `path`, dropping the key once empty.
+    ///
+    /// Returns false if the list does not contain the value.
+    pub fn remove_from_list(
+        &mut self,
+        path: &[&str],
+        value: &str,
+    ) -> Result<bool, ManifestEditError> {
+        let (last, parents) = path.split_last().expect("path must not be empty");
+        let Ok((container, _)) = self.container_mut(parents, false) else {
+            return Ok(false);
+        };
+        let Some(block) = container
+            .iter_mut()
+            .find(|b| b.key().as_deref() == Some(last))
+        else {
+            return Ok(false);
+        };
+
+        let current = block.value();
+        let now_empty = if let Some(mut items) = parse_flow_list(&current) {
+            let before = items.len();
+            items.retain(|i| i != value);
+            if items.len() == before {
+                return Ok(false);
+            }
+            block.set_value(&render_flow_list(&items));
+            items.is_empty()
+        } else if current.is_empty() && !block.verbatim {
+            let before = block.children.len();
+            block
+                .children
+                .retain(|c| seq_item(&c.text).map(scalar_value).as_deref() != Some(value));
+            if block.children.len() == before {
+                return Ok(false);
+            }
+            block.children.is_empty()
+        } else {
+            return Err(unsupported(path, "value is not a list"));
+        };
+
+        if now_empty {
+            self.remove(path);
+        }
+        Ok(true)
+    }
+
+    /// Normalize key order and indentation, keeping comments
+    pub fn format(&mut self) {
+        // A comment block at the top of the file, separated from the first key
+        // by a blank line, stays at the top whatever key ends up first
+        let mut header = Vec::new();
+        if let Some(first) = self.blocks.first_mut() {
+            if let Some(split) = first.leading.iter().rposition(|l| l.trim().is_empty()) {
+                header = first.leading.drain(..=split).collect();
+            }
+        }
+
+        format_blocks(&mut self.blocks, 0, &[]);
+
+        let mut header = normalize_trivia(&header, 0);
+        while header.first().is_some_and(|l| l.is_empty()) {
+            header.remove(0);
+        }
+        if !header.is_empty() && header.last().is_some_and(|l| !l.is_empty()) {
+            header.push(String::new());
+        }
+        if let Some(first) = self.blocks.first_mut() {
+            first.leading.splice(0..0, header);
+        }
+
+        self.trailing = normalize_trivia(&self.trailing, 0);
+        while self.trailing.last().is_some_and(|l| l.is_empty()) {
+            self.trailing.pop();
+        }
+        self.final_newline = true;
+        self.indent_step = INDENT;
+    }
+
+    /// The children of the mapping at `path` and its indentation, optionally
+    /// creating missing mappings on the way
+    fn container_mut(
+        &mut self,
+        path: &[&str],
+        create: bool,
+    ) -> Result<(&mut Vec<Block>, Option<usize>), ManifestEditError> {
+        let step = self.indent_step;
+        let mut container = &mut self.blocks;
+        let mut indent = None;
+
+        for (depth, key) in path.iter().enumerate() {
+            let index = match container
+                .iter()
+                .position(|b| b.key().as_deref() == Some(key))
+            {
+                Some(index) => index,
+                None if create => {
+                    let child = child_indent(container, indent, step);
+                    push_block(container, Block::new(child, format!("{}:", key)));
+                    container.len() - 1
+                }
+                None => return Err(unsupported(&path[..=depth], "key not found")),
+            };
+
+            let block = &mut container[index];
+            match block.value().as_str() {
+                "" => {}
+                "{}" | "~" | "null" if block.children.is_empty() => block.set_value(""),
+                _ => return Err(unsupported(&path[..=depth], "value is not a block mapping")),
+            }
+            if block.verbatim {
+                return Err(unsupported(&path[..=depth], "value is a block scalar"));
+            }
+            indent = Some(block.indent);
+            container = &mut block.children;
+        }
+
+        Ok((container, indent))
+    }
+
+    fn block_mut(&mut self, path: &[&str]) -> Option<&mut Block> {
+        let (first, rest) = path.split_first()?;
+        let mut block = self
+            .blocks
+            .iter_mut()
+            .find(|b| b.key().as_deref() == Some(first))?;
+        for key in rest {
+            block = block
+                .children
+                .iter_mut()
+                .find(|b| b.key().as_deref() == Some(key))?;
+        }
+        Some(block)
+    }
+}
+
+/// Remove the child keyed `key` along with the comments directly above it.
+/// Comments separated from the key by a blank line describe more than this
+/// entry, so they move to the next sibling (or `trailing`).
+fn remove_child(container: &mut Vec<Block>, trailing: &mut Vec<String>, key: &str) -> bool {
+    let Some(index) = container
+        .iter()
+        .position(|b| b.key().as_deref() == Some(key))
+    else {
+        return false;
+    };
+
+    let removed = container.remove(index);
+    let split = removed
+        .leading
+        .iter()
+        .rposition(|l| l.trim().is_empty())
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let detached = &removed.leading[..split];
+    if detached.iter().any(|l| !l.trim().is_empty()) {
+        match container.get_mut(index) {
+            Some(next) => {
+                next.leading.splice(0..0, detached.iter().cloned());
+            }
+            None => {
+                trailing.splice(0..0, detached.iter().cloned());
+            }
+        }
+    }
+    true
+}
+
+/// Format manifest YAML, refusing if the result would not parse to the same data
+pub fn format_manifest(content: &str) -> Result<String, ManifestEditError> {
+    let mut editor = ManifestEditor::parse(content)?;
+    editor.format();
+    let formatted = editor.render();
+
+    let before: serde_yaml::Value = serde_yaml::from_str(content)?;
+    let after: serde_yaml::Value = serde_yaml::from_str(&formatted)
+        .map_err(|e| ManifestEditError::InvalidResult(e.to_string()))?;
+    if before != after {
+        return Err(ManifestEditError::InvalidResult(
+            "formatting would change the manifest's content".to_string(),
+        ));
+    }
+    Ok(formatted)
+}
+
+struct Parser<'a> {
+    lines: Vec<&'a str>,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    /// Parse sibling blocks indented at least `min_indent`. With `seq_only`,
+    /// only `- ` items at exactly `min_indent` are taken (a list written at
+    /// the same indentation as its key).
+    fn parse_blocks(&mut self, min_indent: usize, seq_only: bool) -> Vec<Block> {
+        let mut blocks = Vec::new();
+        loop {
+            let start = self.pos;
+            while self.pos < self.lines.len() && is_trivia(self.lines[self.pos]) {
+                self.pos += 1;
+            }
+            let Some(line) = self.lines.get(self.pos) else {
+                self.pos = start;
+                break;
+            };
+
+            let indent = indent_of(line);
+            let text = &line[indent..];
+            if indent < min_indent
+                || (seq_only && (indent != min_indent || seq_item(text).is_none()))
+            {
+                self.pos = start;
+                break;
+            }
+
+            let mut block = Block::new(indent, text.to_string());
+            block.leading = self.lines[start..self.pos]
+                .iter()
+                .map(|l| l.to_string())
+                .collect();
+            self.pos += 1;
+
+            if opens_block_scalar(text) {
+                block.verbatim = true;
+                block.children = self.parse_verbatim(indent);
+            } else if split_key(text)
+                .is_some_and(|(_, rest)| split_comment(rest).0.trim().is_empty())
+                && self.next_is_seq_item_at(indent)
+            {
+                block.children = self.parse_blocks(indent, true);
+            } else {
+                block.children = self.parse_blocks(indent + 1, false);
+            }
+            blocks.push(block);
+        }
+        blocks
+    }
+
+    /// Block scalar content: every following line indented deeper than the key
+    fn parse_verbatim(&mut self, parent_indent: usize) -> Vec<Block> {
+        let mut end = self.pos;
+        let mut i = self.pos;
+        while let Some(line) = self.lines.get(i) {
+            if line.trim().is_empty() {
+                i += 1;
+            } else if indent_of(line) > parent_indent {
+                i += 1;
+                end = i;
+            } else {
+                break;
+            }
+        }
+
+        let children = self.lines[self.pos..end]
+            .iter()
+            .map(|line| {
+                let indent = indent_of(line);
+                Block::new(indent, line[indent..].to_string())
+            })
+            .collect();
+        self.pos = end;
+        children
+    }
+
+    fn next_is_seq_item_at(&self, indent: usize) -> bool {
+        self.lines[self.pos..]
+            .iter()
+            .find(|l| !is_trivia(l))
+            .is_some_and(|l| indent_of(l) == indent && seq_item(&l[indent..]).is_some())
+    }
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Blank lines, comments and document markers
+fn is_trivia(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.is_empty()
+        || trimmed.starts_with('#')
+        || line.starts_with("---")
+        || line.starts_with("...")
+        || line.starts_with('%')
+}
+
+/// The content of a `- item` line
+fn seq_item(text: &str) -> Option<&str> {
+    if text == "-" {
+        return Some("");
+    }
+    text.strip_prefix("- ")
+        .map(|rest| split_comment(rest).0.trim())
+}
+
+/// Byte offset of the `:` ending a mapping key on this line
+fn key_end(text: &str) -> Option<usize> {
+    if text.starts_with('#') || seq_item(text).is_some() {
+        return None;
+    }
+
+    let bytes = text.as_bytes();
+    let search_from = match bytes.first() {
+        Some(&q @ (b'"' | b'\'')) => text[1..].find(q as char)? + 2,
+        _ => 0,
+    };
+    let offset = text[search_from..]
+        .char_indices()
+        .find(|&(i, c)| {
+            c == ':'
+                && text[search_from + i + 1..]
+                    .chars()
+                    .next()
+                    .map_or(true, |next| next == ' ')
+        })?
+        .0;
+    Some(search_from + offset)
+}
+
+/// Split `key: rest` into the unquoted key and the text after the colon
+fn split_key(text: &str) -> Option<(String, &str)> {
+    let end = key_end(text)?;
+    Some((unquote(text[..end].trim()).to_string(), &text[end + 1..]))
+}
+
+/// Split a value from a trailing ` # comment`, ignoring `#` inside quotes
+fn split_comment(text: &str) -> (&str, Option<&str>) {
+    let mut quote = None;
+    let mut prev = ' ';
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && prev.is_whitespace() => {
+                return (text[..i].trim_end(), Some(&text[i..]));
+            }
+            None => {}
+        }
+        prev = c;
+    }
+    (text, None)
+}
+
+fn unquote(text: &str) -> &str {
+    for q in ['"', '\''] {
+        if text.len() >= 2 && text.starts_with(q) && text.ends_with(q) {
+            return &text[1..text.len() - 1];
+        }
+    }
+    text
+}
+
+/// A scalar as YAML reads it, so that `"web"`, `'web'` and `web` are equal
+fn scalar_value(text: &str) -> String {
+    match serde_yaml::from_str::<serde_yaml::Value>(text) {
+        Ok(serde_yaml::Value::String(s)) => s,
+        Ok(serde_yaml::Value::Number(n)) => n.to_string(),
+        Ok(serde_yaml::Value::Bool(b)) => b.to_string(),
+        _ => unquote(text).to_string(),
+    }
+}
+
+/// Whether the line's value starts a `|` or `>` block scalar
+fn opens_block_scalar(text: &str) -> bool {
+    let mut text = text;
+    while let Some(rest) = text.strip_prefix("- ") {
+        text = rest.trim_start();
+    }
+    let value = match split_key(text) {
+        Some((_, rest)) => rest,
+        None => text,
+    };
+    let value = split_comment(value).0.trim();
+    let mut chars = value.chars();
+    matches!(chars.next(), Some('|' | '>'))
+        && chars.all(|c| c == '-' || c == '+' || c.is_ascii_digit())
+}
+
+fn parse_flow_list(value: &str) -> Option<Vec<String>> {
+    let inner = value.strip_prefix('[')?.strip_suffix(']')?;
+    Some(
+        inner
+            .split(',')
+            .map(|item| scalar_value(item.trim()))
+            .filter(|item| !item.is_empty())
+            .collect(),
+    )
+}
+
+fn render_flow_list(items: &[String]) -> String {
+    let items: Vec<String> = items.iter().map(|i| flow_scalar(i)).collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// A scalar as it would appear in block context, quoted only when needed
+fn yaml_scalar(value: &str) -> String {
+    serde_yaml::to_string(value)
+        .map(|s| s.trim_end().to_string())
+        .unwrap_or_else(|_| format!("{:?}", value))
+}
+
+/// A scalar safe to place inside `[...]`
+fn flow_scalar(value: &str) -> String {
+    if value.contains([',', '[', ']', '{', '}']) {
+        format!("{:?}", value)
+    } else {
+        yaml_scalar(value)
+    }
+}
+
+fn unsupported(path: &[&str], reason: &str) -> ManifestEditError {
+    ManifestEditError::Unsupported {
+        path: path.join("."),
+        reason: reason.to_string(),
+    }
+}
+
+/// Indentation for a new child: match existing siblings, else nest one step
+fn child_indent(container: &[Block], parent: Option<usize>, step: usize) -> usize {
+    container
+        .iter()
+        .find(|b| b.key().is_some())
+        .map(|b| b.indent)
+        .unwrap_or_else(|| parent.map_or(0, |p| p + step))
+}
+
+/// Append a block, separating it with a blank line when its siblings are
+fn push_block(container: &mut Vec<Block>, mut block: Block) {
+    if container.len() >= 2
+        && container
+            .last()
+            .is_some_and(|b| b.leading.first().is_some_and(|l| l.trim().is_empty()))
+    {
+        block.leading.push(String::new());
+    }
+    container.push(block);
+}
+
+/// The indentation of the first nested mapping in the document
+fn infer_indent_step(blocks: &[Block]) -> Option<usize> {
+    blocks.iter().find_map(|block| {
+        if block.verbatim {
+            return None;
+        }
+        block
+            .children
+            .iter()
+            .find(|c| c.key().is_some())
+            .map(|c| c.indent.saturating_sub(block.indent))
+            .filter(|step| *step > 0)
+            .or_else(|| infer_indent_step(&block.children))
+    })
+}
+
+/// Canonical key order for the mapping at `path`, if there is one
+fn key_order(path: &[String]) -> Option<&'static [&'static str]> {
+    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+    match path.as_slice() {
+        [] => Some(TOP_LEVEL_KEYS),
+        ["manifest"] => Some(MANIFEST_REPO_KEYS),
+        ["repos", _] => Some(REPO_KEYS),
+        ["remotes", _] => Some(REMOTE_KEYS),
+        _ => None,
+    }
+}
+
+fn format_blocks(blocks: &mut [Block], indent: usize, path: &[String]) {
+    if let Some(order) = key_order(path) {
+        let rank = |b: &Block| {
+            b.key()
+                .and_then(|k| order.iter().position(|o| *o == k))
+                .unwrap_or(order.len())
+        };
+        blocks.sort_by_key(rank);
+    }
+
+    for (i, block) in blocks.iter_mut().enumerate() {
+        block.leading = normalize_trivia(&block.leading, indent);
+        if i == 0 {
+            while block.leading.first().is_some_and(|l| l.is_empty()) {
+                block.leading.remove(0);
+            }
+        }
+        block.trailing = normalize_trivia(&block.trailing, indent + INDENT);
+
+        let old_indent = block.indent;
+        block.indent = indent;
+        block.text = block.text.trim_end().to_string();
+
+        if block.verbatim {
+            // Shift the content as a whole so its relative layout survives. An
+            // explicit indentation indicator (`|2`) pins the offset from the key.
+            let content_indent = block
+                .children
+                .iter()
+                .filter(|c| !c.text.trim().is_empty())
+                .map(|c| c.indent)
+                .min()
+                .unwrap_or(old_indent + INDENT);
+            let target = if block
+                .text
+                .trim_end()
+                .ends_with(|c: char| c.is_ascii_digit())
+            {
+                indent + content_indent.saturating_sub(old_indent)
+            } else {
+                indent + INDENT
+            };
+            for child in &mut block.children {
+                if child.text.trim().is_empty() {
+                    child.indent = 0;
+                    child.text.clear();
+                } else {
+                    child.indent = child.indent - content_indent + target;
+                }
+            }
+            continue;
+        }
+
+        let mut child_path = path.to_vec();
+        if let Some(key) = block.key() {
+            child_path.push(key);
+        } else {
+            // Entries of a list item are not subject to key ordering
+            child_path.push("-".to_string());
+        }
+        format_blocks(&mut block.children, indent + INDENT, &child_path);
+    }
+}
+
+/// Re-indent comments and collapse runs of blank lines
+fn normalize_trivia(lines: &[String], indent: usize) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            if out.last().is_some_and(|l| l.is_empty()) {
+                continue;
+            }
+            out.push(String::new());
+        } else if line.starts_with('#') || !trimmed.starts_with('#') {
+            // Column-0 comments and document markers stay where they are
+            out.push(line.trim_end().to_string());
+        } else {
+            out.push(format!("{}{}", " ".repeat(indent), trimmed));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"# Workspace manifest
+version: 1
+
+repos:
+  # The main application
+  app:
+    url: git@github.com:org/app.git  # primary remote
+    path: app
+    groups: [web, core]
+
+  lib:
+    url: git@github.com:org/lib.git
+    path: lib
+    groups:
+      - core
+
+settings:
+  pr_prefix: "[cross-repo]"
+"#;
+
+    #[test]
+    fn test_roundtrip_is_lossless() {
+        let editor = ManifestEditor::parse(MANIFEST).unwrap();
+        assert_eq!(editor.render(), MANIFEST);
+
+        let scalar = "workspace:\n  scripts:\n    build:\n      command: |\n        make\n\n          indented\n      cwd: .\n";
+        assert_eq!(ManifestEditor::parse(scalar).unwrap().render(), scalar);
+    }
+
+    #[test]
+    fn test_set_adds_repo_keeping_comments() {
+        let mut editor = ManifestEditor::parse(MANIFEST).unwrap();
+        editor
+            .set(&["repos", "api", "url"], "git@github.com:org/api.git")
+            .unwrap();
+        editor
+            .set(&["repos", "api", "path"], "services/api")
+            .unwrap();
+
+        let out = editor.render();
+        assert!(out.contains("# The main application"));
+        assert!(out.contains("# primary remote"));
+        assert!(out.contains(
+            "      - core\n\n  api:\n    url: git@github.com:org/api.git\n    path: services/api\n\nsettings:"
+        ));
+
+        let manifest = crate::core::manifest::Manifest::parse_raw(&out).unwrap();
+        assert_eq!(manifest.repos["api"].path, "services/api");
+    }
+
+    #[test]
+    fn test_set_replaces_value_and_keeps_comment() {
+        let mut editor = ManifestEditor::parse(MANIFEST).unwrap();
+        editor
+            .set(&["repos", "app", "url"], "git@github.com:org/app2.git")
+            .unwrap();
+        assert!(editor
+            .render()
+            .contains("    url: git@github.com:org/app2.git # primary remote\n"));
+    }
+
+    #[test]
+    fn test_set_creates_missing_section() {
+        let mut editor = ManifestEditor::parse("version: 1\n").unwrap();
+        editor.set(&["repos", "app", "url"], "u").unwrap();
+        assert_eq!(editor.render(), "version: 1\nrepos:\n  app:\n    url: u\n");
+
+        let mut editor = ManifestEditor::parse("version: 1\nrepos: {}\n").unwrap();
+        editor.set(&["repos", "app", "path"], "app").unwrap();
+        assert_eq!(
+            editor.render(),
+            "version: 1\nrepos:\n  app:\n    path: app\n"
+        );
+    }
+
+    #[test]
+    fn test_set_follows_existing_indentation() {
+        let mut editor = ManifestEditor::parse("repos:\n    app:\n        url: u\n").unwrap();
+        editor.set(&["repos", "lib", "url"], "v").unwrap();
+        assert_eq!(
+            editor.render(),
+            "repos:\n    app:\n        url: u\n    lib:\n        url: v\n"
+        );
+    }
+
+    #[test]
+    fn test_remove_repo_with_its_comment() {
+        let mut editor = ManifestEditor::parse(MANIFEST).unwrap();
+        assert!(editor.remove(&["repos", "app"]));
+        assert!(!editor.remove(&["repos", "missing"]));
+
+        let out = editor.render();
+        assert!(!out.contains("The main application"));
+        assert!(!out.contains("app.git"));
+        assert!(out.contains("# Workspace manifest"));
+        assert!(out.contains("  lib:\n"));
+
+        assert!(editor.remove(&["repos", "lib"]));
+        let out = editor.render();
+        assert!(out.contains("repos: {}\n"));
+        crate::core::manifest::Manifest::parse_raw(&out).unwrap();
+    }
+
+    #[test]
+    fn test_remove_keeps_detached_comments() {
+        let content = "repos:\n  # Services\n\n  api:\n    url: u\n  web:\n    url: w\n";
+        let mut editor = ManifestEditor::parse(content).unwrap();
+        editor.remove(&["repos", "api"]);
+        assert_eq!(
+            editor.render(),
+            "repos:\n  # Services\n\n  web:\n    url: w\n"
+        );
+    }
+
+    #[test]
+    fn test_list_edits_flow_and_block() {
+        let mut editor = ManifestEditor::parse(MANIFEST).unwrap();
+
+        assert!(editor
+            .add_to_list(&["repos", "app", "groups"], "api")
+            .unwrap());
+        assert!(!editor
+            .add_to_list(&["repos", "app", "groups"], "web")
+            .unwrap());
+        assert!(editor
+            .add_to_list(&["repos", "lib", "groups"], "tools")
+            .unwrap());
+        assert!(editor
+            .add_to_list(&["repos", "new", "groups"], "web")
+            .unwrap());
+
+        let out = editor.render();
+        assert!(out.contains("    groups: [web, core, api]\n"));
+        assert!(out.contains("    groups:\n      - core\n      - tools\n"));
+        assert!(out.contains("  new:\n    groups: [web]\n"));
+
+        assert!(editor
+            .remove_from_list(&["repos", "app", "groups"], "web")
+            .unwrap());
+        assert!(!editor
+            .remove_from_list(&["repos", "app", "groups"], "web")
+            .unwrap());
+        assert!(editor
+            .remove_from_list(&["repos", "lib", "groups"], "core")
+            .unwrap());
+        assert!(editor
+            .remove_from_list(&["repos", "lib", "groups"], "tools")
+            .unwrap());
+
+        let out = editor.render();
+        assert!(out.contains("    groups: [core, api]\n"));
+        assert!(!out.contains("tools"));
+        assert!(
+            out.contains("  lib:\n    url: git@github.com:org/lib.git\n    path: lib\n\n  new:")
+        );
+    }
+
+    #[test]
+    fn test_list_matches_quoted_values() {
+        let content = "repos:\n  app:\n    groups: [\"web\", 'co''re']\n  lib:\n    groups:\n      - \"web\" # frontend\n";
+        let mut editor = ManifestEditor::parse(content).unwrap();
+
+        assert!(!editor
+            .add_to_list(&["repos", "app", "groups"], "web")
+            .unwrap());
+        assert!(!editor
+            .add_to_list(&["repos", "app", "groups"], "co're")
+            .unwrap());
+        assert!(!editor
+            .add_to_list(&["repos", "lib", "groups"], "web")
+            .unwrap());
+        assert_eq!(editor.render(), content);
+
+        assert!(editor
+            .remove_from_list(&["repos", "lib", "groups"], "web")
+            .unwrap());
+        assert!(!editor.render().contains("lib:\n    groups"));
+    }
+
+    #[test]
+    fn test_list_at_key_indentation() {
+        let content = "repos:\n  app:\n    url: u\n    groups:\n    - web\n    path: app\n";
+        let mut editor = ManifestEditor::parse(content).unwrap();
+        assert_eq!(editor.render(), content);
+        assert!(editor
+            .add_to_list(&["repos", "app", "groups"], "core")
+            .unwrap());
+        assert_eq!(
+            editor.render(),
+            "repos:\n  app:\n    url: u\n    groups:\n    - web\n    - core\n    path: app\n"
+        );
+    }
+
+    #[test]
+    fn test_format_orders_keys_and_keeps_comments() {
+        let content = r#"# Header comment
+
+settings:
+    pr_prefix: "[x]"
+repos:
+    # App comment
+    app:
+        groups:
+        - web
+        path: app   # where it lives
+        url: git@github.com:org/app.git
+
+
+version: 1
+workspace:
+    scripts:
+        build:
+            command: |
+                make
+                  all
+"#;
+        let formatted = format_manifest(content).unwrap();
+        assert_eq!(
+            formatted,
+            r#"# Header comment
+
+version: 1
+repos:
+  # App comment
+  app:
+    url: git@github.com:org/app.git
+    path: app   # where it lives
+    groups:
+      - web
+settings:
+  pr_prefix: "[x]"
+workspace:
+  scripts:
+    build:
+      command: |
+        make
+          all
+"#
+        );
+        assert_eq!(format_manifest(&formatted).unwrap(), formatted);
+    }
+
+    #[test]
+    fn test_invalid_yaml_is_rejected() {
+        assert!(ManifestEditor::parse("repos: [unclosed").is_err());
+    }
+}
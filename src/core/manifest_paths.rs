@@ -12,6 +12,7 @@ pub const LOCAL_SPACE_DIR: &str = ".gitgrip/spaces/local";
 pub const LEGACY_MANIFEST_DIR: &str = ".gitgrip/manifests";
 pub const PRIMARY_FILE_NAME: &str = "gripspace.yml";
 pub const LEGACY_FILE_NAMES: [&str; 2] = ["manifest.yaml", "manifest.yml"];
+pub const LOCK_FILE_NAME: &str = "gripspace.lock";
 
 /// Names reserved for internal use within `.gitgrip/spaces/`.
 /// Included gripspaces that derive one of these names will be auto-suffixed.
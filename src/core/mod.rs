@@ -1,12 +1,20 @@
 //! Core business logic for gitgrip
 
+pub mod affected;
+pub mod bisect;
+pub mod dag;
 pub mod gripspace;
 pub mod griptree;
+pub mod lockfile;
 pub mod manifest;
+pub mod manifest_diff;
+pub mod manifest_editor;
 pub mod manifest_paths;
+pub mod oplog;
 pub mod repo;
 pub mod repo_manifest;
 pub mod state;
+pub mod workspace_import;
 
 pub use manifest::Manifest;
 pub use repo::RepoInfo;
@@ -0,0 +1,158 @@
+//! Operation log
+//!
+//! The operation log (.gitgrip/oplog) records the refs each mutating command
+//! changed, one JSON operation per line, so that `gr undo` can put them back.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::core::state::StateError;
+use crate::git::refs::RefSnapshot;
+
+/// Number of operations kept; older ones are dropped when recording
+pub const MAX_OPERATIONS: usize = 100;
+
+/// Refs of one repo before and after an operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoRefChange {
+    /// Repository name (from manifest)
+    pub repo_name: String,
+    /// Refs before the command ran
+    pub before: RefSnapshot,
+    /// Refs after the command ran
+    pub after: RefSnapshot,
+}
+
+/// One recorded command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    /// Sequential operation ID
+    pub id: u64,
+    /// Command line, without the leading `gr`
+    pub command: String,
+    /// When the command finished
+    pub timestamp: DateTime<Utc>,
+    /// Repos whose refs the command changed
+    pub repos: Vec<RepoRefChange>,
+    /// Set once `gr undo` has restored the `before` refs
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub undone: bool,
+}
+
+/// Path of the operation log in a workspace
+pub fn oplog_path(workspace_root: &Path) -> PathBuf {
+    workspace_root.join(".gitgrip").join("oplog")
+}
+
+/// Load all operations, oldest first
+pub fn load_operations(path: &Path) -> Result<Vec<Operation>, StateError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(path)?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(StateError::from))
+        .collect()
+}
+
+/// Overwrite the log with `operations`
+pub fn save_operations(path: &Path, operations: &[Operation]) -> Result<(), StateError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut content = String::new();
+    for op in operations {
+        content.push_str(&serde_json::to_string(op)?);
+        content.push('\n');
+    }
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// Append an operation, assigning its ID
+///
+/// The log is rewritten (keeping the newest [`MAX_OPERATIONS`]) only when it
+/// has grown past the limit.
+pub fn record_operation(
+    path: &Path,
+    command: &str,
+    repos: Vec<RepoRefChange>,
+) -> Result<Operation, StateError> {
+    let mut operations = load_operations(path)?;
+    let op = Operation {
+        id: operations.last().map(|op| op.id + 1).unwrap_or(1),
+        command: command.to_string(),
+        timestamp: Utc::now(),
+        repos,
+        undone: false,
+    };
+
+    if operations.len() >= MAX_OPERATIONS {
+        operations.push(op.clone());
+        let excess = operations.len() - MAX_OPERATIONS;
+        save_operations(path, &operations[excess..])?;
+    } else {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{}", serde_json::to_string(&op)?)?;
+    }
+    Ok(op)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn change(name: &str) -> RepoRefChange {
+        RepoRefChange {
+            repo_name: name.to_string(),
+            before: RefSnapshot::default(),
+            after: RefSnapshot {
+                head: Some("main".to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_record_and_load() {
+        let temp = TempDir::new().unwrap();
+        let path = oplog_path(temp.path());
+        assert!(load_operations(&path).unwrap().is_empty());
+
+        record_operation(&path, "commit -m one", vec![change("app")]).unwrap();
+        let op = record_operation(&path, "checkout feat", vec![change("lib")]).unwrap();
+        assert_eq!(op.id, 2);
+
+        let ops = load_operations(&path).unwrap();
+        assert_eq!(ops.len(), 2);
+        assert_eq!(ops[0].command, "commit -m one");
+        assert_eq!(ops[1].repos[0].repo_name, "lib");
+        assert!(!ops[1].undone);
+    }
+
+    #[test]
+    fn test_record_trims_old_operations() {
+        let temp = TempDir::new().unwrap();
+        let path = oplog_path(temp.path());
+        for i in 0..MAX_OPERATIONS + 5 {
+            record_operation(&path, &format!("op {}", i), vec![change("app")]).unwrap();
+        }
+        let ops = load_operations(&path).unwrap();
+        assert_eq!(ops.len(), MAX_OPERATIONS);
+        assert_eq!(ops[0].id, 6);
+        assert_eq!(ops.last().unwrap().id, (MAX_OPERATIONS + 5) as u64);
+    }
+}
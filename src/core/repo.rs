@@ -1,9 +1,11 @@
 //! Repository information and operations
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use crate::core::manifest::{
-    Manifest, ManifestRepoConfig, PlatformType, RepoAgentConfig, RepoConfig,
+    CloneConfig, Manifest, ManifestRepoConfig, PlatformType, RemoteConfig, RepoAgentConfig,
+    RepoConfig,
 };
 use crate::core::manifest_paths;
 
@@ -14,12 +16,16 @@ pub struct RepoInfo {
     pub name: String,
     /// Git URL (SSH or HTTPS)
     pub url: String,
+    /// Push URL, when the repo's remote sets a separate push base
+    pub push_url: Option<String>,
     /// Local path relative to manifest root
     pub path: String,
     /// Absolute path on disk
     pub absolute_path: PathBuf,
     /// Default branch (e.g., "main", "master")
     pub default_branch: String,
+    /// Pinned revision (tag, branch or SHA), if any
+    pub revision: Option<String>,
     /// Owner/namespace from git URL
     pub owner: String,
     /// Repo name from git URL
@@ -36,28 +42,47 @@ pub struct RepoInfo {
     pub groups: Vec<String>,
     /// Agent context metadata (build/test/lint commands for AI agents)
     pub agent: Option<RepoAgentConfig>,
+    /// Shallow/partial clone options
+    pub clone: Option<CloneConfig>,
+    /// Sparse checkout directories (empty = full checkout)
+    pub sparse: Vec<String>,
 }
 
 impl RepoInfo {
     /// Create RepoInfo from a manifest RepoConfig
-    pub fn from_config(name: &str, config: &RepoConfig, workspace_root: &PathBuf) -> Option<Self> {
-        let parsed = parse_git_url(&config.url)?;
+    ///
+    /// When the repo references a named remote, the URL is resolved from the
+    /// remote's fetch base and the platform falls back to the remote's platform.
+    pub fn from_config(
+        name: &str,
+        config: &RepoConfig,
+        workspace_root: &PathBuf,
+        remotes: Option<&HashMap<String, RemoteConfig>>,
+    ) -> Option<Self> {
+        let url = config.resolve_url(name, remotes)?;
+        let parsed = parse_git_url(&url)?;
 
         let absolute_path = workspace_root.join(&config.path);
 
-        let platform_type = config
+        let platform = config
             .platform
             .as_ref()
+            .or_else(|| config.remote_config(remotes)?.platform.as_ref());
+        let platform_type = platform
             .map(|p| p.platform_type)
-            .unwrap_or_else(|| detect_platform(&config.url));
-        let platform_base_url = config.platform.as_ref().and_then(|p| p.base_url.clone());
+            .unwrap_or_else(|| detect_platform(&url));
+        let platform_base_url = platform
+            .and_then(|p| p.base_url.clone())
+            .or_else(|| default_platform_base_url(platform_type, &url));
 
         Some(Self {
             name: name.to_string(),
-            url: config.url.clone(),
+            push_url: config.resolve_push_url(name, remotes),
+            url,
             path: config.path.clone(),
             absolute_path,
             default_branch: config.default_branch.clone(),
+            revision: config.revision.clone(),
             owner: parsed.owner,
             repo: parsed.repo,
             platform_type,
@@ -66,6 +91,8 @@ impl RepoInfo {
             reference: config.reference,
             groups: config.groups.clone(),
             agent: config.agent.clone(),
+            clone: config.clone.clone(),
+            sparse: config.sparse.clone(),
         })
     }
 
@@ -197,7 +224,9 @@ pub fn filter_repos(
     manifest
         .repos
         .iter()
-        .filter_map(|(name, config)| RepoInfo::from_config(name, config, workspace_root))
+        .filter_map(|(name, config)| {
+            RepoInfo::from_config(name, config, workspace_root, manifest.remotes.as_ref())
+        })
         .filter(|r| include_reference || !r.reference)
         .filter(|r| {
             repos_filter
@@ -245,19 +274,51 @@ fn create_manifest_repo_info(
         "manifest",
         &RepoConfig {
             url: config.url.clone(),
+            remote: None,
+            name: None,
             path,
             default_branch: config.default_branch.clone(),
+            revision: None,
             copyfile: config.copyfile.clone(),
             linkfile: config.linkfile.clone(),
             platform: config.platform.clone(),
             reference: false,
             groups: Vec::new(),
             agent: None,
+            depends_on: Vec::new(),
+            clone: None,
+            sparse: Vec::new(),
+            pr: None,
         },
         &workspace_root.to_path_buf(),
+        None,
     )
 }
 
+/// Instance URL for platforms that are always served from the repo's own host
+/// (Gitea/Forgejo), or the directory holding a local bare repo, used when the
+/// manifest does not set `base_url`
+fn default_platform_base_url(platform_type: PlatformType, url: &str) -> Option<String> {
+    if platform_type == PlatformType::Local {
+        let path = Path::new(url.strip_prefix("file://")?.trim_end_matches('/'));
+        return Some(path.parent()?.to_string_lossy().into_owned());
+    }
+    if platform_type != PlatformType::Gitea {
+        return None;
+    }
+    if let Some(rest) = url.strip_prefix("git@") {
+        return Some(format!("https://{}", rest.split(':').next()?));
+    }
+    let (scheme, rest) = url.split_once("://")?;
+    let host = rest.split('/').next()?.rsplit('@').next()?;
+    match scheme {
+        "http" | "https" => Some(format!("{}://{}", scheme, host)),
+        // SSH ports are not the web port
+        "ssh" => Some(format!("https://{}", host.split(':').next()?)),
+        _ => None,
+    }
+}
+
 /// Detect platform type from URL
 fn detect_platform(url: &str) -> PlatformType {
     // Check GitHub first (most common)
@@ -280,6 +341,12 @@ fn detect_platform(url: &str) -> PlatformType {
         return PlatformType::GitLab;
     }
 
+    // Gitea/Forgejo instances are recognised by hostname only
+    let host = crate::platform::url_host(url);
+    if host.contains("gitea.") || host.contains("forgejo.") || host == "codeberg.org" {
+        return PlatformType::Gitea;
+    }
+
     // Default to GitHub for backward compatibility
     PlatformType::GitHub
 }
@@ -358,6 +425,99 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_gitea() {
+        assert_eq!(
+            detect_platform("git@codeberg.org:user/repo.git"),
+            PlatformType::Gitea
+        );
+    }
+
+    #[test]
+    fn test_detect_gitlab_with_gitea_in_path() {
+        assert_eq!(
+            detect_platform("git@gitlab.com:org/gitea.chart.git"),
+            PlatformType::GitLab
+        );
+        assert_eq!(
+            detect_platform("https://gitlab.com/x/forgejo.nvim"),
+            PlatformType::GitLab
+        );
+    }
+
+    #[test]
+    fn test_default_platform_base_url() {
+        let base = |url| default_platform_base_url(PlatformType::Gitea, url);
+        assert_eq!(
+            base("git@forgejo.example.com:org/repo.git").as_deref(),
+            Some("https://forgejo.example.com")
+        );
+        assert_eq!(
+            base("http://gitea.local:3000/org/repo.git").as_deref(),
+            Some("http://gitea.local:3000")
+        );
+        assert_eq!(
+            base("ssh://git@gitea.local:2222/org/repo.git").as_deref(),
+            Some("https://gitea.local")
+        );
+        assert_eq!(
+            default_platform_base_url(PlatformType::GitLab, "git@gitlab.com:org/repo.git"),
+            None
+        );
+        assert_eq!(
+            default_platform_base_url(PlatformType::Local, "file:///srv/remotes/app.git")
+                .as_deref(),
+            Some("/srv/remotes")
+        );
+    }
+
+    #[test]
+    fn test_from_config_resolves_remote() {
+        use crate::core::manifest::Manifest;
+
+        let manifest = Manifest::parse(
+            r#"
+remotes:
+  gitlab:
+    fetch: https://gitlab.example.com/platform
+    push: git@gitlab.example.com:platform
+    platform:
+      type: gitlab
+      base_url: https://gitlab.example.com
+repos:
+  api:
+    remote: gitlab
+    name: services/api
+    path: api
+"#,
+        )
+        .unwrap();
+        let workspace = PathBuf::from("/workspace");
+
+        let info = RepoInfo::from_config(
+            "api",
+            &manifest.repos["api"],
+            &workspace,
+            manifest.remotes.as_ref(),
+        )
+        .unwrap();
+        assert_eq!(info.url, "https://gitlab.example.com/platform/services/api");
+        assert_eq!(
+            info.push_url.as_deref(),
+            Some("git@gitlab.example.com:platform/services/api")
+        );
+        assert_eq!(info.owner, "platform");
+        assert_eq!(info.repo, "api");
+        assert_eq!(info.platform_type, PlatformType::GitLab);
+        assert_eq!(
+            info.platform_base_url.as_deref(),
+            Some("https://gitlab.example.com")
+        );
+
+        // Without the remotes map the URL cannot be resolved
+        assert!(RepoInfo::from_config("api", &manifest.repos["api"], &workspace, None).is_none());
+    }
+
     #[test]
     fn test_get_manifest_repo_info_no_manifest() {
         use crate::core::manifest::Manifest;
@@ -368,6 +528,7 @@ mod tests {
         let manifest = Manifest {
             version: 1,
             gripspaces: None,
+            remotes: None,
             manifest: None,
             repos: HashMap::new(),
             settings: Default::default(),
@@ -388,6 +549,7 @@ mod tests {
         let manifest = Manifest {
             version: 1,
             gripspaces: None,
+            remotes: None,
             manifest: Some(ManifestRepoConfig {
                 url: "git@github.com:user/manifest.git".to_string(),
                 default_branch: "main".to_string(),
@@ -422,6 +584,7 @@ mod tests {
         let manifest = Manifest {
             version: 1,
             gripspaces: None,
+            remotes: None,
             manifest: Some(ManifestRepoConfig {
                 url: "git@github.com:user/manifest.git".to_string(),
                 default_branch: "main".to_string(),
@@ -3,6 +3,9 @@
 //! Parses Google's `repo` tool XML manifest format (`default.xml`) and converts
 //! it to a gitgrip `Manifest`. Gerrit remotes (those with a `review` attribute)
 //! are skipped — only non-Gerrit repos get PR capabilities.
+//!
+//! The reverse direction (`XmlManifest::from_manifest`) exports a gitgrip
+//! manifest as a `default.xml` for teams still on `repo` tooling.
 
 use quick_xml::de::from_str;
 use serde::Deserialize;
@@ -11,8 +14,8 @@ use std::path::Path;
 use thiserror::Error;
 
 use crate::core::manifest::{
-    CopyFileConfig, LinkFileConfig, Manifest, ManifestSettings, PlatformConfig, PlatformType,
-    RepoConfig, WorkspaceConfig,
+    join_remote_url, CopyFileConfig, LinkFileConfig, Manifest, ManifestSettings, PlatformConfig,
+    PlatformType, RemoteConfig, RepoConfig, WorkspaceConfig,
 };
 use crate::platform;
 
@@ -67,6 +70,9 @@ pub struct XmlRemote {
     #[serde(rename = "@fetch")]
     pub fetch: String,
 
+    #[serde(rename = "@pushurl", default)]
+    pub pushurl: Option<String>,
+
     /// If present, this remote uses Gerrit for code review
     #[serde(rename = "@review", default)]
     pub review: Option<String>,
@@ -101,6 +107,10 @@ pub struct XmlProject {
     #[serde(rename = "@revision", default)]
     pub revision: Option<String>,
 
+    /// Branch the pinned `revision` came from (used when revision is a SHA or tag)
+    #[serde(rename = "@upstream", default)]
+    pub upstream: Option<String>,
+
     #[serde(rename = "@groups", default)]
     pub groups: Option<String>,
 
@@ -280,6 +290,7 @@ impl XmlManifest {
         let all_projects = self.flatten_projects();
 
         let mut repos = HashMap::new();
+        let mut remotes: HashMap<String, RemoteConfig> = HashMap::new();
         let mut gerrit_skipped = 0;
         let mut non_gerrit_imported = 0;
         let mut platform_counts: HashMap<PlatformType, usize> = HashMap::new();
@@ -311,29 +322,46 @@ impl XmlManifest {
                 continue;
             }
 
-            // Resolve revision
-            let revision = project
+            // Resolve the branch revision inherited from the remote/default
+            let inherited_revision = remote
                 .revision
                 .as_deref()
-                .or(remote.revision.as_deref())
                 .or(default_revision)
-                .unwrap_or("main");
-
-            // Compute default branch from revision
-            let default_branch = revision_to_branch(revision);
+                .filter(|r| !is_pinned_revision(r));
+
+            // Tags and SHAs become pins; branches become the default branch
+            let (default_branch, pinned_revision) = match project.revision.as_deref() {
+                Some(rev) if is_pinned_revision(rev) => {
+                    let branch = project
+                        .upstream
+                        .as_deref()
+                        .or(inherited_revision)
+                        .map(revision_to_branch)
+                        .unwrap_or_else(|| "main".to_string());
+                    (branch, Some(revision_to_branch(rev)))
+                }
+                Some(rev) => (revision_to_branch(rev), None),
+                None => (
+                    revision_to_branch(inherited_revision.unwrap_or("main")),
+                    None,
+                ),
+            };
 
-            // Compute URL
+            // Keep the remote as a named remote; relative fetch bases ("." or
+            // empty) cannot be resolved without the manifest URL, so those
+            // projects keep their name as a literal URL.
             let fetch_base = remote.fetch.trim_end_matches('/');
-            let url = if fetch_base.is_empty() || fetch_base == "." {
+            let named_remote = !(fetch_base.is_empty() || fetch_base == ".");
+            // Project URLs on a remote end in `.git`
+            let remote_project = if project.name.ends_with(".git") {
                 project.name.clone()
             } else {
-                let name_part = &project.name;
-                let full_url = format!("{}/{}", fetch_base, name_part);
-                if full_url.ends_with(".git") {
-                    full_url
-                } else {
-                    format!("{}.git", full_url)
-                }
+                format!("{}.git", project.name)
+            };
+            let url = if named_remote {
+                join_remote_url(fetch_base, &remote_project)
+            } else {
+                project.name.clone()
             };
 
             // Compute path
@@ -383,25 +411,51 @@ impl XmlManifest {
             // Detect platform
             let platform_type = platform::detect_platform(&url);
             *platform_counts.entry(platform_type).or_insert(0) += 1;
+            let platform = Some(PlatformConfig {
+                platform_type,
+                base_url: None,
+            });
 
             // Generate a manifest-safe name from the project name
             let repo_name = project_name_to_key(&project.name);
 
+            let (url, remote_ref, project_name, platform) = if named_remote {
+                remotes
+                    .entry(remote_name.to_string())
+                    .or_insert_with(|| RemoteConfig {
+                        fetch: fetch_base.to_string(),
+                        push: remote.pushurl.clone(),
+                        platform,
+                    });
+                (
+                    String::new(),
+                    Some(remote_name.to_string()),
+                    Some(remote_project),
+                    None,
+                )
+            } else {
+                (url, None, None, platform)
+            };
+
             repos.insert(
                 repo_name,
                 RepoConfig {
                     url,
+                    remote: remote_ref,
+                    name: project_name,
                     path,
                     default_branch,
+                    revision: pinned_revision,
                     copyfile,
                     linkfile,
-                    platform: Some(PlatformConfig {
-                        platform_type,
-                        base_url: None,
-                    }),
+                    platform,
                     reference,
                     groups,
                     agent: None,
+                    depends_on: Vec::new(),
+                    clone: None,
+                    sparse: Vec::new(),
+                    pr: None,
                 },
             );
 
@@ -411,6 +465,7 @@ impl XmlManifest {
         let manifest = Manifest {
             version: 1,
             gripspaces: None,
+            remotes: (!remotes.is_empty()).then_some(remotes),
             manifest: None,
             repos,
             settings: ManifestSettings::default(),
@@ -426,6 +481,186 @@ impl XmlManifest {
         })
     }
 
+    /// Build a git-repo manifest from a gitgrip manifest.
+    ///
+    /// Named remotes are kept; repos with a full `url` are grouped into
+    /// synthesized remotes by fetch base. Pinned revisions are written as
+    /// `revision` with `upstream` set to the default branch (pins for which
+    /// `is_tag(repo key, pin)` holds are written as `refs/tags/<pin>`), and
+    /// reference repos get the `notdefault` group. Platform settings have no
+    /// XML equivalent and are dropped.
+    pub fn from_manifest(manifest: &Manifest, is_tag: impl Fn(&str, &str) -> bool) -> Self {
+        let mut remotes: Vec<XmlRemote> = Vec::new();
+        let mut named: Vec<(&String, &RemoteConfig)> = manifest.remotes.iter().flatten().collect();
+        named.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, remote) in named {
+            remotes.push(XmlRemote {
+                name: name.clone(),
+                fetch: remote.fetch.trim_end_matches('/').to_string(),
+                pushurl: remote.push.clone(),
+                review: None,
+                revision: None,
+            });
+        }
+
+        let mut repo_names: Vec<&String> = manifest.repos.keys().collect();
+        repo_names.sort();
+
+        // (remote name, project name, config) for each repo
+        let mut entries = Vec::new();
+        for key in repo_names {
+            let config = &manifest.repos[key];
+            let (remote_name, project_name) = match config.remote.as_deref() {
+                Some(remote) if config.url.is_empty() => (
+                    remote.to_string(),
+                    config
+                        .project_name(key)
+                        .trim_end_matches(".git")
+                        .to_string(),
+                ),
+                _ => {
+                    let (base, project) = split_repo_url(&config.url);
+                    let remote_name = match remotes.iter().find(|r| r.fetch == base) {
+                        Some(existing) => existing.name.clone(),
+                        None => {
+                            let name = unique_remote_name(&remotes);
+                            remotes.push(XmlRemote {
+                                name: name.clone(),
+                                fetch: base,
+                                pushurl: None,
+                                review: None,
+                                revision: None,
+                            });
+                            name
+                        }
+                    };
+                    (remote_name, project)
+                }
+            };
+            entries.push((key, remote_name, project_name, config));
+        }
+
+        let default_remote = most_common(entries.iter().map(|(_, remote, _, _)| remote.as_str()));
+        let default_revision =
+            most_common(entries.iter().map(|(_, _, _, c)| c.default_branch.as_str()));
+
+        let projects = entries
+            .into_iter()
+            .map(|(key, remote_name, project_name, config)| {
+                let (revision, upstream) = match config.revision.as_deref() {
+                    Some(pin) => (
+                        Some(pin_to_revision(pin, is_tag(key, pin))),
+                        Some(config.default_branch.clone()),
+                    ),
+                    None if Some(config.default_branch.as_str()) != default_revision.as_deref() => {
+                        (Some(config.default_branch.clone()), None)
+                    }
+                    None => (None, None),
+                };
+
+                let mut groups = config.groups.clone();
+                if config.reference && !groups.iter().any(|g| g == "notdefault") {
+                    groups.push("notdefault".to_string());
+                }
+
+                XmlProject {
+                    path: (config.path.trim_start_matches("./") != project_name)
+                        .then(|| config.path.trim_start_matches("./").to_string()),
+                    name: project_name,
+                    remote: (Some(&remote_name) != default_remote.as_ref()).then_some(remote_name),
+                    revision,
+                    upstream,
+                    groups: (!groups.is_empty()).then(|| groups.join(",")),
+                    clone_depth: None,
+                    copyfiles: config
+                        .copyfile
+                        .iter()
+                        .flatten()
+                        .map(|cf| XmlCopyFile {
+                            src: cf.src.clone(),
+                            dest: cf.dest.clone(),
+                        })
+                        .collect(),
+                    linkfiles: config
+                        .linkfile
+                        .iter()
+                        .flatten()
+                        .map(|lf| XmlLinkFile {
+                            src: lf.src.clone(),
+                            dest: lf.dest.clone(),
+                        })
+                        .collect(),
+                    sub_projects: Vec::new(),
+                }
+            })
+            .collect();
+
+        XmlManifest {
+            remotes,
+            default: Some(XmlDefault {
+                remote: default_remote,
+                revision: default_revision,
+                sync_j: None,
+            }),
+            projects,
+            includes: Vec::new(),
+            remove_projects: Vec::new(),
+            extend_projects: Vec::new(),
+        }
+    }
+
+    /// Serialize to git-repo XML (`default.xml` format)
+    pub fn to_xml(&self) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<manifest>\n");
+
+        for remote in &self.remotes {
+            out.push_str("  <remote");
+            push_attr(&mut out, "name", Some(&remote.name));
+            push_attr(&mut out, "fetch", Some(&remote.fetch));
+            push_attr(&mut out, "pushurl", remote.pushurl.as_deref());
+            push_attr(&mut out, "review", remote.review.as_deref());
+            push_attr(&mut out, "revision", remote.revision.as_deref());
+            out.push_str(" />\n");
+        }
+
+        if let Some(ref default) = self.default {
+            out.push_str("  <default");
+            push_attr(&mut out, "remote", default.remote.as_deref());
+            push_attr(&mut out, "revision", default.revision.as_deref());
+            push_attr(&mut out, "sync-j", default.sync_j.as_deref());
+            out.push_str(" />\n");
+        }
+
+        for include in &self.includes {
+            out.push_str("  <include");
+            push_attr(&mut out, "name", Some(&include.name));
+            out.push_str(" />\n");
+        }
+
+        for project in &self.projects {
+            write_project(&mut out, project, 1);
+        }
+
+        for remove in &self.remove_projects {
+            out.push_str("  <remove-project");
+            push_attr(&mut out, "name", Some(&remove.name));
+            out.push_str(" />\n");
+        }
+
+        for ext in &self.extend_projects {
+            out.push_str("  <extend-project");
+            push_attr(&mut out, "name", Some(&ext.name));
+            push_attr(&mut out, "path", ext.path.as_deref());
+            push_attr(&mut out, "remote", ext.remote.as_deref());
+            push_attr(&mut out, "revision", ext.revision.as_deref());
+            push_attr(&mut out, "groups", ext.groups.as_deref());
+            out.push_str(" />\n");
+        }
+
+        out.push_str("</manifest>\n");
+        out
+    }
+
     /// Flatten nested projects into a single list
     fn flatten_projects(&self) -> Vec<XmlProject> {
         let mut result = Vec::new();
@@ -486,6 +721,115 @@ fn revision_to_branch(revision: &str) -> String {
     }
 }
 
+/// Write a `<project>` element (and nested sub-projects) at the given depth
+fn write_project(out: &mut String, project: &XmlProject, depth: usize) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&indent);
+    out.push_str("<project");
+    push_attr(out, "name", Some(&project.name));
+    push_attr(out, "path", project.path.as_deref());
+    push_attr(out, "remote", project.remote.as_deref());
+    push_attr(out, "revision", project.revision.as_deref());
+    push_attr(out, "upstream", project.upstream.as_deref());
+    push_attr(out, "groups", project.groups.as_deref());
+    push_attr(out, "clone-depth", project.clone_depth.as_deref());
+
+    if project.copyfiles.is_empty()
+        && project.linkfiles.is_empty()
+        && project.sub_projects.is_empty()
+    {
+        out.push_str(" />\n");
+        return;
+    }
+
+    out.push_str(">\n");
+    for cf in &project.copyfiles {
+        out.push_str(&format!("{}  <copyfile", indent));
+        push_attr(out, "src", Some(&cf.src));
+        push_attr(out, "dest", Some(&cf.dest));
+        out.push_str(" />\n");
+    }
+    for lf in &project.linkfiles {
+        out.push_str(&format!("{}  <linkfile", indent));
+        push_attr(out, "src", Some(&lf.src));
+        push_attr(out, "dest", Some(&lf.dest));
+        out.push_str(" />\n");
+    }
+    for sub in &project.sub_projects {
+        write_project(out, sub, depth + 1);
+    }
+    out.push_str(&format!("{}</project>\n", indent));
+}
+
+/// Append ` key="value"` (XML-escaped) when the value is present
+fn push_attr(out: &mut String, key: &str, value: Option<&str>) {
+    if let Some(value) = value {
+        out.push_str(&format!(
+            " {}=\"{}\"",
+            key,
+            quick_xml::escape::escape(value)
+        ));
+    }
+}
+
+/// Split a repo URL into a fetch base and a project name (without `.git`).
+///
+/// URLs without a path separator become a relative project under fetch base `.`.
+fn split_repo_url(url: &str) -> (String, String) {
+    let url = url.trim_end_matches('/');
+    let scheme_end = url.find("://").map(|i| i + 3).unwrap_or(0);
+    let split = match url.rfind('/') {
+        Some(i) if i >= scheme_end => Some((url[..i].to_string(), &url[i + 1..])),
+        _ => url
+            .rfind(':')
+            .map(|i| (url[..=i].to_string(), &url[i + 1..])),
+    };
+    match split {
+        Some((base, name)) => (base, name.trim_end_matches(".git").to_string()),
+        None => (".".to_string(), url.to_string()),
+    }
+}
+
+/// Pick a remote name that is not already taken
+fn unique_remote_name(remotes: &[XmlRemote]) -> String {
+    let taken = |name: &str| remotes.iter().any(|r| r.name == name);
+    if !taken("origin") {
+        return "origin".to_string();
+    }
+    (2..)
+        .map(|n| format!("origin-{}", n))
+        .find(|name| !taken(name))
+        .expect("unbounded range always yields a free name")
+}
+
+/// Most frequent value (ties broken alphabetically)
+fn most_common<'a>(values: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(value, _)| value.to_string())
+}
+
+/// Convert a gitgrip pin to a git-repo revision (tags as `refs/tags/...`,
+/// branches and SHAs as-is)
+fn pin_to_revision(pin: &str, is_tag: bool) -> String {
+    if is_tag && !pin.starts_with("refs/") {
+        format!("refs/tags/{}", pin)
+    } else {
+        pin.to_string()
+    }
+}
+
+/// Whether a revision pins an immutable point (a tag or commit SHA) rather than a branch
+fn is_pinned_revision(revision: &str) -> bool {
+    revision.starts_with("refs/tags/")
+        || (matches!(revision.len(), 40 | 64) && revision.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
 /// Convert a project name to a valid manifest key
 /// e.g., "platform/frameworks/base" -> "platform-frameworks-base"
 fn project_name_to_key(name: &str) -> String {
@@ -544,8 +888,13 @@ mod tests {
 
         let manifest = XmlManifest::parse(xml).unwrap();
         let result = manifest.to_manifest().unwrap();
-        let repo = result.manifest.repos.values().next().unwrap();
-        assert_eq!(repo.url, "https://github.com/myorg/myrepo.git");
+        let remotes = result.manifest.remotes.as_ref();
+        let repo = result.manifest.repos.get("myrepo").unwrap();
+        assert_eq!(repo.remote.as_deref(), Some("origin"));
+        assert_eq!(
+            repo.resolve_url("myrepo", remotes).unwrap(),
+            "https://github.com/myorg/myrepo.git"
+        );
     }
 
     #[test]
@@ -628,7 +977,11 @@ mod tests {
         let manifest = XmlManifest::parse(xml).unwrap();
         let result = manifest.to_manifest().unwrap();
         let repo = result.manifest.repos.get("app").unwrap();
-        assert_eq!(repo.url, "https://github.com/fork/app.git");
+        assert_eq!(
+            repo.resolve_url("app", result.manifest.remotes.as_ref())
+                .unwrap(),
+            "https://github.com/fork/app.git"
+        );
         assert_eq!(repo.default_branch, "develop");
     }
 
@@ -651,12 +1004,298 @@ mod tests {
 
         let frontend = result.manifest.repos.get("frontend").unwrap();
         assert_eq!(frontend.default_branch, "main");
-        assert_eq!(frontend.url, "https://github.com/myorg/frontend.git");
+        assert_eq!(
+            frontend
+                .resolve_url("frontend", result.manifest.remotes.as_ref())
+                .unwrap(),
+            "https://github.com/myorg/frontend.git"
+        );
 
         let backend = result.manifest.repos.get("backend").unwrap();
         assert_eq!(backend.default_branch, "develop");
     }
 
+    #[test]
+    fn test_remotes_are_preserved() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest>
+  <remote name="github" fetch="https://github.com/myorg/" pushurl="git@github.com:myorg" />
+  <remote name="local" fetch="." />
+  <default remote="github" revision="main" />
+  <project name="platform/core" path="core" />
+  <project name="tools" remote="local" />
+</manifest>"#;
+
+        let manifest = XmlManifest::parse(xml).unwrap();
+        let result = manifest.to_manifest().unwrap();
+        let remotes = result.manifest.remotes.as_ref().unwrap();
+
+        assert_eq!(remotes.len(), 1);
+        let github = &remotes["github"];
+        assert_eq!(github.fetch, "https://github.com/myorg");
+        assert_eq!(github.push.as_deref(), Some("git@github.com:myorg"));
+        assert_eq!(
+            github.platform.as_ref().unwrap().platform_type,
+            PlatformType::GitHub
+        );
+
+        let core = &result.manifest.repos["platform-core"];
+        assert!(core.url.is_empty());
+        assert_eq!(core.remote.as_deref(), Some("github"));
+        assert_eq!(core.name.as_deref(), Some("platform/core.git"));
+        assert_eq!(
+            core.resolve_push_url("platform-core", Some(remotes))
+                .unwrap(),
+            "git@github.com:myorg/platform/core.git"
+        );
+
+        // Relative fetch bases are kept as literal URLs
+        let tools = &result.manifest.repos["tools"];
+        assert_eq!(tools.url, "tools");
+        assert_eq!(tools.remote, None);
+
+        // The converted manifest is valid YAML that round-trips
+        let yaml = serde_yaml::to_string(&result.manifest).unwrap();
+        Manifest::parse(&yaml).unwrap();
+    }
+
+    /// Fields that survive an XML round trip, keyed by repo name
+    fn comparable(manifest: &Manifest) -> Vec<String> {
+        let mut repos: Vec<String> = manifest
+            .repos
+            .iter()
+            .map(|(key, config)| {
+                format!(
+                    "{} url={:?} path={} branch={} rev={:?} groups={:?} ref={} copy={:?} link={:?}",
+                    key,
+                    config.resolve_url(key, manifest.remotes.as_ref()),
+                    config.path.trim_start_matches("./"),
+                    config.default_branch,
+                    config.revision,
+                    config.groups,
+                    config.reference,
+                    config
+                        .copyfile
+                        .iter()
+                        .flatten()
+                        .map(|c| (&c.src, &c.dest))
+                        .collect::<Vec<_>>(),
+                    config
+                        .linkfile
+                        .iter()
+                        .flatten()
+                        .map(|l| (&l.src, &l.dest))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        repos.sort();
+        repos
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest>
+  <remote name="github" fetch="https://github.com/myorg" pushurl="git@github.com:myorg" />
+  <remote name="lab" fetch="https://gitlab.example.com/group" />
+  <default remote="github" revision="main" />
+  <project name="frontend" path="web" groups="core,web">
+    <copyfile src="Makefile" dest="Makefile" />
+    <linkfile src="docs" dest="frontend-docs" />
+  </project>
+  <project name="backend" revision="develop" />
+  <project name="platform/sdk" remote="lab" revision="refs/tags/v2.0.0" upstream="stable" />
+  <project name="pinned" revision="0123456789abcdef0123456789abcdef01234567" />
+  <project name="tools" groups="notdefault" />
+</manifest>"#;
+
+        let first = XmlManifest::parse(xml)
+            .unwrap()
+            .to_manifest()
+            .unwrap()
+            .manifest;
+        let exported = XmlManifest::from_manifest(&first, |_, pin| pin.starts_with('v')).to_xml();
+        let second = XmlManifest::parse(&exported)
+            .unwrap()
+            .to_manifest()
+            .unwrap()
+            .manifest;
+
+        assert_eq!(comparable(&first), comparable(&second));
+        let remotes = second.remotes.as_ref().unwrap();
+        assert_eq!(
+            remotes["github"].push.as_deref(),
+            Some("git@github.com:myorg")
+        );
+        assert_eq!(
+            second.repos["platform-sdk"].revision.as_deref(),
+            Some("v2.0.0")
+        );
+        assert!(second.repos["tools"].reference);
+    }
+
+    #[test]
+    fn test_export_groups_url_repos_into_remotes() {
+        let yaml = r#"
+repos:
+  frontend:
+    url: git@github.com:myorg/frontend.git
+    path: ./frontend
+    groups: [core]
+  backend:
+    url: git@github.com:myorg/backend.git
+    path: ./backend
+    default_branch: develop
+  docs:
+    url: https://gitlab.com/other/docs.git
+    path: ./docs
+    reference: true
+    revision: v1.0
+"#;
+        let manifest = Manifest::parse(yaml).unwrap();
+        let xml = XmlManifest::from_manifest(&manifest, |_, _| true).to_xml();
+        let parsed = XmlManifest::parse(&xml).unwrap();
+
+        assert_eq!(parsed.remotes.len(), 2);
+        let result = parsed.to_manifest().unwrap().manifest;
+        let remotes = result.remotes.as_ref();
+
+        let backend = &result.repos["backend"];
+        assert_eq!(
+            backend.resolve_url("backend", remotes).as_deref(),
+            Some("git@github.com:myorg/backend.git")
+        );
+        assert_eq!(backend.default_branch, "develop");
+        assert_eq!(result.repos["frontend"].groups, vec!["core"]);
+
+        let docs = &result.repos["docs"];
+        assert_eq!(
+            docs.resolve_url("docs", remotes).as_deref(),
+            Some("https://gitlab.com/other/docs.git")
+        );
+        assert_eq!(docs.revision.as_deref(), Some("v1.0"));
+        assert!(docs.reference);
+    }
+
+    #[test]
+    fn test_export_escapes_attributes() {
+        let yaml = r#"
+repos:
+  app:
+    url: https://github.com/org/app.git
+    path: app
+    copyfile:
+      - src: "a&b.txt"
+        dest: "<out>.txt"
+"#;
+        let manifest = Manifest::parse(yaml).unwrap();
+        let xml = XmlManifest::from_manifest(&manifest, |_, _| false).to_xml();
+        assert!(xml.contains("a&amp;b.txt"));
+        assert!(xml.contains("&lt;out&gt;.txt"));
+
+        let parsed = XmlManifest::parse(&xml).unwrap();
+        assert_eq!(parsed.projects[0].copyfiles[0].src, "a&b.txt");
+        assert_eq!(parsed.projects[0].copyfiles[0].dest, "<out>.txt");
+    }
+
+    #[test]
+    fn test_export_keeps_branch_pins() {
+        let yaml = r#"
+repos:
+  app:
+    url: https://github.com/org/app.git
+    path: app
+    revision: release/1.x
+  lib:
+    url: https://github.com/org/lib.git
+    path: lib
+    revision: v1.0
+"#;
+        let manifest = Manifest::parse(yaml).unwrap();
+        let xml = XmlManifest::from_manifest(&manifest, |key, _| key == "lib").to_xml();
+        let parsed = XmlManifest::parse(&xml).unwrap();
+        let revision = |name: &str| {
+            parsed
+                .projects
+                .iter()
+                .find(|p| p.name == name)
+                .and_then(|p| p.revision.clone())
+        };
+        assert_eq!(revision("app").as_deref(), Some("release/1.x"));
+        assert_eq!(revision("lib").as_deref(), Some("refs/tags/v1.0"));
+    }
+
+    #[test]
+    fn test_split_repo_url() {
+        assert_eq!(
+            split_repo_url("https://github.com/org/app.git"),
+            ("https://github.com/org".to_string(), "app".to_string())
+        );
+        assert_eq!(
+            split_repo_url("git@github.com:org/app.git"),
+            ("git@github.com:org".to_string(), "app".to_string())
+        );
+        assert_eq!(
+            split_repo_url("git@host:app.git"),
+            ("git@host:".to_string(), "app".to_string())
+        );
+        assert_eq!(
+            split_repo_url("tools"),
+            (".".to_string(), "tools".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sha_and_tag_revisions_become_pins() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest>
+  <remote name="github" fetch="https://github.com/myorg" />
+  <default remote="github" revision="refs/heads/develop" />
+  <project name="frontend" revision="0123456789abcdef0123456789abcdef01234567" />
+  <project name="backend" revision="refs/tags/v1.2.0" />
+  <project name="shared" revision="4444444444444444444444444444444444444444" upstream="refs/heads/stable" />
+  <project name="docs" />
+</manifest>"#;
+
+        let manifest = XmlManifest::parse(xml).unwrap();
+        let result = manifest.to_manifest().unwrap();
+        let repos = &result.manifest.repos;
+
+        let frontend = &repos["frontend"];
+        assert_eq!(
+            frontend.revision.as_deref(),
+            Some("0123456789abcdef0123456789abcdef01234567")
+        );
+        assert_eq!(frontend.default_branch, "develop");
+
+        let backend = &repos["backend"];
+        assert_eq!(backend.revision.as_deref(), Some("v1.2.0"));
+        assert_eq!(backend.default_branch, "develop");
+
+        let shared = &repos["shared"];
+        assert_eq!(
+            shared.revision.as_deref(),
+            Some("4444444444444444444444444444444444444444")
+        );
+        assert_eq!(shared.default_branch, "stable");
+
+        let docs = &repos["docs"];
+        assert_eq!(docs.revision, None);
+        assert_eq!(docs.default_branch, "develop");
+    }
+
+    #[test]
+    fn test_is_pinned_revision() {
+        assert!(is_pinned_revision("refs/tags/v1.0"));
+        assert!(is_pinned_revision(
+            "0123456789abcdef0123456789abcdef01234567"
+        ));
+        assert!(!is_pinned_revision("main"));
+        assert!(!is_pinned_revision("refs/heads/main"));
+        assert!(!is_pinned_revision("abc123"));
+    }
+
     #[test]
     fn test_missing_remote_error() {
         let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
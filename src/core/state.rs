@@ -1,8 +1,9 @@
 //! State file management
 //!
 //! The state file (.gitgrip/state.json) tracks persistent state across commands,
-//! including PR links and branch-to-PR mappings.
+//! including PR links, branch-to-PR mappings and workspace stashes.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
@@ -42,6 +43,9 @@ pub struct LinkedPR {
     pub checks_pass: bool,
     /// Can be merged
     pub mergeable: bool,
+    /// Review threads not yet resolved
+    #[serde(default)]
+    pub unresolved_threads: usize,
     /// Hosting platform type
     #[serde(skip_serializing_if = "Option::is_none")]
     pub platform_type: Option<PlatformType>,
@@ -50,6 +54,33 @@ pub struct LinkedPR {
     pub check_details: Option<CheckStatusDetails>,
 }
 
+/// One repo's entry in a workspace stash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StashedRepo {
+    /// Repository name (from manifest)
+    pub repo_name: String,
+    /// Commit SHA of the repo's git stash entry
+    pub commit: String,
+    /// Branch the changes were stashed from
+    pub branch: String,
+}
+
+/// A stash spanning every repo that was dirty when it was pushed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceStash {
+    /// Workspace stash name (`stash-<n>`)
+    pub name: String,
+    /// Message given to `gr stash push -m`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// When the stash was pushed
+    pub created_at: DateTime<Utc>,
+    /// Stashed repos
+    pub repos: Vec<StashedRepo>,
+}
+
 /// The persistent state file structure
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -63,6 +94,9 @@ pub struct StateFile {
     /// Map: manifest PR number -> linked PRs
     #[serde(default)]
     pub pr_links: HashMap<String, Vec<LinkedPR>>,
+    /// Workspace stashes, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stashes: Vec<WorkspaceStash>,
 }
 
 impl StateFile {
@@ -141,11 +175,40 @@ impl StateFile {
         }
     }
 
+    /// Name for the next workspace stash
+    pub fn next_stash_name(&self) -> String {
+        let last = self
+            .stashes
+            .iter()
+            .filter_map(|s| s.name.strip_prefix("stash-")?.parse::<u64>().ok())
+            .max()
+            .unwrap_or(0);
+        format!("stash-{}", last + 1)
+    }
+
+    /// Find a workspace stash by name, or the most recent one
+    pub fn find_stash(&self, name: Option<&str>) -> Option<&WorkspaceStash> {
+        match name {
+            Some(name) => self.stashes.iter().find(|s| s.name == name),
+            None => self.stashes.last(),
+        }
+    }
+
+    /// Remove a workspace stash by name
+    pub fn remove_stash(&mut self, name: &str) -> Option<WorkspaceStash> {
+        let index = self.stashes.iter().position(|s| s.name == name)?;
+        Some(self.stashes.remove(index))
+    }
+
     /// Check if all linked PRs are ready to merge
     pub fn all_linked_prs_ready(&self, manifest_pr: u64) -> bool {
         if let Some(links) = self.get_linked_prs(manifest_pr) {
             links.iter().all(|link| {
-                link.state == PRState::Open && link.approved && link.checks_pass && link.mergeable
+                link.state == PRState::Open
+                    && link.approved
+                    && link.checks_pass
+                    && link.mergeable
+                    && link.unresolved_threads == 0
             })
         } else {
             false
@@ -163,6 +226,7 @@ mod tests {
         assert!(state.current_manifest_pr.is_none());
         assert!(state.branch_to_pr.is_empty());
         assert!(state.pr_links.is_empty());
+        assert!(state.stashes.is_empty());
     }
 
     #[test]
@@ -224,11 +288,42 @@ mod tests {
             approved: true,
             checks_pass: true,
             mergeable: true,
+            unresolved_threads: 0,
             platform_type: None,
             check_details: None,
         };
 
         state.add_linked_pr(42, link);
         assert!(state.all_linked_prs_ready(42));
+
+        state.update_linked_pr(42, "app", |l| l.unresolved_threads = 2);
+        assert!(!state.all_linked_prs_ready(42));
+    }
+
+    #[test]
+    fn test_workspace_stashes() {
+        let mut state = StateFile::default();
+        assert_eq!(state.next_stash_name(), "stash-1");
+        assert!(state.find_stash(None).is_none());
+
+        for name in ["stash-1", "stash-2"] {
+            state.stashes.push(WorkspaceStash {
+                name: name.to_string(),
+                message: None,
+                created_at: Utc::now(),
+                repos: Vec::new(),
+            });
+        }
+        assert_eq!(state.next_stash_name(), "stash-3");
+        assert_eq!(state.find_stash(None).unwrap().name, "stash-2");
+        assert_eq!(state.find_stash(Some("stash-1")).unwrap().name, "stash-1");
+
+        assert!(state.remove_stash("stash-2").is_some());
+        assert!(state.remove_stash("stash-2").is_none());
+        assert_eq!(state.next_stash_name(), "stash-2");
+
+        let json = serde_json::to_string(&state).unwrap();
+        let parsed = StateFile::parse(&json).unwrap();
+        assert_eq!(parsed.stashes.len(), 1);
     }
 }
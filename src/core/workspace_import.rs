@@ -0,0 +1,675 @@
+//! Workspace import from other multi-repo formats
+//!
+//! Converts git submodules (`.gitmodules`), vcstool (`.repos`) and meta
+//! (`.meta`) workspace files into a gitgrip `Manifest`. git-repo XML manifests
+//! are handled separately by `repo_manifest`. Entries that cannot be imported
+//! and source fields with no gitgrip equivalent are reported in the result
+//! rather than silently dropped.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use thiserror::Error;
+
+use crate::core::manifest::{
+    Manifest, ManifestSettings, PlatformConfig, PlatformType, RepoConfig, WorkspaceConfig,
+};
+use crate::platform;
+
+/// Errors from importing a workspace file
+#[derive(Error, Debug)]
+pub enum WorkspaceImportError {
+    #[error("Failed to read workspace file: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to parse {format} file: {message}")]
+    ParseError {
+        format: ImportFormat,
+        message: String,
+    },
+
+    #[error("Unrecognized workspace format: {0}")]
+    UnknownFormat(String),
+}
+
+/// Supported workspace file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// git-repo XML manifest (`default.xml`)
+    RepoXml,
+    /// git submodules (`.gitmodules`)
+    GitModules,
+    /// vcstool repositories file (`.repos`)
+    VcsTool,
+    /// meta workspace file (`.meta`)
+    Meta,
+}
+
+impl std::fmt::Display for ImportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportFormat::RepoXml => write!(f, "git-repo XML"),
+            ImportFormat::GitModules => write!(f, ".gitmodules"),
+            ImportFormat::VcsTool => write!(f, "vcstool .repos"),
+            ImportFormat::Meta => write!(f, "meta .meta"),
+        }
+    }
+}
+
+impl ImportFormat {
+    /// Detect the format from the file name, falling back to the content
+    pub fn detect(path: &Path, content: &str) -> Option<Self> {
+        let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+        if file_name == ".gitmodules" {
+            return Some(ImportFormat::GitModules);
+        }
+        if file_name == ".meta" || extension == "meta" {
+            return Some(ImportFormat::Meta);
+        }
+        if extension == "repos" {
+            return Some(ImportFormat::VcsTool);
+        }
+        if extension == "xml" {
+            return Some(ImportFormat::RepoXml);
+        }
+
+        let trimmed = content.trim_start();
+        if trimmed.starts_with('<') {
+            Some(ImportFormat::RepoXml)
+        } else if trimmed.starts_with("[submodule") {
+            Some(ImportFormat::GitModules)
+        } else if trimmed.starts_with('{') {
+            Some(ImportFormat::Meta)
+        } else if content.lines().any(|l| l.trim_end() == "repositories:") {
+            Some(ImportFormat::VcsTool)
+        } else {
+            None
+        }
+    }
+}
+
+/// Result of importing a workspace file
+#[derive(Debug)]
+pub struct ImportResult {
+    /// The converted gitgrip manifest
+    pub manifest: Manifest,
+    /// Source format
+    pub format: ImportFormat,
+    /// Total entries in the source file
+    pub total_entries: usize,
+    /// Entries that were imported
+    pub imported: usize,
+    /// Entries that were skipped, with the reason
+    pub skipped: Vec<(String, String)>,
+    /// Source fields that have no gitgrip equivalent and were ignored
+    pub unsupported_fields: BTreeSet<String>,
+    /// Platform breakdown
+    pub platform_counts: HashMap<PlatformType, usize>,
+}
+
+/// One repository read from a source file, before conversion
+struct ImportedRepo {
+    path: String,
+    url: String,
+    default_branch: Option<String>,
+    revision: Option<String>,
+}
+
+/// Import a workspace file of the given (non-XML) format
+pub fn import_workspace(
+    path: &Path,
+    content: &str,
+    format: ImportFormat,
+) -> Result<ImportResult, WorkspaceImportError> {
+    let mut unsupported = BTreeSet::new();
+    let mut skipped = Vec::new();
+
+    let (total_entries, repos) = match format {
+        ImportFormat::GitModules => {
+            parse_gitmodules(path, content, &mut unsupported, &mut skipped)?
+        }
+        ImportFormat::VcsTool => parse_vcstool(content, &mut unsupported, &mut skipped)?,
+        ImportFormat::Meta => parse_meta(content, &mut unsupported, &mut skipped)?,
+        ImportFormat::RepoXml => {
+            return Err(WorkspaceImportError::UnknownFormat(
+                "git-repo XML is imported with XmlManifest".to_string(),
+            ))
+        }
+    };
+
+    let mut manifest_repos = HashMap::new();
+    let mut platform_counts: HashMap<PlatformType, usize> = HashMap::new();
+    for repo in repos {
+        let platform_type = platform::detect_platform(&repo.url);
+        *platform_counts.entry(platform_type).or_insert(0) += 1;
+
+        // Paths like `a/b` and `a-b` map to the same key
+        let base_key = path_to_key(&repo.path);
+        let key = std::iter::once(base_key.clone())
+            .chain((2..).map(|n| format!("{}-{}", base_key, n)))
+            .find(|key| !manifest_repos.contains_key(key))
+            .expect("unbounded range always yields a free key");
+        manifest_repos.insert(
+            key,
+            RepoConfig {
+                url: repo.url,
+                remote: None,
+                name: None,
+                path: repo.path,
+                default_branch: repo.default_branch.unwrap_or_else(|| "main".to_string()),
+                revision: repo.revision,
+                copyfile: None,
+                linkfile: None,
+                platform: Some(PlatformConfig {
+                    platform_type,
+                    base_url: None,
+                }),
+                reference: false,
+                groups: Vec::new(),
+                agent: None,
+                depends_on: Vec::new(),
+                clone: None,
+                sparse: Vec::new(),
+                pr: None,
+            },
+        );
+    }
+
+    Ok(ImportResult {
+        imported: manifest_repos.len(),
+        manifest: Manifest {
+            version: 1,
+            gripspaces: None,
+            remotes: None,
+            manifest: None,
+            repos: manifest_repos,
+            settings: ManifestSettings::default(),
+            workspace: Some(WorkspaceConfig::default()),
+        },
+        format,
+        total_entries,
+        skipped,
+        unsupported_fields: unsupported,
+        platform_counts,
+    })
+}
+
+// ── .gitmodules ────────────────────────────────────────────────────────────
+
+/// Parse `.gitmodules` (git config syntax).
+///
+/// Pinned revisions come from the superproject's gitlink entries when the file
+/// sits in a git checkout; relative URLs are resolved against its `origin`.
+fn parse_gitmodules(
+    path: &Path,
+    content: &str,
+    unsupported: &mut BTreeSet<String>,
+    skipped: &mut Vec<(String, String)>,
+) -> Result<(usize, Vec<ImportedRepo>), WorkspaceImportError> {
+    let mut sections: Vec<(String, HashMap<String, String>)> = Vec::new();
+
+    for (line_no, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header.trim_end_matches(']').trim();
+            let name = header
+                .strip_prefix("submodule")
+                .map(|rest| rest.trim().trim_matches('"').to_string())
+                .ok_or_else(|| WorkspaceImportError::ParseError {
+                    format: ImportFormat::GitModules,
+                    message: format!("line {}: unexpected section [{}]", line_no + 1, header),
+                })?;
+            sections.push((name, HashMap::new()));
+            continue;
+        }
+        let (key, value) =
+            line.split_once('=')
+                .ok_or_else(|| WorkspaceImportError::ParseError {
+                    format: ImportFormat::GitModules,
+                    message: format!("line {}: expected 'key = value'", line_no + 1),
+                })?;
+        let Some((_, fields)) = sections.last_mut() else {
+            return Err(WorkspaceImportError::ParseError {
+                format: ImportFormat::GitModules,
+                message: format!("line {}: key outside a [submodule] section", line_no + 1),
+            });
+        };
+        fields.insert(
+            key.trim().to_lowercase(),
+            value.trim().trim_matches('"').to_string(),
+        );
+    }
+
+    let superproject = path
+        .parent()
+        .and_then(|dir| git2::Repository::open(dir).ok());
+    let origin_url = superproject
+        .as_ref()
+        .and_then(|r| r.find_remote("origin").ok())
+        .and_then(|r| r.url().map(|u| u.to_string()));
+
+    let total = sections.len();
+    let mut repos = Vec::new();
+    for (name, fields) in sections {
+        for key in fields.keys() {
+            if !matches!(key.as_str(), "path" | "url" | "branch") {
+                unsupported.insert(format!("submodule.{}", key));
+            }
+        }
+
+        let (Some(sub_path), Some(url)) = (fields.get("path"), fields.get("url")) else {
+            skipped.push((name, "missing path or url".to_string()));
+            continue;
+        };
+
+        let url = if url.starts_with("./") || url.starts_with("../") {
+            match origin_url.as_deref() {
+                Some(base) => resolve_relative_url(base, url),
+                None => {
+                    skipped.push((
+                        name,
+                        format!("relative url '{}' needs the superproject's origin", url),
+                    ));
+                    continue;
+                }
+            }
+        } else {
+            url.clone()
+        };
+
+        let default_branch = match fields.get("branch").map(String::as_str) {
+            Some(".") => {
+                unsupported.insert("submodule.branch = .".to_string());
+                None
+            }
+            Some(branch) => Some(branch.to_string()),
+            None => None,
+        };
+
+        repos.push(ImportedRepo {
+            revision: superproject.as_ref().and_then(|r| gitlink_sha(r, sub_path)),
+            path: sub_path.clone(),
+            url,
+            default_branch,
+        });
+    }
+
+    Ok((total, repos))
+}
+
+/// Commit recorded for a submodule path in the superproject's HEAD tree
+fn gitlink_sha(repo: &git2::Repository, path: &str) -> Option<String> {
+    let tree = repo.head().ok()?.peel_to_tree().ok()?;
+    let entry = tree.get_path(Path::new(path)).ok()?;
+    (entry.kind() == Some(git2::ObjectType::Commit)).then(|| entry.id().to_string())
+}
+
+/// Resolve a submodule URL like `../lib.git` against the superproject URL
+fn resolve_relative_url(base: &str, relative: &str) -> String {
+    let mut base = base.trim_end_matches('/').to_string();
+    let mut rest = relative;
+    loop {
+        if let Some(stripped) = rest.strip_prefix("./") {
+            rest = stripped;
+        } else if let Some(stripped) = rest.strip_prefix("../") {
+            rest = stripped;
+            match base.rfind(['/', ':']) {
+                Some(i) if base.as_bytes()[i] == b':' => base.truncate(i + 1),
+                Some(i) => base.truncate(i),
+                None => base.clear(),
+            }
+        } else {
+            break;
+        }
+    }
+    if base.ends_with(':') {
+        format!("{}{}", base, rest)
+    } else {
+        format!("{}/{}", base, rest)
+    }
+}
+
+// ── vcstool ────────────────────────────────────────────────────────────────
+
+/// Parse a vcstool `.repos` file.
+///
+/// `version` (a branch, tag or commit SHA; vcstool does not distinguish
+/// them) becomes the pinned revision.
+fn parse_vcstool(
+    content: &str,
+    unsupported: &mut BTreeSet<String>,
+    skipped: &mut Vec<(String, String)>,
+) -> Result<(usize, Vec<ImportedRepo>), WorkspaceImportError> {
+    let parse_error = |message: String| WorkspaceImportError::ParseError {
+        format: ImportFormat::VcsTool,
+        message,
+    };
+
+    let doc: serde_yaml::Value =
+        serde_yaml::from_str(content).map_err(|e| parse_error(e.to_string()))?;
+    let entries = doc
+        .get("repositories")
+        .and_then(|r| r.as_mapping())
+        .ok_or_else(|| parse_error("missing 'repositories' mapping".to_string()))?;
+
+    let total = entries.len();
+    let mut repos = Vec::new();
+    for (key, value) in entries {
+        let Some(path) = key.as_str() else {
+            return Err(parse_error("repository keys must be paths".to_string()));
+        };
+        let field = |name: &str| value.get(name).and_then(|v| v.as_str()).map(String::from);
+
+        if let Some(fields) = value.as_mapping() {
+            for field_name in fields.keys().filter_map(|k| k.as_str()) {
+                if !matches!(field_name, "type" | "url" | "version") {
+                    unsupported.insert(format!("repositories.*.{}", field_name));
+                }
+            }
+        }
+
+        let vcs_type = field("type").unwrap_or_else(|| "git".to_string());
+        if vcs_type != "git" {
+            skipped.push((path.to_string(), format!("unsupported type '{}'", vcs_type)));
+            continue;
+        }
+        let Some(url) = field("url") else {
+            skipped.push((path.to_string(), "missing url".to_string()));
+            continue;
+        };
+
+        repos.push(ImportedRepo {
+            path: path.to_string(),
+            url,
+            default_branch: None,
+            revision: field("version"),
+        });
+    }
+
+    Ok((total, repos))
+}
+
+// ── meta ───────────────────────────────────────────────────────────────────
+
+/// Parse a meta `.meta` JSON file (`{"projects": {"path": "url"}}`)
+fn parse_meta(
+    content: &str,
+    unsupported: &mut BTreeSet<String>,
+    skipped: &mut Vec<(String, String)>,
+) -> Result<(usize, Vec<ImportedRepo>), WorkspaceImportError> {
+    let parse_error = |message: String| WorkspaceImportError::ParseError {
+        format: ImportFormat::Meta,
+        message,
+    };
+
+    let doc: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| parse_error(e.to_string()))?;
+    let object = doc
+        .as_object()
+        .ok_or_else(|| parse_error("expected a JSON object".to_string()))?;
+    let projects = object
+        .get("projects")
+        .and_then(|p| p.as_object())
+        .ok_or_else(|| parse_error("missing 'projects' object".to_string()))?;
+
+    for key in object.keys().filter(|k| k.as_str() != "projects") {
+        unsupported.insert(key.clone());
+    }
+
+    let mut repos = Vec::new();
+    for (path, url) in projects {
+        match url.as_str() {
+            Some(url) => repos.push(ImportedRepo {
+                path: path.clone(),
+                url: url.to_string(),
+                default_branch: None,
+                revision: None,
+            }),
+            None => skipped.push((path.clone(), "url is not a string".to_string())),
+        }
+    }
+
+    Ok((projects.len(), repos))
+}
+
+// ── Helpers ────────────────────────────────────────────────────────────────
+
+/// Convert a repo path to a manifest key (e.g., "src/ros2/rclcpp" -> "src-ros2-rclcpp")
+fn path_to_key(path: &str) -> String {
+    path.trim_start_matches("./")
+        .trim_end_matches('/')
+        .replace(['/', ' '], "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(
+            ImportFormat::detect(Path::new(".gitmodules"), ""),
+            Some(ImportFormat::GitModules)
+        );
+        assert_eq!(
+            ImportFormat::detect(Path::new("ros2.repos"), ""),
+            Some(ImportFormat::VcsTool)
+        );
+        assert_eq!(
+            ImportFormat::detect(Path::new(".meta"), ""),
+            Some(ImportFormat::Meta)
+        );
+        assert_eq!(
+            ImportFormat::detect(Path::new("default.xml"), ""),
+            Some(ImportFormat::RepoXml)
+        );
+        assert_eq!(
+            ImportFormat::detect(Path::new("workspace"), "repositories:\n  a:\n"),
+            Some(ImportFormat::VcsTool)
+        );
+        assert_eq!(
+            ImportFormat::detect(Path::new("workspace"), "{\"projects\": {}}"),
+            Some(ImportFormat::Meta)
+        );
+        assert_eq!(
+            ImportFormat::detect(Path::new("modules"), "[submodule \"a\"]\n"),
+            Some(ImportFormat::GitModules)
+        );
+        assert_eq!(ImportFormat::detect(Path::new("notes.txt"), "hello"), None);
+    }
+
+    #[test]
+    fn test_import_gitmodules() {
+        let content = r#"
+[submodule "libs/core"]
+	path = libs/core
+	url = https://github.com/org/core.git
+	branch = develop
+	update = rebase
+[submodule "broken"]
+	path = broken
+"#;
+        let result = import_workspace(
+            Path::new("/nonexistent/.gitmodules"),
+            content,
+            ImportFormat::GitModules,
+        )
+        .unwrap();
+
+        assert_eq!(result.total_entries, 2);
+        assert_eq!(result.imported, 1);
+        assert_eq!(result.skipped.len(), 1);
+        assert!(result.unsupported_fields.contains("submodule.update"));
+
+        let core = &result.manifest.repos["libs-core"];
+        assert_eq!(core.url, "https://github.com/org/core.git");
+        assert_eq!(core.path, "libs/core");
+        assert_eq!(core.default_branch, "develop");
+        assert_eq!(core.revision, None);
+        result.manifest.validate().unwrap();
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn test_import_gitmodules_reads_gitlink_and_relative_url() {
+        let temp = TempDir::new().unwrap();
+        let superproject = temp.path().join("super");
+        std::fs::create_dir_all(&superproject).unwrap();
+        git(&superproject, &["init", "-b", "main"]);
+        git(&superproject, &["config", "user.email", "test@example.com"]);
+        git(&superproject, &["config", "user.name", "Test User"]);
+        git(
+            &superproject,
+            &["remote", "add", "origin", "git@github.com:org/super.git"],
+        );
+
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        git(
+            &superproject,
+            &[
+                "update-index",
+                "--add",
+                "--cacheinfo",
+                &format!("160000,{},libs/core", sha),
+            ],
+        );
+        let gitmodules = "[submodule \"core\"]\n\tpath = libs/core\n\turl = ../core.git\n";
+        std::fs::write(superproject.join(".gitmodules"), gitmodules).unwrap();
+        git(&superproject, &["add", ".gitmodules"]);
+        git(&superproject, &["commit", "-m", "Add submodule"]);
+
+        let path = superproject.join(".gitmodules");
+        let result = import_workspace(&path, gitmodules, ImportFormat::GitModules).unwrap();
+
+        let core = &result.manifest.repos["libs-core"];
+        assert_eq!(core.url, "git@github.com:org/core.git");
+        assert_eq!(core.revision.as_deref(), Some(sha));
+    }
+
+    #[test]
+    fn test_import_vcstool() {
+        let content = r#"
+repositories:
+  src/ros2/rclcpp:
+    type: git
+    url: https://github.com/ros2/rclcpp.git
+    version: rolling
+  src/pinned:
+    type: git
+    url: https://github.com/org/pinned.git
+    version: 0123456789abcdef0123456789abcdef01234567
+    recursive: true
+  src/legacy:
+    type: svn
+    url: https://svn.example.com/legacy
+"#;
+        let result =
+            import_workspace(Path::new("ws.repos"), content, ImportFormat::VcsTool).unwrap();
+
+        assert_eq!(result.total_entries, 3);
+        assert_eq!(result.imported, 2);
+        assert_eq!(result.skipped[0].0, "src/legacy");
+        assert!(result
+            .unsupported_fields
+            .contains("repositories.*.recursive"));
+
+        let rclcpp = &result.manifest.repos["src-ros2-rclcpp"];
+        assert_eq!(rclcpp.path, "src/ros2/rclcpp");
+        assert_eq!(rclcpp.default_branch, "main");
+        assert_eq!(rclcpp.revision.as_deref(), Some("rolling"));
+
+        let pinned = &result.manifest.repos["src-pinned"];
+        assert_eq!(pinned.default_branch, "main");
+        assert_eq!(
+            pinned.revision.as_deref(),
+            Some("0123456789abcdef0123456789abcdef01234567")
+        );
+    }
+
+    #[test]
+    fn test_import_keeps_repos_with_colliding_keys() {
+        let content = r#"{
+  "projects": {
+    "libs/core": "git@github.com:org/core.git",
+    "libs-core": "git@github.com:org/libs-core.git"
+  }
+}"#;
+        let result = import_workspace(Path::new(".meta"), content, ImportFormat::Meta).unwrap();
+
+        assert_eq!(result.imported, 2);
+        let mut paths: Vec<&str> = result
+            .manifest
+            .repos
+            .values()
+            .map(|r| r.path.as_str())
+            .collect();
+        paths.sort();
+        assert_eq!(paths, ["libs-core", "libs/core"]);
+        assert!(result.manifest.repos.contains_key("libs-core-2"));
+    }
+
+    #[test]
+    fn test_import_meta() {
+        let content = r#"{
+  "projects": {
+    "api": "git@github.com:org/api.git",
+    "web/app": "https://gitlab.com/org/app.git"
+  },
+  "ignore": [".vscode"]
+}"#;
+        let result = import_workspace(Path::new(".meta"), content, ImportFormat::Meta).unwrap();
+
+        assert_eq!(result.imported, 2);
+        assert!(result.unsupported_fields.contains("ignore"));
+        assert_eq!(result.manifest.repos["web-app"].path, "web/app");
+        assert_eq!(
+            result.manifest.repos["api"].url,
+            "git@github.com:org/api.git"
+        );
+        assert_eq!(result.platform_counts[&PlatformType::GitLab], 1);
+    }
+
+    #[test]
+    fn test_import_meta_invalid_json() {
+        let result = import_workspace(Path::new(".meta"), "{not json", ImportFormat::Meta);
+        assert!(matches!(
+            result,
+            Err(WorkspaceImportError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_resolve_relative_url() {
+        assert_eq!(
+            resolve_relative_url("https://github.com/org/super.git", "../lib.git"),
+            "https://github.com/org/lib.git"
+        );
+        assert_eq!(
+            resolve_relative_url("git@github.com:org/super.git", "../../other/lib.git"),
+            "git@github.com:other/lib.git"
+        );
+        assert_eq!(
+            resolve_relative_url("https://github.com/org/super", "./nested"),
+            "https://github.com/org/super/nested"
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! git CLI / libgit2 backend
+
+use git2::Repository;
+use std::path::Path;
+
+use super::{BackendKind, CloneRequest, GitBackend};
+use crate::git::status::RepoStatusInfo;
+use crate::git::GitError;
+
+/// Backend that shells out to the git CLI, with libgit2 for local reads
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CliBackend;
+
+impl GitBackend for CliBackend {
+    fn kind(&self) -> BackendKind {
+        BackendKind::Git
+    }
+
+    fn clone_repo(&self, url: &str, path: &Path, request: &CloneRequest) -> Result<(), GitError> {
+        let mut args = crate::git::clone_option_args(request.options, request.sparse);
+        if let Some(reference) = request.reference {
+            args.push("--reference-if-able".to_string());
+            args.push(reference.to_string_lossy().to_string());
+        }
+        crate::git::clone_with_args(url, path, request.branch, &args).map(|_| ())
+    }
+
+    fn fetch(&self, repo: &Repository, remote: &str) -> Result<(), GitError> {
+        crate::git::remote::fetch_remote_cli(repo, remote)
+    }
+
+    fn status(&self, repo: &Repository) -> Result<RepoStatusInfo, GitError> {
+        crate::git::status::get_status_info_cli(repo)
+    }
+
+    fn local_branches(&self, repo: &Repository) -> Result<Vec<String>, GitError> {
+        crate::git::branch::list_local_branches_cli(repo)
+    }
+}
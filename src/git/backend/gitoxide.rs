@@ -0,0 +1,390 @@
+//! gitoxide (gix) backend
+//!
+//! Runs clone, fetch, status and branch listing in-process. Clone options
+//! gix does not support (partial clone filters, single-branch, sparse
+//! checkout, borrowing objects from a mirror) are handed to the CLI backend.
+
+use git2::Repository;
+use gix::bstr::{BString, ByteSlice};
+use gix::status::index_worktree::iter::Summary;
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+use super::{BackendKind, CliBackend, CloneRequest, GitBackend};
+use crate::git::status::RepoStatusInfo;
+use crate::git::{get_current_branch, get_workdir, GitError};
+
+/// Backend built on gitoxide
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GixBackend;
+
+/// Why a gix clone failed
+enum CloneFailure {
+    /// The requested branch does not exist on the remote
+    BranchMissing,
+    Other(String),
+}
+
+/// Render an error with its causes; gix's top-level messages are terse
+fn error_chain(e: &dyn std::error::Error) -> String {
+    let mut message = e.to_string();
+    let mut source = e.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+fn failed(e: impl std::error::Error) -> GitError {
+    GitError::OperationFailed(error_chain(&e))
+}
+
+fn open(repo: &Repository) -> Result<gix::Repository, GitError> {
+    let path = get_workdir(repo);
+    gix::open(path).map_err(|e| GitError::NotARepo(format!("{}: {}", path.display(), e)))
+}
+
+fn try_clone(
+    url: &str,
+    path: &Path,
+    branch: Option<&str>,
+    depth: Option<u32>,
+) -> Result<(), CloneFailure> {
+    let other = |e: &dyn std::error::Error| CloneFailure::Other(error_chain(e));
+    let interrupt = AtomicBool::new(false);
+
+    let mut prepare = gix::prepare_clone(url, path).map_err(|e| other(&e))?;
+    if branch.is_some() {
+        prepare = prepare.with_ref_name(branch).map_err(|e| other(&e))?;
+    }
+    if let Some(depth) = depth.and_then(NonZeroU32::new) {
+        prepare = prepare.with_shallow(gix::remote::fetch::Shallow::DepthAtRemote(depth));
+    }
+
+    let (mut checkout, _) = prepare
+        .fetch_then_checkout(gix::progress::Discard, &interrupt)
+        .map_err(|e| match e {
+            gix::clone::fetch::Error::RefNameMissing { .. } => CloneFailure::BranchMissing,
+            e => other(&e),
+        })?;
+    checkout
+        .main_worktree(gix::progress::Discard, &interrupt)
+        .map_err(|e| other(&e))?;
+    Ok(())
+}
+
+/// Paths whose index entry differs from `HEAD` (`git diff --cached --name-only`)
+fn staged_paths(repo: &gix::Repository) -> Result<Vec<String>, GitError> {
+    let index = repo.index_or_empty().map_err(failed)?;
+
+    let mut head: HashMap<BString, (gix::ObjectId, gix::objs::tree::EntryMode)> = HashMap::new();
+    // An unborn HEAD has no tree: everything in the index is staged
+    if let Ok(tree) = repo.head_tree() {
+        let mut recorder = gix::traverse::tree::Recorder::default();
+        tree.traverse()
+            .breadthfirst(&mut recorder)
+            .map_err(failed)?;
+        for entry in recorder.records {
+            if !entry.mode.is_tree() {
+                head.insert(entry.filepath, (entry.oid, entry.mode));
+            }
+        }
+    }
+
+    let mut staged = Vec::new();
+    for entry in index.entries() {
+        let path = entry.path(&index);
+        let in_head = head.remove(path);
+        // Conflicts and intent-to-add entries are not staged changes
+        if entry.stage_raw() != 0
+            || entry
+                .flags
+                .contains(gix::index::entry::Flags::INTENT_TO_ADD)
+        {
+            continue;
+        }
+        let unchanged = in_head.is_some_and(|(id, mode)| {
+            id == entry.id && entry.mode.to_tree_entry_mode() == Some(mode)
+        });
+        if !unchanged {
+            staged.push(path.to_str_lossy().into_owned());
+        }
+    }
+    // Deleted from the index
+    staged.extend(head.into_keys().map(|p| p.to_str_lossy().into_owned()));
+    staged.sort();
+    staged.dedup();
+    Ok(staged)
+}
+
+/// Commits ahead of/behind the current branch's upstream, `(0, 0)` without one
+fn ahead_behind(repo: &Repository) -> (usize, usize) {
+    let upstream = || -> Result<(git2::Oid, git2::Oid), git2::Error> {
+        let head = repo.head()?;
+        let local = head
+            .target()
+            .ok_or_else(|| git2::Error::from_str("no HEAD"))?;
+        let name = head
+            .shorthand()
+            .ok_or_else(|| git2::Error::from_str("no branch"))?;
+        let upstream = repo
+            .find_branch(name, git2::BranchType::Local)?
+            .upstream()?;
+        let remote = upstream
+            .get()
+            .target()
+            .ok_or_else(|| git2::Error::from_str("no upstream target"))?;
+        Ok((local, remote))
+    };
+    upstream()
+        .and_then(|(local, remote)| repo.graph_ahead_behind(local, remote))
+        .unwrap_or((0, 0))
+}
+
+impl GitBackend for GixBackend {
+    fn kind(&self) -> BackendKind {
+        BackendKind::Gitoxide
+    }
+
+    fn clone_repo(&self, url: &str, path: &Path, request: &CloneRequest) -> Result<(), GitError> {
+        let options = request.options;
+        if options.filter.is_some()
+            || options.single_branch
+            || !request.sparse.is_empty()
+            || request.reference.is_some()
+        {
+            return CliBackend.clone_repo(url, path, request);
+        }
+
+        match try_clone(url, path, request.branch, options.depth) {
+            Ok(()) => Ok(()),
+            // Same fallback as the CLI: use the remote's default branch
+            Err(CloneFailure::BranchMissing) => {
+                try_clone(url, path, None, options.depth).map_err(|e| match e {
+                    CloneFailure::BranchMissing => GitError::OperationFailed(
+                        "git clone failed: remote has no default branch".to_string(),
+                    ),
+                    CloneFailure::Other(msg) => {
+                        GitError::OperationFailed(format!("git clone failed: {}", msg))
+                    }
+                })
+            }
+            Err(CloneFailure::Other(msg)) => Err(GitError::OperationFailed(format!(
+                "git clone failed: {}",
+                msg
+            ))),
+        }
+    }
+
+    fn fetch(&self, repo: &Repository, remote: &str) -> Result<(), GitError> {
+        let mut repo = open(repo)?;
+        // Ref updates write reflogs, which need a committer; git falls back
+        // to a default identity, gix refuses
+        if repo.committer().is_none() {
+            let mut config = repo.config_snapshot_mut();
+            config
+                .set_value(&gix::config::tree::Committer::NAME, "gitgrip")
+                .map_err(failed)?;
+            config
+                .set_value(&gix::config::tree::Committer::EMAIL, "gitgrip@localhost")
+                .map_err(failed)?;
+        }
+        let remote = repo.find_remote(remote).map_err(failed)?;
+        remote
+            .connect(gix::remote::Direction::Fetch)
+            .map_err(failed)?
+            .prepare_fetch(gix::progress::Discard, Default::default())
+            .map_err(failed)?
+            .receive(gix::progress::Discard, &AtomicBool::new(false))
+            .map_err(failed)?;
+        Ok(())
+    }
+
+    fn status(&self, repo: &Repository) -> Result<RepoStatusInfo, GitError> {
+        let current_branch = get_current_branch(repo)?;
+        let gix_repo = open(repo)?;
+
+        let staged = staged_paths(&gix_repo)?;
+        let mut modified = Vec::new();
+        let mut untracked = Vec::new();
+
+        let iter = gix_repo
+            .status(gix::progress::Discard)
+            .map_err(failed)?
+            .untracked_files(gix::status::UntrackedFiles::Collapsed)
+            .index_worktree_rewrites(None)
+            .into_index_worktree_iter(Vec::<BString>::new())
+            .map_err(failed)?;
+        for item in iter {
+            let item = item.map_err(failed)?;
+            let mut path = item.rela_path().to_str_lossy().into_owned();
+            match item.summary() {
+                Some(Summary::Added) => {
+                    // Match `git status`, which shows untracked directories as `dir/`
+                    if let gix::status::index_worktree::iter::Item::DirectoryContents {
+                        entry,
+                        ..
+                    } = &item
+                    {
+                        if entry.disk_kind.is_some_and(|k| k.is_dir()) {
+                            path.push('/');
+                        }
+                    }
+                    untracked.push(path);
+                }
+                Some(Summary::Modified | Summary::Removed | Summary::TypeChange) => {
+                    modified.push(path)
+                }
+                _ => {}
+            }
+        }
+
+        let is_clean = staged.is_empty() && modified.is_empty() && untracked.is_empty();
+        let (ahead, behind) = ahead_behind(repo);
+
+        Ok(RepoStatusInfo {
+            current_branch,
+            is_clean,
+            staged,
+            modified,
+            untracked,
+            ahead,
+            behind,
+        })
+    }
+
+    fn local_branches(&self, repo: &Repository) -> Result<Vec<String>, GitError> {
+        let repo = open(repo)?;
+        let references = repo.references().map_err(failed)?;
+        let mut branches = Vec::new();
+        for reference in references.local_branches().map_err(failed)? {
+            let reference = reference.map_err(|e| GitError::OperationFailed(error_chain(&*e)))?;
+            branches.push(reference.name().shorten().to_str_lossy().into_owned());
+        }
+        Ok(branches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn setup_remote(temp: &TempDir) -> String {
+        let origin = temp.path().join("origin");
+        std::fs::create_dir(&origin).unwrap();
+        git(&origin, &["init", "-b", "main"]);
+        git(&origin, &["config", "user.email", "test@example.com"]);
+        git(&origin, &["config", "user.name", "Test User"]);
+        std::fs::create_dir(origin.join("src")).unwrap();
+        std::fs::write(origin.join("src/lib.rs"), "// lib").unwrap();
+        std::fs::write(origin.join("README.md"), "# Test").unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "Initial commit"]);
+        git(&origin, &["branch", "feature"]);
+        format!("file://{}", origin.display())
+    }
+
+    #[test]
+    fn test_status_matches_cli_backend() {
+        let temp = TempDir::new().unwrap();
+        let url = setup_remote(&temp);
+        let clone = temp.path().join("clone");
+        git(temp.path(), &["clone", &url, clone.to_str().unwrap()]);
+        git(&clone, &["config", "user.email", "test@example.com"]);
+        git(&clone, &["config", "user.name", "Test User"]);
+
+        std::fs::write(clone.join("README.md"), "# Changed").unwrap();
+        std::fs::write(clone.join("staged.txt"), "staged").unwrap();
+        git(&clone, &["add", "staged.txt"]);
+        git(&clone, &["rm", "-q", "--cached", "src/lib.rs"]);
+        std::fs::create_dir(clone.join("new")).unwrap();
+        std::fs::write(clone.join("new/file.txt"), "new").unwrap();
+
+        let repo = Repository::open(&clone).unwrap();
+        let gix = GixBackend.status(&repo).unwrap();
+        let cli = CliBackend.status(&repo).unwrap();
+        assert_eq!(gix.current_branch, cli.current_branch);
+        assert_eq!(gix.staged, vec!["src/lib.rs", "staged.txt"]);
+        assert_eq!(gix.staged, cli.staged);
+        assert_eq!(gix.modified, cli.modified);
+        let mut cli_untracked = cli.untracked.clone();
+        cli_untracked.sort();
+        let mut gix_untracked = gix.untracked.clone();
+        gix_untracked.sort();
+        assert_eq!(gix_untracked, cli_untracked);
+        assert!(!gix.is_clean);
+
+        git(&clone, &["reset", "-q", "--hard"]);
+        git(&clone, &["clean", "-qfd"]);
+        git(&clone, &["commit", "--allow-empty", "-m", "Local"]);
+        let gix = GixBackend.status(&repo).unwrap();
+        assert!(gix.is_clean);
+        assert_eq!((gix.ahead, gix.behind), (1, 0));
+    }
+
+    #[test]
+    fn test_clone_fetch_and_branches() {
+        let temp = TempDir::new().unwrap();
+        let url = setup_remote(&temp);
+        let dest = temp.path().join("clone");
+        let options = crate::core::manifest::CloneConfig::default();
+        let request = CloneRequest {
+            branch: Some("feature"),
+            options: &options,
+            sparse: &[],
+            reference: None,
+        };
+        GixBackend.clone_repo(&url, &dest, &request).unwrap();
+        let repo = Repository::open(&dest).unwrap();
+        assert_eq!(get_current_branch(&repo).unwrap(), "feature");
+        assert!(dest.join("src/lib.rs").exists());
+
+        // Missing branch falls back to the remote's default
+        let fallback = temp.path().join("fallback");
+        let request = CloneRequest {
+            branch: Some("does-not-exist"),
+            ..request
+        };
+        GixBackend.clone_repo(&url, &fallback, &request).unwrap();
+        let fallback_repo = Repository::open(&fallback).unwrap();
+        assert_eq!(get_current_branch(&fallback_repo).unwrap(), "main");
+
+        let origin = temp.path().join("origin");
+        git(&origin, &["commit", "--allow-empty", "-m", "Upstream"]);
+        GixBackend.fetch(&repo, "origin").unwrap();
+        let upstream = Repository::open(&origin)
+            .unwrap()
+            .refname_to_id("refs/heads/main")
+            .unwrap();
+        assert_eq!(
+            repo.refname_to_id("refs/remotes/origin/main").unwrap(),
+            upstream
+        );
+        assert!(matches!(
+            GixBackend.fetch(&repo, "nope"),
+            Err(GitError::OperationFailed(_))
+        ));
+
+        assert_eq!(GixBackend.local_branches(&repo).unwrap(), vec!["feature"]);
+    }
+}
@@ -0,0 +1,209 @@
+//! Pluggable git backends
+//!
+//! Clone, fetch, status and local branch listing go through a [`GitBackend`]:
+//!
+//! - `git` drives the git CLI and libgit2 (the default)
+//! - `gitoxide` runs them in-process with gix, avoiding a `git` process per
+//!   repo (requires the `gitoxide` cargo feature, which also makes it the
+//!   default)
+//!
+//! The backend is chosen once per process: `gr --git-backend <name>`, else the
+//! `GITGRIP_GIT_BACKEND` environment variable, else the compile-time default.
+//! Both backends report failures through the same [`GitError`] variants.
+
+mod cli;
+#[cfg(feature = "gitoxide")]
+mod gitoxide;
+
+use git2::Repository;
+use once_cell::sync::OnceCell;
+use std::path::Path;
+use std::str::FromStr;
+
+use super::status::RepoStatusInfo;
+use super::GitError;
+use crate::core::manifest::CloneConfig;
+
+pub use cli::CliBackend;
+#[cfg(feature = "gitoxide")]
+pub use gitoxide::GixBackend;
+
+/// Environment variable selecting the backend at runtime
+pub const BACKEND_ENV: &str = "GITGRIP_GIT_BACKEND";
+
+/// Available git backends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendKind {
+    /// git CLI and libgit2
+    Git,
+    /// gitoxide (gix), in-process
+    Gitoxide,
+}
+
+impl BackendKind {
+    /// The backend used when none is selected at runtime
+    pub fn compiled_default() -> Self {
+        if cfg!(feature = "gitoxide") {
+            BackendKind::Gitoxide
+        } else {
+            BackendKind::Git
+        }
+    }
+
+    /// Whether this binary was built with support for the backend
+    pub fn is_available(self) -> bool {
+        match self {
+            BackendKind::Git => true,
+            BackendKind::Gitoxide => cfg!(feature = "gitoxide"),
+        }
+    }
+}
+
+impl FromStr for BackendKind {
+    type Err = GitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "git" | "cli" | "git2" => Ok(BackendKind::Git),
+            "gitoxide" | "gix" => Ok(BackendKind::Gitoxide),
+            other => Err(GitError::OperationFailed(format!(
+                "unknown git backend '{}' (expected 'git' or 'gitoxide')",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for BackendKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackendKind::Git => write!(f, "git"),
+            BackendKind::Gitoxide => write!(f, "gitoxide"),
+        }
+    }
+}
+
+/// What to clone, beyond the URL and destination
+#[derive(Debug, Clone, Copy)]
+pub struct CloneRequest<'a> {
+    /// Branch to check out; falls back to the remote's default if missing
+    pub branch: Option<&'a str>,
+    /// Shallow/partial clone options
+    pub options: &'a CloneConfig,
+    /// Cone-mode sparse checkout directories (empty = full checkout)
+    pub sparse: &'a [String],
+    /// Local repository to borrow objects from (`--reference`)
+    pub reference: Option<&'a Path>,
+}
+
+/// Git operations that have more than one implementation
+pub trait GitBackend: Send + Sync {
+    /// Which backend this is
+    fn kind(&self) -> BackendKind;
+
+    /// Clone `url` into `path`
+    ///
+    /// Leaves sparse checkout patterns to the caller; `request.sparse` only
+    /// tells the backend not to check out the full tree.
+    fn clone_repo(&self, url: &str, path: &Path, request: &CloneRequest) -> Result<(), GitError>;
+
+    /// Fetch from a named remote
+    fn fetch(&self, repo: &Repository, remote: &str) -> Result<(), GitError>;
+
+    /// Branch, worktree changes and ahead/behind counts
+    fn status(&self, repo: &Repository) -> Result<RepoStatusInfo, GitError>;
+
+    /// Short names of local branches
+    fn local_branches(&self, repo: &Repository) -> Result<Vec<String>, GitError>;
+}
+
+static BACKEND: OnceCell<Box<dyn GitBackend>> = OnceCell::new();
+
+/// Select the backend for this process
+///
+/// Must be called before the first git operation; fails if the backend is not
+/// compiled in or a different backend is already in use.
+pub fn init_backend(kind: BackendKind) -> Result<(), GitError> {
+    let backend = new_backend(kind)?;
+    let current = BACKEND.get_or_init(|| backend);
+    if current.kind() != kind {
+        return Err(GitError::OperationFailed(format!(
+            "git backend already initialized as '{}'",
+            current.kind()
+        )));
+    }
+    Ok(())
+}
+
+/// The active backend
+///
+/// Without [`init_backend`], `GITGRIP_GIT_BACKEND` is consulted; an unknown
+/// or unavailable backend there falls back to the compiled default.
+pub fn backend() -> &'static dyn GitBackend {
+    BACKEND
+        .get_or_init(|| {
+            let requested = std::env::var(BACKEND_ENV)
+                .ok()
+                .filter(|v| !v.trim().is_empty());
+            let kind = match requested.map(|v| v.parse::<BackendKind>()) {
+                Some(Ok(kind)) if kind.is_available() => kind,
+                Some(Ok(kind)) => {
+                    tracing::warn!(
+                        "{}={} is not available in this build; using the {} backend",
+                        BACKEND_ENV,
+                        kind,
+                        BackendKind::compiled_default()
+                    );
+                    BackendKind::compiled_default()
+                }
+                Some(Err(e)) => {
+                    tracing::warn!("{}: {}", BACKEND_ENV, e);
+                    BackendKind::compiled_default()
+                }
+                None => BackendKind::compiled_default(),
+            };
+            new_backend(kind).unwrap_or_else(|_| Box::new(CliBackend))
+        })
+        .as_ref()
+}
+
+/// Create a backend instance, independent of the process-wide selection
+pub fn new_backend(kind: BackendKind) -> Result<Box<dyn GitBackend>, GitError> {
+    match kind {
+        BackendKind::Git => Ok(Box::new(CliBackend)),
+        #[cfg(feature = "gitoxide")]
+        BackendKind::Gitoxide => Ok(Box::new(GixBackend)),
+        #[cfg(not(feature = "gitoxide"))]
+        BackendKind::Gitoxide => Err(GitError::OperationFailed(
+            "the gitoxide backend is not available: rebuild with `--features gitoxide`".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_backend_kind() {
+        assert_eq!("git".parse::<BackendKind>().unwrap(), BackendKind::Git);
+        assert_eq!("GIX".parse::<BackendKind>().unwrap(), BackendKind::Gitoxide);
+        assert_eq!(
+            "gitoxide".parse::<BackendKind>().unwrap(),
+            BackendKind::Gitoxide
+        );
+        assert!("svn".parse::<BackendKind>().is_err());
+    }
+
+    #[test]
+    fn test_new_backend_availability() {
+        assert_eq!(
+            new_backend(BackendKind::Git).unwrap().kind(),
+            BackendKind::Git
+        );
+        assert_eq!(
+            new_backend(BackendKind::Gitoxide).is_ok(),
+            cfg!(feature = "gitoxide")
+        );
+    }
+}
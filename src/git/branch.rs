@@ -260,6 +260,11 @@ pub fn is_branch_merged(
 
 /// Get list of local branches
 pub fn list_local_branches(repo: &Repository) -> Result<Vec<String>, GitError> {
+    super::backend::backend().local_branches(repo)
+}
+
+/// Local branches via `git branch`
+pub(crate) fn list_local_branches_cli(repo: &Repository) -> Result<Vec<String>, GitError> {
     let repo_path = super::get_workdir(repo);
 
     let mut cmd = Command::new("git");
@@ -374,7 +379,7 @@ mod tests {
 
     #[test]
     fn test_create_and_checkout_branch() {
-        let (temp, repo) = setup_test_repo();
+        let (_temp, repo) = setup_test_repo();
 
         create_and_checkout_branch(&repo, "feature").unwrap();
 
@@ -384,7 +389,7 @@ mod tests {
 
     #[test]
     fn test_branch_exists() {
-        let (temp, repo) = setup_test_repo();
+        let (_temp, repo) = setup_test_repo();
 
         assert!(!branch_exists(&repo, "feature"));
 
@@ -394,7 +399,7 @@ mod tests {
 
     #[test]
     fn test_checkout_branch() {
-        let (temp, repo) = setup_test_repo();
+        let (_temp, repo) = setup_test_repo();
 
         // Create a feature branch
         create_and_checkout_branch(&repo, "feature").unwrap();
@@ -413,7 +418,7 @@ mod tests {
 
     #[test]
     fn test_list_local_branches() {
-        let (temp, repo) = setup_test_repo();
+        let (_temp, repo) = setup_test_repo();
 
         create_and_checkout_branch(&repo, "feature1").unwrap();
         create_and_checkout_branch(&repo, "feature2").unwrap();
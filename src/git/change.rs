@@ -0,0 +1,157 @@
+//! Cross-repo change IDs
+//!
+//! A logical change spanning several repos is linked by a shared
+//! `Gitgrip-Change-Id:` trailer on each of its commits. The ID survives
+//! merges and rebases (unlike the branch name), so the commits can be found
+//! again with `git log --grep`.
+
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::{run_git, GitError};
+
+/// Commit message trailer carrying the change ID
+pub const CHANGE_ID_TRAILER: &str = "Gitgrip-Change-Id";
+
+/// Shortest change ID prefix accepted for lookups, in hex digits after the `I`
+const MIN_PREFIX_LEN: usize = 6;
+
+/// Generate a new change ID: `I` followed by 40 hex digits
+///
+/// `seed` (e.g. the commit message) is hashed together with the current time
+/// and process ID.
+pub fn generate_change_id(seed: &str) -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let input = format!("{}\n{}\n{}", nanos, std::process::id(), seed);
+    let oid = git2::Oid::hash_object(git2::ObjectType::Blob, input.as_bytes())
+        .unwrap_or_else(|_| git2::Oid::zero());
+    format!("I{}", oid)
+}
+
+/// Check that `id` is a change ID or an unambiguous-looking prefix of one
+pub fn is_valid_change_id(id: &str) -> bool {
+    id.strip_prefix('I').is_some_and(|hex| {
+        (MIN_PREFIX_LEN..=40).contains(&hex.len()) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+/// The change ID in a commit message's trailers, if any
+pub fn change_id_of(message: &str) -> Option<String> {
+    let prefix = format!("{}:", CHANGE_ID_TRAILER);
+    message
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+}
+
+/// Append the change ID trailer to a commit message
+///
+/// Joins an existing trailer block (e.g. `Signed-off-by:`) instead of
+/// starting a new paragraph. Messages that already carry a change ID are
+/// returned unchanged.
+pub fn with_change_id(message: &str, id: &str) -> String {
+    if change_id_of(message).is_some() {
+        return message.to_string();
+    }
+    let message = message.trim_end();
+    let trailer = format!("{}: {}", CHANGE_ID_TRAILER, id);
+
+    let paragraphs: Vec<&str> = message.split("\n\n").collect();
+    let last_is_trailers = paragraphs.len() > 1
+        && paragraphs.last().is_some_and(|p| {
+            p.lines().all(|line| {
+                line.split_once(": ").is_some_and(|(key, _)| {
+                    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+                })
+            })
+        });
+
+    if last_is_trailers {
+        format!("{}\n{}\n", message, trailer)
+    } else {
+        format!("{}\n\n{}\n", message, trailer)
+    }
+}
+
+/// `git log --grep` pattern matching commits of a change (or ID prefix)
+pub fn change_id_pattern(id: &str) -> String {
+    format!("^{}: {}", CHANGE_ID_TRAILER, id)
+}
+
+/// Full message of the `HEAD` commit
+pub fn head_message(repo_path: &Path) -> Result<String, GitError> {
+    run_git(repo_path, &["log", "-1", "--format=%B"])
+}
+
+/// Revert commits (newest first) without committing
+///
+/// On failure the revert is aborted, leaving the repo as it was.
+pub fn revert_no_commit(repo_path: &Path, shas: &[String]) -> Result<(), GitError> {
+    let mut args = vec!["revert", "--no-commit"];
+    args.extend(shas.iter().map(String::as_str));
+    if let Err(e) = run_git(repo_path, &args) {
+        let _ = run_git(repo_path, &["revert", "--abort"]);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Reset the branch, index and tracked files to `sha`
+pub fn reset_hard(repo_path: &Path, sha: &str) -> Result<(), GitError> {
+    run_git(repo_path, &["reset", "--hard", "-q", sha]).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_change_id() {
+        let a = generate_change_id("msg");
+        let b = generate_change_id("msg");
+        assert_eq!(a.len(), 41);
+        assert!(is_valid_change_id(&a));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_is_valid_change_id() {
+        assert!(is_valid_change_id("I1a2b3c"));
+        assert!(!is_valid_change_id("I1a2b"));
+        assert!(!is_valid_change_id("I1a2bc"));
+        assert!(!is_valid_change_id("1a2b3c4d"));
+        assert!(!is_valid_change_id("I1a2b3c.*"));
+    }
+
+    #[test]
+    fn test_with_change_id() {
+        assert_eq!(
+            with_change_id("Fix bug", "I123456"),
+            "Fix bug\n\nGitgrip-Change-Id: I123456\n"
+        );
+        assert_eq!(
+            with_change_id("Fix bug\n\nSigned-off-by: A <a@example.com>\n", "I123456"),
+            "Fix bug\n\nSigned-off-by: A <a@example.com>\nGitgrip-Change-Id: I123456\n"
+        );
+        assert_eq!(
+            with_change_id("Fix bug\n\nDetails: see issue", "I123456"),
+            "Fix bug\n\nDetails: see issue\nGitgrip-Change-Id: I123456\n"
+        );
+        let tagged = with_change_id("Fix bug", "I123456");
+        assert_eq!(with_change_id(&tagged, "I999999"), tagged);
+    }
+
+    #[test]
+    fn test_change_id_of() {
+        assert_eq!(
+            change_id_of("Fix\n\nGitgrip-Change-Id: I123456\n").as_deref(),
+            Some("I123456")
+        );
+        assert_eq!(change_id_of("Fix"), None);
+    }
+}
@@ -0,0 +1,327 @@
+//! Commit history queries
+
+use git2::Repository;
+
+use super::change::change_id_pattern;
+use super::{run_git, GitError};
+
+/// Filters passed through to `git log`
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter<'a> {
+    /// Only commits more recent than this date (any format git accepts)
+    pub since: Option<&'a str>,
+    /// Only commits older than this date
+    pub until: Option<&'a str>,
+    /// Only commits whose author matches this pattern
+    pub author: Option<&'a str>,
+    /// Only commits whose message matches this pattern
+    pub grep: Option<&'a str>,
+    /// Only commits carrying this change ID (or ID prefix), searched in all
+    /// refs unless a range is given
+    pub change_id: Option<&'a str>,
+    /// At most this many commits
+    pub max_count: Option<usize>,
+}
+
+/// A commit in a repo's history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    /// Full commit SHA
+    pub sha: String,
+    /// Author name
+    pub author: String,
+    /// Author email
+    pub email: String,
+    /// Committer date, seconds since the Unix epoch
+    pub timestamp: i64,
+    /// First line of the commit message
+    pub subject: String,
+}
+
+/// Commits reachable from `range` (default `HEAD`), newest first
+///
+/// `range` accepts anything `git log` does, e.g. `origin/main..HEAD`. A repo
+/// without commits has an empty history.
+pub fn get_log(
+    repo: &Repository,
+    range: Option<&str>,
+    filter: &LogFilter,
+) -> Result<Vec<LogEntry>, GitError> {
+    if repo.head().is_err() {
+        return Ok(Vec::new());
+    }
+
+    let mut args = log_args(filter);
+    match range {
+        Some(range) => args.push(range.to_string()),
+        // Merged commits of a change may only be reachable from other branches
+        None if filter.change_id.is_some() => {
+            args.push("--exclude=refs/stash".to_string());
+            args.push("--all".to_string());
+        }
+        None => args.push("HEAD".to_string()),
+    }
+    run_log(repo, args)
+}
+
+/// The given commits (e.g. from [`get_commits_between`]) that pass
+/// `filter`, newest first
+///
+/// [`get_commits_between`]: super::branch::get_commits_between
+pub fn get_log_of(
+    repo: &Repository,
+    shas: &[String],
+    filter: &LogFilter,
+) -> Result<Vec<LogEntry>, GitError> {
+    if shas.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut args = log_args(filter);
+    args.push("--no-walk=sorted".to_string());
+    args.extend(shas.iter().cloned());
+    run_log(repo, args)
+}
+
+/// `git log` arguments for `filter`, without any revisions
+fn log_args(filter: &LogFilter) -> Vec<String> {
+    let mut args = vec![
+        "log".to_string(),
+        "--date-order".to_string(),
+        "--format=%H%x1f%an%x1f%ae%x1f%ct%x1f%s".to_string(),
+    ];
+    if let Some(since) = filter.since {
+        args.push(format!("--since={}", since));
+    }
+    if let Some(until) = filter.until {
+        args.push(format!("--until={}", until));
+    }
+    if let Some(author) = filter.author {
+        args.push(format!("--author={}", author));
+    }
+    if let Some(grep) = filter.grep {
+        args.push(format!("--grep={}", grep));
+    }
+    if let Some(id) = filter.change_id {
+        args.push(format!("--grep={}", change_id_pattern(id)));
+        if filter.grep.is_some() {
+            args.push("--all-match".to_string());
+        }
+    }
+    if let Some(max) = filter.max_count {
+        args.push(format!("--max-count={}", max));
+    }
+    args
+}
+
+/// Run `git log` with `args` (revisions included) and parse its entries
+fn run_log(repo: &Repository, mut args: Vec<String>) -> Result<Vec<LogEntry>, GitError> {
+    args.push("--".to_string());
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    Ok(run_git(super::get_workdir(repo), &args)?
+        .lines()
+        .filter_map(parse_log_line)
+        .collect())
+}
+
+/// First-parent history of `HEAD`, newest first, down to and including the
+/// first commit made at or before `stop_at` (seconds since the Unix epoch)
+///
+/// If every commit is newer than `stop_at`, the whole history is returned.
+pub fn first_parent_log(repo: &Repository, stop_at: i64) -> Result<Vec<LogEntry>, GitError> {
+    if repo.head().is_err() {
+        return Ok(Vec::new());
+    }
+
+    let mut walk = repo.revwalk()?;
+    walk.push_head()?;
+    walk.simplify_first_parent()?;
+
+    let mut entries = Vec::new();
+    for oid in walk {
+        let commit = repo.find_commit(oid?)?;
+        let author = commit.author();
+        let entry = LogEntry {
+            sha: commit.id().to_string(),
+            author: author.name().unwrap_or_default().to_string(),
+            email: author.email().unwrap_or_default().to_string(),
+            timestamp: commit.time().seconds(),
+            subject: commit.summary().unwrap_or_default().to_string(),
+        };
+        let done = entry.timestamp <= stop_at;
+        entries.push(entry);
+        if done {
+            break;
+        }
+    }
+    Ok(entries)
+}
+
+/// `git show` output for a commit (`--stat` summary only if `stat`)
+pub fn show_commit(repo: &Repository, sha: &str, stat: bool) -> Result<String, GitError> {
+    let mut args = vec!["show"];
+    if stat {
+        args.push("--stat");
+    }
+    args.push(sha);
+    Ok(run_git(super::get_workdir(repo), &args)?
+        .trim_end()
+        .to_string())
+}
+
+fn parse_log_line(line: &str) -> Option<LogEntry> {
+    let mut fields = line.splitn(5, '\x1f');
+    Some(LogEntry {
+        sha: fields.next()?.to_string(),
+        author: fields.next()?.to_string(),
+        email: fields.next()?.to_string(),
+        timestamp: fields.next()?.parse().ok()?,
+        subject: fields.next().unwrap_or_default().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &std::path::Path, args: &[&str], env: &[(&str, &str)]) {
+        let output = Command::new("git")
+            .args(args)
+            .envs(env.iter().copied())
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn commit(dir: &std::path::Path, message: &str, author: &str, date: &str) {
+        let author_arg = format!("--author={} <{}@example.com>", author, author);
+        git(
+            dir,
+            &["commit", "--allow-empty", "-m", message, &author_arg],
+            &[("GIT_AUTHOR_DATE", date), ("GIT_COMMITTER_DATE", date)],
+        );
+    }
+
+    #[test]
+    fn test_parse_log_line() {
+        let entry =
+            parse_log_line("abc\x1fAlice\x1falice@example.com\x1f1700000000\x1fFix: a\x1fb")
+                .unwrap();
+        assert_eq!(entry.sha, "abc");
+        assert_eq!(entry.author, "Alice");
+        assert_eq!(entry.timestamp, 1_700_000_000);
+        assert_eq!(entry.subject, "Fix: a\x1fb");
+        assert!(parse_log_line("garbage").is_none());
+    }
+
+    #[test]
+    fn test_get_log_with_filters() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path();
+        git(path, &["init", "-b", "main"], &[]);
+        git(path, &["config", "user.email", "test@example.com"], &[]);
+        git(path, &["config", "user.name", "Test User"], &[]);
+        let repo = Repository::open(path).unwrap();
+        assert!(get_log(&repo, None, &LogFilter::default())
+            .unwrap()
+            .is_empty());
+
+        commit(path, "Initial commit", "alice", "2024-01-01T10:00:00Z");
+        commit(path, "Add feature", "bob", "2024-02-01T10:00:00Z");
+        git(path, &["checkout", "-q", "-b", "feat"], &[]);
+        commit(path, "Fix feature", "alice", "2024-03-01T10:00:00Z");
+
+        let all = get_log(&repo, None, &LogFilter::default()).unwrap();
+        let subjects: Vec<_> = all.iter().map(|e| e.subject.as_str()).collect();
+        assert_eq!(subjects, ["Fix feature", "Add feature", "Initial commit"]);
+
+        let filter = LogFilter {
+            author: Some("alice"),
+            since: Some("2024-01-15"),
+            ..Default::default()
+        };
+        let filtered = get_log(&repo, None, &filter).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].subject, "Fix feature");
+
+        let filter = LogFilter {
+            grep: Some("feature"),
+            max_count: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(get_log(&repo, None, &filter).unwrap().len(), 1);
+
+        let branch = get_log(&repo, Some("main..HEAD"), &LogFilter::default()).unwrap();
+        assert_eq!(branch.len(), 1);
+        assert_eq!(branch[0].subject, "Fix feature");
+
+        let shas = crate::git::branch::get_commits_between(&repo, "main", None).unwrap();
+        let filter = LogFilter {
+            author: Some("bob"),
+            ..Default::default()
+        };
+        assert_eq!(
+            get_log_of(&repo, &shas, &LogFilter::default()).unwrap(),
+            branch
+        );
+        assert!(get_log_of(&repo, &shas, &filter).unwrap().is_empty());
+
+        // Change IDs are found on any branch
+        git(path, &["checkout", "-q", "main"], &[]);
+        commit(
+            path,
+            "Linked\n\nGitgrip-Change-Id: Iabcdef0123",
+            "bob",
+            "2024-04-01T10:00:00Z",
+        );
+        git(path, &["checkout", "-q", "feat"], &[]);
+        let filter = LogFilter {
+            change_id: Some("Iabcdef"),
+            ..Default::default()
+        };
+        let linked = get_log(&repo, None, &filter).unwrap();
+        assert_eq!(linked.len(), 1);
+        assert_eq!(linked[0].subject, "Linked");
+    }
+
+    #[test]
+    fn test_first_parent_log() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path();
+        git(path, &["init", "-b", "main"], &[]);
+        git(path, &["config", "user.email", "test@example.com"], &[]);
+        git(path, &["config", "user.name", "Test User"], &[]);
+        let repo = Repository::open(path).unwrap();
+
+        commit(path, "One", "alice", "2024-01-01T10:00:00Z");
+        commit(path, "Two", "alice", "2024-02-01T10:00:00Z");
+        git(path, &["checkout", "-q", "-b", "side"], &[]);
+        commit(path, "Side", "bob", "2024-02-15T10:00:00Z");
+        git(path, &["checkout", "-q", "main"], &[]);
+        commit(path, "Three", "alice", "2024-03-01T10:00:00Z");
+        git(
+            path,
+            &["merge", "-q", "--no-ff", "-m", "Merge side", "side"],
+            &[
+                ("GIT_AUTHOR_DATE", "2024-04-01T10:00:00Z"),
+                ("GIT_COMMITTER_DATE", "2024-04-01T10:00:00Z"),
+            ],
+        );
+
+        // 2024-01-20: stops at "One", the last commit before that date
+        let log = first_parent_log(&repo, 1_705_708_800).unwrap();
+        let subjects: Vec<_> = log.iter().map(|e| e.subject.as_str()).collect();
+        assert_eq!(subjects, ["Merge side", "Three", "Two", "One"]);
+
+        let all = first_parent_log(&repo, 0).unwrap();
+        assert_eq!(all.len(), 4);
+    }
+}
@@ -0,0 +1,219 @@
+//! Shared local object mirrors
+//!
+//! Bare `git clone --mirror` copies of manifest repos kept in a user-level
+//! cache directory. New clones borrow objects from a repo's mirror through
+//! `--reference` (git alternates), so a second workspace does not download
+//! the same history again.
+//!
+//! Clones that borrow from a mirror need its objects to stay around, so
+//! mirrors are created with `gc.pruneExpire=never`: refs deleted upstream
+//! disappear from the mirror but their objects are kept.
+
+use std::path::{Path, PathBuf};
+
+use git2::Repository;
+
+use super::{run_git, GitError};
+
+/// Environment variable overriding the mirror directory
+pub const MIRROR_DIR_ENV: &str = "GITGRIP_MIRROR_DIR";
+
+/// The user-level mirror directory
+///
+/// `$GITGRIP_MIRROR_DIR`, else `$XDG_CACHE_HOME/gitgrip/mirrors`, else
+/// `~/.cache/gitgrip/mirrors`. `None` if no home directory is known.
+pub fn mirror_root() -> Option<PathBuf> {
+    let non_empty = |key: &str| std::env::var_os(key).filter(|v| !v.is_empty());
+    if let Some(dir) = non_empty(MIRROR_DIR_ENV) {
+        return Some(PathBuf::from(dir));
+    }
+    let cache = non_empty("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| non_empty("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(cache.join("gitgrip").join("mirrors"))
+}
+
+/// Where the mirror of `url` lives under `root`
+///
+/// SSH and HTTPS URLs of the same repo share a mirror:
+/// `git@github.com:org/repo.git` and `https://github.com/org/repo` both map
+/// to `<root>/github.com/org/repo.git`.
+pub fn mirror_path(root: &Path, url: &str) -> PathBuf {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let without_user = match without_scheme.split_once('@') {
+        Some((user, rest)) if !user.contains('/') => rest,
+        _ => without_scheme,
+    };
+
+    let mut path = root.to_path_buf();
+    let mut last = String::new();
+    for part in without_user
+        .split(['/', ':', '\\'])
+        .filter(|p| !p.is_empty() && *p != "." && *p != "..")
+    {
+        if !last.is_empty() {
+            path.push(&last);
+        }
+        last = part.to_string();
+    }
+    if last.is_empty() {
+        last = "repo".to_string();
+    }
+    if !last.ends_with(".git") {
+        last.push_str(".git");
+    }
+    path.join(last)
+}
+
+/// The existing mirror of `url` in the user-level mirror directory, if any
+pub fn find_mirror(url: &str) -> Option<PathBuf> {
+    let path = mirror_path(&mirror_root()?, url);
+    path.join("HEAD").is_file().then_some(path)
+}
+
+/// Create the mirror of `url` at `path`, or refresh it if it already exists
+///
+/// Returns `true` if the mirror was newly created.
+pub fn ensure_mirror(url: &str, path: &Path) -> Result<bool, GitError> {
+    if path.join("HEAD").is_file() {
+        update_mirror(path)?;
+        return Ok(false);
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let path_str = path.to_str().unwrap_or(".");
+    run_git(Path::new("."), &["clone", "--mirror", url, path_str])?;
+    if let Err(e) = run_git(path, &["config", "gc.pruneExpire", "never"]) {
+        let _ = std::fs::remove_dir_all(path);
+        return Err(e);
+    }
+    Ok(true)
+}
+
+/// Fetch new objects and refs into an existing mirror
+pub fn update_mirror(path: &Path) -> Result<(), GitError> {
+    run_git(path, &["remote", "update", "--prune"]).map(|_| ())
+}
+
+/// Update `origin`'s remote-tracking refs and tags from a local mirror
+///
+/// The objects come from disk; a later fetch from `origin` only has to
+/// negotiate refs.
+pub fn fetch_from_mirror(repo: &Repository, mirror: &Path) -> Result<(), GitError> {
+    let mirror_str = mirror.to_str().unwrap_or(".");
+    run_git(
+        super::get_workdir(repo),
+        &[
+            "fetch",
+            "--no-tags",
+            mirror_str,
+            "+refs/heads/*:refs/remotes/origin/*",
+            "+refs/tags/*:refs/tags/*",
+        ],
+    )
+    .map(|_| ())
+}
+
+/// Check whether a repo borrows objects from `mirror` (git alternates)
+pub fn uses_mirror(repo: &Repository, mirror: &Path) -> bool {
+    // Worktrees share the main repository's object store
+    let git_dir = repo.path();
+    let common_dir = std::fs::read_to_string(git_dir.join("commondir"))
+        .map(|dir| git_dir.join(dir.trim()))
+        .unwrap_or_else(|_| git_dir.to_path_buf());
+    let alternates = common_dir.join("objects/info/alternates");
+    let Ok(content) = std::fs::read_to_string(alternates) else {
+        return false;
+    };
+    let mirror_objects = mirror.join("objects");
+    content
+        .lines()
+        .any(|line| Path::new(line.trim()) == mirror_objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn test_mirror_path() {
+        let root = Path::new("/cache");
+        let expected = Path::new("/cache/github.com/org/repo.git");
+        assert_eq!(mirror_path(root, "git@github.com:org/repo.git"), expected);
+        assert_eq!(mirror_path(root, "https://github.com/org/repo"), expected);
+        assert_eq!(
+            mirror_path(root, "ssh://git@github.com/org/repo.git"),
+            expected
+        );
+        assert_eq!(
+            mirror_path(root, "file:///srv/git/../app.git"),
+            Path::new("/cache/srv/git/app.git")
+        );
+    }
+
+    #[test]
+    fn test_ensure_mirror_and_fetch() {
+        let temp = TempDir::new().unwrap();
+        let origin = temp.path().join("origin");
+        std::fs::create_dir(&origin).unwrap();
+        git(&origin, &["init", "-b", "main"]);
+        git(&origin, &["config", "user.email", "test@example.com"]);
+        git(&origin, &["config", "user.name", "Test User"]);
+        std::fs::write(origin.join("README.md"), "# Test").unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "Initial commit"]);
+        let url = format!("file://{}", origin.display());
+
+        let mirror = mirror_path(&temp.path().join("mirrors"), &url);
+        assert!(ensure_mirror(&url, &mirror).unwrap());
+        let config = Repository::open_bare(&mirror).unwrap().config().unwrap();
+        assert_eq!(config.get_string("gc.pruneExpire").unwrap(), "never");
+
+        let clone = temp.path().join("clone");
+        let clone_str = clone.to_str().unwrap();
+        let mirror_str = mirror.to_str().unwrap();
+        git(
+            temp.path(),
+            &["clone", "--reference", mirror_str, &url, clone_str],
+        );
+        let repo = Repository::open(&clone).unwrap();
+        assert!(uses_mirror(&repo, &mirror));
+
+        std::fs::write(origin.join("new.txt"), "new").unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "Second commit"]);
+        assert!(!ensure_mirror(&url, &mirror).unwrap());
+        fetch_from_mirror(&repo, &mirror).unwrap();
+
+        let head = Repository::open(&origin)
+            .unwrap()
+            .head()
+            .unwrap()
+            .target()
+            .unwrap();
+        let tracking = repo
+            .find_reference("refs/remotes/origin/main")
+            .unwrap()
+            .target()
+            .unwrap();
+        assert_eq!(tracking, head);
+    }
+}
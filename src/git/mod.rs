@@ -1,21 +1,31 @@
 //! Git operations wrapper
 //!
 //! Provides a unified interface for git operations.
-//! Uses git2 (libgit2 bindings) by default.
-//! Can optionally use gitoxide (gix) with the "gitoxide" feature flag.
+//! Uses git2 (libgit2 bindings) and the git CLI by default. Clone, fetch,
+//! status and branch listing can run on gitoxide (gix) instead; see
+//! [`backend`].
 
+pub mod backend;
 pub mod branch;
 pub mod cache;
+pub mod change;
 pub mod cherry_pick;
 pub mod gc;
+pub mod log;
+pub mod mirror;
+pub mod refs;
 pub mod remote;
+pub mod sparse;
+pub mod stash;
 pub mod status;
+pub mod tag;
 
 pub use branch::*;
 pub use cache::{invalidate_status_cache, GitStatusCache, STATUS_CACHE};
 pub use remote::*;
 pub use status::*;
 
+use crate::core::manifest::CloneConfig;
 use crate::util::log_cmd;
 use git2::Repository;
 use std::path::Path;
@@ -119,16 +129,59 @@ pub fn clone_repo<P: AsRef<Path>>(
     url: &str,
     path: P,
     branch: Option<&str>,
+) -> Result<Repository, GitError> {
+    clone_repo_with_options(url, path, branch, &CloneConfig::default(), &[])
+}
+
+/// Clone a repository with shallow/partial clone options
+///
+/// `depth` truncates history (all branches are still fetched unless
+/// `single_branch` is set) and `filter` makes a partial clone. Servers that
+/// don't support filters fall back to a full clone. A non-empty `sparse` list
+/// checks out only those directories (cone mode). If the user-level mirror
+/// directory has a mirror of `url`, objects are borrowed from it
+/// (`--reference`). The same branch fallback as [`clone_repo`] applies.
+pub fn clone_repo_with_options<P: AsRef<Path>>(
+    url: &str,
+    path: P,
+    branch: Option<&str>,
+    options: &CloneConfig,
+    sparse: &[String],
 ) -> Result<Repository, GitError> {
     let path = path.as_ref();
+    let mirror = mirror::find_mirror(url);
+    backend::backend().clone_repo(
+        url,
+        path,
+        &backend::CloneRequest {
+            branch,
+            options,
+            sparse,
+            reference: mirror.as_deref(),
+        },
+    )?;
+    let repo = open_repo(path)?;
+    if !sparse.is_empty() {
+        sparse::set_sparse_checkout(&repo, sparse)?;
+    }
+    Ok(repo)
+}
+
+/// `git clone` via the CLI, with the branch fallback of [`clone_repo`]
+pub(crate) fn clone_with_args(
+    url: &str,
+    path: &Path,
+    branch: Option<&str>,
+    option_args: &[String],
+) -> Result<Repository, GitError> {
     let path_str = path.to_str().unwrap_or(".");
 
     // Try cloning with specified branch first
     if let Some(b) = branch {
-        let args = vec!["clone", "-b", b, url, path_str];
-
         let mut cmd = Command::new("git");
-        cmd.args(&args);
+        cmd.arg("clone")
+            .args(option_args)
+            .args(["-b", b, url, path_str]);
         log_cmd(&cmd);
         let output = cmd
             .output()
@@ -152,10 +205,8 @@ pub fn clone_repo<P: AsRef<Path>>(
     }
 
     // Clone without -b flag (uses remote's default branch)
-    let args = vec!["clone", url, path_str];
-
     let mut cmd = Command::new("git");
-    cmd.args(&args);
+    cmd.arg("clone").args(option_args).args([url, path_str]);
     log_cmd(&cmd);
     let output = cmd
         .output()
@@ -172,6 +223,28 @@ pub fn clone_repo<P: AsRef<Path>>(
     open_repo(path)
 }
 
+/// `git clone` arguments for the given clone options
+pub(crate) fn clone_option_args(options: &CloneConfig, sparse: &[String]) -> Vec<String> {
+    let mut args = Vec::new();
+    if !sparse.is_empty() {
+        // Start from top-level files only; the cone is set right after cloning
+        args.push("--sparse".to_string());
+    }
+    if let Some(depth) = options.depth {
+        args.push(format!("--depth={}", depth));
+    }
+    if let Some(ref filter) = options.filter {
+        args.push(format!("--filter={}", filter));
+    }
+    if options.single_branch {
+        args.push("--single-branch".to_string());
+    } else if options.depth.is_some() {
+        // --depth implies --single-branch; keep other branches available
+        args.push("--no-single-branch".to_string());
+    }
+    args
+}
+
 /// Get the working directory of a repository
 /// Works correctly for both regular repos and worktrees
 pub fn get_workdir(repo: &Repository) -> &Path {
@@ -196,6 +269,62 @@ pub fn get_current_branch(repo: &Repository) -> Result<String, GitError> {
     }
 }
 
+/// Get the full SHA of the commit HEAD points at
+pub fn get_head_sha(repo: &Repository) -> Result<String, GitError> {
+    let commit = repo
+        .head()
+        .and_then(|head| head.peel_to_commit())
+        .map_err(|e| GitError::Reference(e.to_string()))?;
+    Ok(commit.id().to_string())
+}
+
+/// Resolve a revision (branch, tag or SHA) to a full commit SHA.
+///
+/// Branch names are resolved against `origin/<name>` first so a pin follows
+/// the fetched remote branch rather than a possibly stale local one.
+pub fn resolve_revision(repo: &Repository, revision: &str) -> Result<String, GitError> {
+    let object = repo
+        .revparse_single(&format!("refs/remotes/origin/{}", revision))
+        .or_else(|_| repo.revparse_single(revision))
+        .map_err(|e| GitError::Reference(format!("cannot resolve '{}': {}", revision, e)))?;
+    let commit = object
+        .peel_to_commit()
+        .map_err(|e| GitError::Object(format!("'{}' is not a commit: {}", revision, e)))?;
+    Ok(commit.id().to_string())
+}
+
+/// Run git in `dir`, returning its stdout
+pub(crate) fn run_git(dir: &Path, args: &[&str]) -> Result<String, GitError> {
+    let mut cmd = Command::new("git");
+    cmd.args(args).current_dir(dir);
+    log_cmd(&cmd);
+    let output = cmd
+        .output()
+        .map_err(|e| GitError::OperationFailed(e.to_string()))?;
+
+    if !output.status.success() {
+        return Err(GitError::OperationFailed(format!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Initialize a repo on `main` with a test identity and an empty initial commit
+#[cfg(test)]
+pub(crate) fn init_test_repo(path: &Path) {
+    for args in [
+        vec!["init", "-q", "-b", "main"],
+        vec!["config", "user.email", "test@example.com"],
+        vec!["config", "user.name", "Test User"],
+        vec!["commit", "-q", "--allow-empty", "-m", "Initial commit"],
+    ] {
+        run_git(path, &args).unwrap();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,6 +439,86 @@ mod tests {
         assert_eq!(branch, "main");
     }
 
+    #[test]
+    fn test_clone_repo_with_options_shallow() {
+        let (temp, remote_url) = setup_bare_remote();
+        let staging = temp.path().join("staging");
+        fs::write(staging.join("CHANGELOG.md"), "v2").unwrap();
+        git(&staging, &["add", "CHANGELOG.md"]);
+        git(&staging, &["commit", "-m", "Second commit"]);
+        git(&staging, &["push", "origin", "main"]);
+
+        let dest_root = TempDir::new().unwrap();
+        let dest = dest_root.path().join("dest");
+        let options = CloneConfig {
+            depth: Some(1),
+            filter: Some("blob:none".to_string()),
+            single_branch: true,
+        };
+        let repo =
+            clone_repo_with_options(&remote_url, &dest, Some("main"), &options, &[]).unwrap();
+
+        assert!(repo.is_shallow());
+        let mut revwalk = repo.revwalk().unwrap();
+        revwalk.push_head().unwrap();
+        assert_eq!(revwalk.count(), 1);
+
+        unshallow(&repo, "origin").unwrap();
+        let repo = open_repo(&dest).unwrap();
+        assert!(!repo.is_shallow());
+    }
+
+    #[test]
+    fn test_clone_option_args() {
+        assert!(clone_option_args(&CloneConfig::default(), &[]).is_empty());
+        let shallow = CloneConfig {
+            depth: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(
+            clone_option_args(&shallow, &[]),
+            vec!["--depth=5", "--no-single-branch"]
+        );
+        let blobless = CloneConfig {
+            filter: Some("blob:none".to_string()),
+            single_branch: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            clone_option_args(&blobless, &["src".to_string()]),
+            vec!["--sparse", "--filter=blob:none", "--single-branch"]
+        );
+    }
+
+    #[test]
+    fn test_get_head_sha() {
+        let (_temp, remote_url) = setup_bare_remote();
+        let dest_root = TempDir::new().unwrap();
+        let dest = dest_root.path().join("dest");
+        clone_repo(&remote_url, &dest, Some("main")).unwrap();
+
+        let repo = open_repo(&dest).expect("open repo");
+        let sha = get_head_sha(&repo).expect("head sha");
+        assert_eq!(sha.len(), 40);
+        assert!(sha.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_resolve_revision_branch_tag_and_sha() {
+        let (_temp, remote_url) = setup_bare_remote();
+        let dest_root = TempDir::new().unwrap();
+        let dest = dest_root.path().join("dest");
+        clone_repo(&remote_url, &dest, Some("main")).unwrap();
+        git(&dest, &["tag", "v1.0"]);
+
+        let repo = open_repo(&dest).expect("open repo");
+        let head = get_head_sha(&repo).unwrap();
+        assert_eq!(resolve_revision(&repo, "main").unwrap(), head);
+        assert_eq!(resolve_revision(&repo, "v1.0").unwrap(), head);
+        assert_eq!(resolve_revision(&repo, &head).unwrap(), head);
+        assert!(resolve_revision(&repo, "does-not-exist").is_err());
+    }
+
     #[test]
     fn test_git_lock_exists_none() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,175 @@
+//! Ref snapshots
+//!
+//! Captures a repo's `HEAD` and local branches so that they can be put back
+//! later (see `gr undo`).
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use git2::{BranchType, Repository};
+use serde::{Deserialize, Serialize};
+
+use super::{run_git, GitError};
+
+/// `HEAD` and local branch tips of a repo
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefSnapshot {
+    /// Branch `HEAD` points to, or `None` if detached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+    /// Commit `HEAD` resolves to, or `None` on an unborn branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_sha: Option<String>,
+    /// Map: local branch name -> commit SHA
+    #[serde(default)]
+    pub branches: BTreeMap<String, String>,
+}
+
+impl RefSnapshot {
+    /// Branches whose tip differs between `self` and `other` (including
+    /// branches that only exist in one of them)
+    pub fn changed_branches<'a>(&'a self, other: &'a RefSnapshot) -> Vec<&'a str> {
+        let mut names: Vec<&str> = self
+            .branches
+            .keys()
+            .chain(other.branches.keys())
+            .map(String::as_str)
+            .filter(|name| self.branches.get(*name) != other.branches.get(*name))
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Whether `HEAD` points somewhere else in `other`
+    pub fn head_differs(&self, other: &RefSnapshot) -> bool {
+        self.head != other.head || self.head_sha != other.head_sha
+    }
+
+    /// Whether going from `self` to `other` checks out something else,
+    /// rather than only moving the tip of the checked out branch
+    pub fn switches_checkout(&self, other: &RefSnapshot) -> bool {
+        let same_branch = self.head.is_some() && self.head == other.head;
+        !same_branch && self.head_differs(other)
+    }
+}
+
+/// Capture `HEAD` and the local branches of a repo
+pub fn snapshot_refs(repo: &Repository) -> Result<RefSnapshot, GitError> {
+    let mut snapshot = RefSnapshot::default();
+
+    if let Ok(head) = repo.head() {
+        if head.is_branch() {
+            snapshot.head = head.shorthand().map(str::to_string);
+        }
+        snapshot.head_sha = head.target().map(|oid| oid.to_string());
+    } else if let Ok(head) = repo.find_reference("HEAD") {
+        // Unborn branch: HEAD is symbolic but its target does not exist yet
+        snapshot.head = head
+            .symbolic_target()
+            .and_then(|t| t.strip_prefix("refs/heads/"))
+            .map(str::to_string);
+    }
+
+    for branch in repo.branches(Some(BranchType::Local))? {
+        let (branch, _) = branch?;
+        let name = branch.name()?.map(str::to_string);
+        let target = branch.get().target();
+        if let (Some(name), Some(target)) = (name, target) {
+            snapshot.branches.insert(name, target.to_string());
+        }
+    }
+
+    Ok(snapshot)
+}
+
+/// Move `HEAD` and local branches from `from` back to `to`
+///
+/// Only branches that differ between the two snapshots are touched. When
+/// `HEAD` stays on the same branch, its tip is moved with `git reset --soft`
+/// if `soft` (undoing a commit keeps its changes staged), else with
+/// `git reset --keep` so the worktree follows. When `HEAD` moves elsewhere,
+/// the worktree is checked out at the new commit, so tracked files must be
+/// clean.
+pub fn restore_refs(
+    repo_path: &Path,
+    from: &RefSnapshot,
+    to: &RefSnapshot,
+    soft: bool,
+) -> Result<(), GitError> {
+    let move_head = from.switches_checkout(to) && to.head_sha.is_some();
+
+    // Detach first so the checked out branch can be moved freely
+    if move_head {
+        run_git(repo_path, &["checkout", "-q", "--detach"])?;
+    }
+
+    for name in from.changed_branches(to) {
+        let refname = format!("refs/heads/{}", name);
+        match to.branches.get(name) {
+            Some(sha) if !move_head && to.head.as_deref() == Some(name) => {
+                let mode = if soft { "--soft" } else { "--keep" };
+                run_git(repo_path, &["reset", "-q", mode, sha])?
+            }
+            Some(sha) => run_git(repo_path, &["update-ref", &refname, sha])?,
+            None => run_git(repo_path, &["update-ref", "-d", &refname])?,
+        };
+    }
+
+    if move_head {
+        match (&to.head, &to.head_sha) {
+            (Some(branch), _) => run_git(repo_path, &["checkout", "-q", branch])?,
+            (None, Some(sha)) => run_git(repo_path, &["checkout", "-q", "--detach", sha])?,
+            (None, None) => unreachable!("checked above"),
+        };
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::init_test_repo;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path();
+        init_test_repo(path);
+        let repo = Repository::open(path).unwrap();
+        let before = snapshot_refs(&repo).unwrap();
+        assert_eq!(before.head.as_deref(), Some("main"));
+        assert_eq!(before.branches.len(), 1);
+
+        run_git(path, &["checkout", "-q", "-b", "feat"]).unwrap();
+        run_git(path, &["commit", "-q", "--allow-empty", "-m", "Work"]).unwrap();
+        let after = snapshot_refs(&repo).unwrap();
+        assert!(before.head_differs(&after));
+        assert_eq!(before.changed_branches(&after), ["feat"]);
+
+        restore_refs(path, &after, &before, false).unwrap();
+        assert_eq!(snapshot_refs(&repo).unwrap(), before);
+    }
+
+    #[test]
+    fn test_restore_soft_keeps_commit_changes() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path();
+        init_test_repo(path);
+        let repo = Repository::open(path).unwrap();
+        let before = snapshot_refs(&repo).unwrap();
+
+        std::fs::write(path.join("work.txt"), "work").unwrap();
+        run_git(path, &["add", "work.txt"]).unwrap();
+        run_git(path, &["commit", "-q", "-m", "Work"]).unwrap();
+        let after = snapshot_refs(&repo).unwrap();
+
+        restore_refs(path, &after, &before, true).unwrap();
+        assert_eq!(snapshot_refs(&repo).unwrap(), before);
+        assert!(path.join("work.txt").exists());
+        let staged = run_git(path, &["diff", "--cached", "--name-only"]).unwrap();
+        assert_eq!(staged.trim(), "work.txt");
+    }
+}
@@ -62,25 +62,37 @@ pub fn set_remote_url(repo: &Repository, remote: &str, url: &str) -> Result<(),
     Ok(())
 }
 
-/// Fetch from remote
-#[cfg_attr(feature = "telemetry", instrument(skip(repo), fields(remote, success)))]
-pub fn fetch_remote(repo: &Repository, remote: &str) -> Result<(), GitError> {
+/// Set a separate push URL for an existing remote
+pub fn set_remote_push_url(repo: &Repository, remote: &str, url: &str) -> Result<(), GitError> {
     let repo_path = super::get_workdir(repo);
 
-    #[cfg(feature = "telemetry")]
-    let start = Instant::now();
-
     let mut cmd = Command::new("git");
-    cmd.args(["fetch", remote]).current_dir(repo_path);
+    cmd.args(["remote", "set-url", "--push", remote, url])
+        .current_dir(repo_path);
     log_cmd(&cmd);
     let output = cmd
         .output()
         .map_err(|e| GitError::OperationFailed(e.to_string()))?;
 
-    let success = output.status.success();
+    if !output.status.success() {
+        return Err(GitError::OperationFailed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Fetch from remote
+#[cfg_attr(feature = "telemetry", instrument(skip(repo), fields(remote, success)))]
+pub fn fetch_remote(repo: &Repository, remote: &str) -> Result<(), GitError> {
+    #[cfg(feature = "telemetry")]
+    let start = Instant::now();
+
+    let result = super::backend::backend().fetch(repo, remote);
 
     #[cfg(feature = "telemetry")]
     {
+        let success = result.is_ok();
         let duration = start.elapsed();
         GLOBAL_METRICS.record_git("fetch", duration, success);
         debug!(
@@ -91,7 +103,21 @@ pub fn fetch_remote(repo: &Repository, remote: &str) -> Result<(), GitError> {
         );
     }
 
-    if !success {
+    result
+}
+
+/// `git fetch <remote>` via the CLI
+pub(crate) fn fetch_remote_cli(repo: &Repository, remote: &str) -> Result<(), GitError> {
+    let repo_path = super::get_workdir(repo);
+
+    let mut cmd = Command::new("git");
+    cmd.args(["fetch", remote]).current_dir(repo_path);
+    log_cmd(&cmd);
+    let output = cmd
+        .output()
+        .map_err(|e| GitError::OperationFailed(e.to_string()))?;
+
+    if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         return Err(GitError::OperationFailed(interpret_push_error(&stderr)));
     }
@@ -99,6 +125,29 @@ pub fn fetch_remote(repo: &Repository, remote: &str) -> Result<(), GitError> {
     Ok(())
 }
 
+/// Fetch the full history of a shallow clone (`git fetch --unshallow`)
+///
+/// Plain fetches of a shallow clone keep it shallow: only history newer than
+/// the shallow boundary is downloaded.
+pub fn unshallow(repo: &Repository, remote: &str) -> Result<(), GitError> {
+    let repo_path = super::get_workdir(repo);
+
+    let mut cmd = Command::new("git");
+    cmd.args(["fetch", "--unshallow", remote])
+        .current_dir(repo_path);
+    log_cmd(&cmd);
+    let output = cmd
+        .output()
+        .map_err(|e| GitError::OperationFailed(e.to_string()))?;
+
+    if !output.status.success() {
+        return Err(GitError::OperationFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(())
+}
+
 /// Pull latest changes (fetch + merge)
 #[cfg_attr(feature = "telemetry", instrument(skip(repo), fields(remote, success)))]
 pub fn pull_latest(repo: &Repository, remote: &str) -> Result<(), GitError> {
@@ -667,8 +716,31 @@ mod tests {
     }
 
     #[test]
-    fn test_set_remote_url() {
+    fn test_set_remote_push_url() {
         let (temp, repo) = setup_test_repo();
+        set_remote_url(&repo, "origin", "https://github.com/test/repo.git").unwrap();
+
+        set_remote_push_url(&repo, "origin", "git@github.com:test/repo.git").unwrap();
+
+        let output = Command::new("git")
+            .args(["remote", "get-url", "--push", "origin"])
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "git@github.com:test/repo.git"
+        );
+        // Fetch URL is unchanged
+        assert_eq!(
+            get_remote_url(&repo, "origin").unwrap(),
+            Some("https://github.com/test/repo.git".to_string())
+        );
+    }
+
+    #[test]
+    fn test_set_remote_url() {
+        let (_temp, repo) = setup_test_repo();
 
         // Create new remote
         set_remote_url(&repo, "origin", "https://github.com/test/repo1.git").unwrap();
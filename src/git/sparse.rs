@@ -0,0 +1,179 @@
+//! Sparse checkout operations
+//!
+//! Cone-mode sparse checkout via the git CLI. libgit2 does not understand
+//! sparse checkouts and reports every path outside the cone as deleted, so
+//! code that inspects the worktree through git2 filters those paths out with
+//! [`skip_worktree_paths`].
+
+use git2::Repository;
+use std::collections::HashSet;
+use std::process::Command;
+
+use super::GitError;
+use crate::util::log_cmd;
+
+/// Index entry flag (extended flags) marking a path outside the sparse checkout
+const SKIP_WORKTREE: u16 = 1 << 14;
+
+/// Check whether sparse checkout is enabled (`core.sparseCheckout`)
+///
+/// Reads the config from disk: a `Repository`'s cached config does not see
+/// changes made by `git sparse-checkout`.
+pub fn is_sparse(repo: &Repository) -> bool {
+    Repository::open(repo.path())
+        .and_then(|r| r.config())
+        .and_then(|c| c.get_bool("core.sparseCheckout"))
+        .unwrap_or(false)
+}
+
+/// Current cone-mode directories, or an empty list when the repo is not sparse
+pub fn sparse_patterns(repo: &Repository) -> Result<Vec<String>, GitError> {
+    if !is_sparse(repo) {
+        return Ok(Vec::new());
+    }
+    let stdout = run_sparse_checkout(repo, &["list"])?;
+    Ok(stdout.lines().map(normalize_pattern).collect())
+}
+
+/// Check whether the repo's sparse checkout already matches `patterns`
+///
+/// An empty `patterns` list means a full checkout.
+pub fn sparse_matches(repo: &Repository, patterns: &[String]) -> Result<bool, GitError> {
+    let mut current = sparse_patterns(repo)?;
+    let mut wanted: Vec<String> = patterns.iter().map(|p| normalize_pattern(p)).collect();
+    current.sort();
+    current.dedup();
+    wanted.sort();
+    wanted.dedup();
+    Ok(current == wanted)
+}
+
+/// Restrict the worktree to `patterns` (cone mode), enabling sparse checkout if needed
+pub fn set_sparse_checkout(repo: &Repository, patterns: &[String]) -> Result<(), GitError> {
+    let dirs: Vec<String> = patterns.iter().map(|p| normalize_pattern(p)).collect();
+    let mut args = vec!["set", "--cone", "--"];
+    args.extend(dirs.iter().map(String::as_str));
+    run_sparse_checkout(repo, &args).map(|_| ())
+}
+
+/// Restore the full worktree
+pub fn disable_sparse_checkout(repo: &Repository) -> Result<(), GitError> {
+    run_sparse_checkout(repo, &["disable"]).map(|_| ())
+}
+
+/// Paths excluded by the sparse checkout (empty when the repo is not sparse)
+pub fn skip_worktree_paths(repo: &Repository) -> HashSet<String> {
+    if !is_sparse(repo) {
+        return HashSet::new();
+    }
+    let Ok(mut index) = repo.index() else {
+        return HashSet::new();
+    };
+    // Pick up index changes made by the git CLI since the repo was opened
+    let _ = index.read(false);
+    index
+        .iter()
+        .filter(|entry| entry.flags_extended & SKIP_WORKTREE != 0)
+        .filter_map(|entry| String::from_utf8(entry.path).ok())
+        .collect()
+}
+
+/// Normalize a cone directory for comparison (`/services/api/` -> `services/api`)
+fn normalize_pattern(pattern: &str) -> String {
+    pattern.trim().trim_matches('/').to_string()
+}
+
+fn run_sparse_checkout(repo: &Repository, args: &[&str]) -> Result<String, GitError> {
+    let repo_path = super::get_workdir(repo);
+
+    let mut cmd = Command::new("git");
+    cmd.arg("sparse-checkout").args(args).current_dir(repo_path);
+    log_cmd(&cmd);
+    let output = cmd
+        .output()
+        .map_err(|e| GitError::OperationFailed(e.to_string()))?;
+
+    if !output.status.success() {
+        return Err(GitError::OperationFailed(format!(
+            "git sparse-checkout {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn setup_repo() -> (TempDir, Repository) {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        git(dir, &["init", "-b", "main"]);
+        git(dir, &["config", "user.email", "test@example.com"]);
+        git(dir, &["config", "user.name", "Test User"]);
+        for file in [
+            "services/api/main.rs",
+            "services/web/index.js",
+            "docs/README.md",
+        ] {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, file).unwrap();
+        }
+        git(dir, &["add", "."]);
+        git(dir, &["commit", "-m", "Initial commit"]);
+        let repo = Repository::open(dir).unwrap();
+        (temp, repo)
+    }
+
+    #[test]
+    fn test_set_and_disable_sparse_checkout() {
+        let (temp, repo) = setup_repo();
+        assert!(!is_sparse(&repo));
+        assert!(sparse_matches(&repo, &[]).unwrap());
+
+        let patterns = vec!["/services/api/".to_string()];
+        set_sparse_checkout(&repo, &patterns).unwrap();
+        assert!(is_sparse(&repo));
+        assert_eq!(sparse_patterns(&repo).unwrap(), vec!["services/api"]);
+        assert!(sparse_matches(&repo, &patterns).unwrap());
+        assert!(!sparse_matches(&repo, &["docs".to_string()]).unwrap());
+        assert!(temp.path().join("services/api/main.rs").exists());
+        assert!(!temp.path().join("docs/README.md").exists());
+
+        disable_sparse_checkout(&repo).unwrap();
+        assert!(!is_sparse(&repo));
+        assert!(temp.path().join("docs/README.md").exists());
+    }
+
+    #[test]
+    fn test_skip_worktree_paths() {
+        let (_temp, repo) = setup_repo();
+        assert!(skip_worktree_paths(&repo).is_empty());
+
+        set_sparse_checkout(&repo, &["services/api".to_string()]).unwrap();
+        let skipped = skip_worktree_paths(&repo);
+        assert!(skipped.contains("docs/README.md"));
+        assert!(skipped.contains("services/web/index.js"));
+        assert!(!skipped.contains("services/api/main.rs"));
+    }
+}
@@ -0,0 +1,116 @@
+//! Stash operations
+//!
+//! Stashes are identified by their commit SHA rather than `stash@{n}`, so an
+//! entry can be found again after other stashes were pushed or dropped.
+
+use std::path::Path;
+
+use super::{run_git, GitError};
+
+/// Stash tracked and untracked changes
+///
+/// Returns the stash commit SHA, or `None` if there was nothing to stash.
+pub fn stash_push(repo_path: &Path, message: &str) -> Result<Option<String>, GitError> {
+    let output = run_git(repo_path, &["stash", "push", "-u", "-m", message])?;
+    if output.contains("No local changes to save") {
+        return Ok(None);
+    }
+    let sha = run_git(repo_path, &["rev-parse", "refs/stash"])?;
+    Ok(Some(sha.trim().to_string()))
+}
+
+/// Apply and drop the most recent stash
+pub fn stash_pop(repo_path: &Path) -> Result<(), GitError> {
+    run_git(repo_path, &["stash", "pop"]).map(|_| ())
+}
+
+/// Position of the stash with commit `sha` in the stash list
+pub fn find_stash(repo_path: &Path, sha: &str) -> Result<Option<usize>, GitError> {
+    let list = run_git(repo_path, &["stash", "list", "--format=%H"])?;
+    Ok(list.lines().position(|line| line.trim() == sha))
+}
+
+/// Apply the stash with commit `sha`, keeping it in the stash list
+pub fn stash_apply(repo_path: &Path, sha: &str) -> Result<(), GitError> {
+    run_git(repo_path, &["stash", "apply", sha]).map(|_| ())
+}
+
+/// Drop the stash with commit `sha`
+pub fn stash_drop(repo_path: &Path, sha: &str) -> Result<(), GitError> {
+    let index = find_stash(repo_path, sha)?
+        .ok_or_else(|| GitError::OperationFailed(format!("stash {} not found", short_sha(sha))))?;
+    run_git(
+        repo_path,
+        &["stash", "drop", &format!("stash@{{{}}}", index)],
+    )
+    .map(|_| ())
+}
+
+/// Throw away all worktree and index changes, including untracked files
+///
+/// Used to undo a stash apply in a repo that was clean beforehand.
+pub fn discard_changes(repo_path: &Path) -> Result<(), GitError> {
+    run_git(repo_path, &["reset", "--hard", "-q"])?;
+    run_git(repo_path, &["clean", "-fdq"]).map(|_| ())
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::init_test_repo;
+    use tempfile::TempDir;
+
+    fn setup_repo() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path();
+        init_test_repo(path);
+        std::fs::write(path.join("README.md"), "# Test").unwrap();
+        run_git(path, &["add", "."]).unwrap();
+        run_git(path, &["commit", "-q", "-m", "Add README"]).unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_stash_push_nothing_to_stash() {
+        let temp = setup_repo();
+        assert_eq!(stash_push(temp.path(), "empty").unwrap(), None);
+    }
+
+    #[test]
+    fn test_stash_by_sha() {
+        let temp = setup_repo();
+        let path = temp.path();
+
+        std::fs::write(path.join("README.md"), "# First").unwrap();
+        let first = stash_push(path, "first").unwrap().unwrap();
+        std::fs::write(path.join("new.txt"), "untracked").unwrap();
+        let second = stash_push(path, "second").unwrap().unwrap();
+        assert!(!path.join("new.txt").exists());
+
+        assert_eq!(find_stash(path, &first).unwrap(), Some(1));
+        assert_eq!(find_stash(path, &second).unwrap(), Some(0));
+
+        stash_apply(path, &first).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(path.join("README.md")).unwrap(),
+            "# First"
+        );
+        discard_changes(path).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(path.join("README.md")).unwrap(),
+            "# Test"
+        );
+
+        stash_drop(path, &first).unwrap();
+        assert_eq!(find_stash(path, &first).unwrap(), None);
+        assert_eq!(find_stash(path, &second).unwrap(), Some(0));
+        assert!(stash_drop(path, &first).is_err());
+
+        stash_pop(path).unwrap();
+        assert!(path.join("new.txt").exists());
+    }
+}
@@ -55,8 +55,13 @@ pub struct RepoStatus {
     pub exists: bool,
 }
 
-/// Get detailed status for a repository using git2
+/// Get detailed status for a repository using the active git backend
 pub fn get_status_info(repo: &Repository) -> Result<RepoStatusInfo, GitError> {
+    super::backend::backend().status(repo)
+}
+
+/// Status via `git status --porcelain` and `git rev-list`
+pub(crate) fn get_status_info_cli(repo: &Repository) -> Result<RepoStatusInfo, GitError> {
     let current_branch = get_current_branch(repo)?;
 
     // Use git porcelain status for reliable parsing
@@ -269,6 +274,55 @@ pub fn get_changed_files(repo: &Repository) -> Result<Vec<String>, GitError> {
     Ok(files)
 }
 
+/// Get files changed since `since`, including uncommitted and untracked files.
+///
+/// Changes are measured from the merge base of `since` and HEAD (like
+/// `git diff <since>...`), so commits that landed on `since` after the current
+/// branch forked are not counted.
+pub fn get_changed_files_since(repo: &Repository, since: &str) -> Result<Vec<String>, GitError> {
+    let since_oid = git2::Oid::from_str(&super::resolve_revision(repo, since)?)?;
+    let base_oid = match repo.head().ok().and_then(|h| h.target()) {
+        Some(head) => repo.merge_base(since_oid, head).unwrap_or(since_oid),
+        None => since_oid,
+    };
+    let base_tree = repo.find_commit(base_oid)?.tree()?;
+
+    let mut opts = git2::DiffOptions::new();
+    opts.include_untracked(true).recurse_untracked_dirs(true);
+    let diff = repo.diff_tree_to_workdir_with_index(Some(&base_tree), Some(&mut opts))?;
+
+    let delta_paths = |diff: &git2::Diff| -> Vec<String> {
+        diff.deltas()
+            .filter_map(|delta| {
+                delta
+                    .new_file()
+                    .path()
+                    .or_else(|| delta.old_file().path())
+                    .map(|p| p.to_string_lossy().to_string())
+            })
+            .collect()
+    };
+
+    // libgit2 reports paths outside a sparse checkout as deleted from the
+    // worktree; compare those against the index instead
+    let skipped = super::sparse::skip_worktree_paths(repo);
+    let mut files: Vec<String> = delta_paths(&diff)
+        .into_iter()
+        .filter(|path| !skipped.contains(path))
+        .collect();
+    if !skipped.is_empty() {
+        let index_diff = repo.diff_tree_to_index(Some(&base_tree), None, None)?;
+        files.extend(
+            delta_paths(&index_diff)
+                .into_iter()
+                .filter(|path| skipped.contains(path)),
+        );
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
 /// Check if there are uncommitted changes
 pub fn has_uncommitted_changes(repo: &Repository) -> Result<bool, GitError> {
     let status = get_status_info(repo)?;
@@ -389,4 +443,32 @@ mod tests {
         assert_eq!(status.staged.len(), 1);
         assert!(status.staged.contains(&"staged.txt".to_string()));
     }
+
+    #[test]
+    fn test_changed_files_since() {
+        let (temp, repo) = setup_test_repo();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(temp.path())
+                .output()
+                .unwrap();
+        };
+
+        fs::write(temp.path().join("README.md"), "# Test").unwrap();
+        git(&["add", "README.md"]);
+        git(&["commit", "-m", "Initial commit"]);
+        git(&["tag", "base"]);
+
+        fs::write(temp.path().join("committed.txt"), "content").unwrap();
+        git(&["add", "committed.txt"]);
+        git(&["commit", "-m", "Add file"]);
+        fs::write(temp.path().join("README.md"), "# Changed").unwrap();
+        fs::write(temp.path().join("untracked.txt"), "content").unwrap();
+
+        let files = get_changed_files_since(&repo, "base").unwrap();
+        assert_eq!(files, vec!["README.md", "committed.txt", "untracked.txt"]);
+
+        assert!(get_changed_files_since(&repo, "no-such-ref").is_err());
+    }
 }
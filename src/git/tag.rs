@@ -0,0 +1,92 @@
+//! Tag operations
+
+use std::path::Path;
+
+use super::{run_git, GitError};
+
+/// Names of all tags in a repo, sorted
+pub fn list_tags(repo_path: &Path) -> Result<Vec<String>, GitError> {
+    let output = run_git(repo_path, &["tag", "--list"])?;
+    let mut tags: Vec<String> = output.lines().map(|l| l.trim().to_string()).collect();
+    tags.retain(|t| !t.is_empty());
+    tags.sort();
+    Ok(tags)
+}
+
+/// Check whether a tag exists
+pub fn tag_exists(repo_path: &Path, name: &str) -> bool {
+    run_git(
+        repo_path,
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/tags/{}", name),
+        ],
+    )
+    .is_ok()
+}
+
+/// Create an annotated (or GPG-signed) tag at `HEAD`
+pub fn create_tag(repo_path: &Path, name: &str, message: &str, sign: bool) -> Result<(), GitError> {
+    let kind = if sign { "-s" } else { "-a" };
+    run_git(repo_path, &["tag", kind, name, "-m", message]).map(|_| ())
+}
+
+/// Delete a local tag
+pub fn delete_tag(repo_path: &Path, name: &str) -> Result<(), GitError> {
+    run_git(repo_path, &["tag", "-d", name]).map(|_| ())
+}
+
+/// Push a tag to a remote
+pub fn push_tag(repo_path: &Path, remote: &str, name: &str) -> Result<(), GitError> {
+    let refspec = format!("refs/tags/{}", name);
+    run_git(repo_path, &["push", remote, &refspec]).map(|_| ())
+}
+
+/// Delete a tag on a remote
+pub fn delete_remote_tag(repo_path: &Path, remote: &str, name: &str) -> Result<(), GitError> {
+    let refspec = format!(":refs/tags/{}", name);
+    run_git(repo_path, &["push", remote, &refspec]).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::init_test_repo;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_tag_lifecycle() {
+        let temp = TempDir::new().unwrap();
+        let remote = temp.path().join("remote.git");
+        let path = temp.path().join("repo");
+        std::fs::create_dir(&path).unwrap();
+        run_git(
+            temp.path(),
+            &["init", "--bare", "-q", remote.to_str().unwrap()],
+        )
+        .unwrap();
+        init_test_repo(&path);
+        run_git(
+            &path,
+            &["remote", "add", "origin", remote.to_str().unwrap()],
+        )
+        .unwrap();
+
+        assert!(list_tags(&path).unwrap().is_empty());
+        create_tag(&path, "qa-1", "QA snapshot", false).unwrap();
+        create_tag(&path, "qa-0", "Older", false).unwrap();
+        assert_eq!(list_tags(&path).unwrap(), ["qa-0", "qa-1"]);
+        assert!(tag_exists(&path, "qa-1"));
+        assert!(create_tag(&path, "qa-1", "again", false).is_err());
+
+        push_tag(&path, "origin", "qa-1").unwrap();
+        assert!(tag_exists(&remote, "qa-1"));
+        delete_remote_tag(&path, "origin", "qa-1").unwrap();
+        assert!(!tag_exists(&remote, "qa-1"));
+
+        delete_tag(&path, "qa-1").unwrap();
+        assert!(!tag_exists(&path, "qa-1"));
+    }
+}
@@ -20,6 +20,28 @@ struct Cli {
     #[arg(long, global = true)]
     json: bool,
 
+    /// Only operate on repos affected by changes since REF (default: each repo's
+    /// default branch). Supported by forall, agent test, ci run and verify
+    #[arg(
+        long,
+        global = true,
+        value_name = "REF",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = ""
+    )]
+    affected: Option<String>,
+
+    /// Git backend for clone, fetch, status and branch listing: git or
+    /// gitoxide (requires a build with the `gitoxide` feature)
+    #[arg(
+        long,
+        global = true,
+        value_name = "BACKEND",
+        env = "GITGRIP_GIT_BACKEND"
+    )]
+    git_backend: Option<String>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -72,6 +94,18 @@ enum Commands {
         /// Skip post-sync hooks
         #[arg(long)]
         no_hooks: bool,
+        /// Check out the exact commits recorded in gripspace.lock
+        #[arg(long)]
+        locked: bool,
+        /// Clone new repos with truncated history (overrides clone.depth)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "unshallow")]
+        depth: Option<u32>,
+        /// Partial clone filter for new repos, e.g. blob:none (overrides clone.filter)
+        #[arg(long)]
+        filter: Option<String>,
+        /// Fetch full history for shallow clones
+        #[arg(long)]
+        unshallow: bool,
     },
     /// Show status of all repositories
     Status {
@@ -125,6 +159,72 @@ enum Commands {
         #[arg(long)]
         staged: bool,
     },
+    /// Create, list or delete tags across repos
+    Tag {
+        /// Tag name (omit to list tags and the repos missing them)
+        name: Option<String>,
+        /// Tag message (default: the tag name)
+        #[arg(short, long, conflicts_with = "delete")]
+        message: Option<String>,
+        /// Create GPG-signed tags
+        #[arg(short, long, conflicts_with = "delete")]
+        sign: bool,
+        /// Delete the tag instead of creating it
+        #[arg(short, long, requires = "name")]
+        delete: bool,
+        /// Also push the tag (or its deletion) to origin
+        #[arg(long, requires = "name")]
+        push: bool,
+        /// Only operate on specific repos
+        #[arg(long, value_delimiter = ',')]
+        repo: Option<Vec<String>>,
+        /// Only operate on repos in these groups
+        #[arg(long, value_delimiter = ',')]
+        group: Option<Vec<String>>,
+    },
+    /// Show every commit of a cross-repo change
+    Show {
+        /// Change ID (Gitgrip-Change-Id trailer), or a prefix of at least 6 characters
+        id: String,
+        /// Show a diffstat instead of the full patch
+        #[arg(long)]
+        stat: bool,
+    },
+    /// Revert every commit of a cross-repo change on the current branches
+    Revert {
+        /// Change ID (Gitgrip-Change-Id trailer), or a prefix of at least 6 characters
+        id: String,
+    },
+    /// Show commits from all repos in one timeline, newest first
+    Log {
+        /// Only commits more recent than this date
+        #[arg(long)]
+        since: Option<String>,
+        /// Only commits older than this date
+        #[arg(long)]
+        until: Option<String>,
+        /// Only commits whose author matches this pattern
+        #[arg(long)]
+        author: Option<String>,
+        /// Only commits whose message matches this pattern
+        #[arg(long)]
+        grep: Option<String>,
+        /// Only commits on the current branch that are not on the default branch
+        #[arg(long)]
+        branch: bool,
+        /// Only commits of this cross-repo change (Gitgrip-Change-Id), on any branch
+        #[arg(long, value_name = "ID")]
+        change: Option<String>,
+        /// Maximum number of commits to show
+        #[arg(short = 'n', long, default_value_t = 50)]
+        max_count: usize,
+        /// Only show specific repos
+        #[arg(long, value_delimiter = ',')]
+        repo: Option<Vec<String>>,
+        /// Only show repos in these groups
+        #[arg(long, value_delimiter = ',')]
+        group: Option<Vec<String>>,
+    },
     /// Commit changes across repos
     Commit {
         /// Commit message
@@ -160,6 +260,11 @@ enum Commands {
         #[command(subcommand)]
         action: PrCommands,
     },
+    /// Issue operations across repos (create, list, view, comment, edit, close, label)
+    Issue {
+        #[command(subcommand)]
+        action: IssueCommands,
+    },
     /// Griptree (worktree) operations
     Tree {
         #[command(subcommand)]
@@ -199,6 +304,9 @@ enum Commands {
         /// Only run in repos in these groups
         #[arg(long, value_delimiter = ',')]
         group: Option<Vec<String>>,
+        /// Run in dependency order (`depends_on`); with --parallel, independent repos run concurrently
+        #[arg(long)]
+        ordered: bool,
     },
     /// Rebase branches across repos
     Rebase {
@@ -257,6 +365,32 @@ enum Commands {
         #[command(subcommand)]
         action: GroupCommands,
     },
+    /// Stash and restore changes across all repos at once (push, list, pop, drop)
+    Stash {
+        #[command(subcommand)]
+        action: StashCommands,
+    },
+    /// Bisect across the combined history of all repos (start, good, bad, skip, run, reset)
+    Bisect {
+        #[command(subcommand)]
+        action: BisectCommands,
+    },
+    /// Undo the last operations, restoring refs and HEADs in every repo
+    Undo {
+        /// Number of operations to undo
+        #[arg(default_value_t = 1)]
+        count: usize,
+    },
+    /// Operation log of ref-changing commands (log)
+    Op {
+        #[command(subcommand)]
+        action: OpCommands,
+    },
+    /// Shared object mirrors in the user-level cache (sync, list)
+    Mirror {
+        #[command(subcommand)]
+        action: MirrorCommands,
+    },
     /// Run garbage collection across repos
     Gc {
         /// More thorough gc (slower)
@@ -295,7 +429,7 @@ enum Commands {
         #[command(subcommand)]
         action: CiCommands,
     },
-    /// Manifest operations (import, sync)
+    /// Manifest operations (import, export, sync, lock, diff, fmt)
     Manifest {
         #[command(subcommand)]
         action: ManifestCommands,
@@ -331,6 +465,12 @@ enum Commands {
         #[arg(value_enum)]
         shell: Shell,
     },
+    /// List repos changed since a ref plus the repos that depend on them
+    Affected {
+        /// Ref to compare against (default: each repo's default branch)
+        #[arg(long, value_name = "REF")]
+        since: Option<String>,
+    },
     /// Verify workspace assertions (exit 0 = pass, 1 = fail)
     Verify {
         /// All repos are clean (no uncommitted changes)
@@ -345,6 +485,9 @@ enum Commands {
         /// All repos are synced with remote (not ahead/behind)
         #[arg(long)]
         synced: bool,
+        /// All repos and gripspaces match gripspace.lock
+        #[arg(long)]
+        locked: bool,
         /// Only verify repos in these groups
         #[arg(long, value_delimiter = ',')]
         group: Option<Vec<String>>,
@@ -398,6 +541,21 @@ enum PrCommands {
         /// Create as draft
         #[arg(long)]
         draft: bool,
+        /// Request reviews from these users (added to settings.pr)
+        #[arg(long = "reviewer", value_delimiter = ',')]
+        reviewers: Vec<String>,
+        /// Request reviews from these teams
+        #[arg(long = "team-reviewer", value_delimiter = ',')]
+        team_reviewers: Vec<String>,
+        /// Labels to add
+        #[arg(short, long = "label", value_delimiter = ',')]
+        labels: Vec<String>,
+        /// Users to assign
+        #[arg(long = "assignee", value_delimiter = ',')]
+        assignees: Vec<String>,
+        /// Milestone title (overrides settings.pr)
+        #[arg(long)]
+        milestone: Option<String>,
         /// Preview without creating PR
         #[arg(long)]
         dry_run: bool,
@@ -433,6 +591,122 @@ enum PrCommands {
         #[arg(long)]
         stat: bool,
     },
+    /// Show comments and review threads across linked PRs
+    Comments {
+        /// Only show unresolved review threads
+        #[arg(long)]
+        unresolved: bool,
+    },
+    /// Comment on every linked PR
+    Comment {
+        /// Comment text
+        #[arg(short, long)]
+        message: String,
+        /// Only comment on these repos' PRs
+        #[arg(long, value_delimiter = ',')]
+        repo: Option<Vec<String>>,
+    },
+}
+
+#[derive(Subcommand)]
+enum IssueCommands {
+    /// Open an issue in each selected repo
+    Create {
+        /// Issue title
+        #[arg(short, long)]
+        title: String,
+        /// Issue body
+        #[arg(short, long)]
+        body: Option<String>,
+        /// Labels to add
+        #[arg(short, long, value_delimiter = ',')]
+        label: Vec<String>,
+        /// Repos to open the issue in
+        #[arg(long, value_delimiter = ',')]
+        repo: Option<Vec<String>>,
+        /// Open the issue in every repo of these groups
+        #[arg(long, value_delimiter = ',')]
+        group: Option<Vec<String>>,
+        /// Link the issues as one cross-repo tracking issue
+        #[arg(long)]
+        tracking: bool,
+    },
+    /// List issues across repos
+    List {
+        /// Issue state (open, closed)
+        #[arg(long, default_value = "open", value_parser = ["open", "closed"])]
+        state: String,
+        /// Only issues with all of these labels
+        #[arg(short, long, value_delimiter = ',')]
+        label: Vec<String>,
+        /// Only these repos
+        #[arg(long, value_delimiter = ',')]
+        repo: Option<Vec<String>>,
+        /// Only repos in these groups
+        #[arg(long, value_delimiter = ',')]
+        group: Option<Vec<String>>,
+    },
+    /// Show an issue
+    View {
+        /// Issue number
+        number: u64,
+        /// Repo the issue belongs to
+        #[arg(long)]
+        repo: String,
+    },
+    /// Comment on an issue
+    Comment {
+        /// Issue number
+        number: u64,
+        /// Repo the issue belongs to
+        #[arg(long)]
+        repo: String,
+        /// Comment text
+        #[arg(short, long)]
+        message: String,
+        /// Also comment on every issue linked to it
+        #[arg(long)]
+        linked: bool,
+    },
+    /// Edit an issue's title or body
+    Edit {
+        /// Issue number
+        number: u64,
+        /// Repo the issue belongs to
+        #[arg(long)]
+        repo: String,
+        /// New title
+        #[arg(short, long)]
+        title: Option<String>,
+        /// New body (tracking links are kept)
+        #[arg(short, long)]
+        body: Option<String>,
+    },
+    /// Close an issue
+    Close {
+        /// Issue number
+        number: u64,
+        /// Repo the issue belongs to
+        #[arg(long)]
+        repo: String,
+        /// Comment to leave when closing
+        #[arg(short, long)]
+        message: Option<String>,
+        /// Also close every issue linked to it
+        #[arg(long)]
+        linked: bool,
+    },
+    /// Add labels to an issue
+    Label {
+        /// Issue number
+        number: u64,
+        /// Repo the issue belongs to
+        #[arg(long)]
+        repo: String,
+        /// Labels to add
+        #[arg(required = true)]
+        labels: Vec<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -518,6 +792,71 @@ enum GroupCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum StashCommands {
+    /// Stash every dirty repo under one workspace stash
+    Push {
+        /// Stash message
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+    /// List workspace stashes, newest first
+    List,
+    /// Restore a workspace stash in all its repos (default: the most recent)
+    Pop {
+        /// Stash name (e.g. stash-1)
+        name: Option<String>,
+    },
+    /// Discard a workspace stash (default: the most recent)
+    Drop {
+        /// Stash name (e.g. stash-1)
+        name: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum BisectCommands {
+    /// Start bisecting between two workspace points (dates or manifest repo commits)
+    Start {
+        /// Point where the problem exists
+        bad: String,
+        /// Older point where it did not
+        good: String,
+    },
+    /// Mark the checked out state as good
+    Good,
+    /// Mark the checked out state as bad
+    Bad,
+    /// Skip the checked out state (it cannot be tested)
+    Skip,
+    /// Test each state with a command (exit 0 good, 125 skip, 1-127 bad)
+    Run {
+        /// Command to run in the workspace root
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
+    },
+    /// End the bisect and return every repo to its original branch
+    Reset,
+}
+
+#[derive(Subcommand)]
+enum OpCommands {
+    /// List recorded operations, newest first
+    Log,
+}
+
+#[derive(Subcommand)]
+enum MirrorCommands {
+    /// Create or refresh the mirror of every manifest repo
+    Sync {
+        /// Only mirror repos in these groups
+        #[arg(long, value_delimiter = ',')]
+        group: Option<Vec<String>>,
+    },
+    /// List mirrors and whether workspace clones share their objects
+    List,
+}
+
 #[derive(Subcommand)]
 enum CiCommands {
     /// Run a CI pipeline
@@ -533,16 +872,40 @@ enum CiCommands {
 
 #[derive(Subcommand)]
 enum ManifestCommands {
-    /// Convert git-repo XML manifest to gitgrip YAML
+    /// Convert a git-repo XML, .gitmodules, vcstool or meta workspace to gitgrip YAML
     Import {
-        /// Path to XML manifest (e.g., .repo/manifests/default.xml)
+        /// Path to workspace file (e.g., .repo/manifests/default.xml, .gitmodules, ros2.repos, .meta)
         path: String,
         /// Output path for YAML manifest
         #[arg(short, long)]
         output: Option<String>,
     },
+    /// Export the workspace manifest (e.g., as git-repo XML)
+    Export {
+        /// Output format (repo-xml)
+        #[arg(long, default_value = "repo-xml")]
+        format: String,
+        /// Output path (default: stdout)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
     /// Re-sync gitgrip YAML from .repo/ manifest after repo sync
     Sync,
+    /// Write gripspace.lock with the current commit of every repo and gripspace
+    Lock,
+    /// Compare two versions of the manifest (revisions in the manifest repo or files)
+    Diff {
+        /// Old version: manifest repo revision or file path (default: HEAD)
+        rev_a: Option<String>,
+        /// New version: manifest repo revision or file path (default: working copy)
+        rev_b: Option<String>,
+    },
+    /// Normalize manifest key order and indentation, keeping comments
+    Fmt {
+        /// Fail if the manifest is not formatted instead of rewriting it
+        #[arg(long)]
+        check: bool,
+    },
     /// Show manifest schema specification
     Schema {
         /// Output format (yaml, json, markdown)
@@ -596,6 +959,15 @@ async fn main() -> anyhow::Result<()> {
     let cli_quiet = cli.quiet;
     let cli_verbose = cli.verbose;
     let cli_json = cli.json;
+    let cli_affected = cli.affected.clone();
+
+    if let Some(name) = cli.git_backend.as_deref().filter(|n| !n.trim().is_empty()) {
+        gitgrip::git::backend::init_backend(name.parse()?)?;
+    }
+
+    if cli_affected.is_some() && !supports_affected_filter(&cli.command) {
+        anyhow::bail!("--affected is only supported by forall, agent test, ci run and verify");
+    }
 
     match cli.command {
         Some(Commands::Status { verbose, group }) => {
@@ -615,18 +987,29 @@ async fn main() -> anyhow::Result<()> {
             group,
             sequential,
             no_hooks,
+            locked,
+            depth,
+            filter,
+            unshallow,
         }) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
-            gitgrip::cli::commands::sync::run_sync(
-                &ctx.workspace_root,
-                &ctx.manifest,
-                force,
-                ctx.quiet,
-                group.as_deref(),
-                sequential,
-                reset_refs,
-                ctx.json,
-                no_hooks,
+            recorded_async(
+                &ctx,
+                gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+                    workspace_root: &ctx.workspace_root,
+                    manifest: &ctx.manifest,
+                    force,
+                    quiet: ctx.quiet,
+                    group_filter: group.as_deref(),
+                    sequential,
+                    reset_refs,
+                    json: ctx.json,
+                    no_hooks,
+                    locked,
+                    depth,
+                    filter: filter.as_deref(),
+                    unshallow,
+                }),
             )
             .await?;
         }
@@ -639,18 +1022,20 @@ async fn main() -> anyhow::Result<()> {
             group,
         }) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
-            gitgrip::cli::commands::branch::run_branch(
-                gitgrip::cli::commands::branch::BranchOptions {
-                    workspace_root: &ctx.workspace_root,
-                    manifest: &ctx.manifest,
-                    name: name.as_deref(),
-                    delete,
-                    move_commits: r#move,
-                    repos_filter: repo.as_deref(),
-                    group_filter: group.as_deref(),
-                    json: ctx.json,
-                },
-            )?;
+            recorded(&ctx, || {
+                gitgrip::cli::commands::branch::run_branch(
+                    gitgrip::cli::commands::branch::BranchOptions {
+                        workspace_root: &ctx.workspace_root,
+                        manifest: &ctx.manifest,
+                        name: name.as_deref(),
+                        delete,
+                        move_commits: r#move,
+                        repos_filter: repo.as_deref(),
+                        group_filter: group.as_deref(),
+                        json: ctx.json,
+                    },
+                )
+            })?;
         }
         Some(Commands::Checkout { name, create, base }) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
@@ -664,12 +1049,14 @@ async fn main() -> anyhow::Result<()> {
                 name.ok_or_else(|| anyhow::anyhow!("Branch name is required"))?
             };
 
-            gitgrip::cli::commands::checkout::run_checkout(
-                &ctx.workspace_root,
-                &ctx.manifest,
-                &branch,
-                create,
-            )?;
+            recorded(&ctx, || {
+                gitgrip::cli::commands::checkout::run_checkout(
+                    &ctx.workspace_root,
+                    &ctx.manifest,
+                    &branch,
+                    create,
+                )
+            })?;
         }
         Some(Commands::Add { files }) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
@@ -690,13 +1077,15 @@ async fn main() -> anyhow::Result<()> {
                 eprintln!("Error: commit message required (-m)");
                 std::process::exit(1);
             });
-            gitgrip::cli::commands::commit::run_commit(
-                &ctx.workspace_root,
-                &ctx.manifest,
-                &msg,
-                amend,
-                ctx.json,
-            )?;
+            recorded(&ctx, || {
+                gitgrip::cli::commands::commit::run_commit(
+                    &ctx.workspace_root,
+                    &ctx.manifest,
+                    &msg,
+                    amend,
+                    ctx.json,
+                )
+            })?;
         }
         Some(Commands::Push {
             set_upstream,
@@ -718,13 +1107,135 @@ async fn main() -> anyhow::Result<()> {
             group,
         }) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
-            gitgrip::cli::commands::prune::run_prune(
-                &ctx.workspace_root,
-                &ctx.manifest,
-                execute,
-                remote,
-                group.as_deref(),
-            )?;
+            recorded(&ctx, || {
+                gitgrip::cli::commands::prune::run_prune(
+                    &ctx.workspace_root,
+                    &ctx.manifest,
+                    execute,
+                    remote,
+                    group.as_deref(),
+                )
+            })?;
+        }
+        Some(Commands::Issue { action }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            match action {
+                IssueCommands::Create {
+                    title,
+                    body,
+                    label,
+                    repo,
+                    group,
+                    tracking,
+                } => {
+                    gitgrip::cli::commands::issue::run_issue_create(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &title,
+                        body.as_deref(),
+                        &label,
+                        repo.as_deref(),
+                        group.as_deref(),
+                        tracking,
+                        ctx.json,
+                    )
+                    .await?;
+                }
+                IssueCommands::List {
+                    state,
+                    label,
+                    repo,
+                    group,
+                } => {
+                    let state = if state == "closed" {
+                        gitgrip::platform::IssueState::Closed
+                    } else {
+                        gitgrip::platform::IssueState::Open
+                    };
+                    gitgrip::cli::commands::issue::run_issue_list(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        state,
+                        &label,
+                        repo.as_deref(),
+                        group.as_deref(),
+                        ctx.json,
+                    )
+                    .await?;
+                }
+                IssueCommands::View { number, repo } => {
+                    gitgrip::cli::commands::issue::run_issue_view(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &repo,
+                        number,
+                        ctx.json,
+                    )
+                    .await?;
+                }
+                IssueCommands::Comment {
+                    number,
+                    repo,
+                    message,
+                    linked,
+                } => {
+                    gitgrip::cli::commands::issue::run_issue_comment(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &repo,
+                        number,
+                        &message,
+                        linked,
+                    )
+                    .await?;
+                }
+                IssueCommands::Edit {
+                    number,
+                    repo,
+                    title,
+                    body,
+                } => {
+                    gitgrip::cli::commands::issue::run_issue_edit(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &repo,
+                        number,
+                        title.as_deref(),
+                        body.as_deref(),
+                    )
+                    .await?;
+                }
+                IssueCommands::Close {
+                    number,
+                    repo,
+                    message,
+                    linked,
+                } => {
+                    gitgrip::cli::commands::issue::run_issue_close(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &repo,
+                        number,
+                        message.as_deref(),
+                        linked,
+                    )
+                    .await?;
+                }
+                IssueCommands::Label {
+                    number,
+                    repo,
+                    labels,
+                } => {
+                    gitgrip::cli::commands::issue::run_issue_label(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &repo,
+                        number,
+                        &labels,
+                    )
+                    .await?;
+                }
+            }
         }
         Some(Commands::Pr { action }) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
@@ -734,13 +1245,27 @@ async fn main() -> anyhow::Result<()> {
                     body,
                     push,
                     draft,
+                    reviewers,
+                    team_reviewers,
+                    labels,
+                    assignees,
+                    milestone,
                     dry_run,
                 } => {
+                    let non_empty = |names: Vec<String>| (!names.is_empty()).then_some(names);
+                    let metadata = gitgrip::core::manifest::PrDefaults {
+                        reviewers: non_empty(reviewers),
+                        team_reviewers: non_empty(team_reviewers),
+                        labels: non_empty(labels),
+                        assignees: non_empty(assignees),
+                        milestone,
+                    };
                     gitgrip::cli::commands::pr::run_pr_create(
                         &ctx.workspace_root,
                         &ctx.manifest,
                         title.as_deref(),
                         body.as_deref(),
+                        &metadata,
                         draft,
                         push,
                         dry_run,
@@ -793,6 +1318,24 @@ async fn main() -> anyhow::Result<()> {
                     )
                     .await?;
                 }
+                PrCommands::Comments { unresolved } => {
+                    gitgrip::cli::commands::pr::run_pr_comments(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        unresolved,
+                        ctx.json,
+                    )
+                    .await?;
+                }
+                PrCommands::Comment { message, repo } => {
+                    gitgrip::cli::commands::pr::run_pr_comment(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &message,
+                        repo.as_deref(),
+                    )
+                    .await?;
+                }
             }
         }
         Some(Commands::Init {
@@ -873,6 +1416,101 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
         }
+        Some(Commands::Tag {
+            name,
+            message,
+            sign,
+            delete,
+            push,
+            repo,
+            group,
+        }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            match name {
+                Some(name) if delete => {
+                    gitgrip::cli::commands::tag::run_tag_delete(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &name,
+                        push,
+                        repo.as_deref(),
+                        group.as_deref(),
+                    )?;
+                }
+                Some(name) => {
+                    gitgrip::cli::commands::tag::run_tag_create(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &name,
+                        message.as_deref(),
+                        sign,
+                        push,
+                        repo.as_deref(),
+                        group.as_deref(),
+                    )?;
+                }
+                None => {
+                    gitgrip::cli::commands::tag::run_tag_list(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        repo.as_deref(),
+                        group.as_deref(),
+                        ctx.json,
+                    )?;
+                }
+            }
+        }
+        Some(Commands::Show { id, stat }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            gitgrip::cli::commands::change::run_show(
+                &ctx.workspace_root,
+                &ctx.manifest,
+                &id,
+                stat,
+                ctx.json,
+            )?;
+        }
+        Some(Commands::Revert { id }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            gitgrip::cli::commands::change::run_revert(&ctx.workspace_root, &ctx.manifest, &id)?;
+        }
+        Some(Commands::Log {
+            since,
+            until,
+            author,
+            grep,
+            branch,
+            change,
+            max_count,
+            repo,
+            group,
+        }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            if let Some(id) = change.as_deref() {
+                if !gitgrip::git::change::is_valid_change_id(id) {
+                    anyhow::bail!("Invalid change ID '{}'", id);
+                }
+            }
+            let options = gitgrip::cli::commands::log::LogOptions {
+                filter: gitgrip::git::log::LogFilter {
+                    since: since.as_deref(),
+                    until: until.as_deref(),
+                    author: author.as_deref(),
+                    grep: grep.as_deref(),
+                    change_id: change.as_deref(),
+                    max_count: Some(max_count),
+                },
+                branch_only: branch,
+            };
+            gitgrip::cli::commands::log::run_log(
+                &ctx.workspace_root,
+                &ctx.manifest,
+                &options,
+                repo.as_deref(),
+                group.as_deref(),
+                ctx.json,
+            )?;
+        }
         Some(Commands::Grep {
             pattern,
             ignore_case,
@@ -897,8 +1535,10 @@ async fn main() -> anyhow::Result<()> {
             all,
             no_intercept,
             group,
+            ordered,
         }) => {
-            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            let mut ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            apply_affected_filter(&mut ctx, cli_affected.as_deref());
             gitgrip::cli::commands::forall::run_forall(
                 &ctx.workspace_root,
                 &ctx.manifest,
@@ -907,6 +1547,7 @@ async fn main() -> anyhow::Result<()> {
                 !all, // Default: only repos with changes (changed_only=true unless --all)
                 no_intercept,
                 group.as_deref(),
+                ordered,
             )?;
         }
         Some(Commands::Rebase {
@@ -916,14 +1557,16 @@ async fn main() -> anyhow::Result<()> {
             continue_rebase,
         }) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
-            gitgrip::cli::commands::rebase::run_rebase(
-                &ctx.workspace_root,
-                &ctx.manifest,
-                onto.as_deref(),
-                upstream,
-                abort,
-                continue_rebase,
-            )?;
+            recorded(&ctx, || {
+                gitgrip::cli::commands::rebase::run_rebase(
+                    &ctx.workspace_root,
+                    &ctx.manifest,
+                    onto.as_deref(),
+                    upstream,
+                    abort,
+                    continue_rebase,
+                )
+            })?;
         }
         Some(Commands::Pull {
             rebase,
@@ -931,13 +1574,16 @@ async fn main() -> anyhow::Result<()> {
             sequential,
         }) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
-            gitgrip::cli::commands::pull::run_pull(
-                &ctx.workspace_root,
-                &ctx.manifest,
-                rebase,
-                group.as_deref(),
-                sequential,
-                ctx.quiet,
+            recorded_async(
+                &ctx,
+                gitgrip::cli::commands::pull::run_pull(
+                    &ctx.workspace_root,
+                    &ctx.manifest,
+                    rebase,
+                    group.as_deref(),
+                    sequential,
+                    ctx.quiet,
+                ),
             )
             .await?;
         }
@@ -960,6 +1606,16 @@ async fn main() -> anyhow::Result<()> {
                 list,
             )?;
         }
+        Some(Commands::Affected { since }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            gitgrip::cli::commands::affected::run_affected(
+                &ctx.workspace_root,
+                &ctx.manifest,
+                since.as_deref(),
+                ctx.json,
+                ctx.quiet,
+            )?;
+        }
         Some(Commands::Env) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
             gitgrip::cli::commands::env::run_env(&ctx.workspace_root, &ctx.manifest)?;
@@ -1018,6 +1674,102 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
         }
+        Some(Commands::Stash { action }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            match action {
+                StashCommands::Push { message } => {
+                    gitgrip::cli::commands::stash::run_stash_push(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        message.as_deref(),
+                    )?;
+                }
+                StashCommands::List => {
+                    gitgrip::cli::commands::stash::run_stash_list(&ctx.workspace_root, ctx.json)?;
+                }
+                StashCommands::Pop { name } => {
+                    gitgrip::cli::commands::stash::run_stash_pop(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        name.as_deref(),
+                    )?;
+                }
+                StashCommands::Drop { name } => {
+                    gitgrip::cli::commands::stash::run_stash_drop(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        name.as_deref(),
+                    )?;
+                }
+            }
+        }
+        Some(Commands::Bisect { action }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            let mark = |verdict| {
+                gitgrip::cli::commands::bisect::run_bisect_mark(
+                    &ctx.workspace_root,
+                    &ctx.manifest,
+                    verdict,
+                )
+            };
+            match action {
+                BisectCommands::Start { bad, good } => {
+                    gitgrip::cli::commands::bisect::run_bisect_start(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &bad,
+                        &good,
+                    )?;
+                }
+                BisectCommands::Good => mark(gitgrip::core::bisect::BisectVerdict::Good)?,
+                BisectCommands::Bad => mark(gitgrip::core::bisect::BisectVerdict::Bad)?,
+                BisectCommands::Skip => mark(gitgrip::core::bisect::BisectVerdict::Skip)?,
+                BisectCommands::Run { command } => {
+                    gitgrip::cli::commands::bisect::run_bisect_run(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        &command,
+                    )?;
+                }
+                BisectCommands::Reset => {
+                    gitgrip::cli::commands::bisect::run_bisect_reset(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                    )?;
+                }
+            }
+        }
+        Some(Commands::Undo { count }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            gitgrip::cli::commands::op::run_undo(&ctx.workspace_root, &ctx.manifest, count)?;
+        }
+        Some(Commands::Op { action }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            match action {
+                OpCommands::Log => {
+                    gitgrip::cli::commands::op::run_op_log(&ctx.workspace_root, ctx.json)?;
+                }
+            }
+        }
+        Some(Commands::Mirror { action }) => {
+            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            match action {
+                MirrorCommands::Sync { group } => {
+                    gitgrip::cli::commands::mirror::run_mirror_sync(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                        group.as_deref(),
+                        ctx.quiet,
+                    )?;
+                }
+                MirrorCommands::List => {
+                    gitgrip::cli::commands::mirror::run_mirror_list(
+                        &ctx.workspace_root,
+                        &ctx.manifest,
+                    )?;
+                }
+            }
+        }
         Some(Commands::Gc {
             aggressive,
             dry_run,
@@ -1042,24 +1794,28 @@ async fn main() -> anyhow::Result<()> {
             group,
         }) => {
             let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
-            gitgrip::cli::commands::cherry_pick::run_cherry_pick(
-                &ctx.workspace_root,
-                &ctx.manifest,
-                commit.as_deref(),
-                abort,
-                continue_pick,
-                repo.as_deref(),
-                group.as_deref(),
-            )?;
+            recorded(&ctx, || {
+                gitgrip::cli::commands::cherry_pick::run_cherry_pick(
+                    &ctx.workspace_root,
+                    &ctx.manifest,
+                    commit.as_deref(),
+                    abort,
+                    continue_pick,
+                    repo.as_deref(),
+                    group.as_deref(),
+                )
+            })?;
         }
         Some(Commands::Ci { action }) => {
-            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            let mut ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            apply_affected_filter(&mut ctx, cli_affected.as_deref());
             match action {
                 CiCommands::Run { name } => {
                     gitgrip::cli::commands::ci::run_ci_run(
                         &ctx.workspace_root,
                         &ctx.manifest,
                         &name,
+                        cli_affected.is_some(),
                         ctx.json,
                     )?;
                 }
@@ -1075,10 +1831,45 @@ async fn main() -> anyhow::Result<()> {
             ManifestCommands::Import { path, output } => {
                 gitgrip::cli::commands::manifest::run_manifest_import(&path, output.as_deref())?;
             }
+            ManifestCommands::Export { format, output } => {
+                let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+                gitgrip::cli::commands::manifest::run_manifest_export(
+                    &ctx.workspace_root,
+                    &ctx.manifest,
+                    &format,
+                    output.as_deref(),
+                )?;
+            }
             ManifestCommands::Sync => {
                 let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
                 gitgrip::cli::commands::manifest::run_manifest_sync(&ctx.workspace_root)?;
             }
+            ManifestCommands::Lock => {
+                let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+                gitgrip::cli::commands::manifest::run_manifest_lock(
+                    &ctx.workspace_root,
+                    &ctx.manifest,
+                    ctx.quiet,
+                    ctx.json,
+                )?;
+            }
+            ManifestCommands::Diff { rev_a, rev_b } => {
+                let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+                gitgrip::cli::commands::manifest::run_manifest_diff(
+                    &ctx.workspace_root,
+                    rev_a.as_deref(),
+                    rev_b.as_deref(),
+                    ctx.json,
+                )?;
+            }
+            ManifestCommands::Fmt { check } => {
+                let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+                gitgrip::cli::commands::manifest::run_manifest_fmt(
+                    &ctx.workspace_root,
+                    check,
+                    ctx.quiet,
+                )?;
+            }
             ManifestCommands::Schema { format } => {
                 gitgrip::cli::commands::manifest::run_manifest_schema(&format)?;
             }
@@ -1087,7 +1878,8 @@ async fn main() -> anyhow::Result<()> {
             gitgrip::cli::commands::bench::run(args).await?;
         }
         Some(Commands::Agent { action }) => {
-            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            let mut ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            apply_affected_filter(&mut ctx, cli_affected.as_deref());
             match action {
                 AgentCommands::Context { repo } => {
                     gitgrip::cli::commands::agent::run_agent_context(
@@ -1162,9 +1954,11 @@ async fn main() -> anyhow::Result<()> {
             links,
             on_branch,
             synced,
+            locked,
             group,
         }) => {
-            let ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            let mut ctx = load_workspace_context(cli_quiet, cli_verbose, cli_json)?;
+            apply_affected_filter(&mut ctx, cli_affected.as_deref());
             gitgrip::cli::commands::verify::run_verify(
                 gitgrip::cli::commands::verify::VerifyOptions {
                     workspace_root: &ctx.workspace_root,
@@ -1176,6 +1970,7 @@ async fn main() -> anyhow::Result<()> {
                     links,
                     on_branch: on_branch.as_deref(),
                     synced,
+                    locked,
                 },
             )?;
         }
@@ -1228,6 +2023,79 @@ fn load_gripspace() -> anyhow::Result<(std::path::PathBuf, gitgrip::core::manife
     load_from_workspace(&current)
 }
 
+/// Commands that honor the global `--affected` filter
+fn supports_affected_filter(command: &Option<Commands>) -> bool {
+    matches!(
+        command,
+        Some(Commands::Forall { .. })
+            | Some(Commands::Verify { .. })
+            | Some(Commands::Agent {
+                action: AgentCommands::Test { .. }
+            })
+            | Some(Commands::Ci {
+                action: CiCommands::Run { .. }
+            })
+    )
+}
+
+/// Restrict the workspace manifest to repos affected since `since` (`--affected`).
+///
+/// An empty `since` compares each repo against its default branch.
+fn apply_affected_filter(ctx: &mut gitgrip::cli::context::WorkspaceContext, since: Option<&str>) {
+    let Some(since) = since else {
+        return;
+    };
+    let since = (!since.is_empty()).then_some(since);
+    let affected = gitgrip::cli::commands::affected::affected_repo_names(
+        &ctx.workspace_root,
+        &ctx.manifest,
+        since,
+    );
+    ctx.manifest.repos.retain(|name, _| affected.contains(name));
+}
+
+/// Run a mutating command, recording the refs it moves in the operation log
+fn recorded<T>(ctx: &gitgrip::cli::context::WorkspaceContext, f: impl FnOnce() -> T) -> T {
+    let op = gitgrip::cli::commands::op::PendingOperation::begin(
+        &ctx.workspace_root,
+        &ctx.manifest,
+        &command_line(),
+    );
+    let result = f();
+    op.finish();
+    result
+}
+
+/// [`recorded`] for async commands
+async fn recorded_async<T>(
+    ctx: &gitgrip::cli::context::WorkspaceContext,
+    command: impl std::future::Future<Output = T>,
+) -> T {
+    let op = gitgrip::cli::commands::op::PendingOperation::begin(
+        &ctx.workspace_root,
+        &ctx.manifest,
+        &command_line(),
+    );
+    let result = command.await;
+    op.finish();
+    result
+}
+
+/// The command line without the leading `gr`, as recorded in the operation log
+fn command_line() -> String {
+    std::env::args()
+        .skip(1)
+        .map(|arg| {
+            if arg.contains(char::is_whitespace) {
+                format!("{:?}", arg)
+            } else {
+                arg
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Load the gripspace manifest and return a WorkspaceContext with global CLI flags.
 fn load_workspace_context(
     quiet: bool,
@@ -75,6 +75,118 @@ struct AzurePullRequestWithReviewers {
     reviewers: Option<Vec<AzureReviewer>>,
 }
 
+/// Azure DevOps pull request comment thread
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureThread {
+    status: Option<String>, // active, pending, fixed, wontFix, closed, byDesign
+    thread_context: Option<AzureThreadContext>,
+    #[serde(default)]
+    comments: Vec<AzureComment>,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureThreadContext {
+    file_path: Option<String>,
+    right_file_start: Option<AzureFilePosition>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureFilePosition {
+    line: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureComment {
+    author: Option<AzureIdentity>,
+    content: Option<String>,
+    published_date: Option<String>,
+    comment_type: Option<String>, // text, codeChange, system
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureIdentity {
+    display_name: Option<String>,
+}
+
+impl AzureThread {
+    /// Non-deleted comments written by people
+    fn text_comments(self) -> Vec<PRComment> {
+        self.comments
+            .into_iter()
+            .filter(|c| !c.is_deleted && c.comment_type.as_deref() != Some("system"))
+            .map(|c| PRComment {
+                author: c.author.and_then(|a| a.display_name).unwrap_or_default(),
+                body: c.content.unwrap_or_default(),
+                created_at: c.published_date,
+            })
+            .collect()
+    }
+}
+
+/// Azure Boards work item (issues are work items of type "Issue")
+#[derive(Debug, Deserialize)]
+struct AzureWorkItem {
+    id: u64,
+    fields: AzureWorkItemFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureWorkItemFields {
+    #[serde(rename = "System.Title")]
+    title: String,
+    #[serde(rename = "System.Description")]
+    description: Option<String>,
+    #[serde(rename = "System.State")]
+    state: String,
+    #[serde(rename = "System.WorkItemType")]
+    work_item_type: String,
+    #[serde(rename = "System.Tags")]
+    tags: Option<String>,
+}
+
+impl AzureWorkItemFields {
+    /// Tags are stored as one "a; b" string
+    fn tag_list(&self) -> Vec<String> {
+        self.tags
+            .as_deref()
+            .unwrap_or("")
+            .split(';')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect()
+    }
+}
+
+/// Work item state with its category (Proposed, InProgress, Resolved, Completed, Removed)
+#[derive(Debug, Deserialize)]
+struct AzureWorkItemState {
+    name: String,
+    category: String,
+}
+
+/// WIQL query result
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureWiqlResult {
+    work_items: Vec<AzureWorkItemRef>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureWorkItemRef {
+    id: u64,
+}
+
+/// Work item type used for issues
+const ISSUE_WORK_ITEM_TYPE: &str = "Issue";
+
 /// Azure DevOps API adapter
 pub struct AzureDevOpsAdapter {
     base_url: String,
@@ -200,6 +312,219 @@ impl AzureDevOpsAdapter {
         Ok(())
     }
 
+    /// ID of the user or group identity matching `name` (account name,
+    /// email or `[Project]\\Team`)
+    async fn identity_id(&self, ctx: &AzureContext, name: &str) -> Result<String, PlatformError> {
+        let token = self.get_token().await?;
+        // Identities live on the organization's vssps host in Azure DevOps Services
+        let host = if self.base_url == "https://dev.azure.com" {
+            "https://vssps.dev.azure.com"
+        } else {
+            self.base_url.as_str()
+        };
+        let url = format!(
+            "{}/{}/_apis/identities?searchFilter=General&filterValue={}&queryMembership=None&api-version=7.0",
+            host,
+            ctx.organization,
+            urlencoding::encode(name)
+        );
+
+        let auth = STANDARD.encode(format!(":{}", token));
+        let response = self
+            .http_client
+            .get(&url)
+            .header("Authorization", format!("Basic {}", auth))
+            .send()
+            .await
+            .map_err(|e| PlatformError::NetworkError(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(PlatformError::ApiError(format!(
+                "Azure DevOps API error ({}): {}",
+                status, error_text
+            )));
+        }
+
+        #[derive(Deserialize)]
+        struct Identity {
+            id: String,
+        }
+
+        let identities: ListResponse<Identity> = response
+            .json()
+            .await
+            .map_err(|e| PlatformError::ParseError(e.to_string()))?;
+        identities
+            .value
+            .into_iter()
+            .next()
+            .map(|i| i.id)
+            .ok_or_else(|| PlatformError::NotFound(format!("Azure DevOps identity '{}'", name)))
+    }
+
+    /// Non-deleted comment threads on a pull request
+    async fn get_threads(
+        &self,
+        ctx: &AzureContext,
+        pull_number: u64,
+    ) -> Result<Vec<AzureThread>, PlatformError> {
+        let threads: ListResponse<AzureThread> = self
+            .api_request(
+                reqwest::Method::GET,
+                ctx,
+                &format!(
+                    "/git/repositories/{}/pullRequests/{}/threads",
+                    ctx.repository, pull_number
+                ),
+                None::<()>,
+            )
+            .await?;
+        Ok(threads
+            .value
+            .into_iter()
+            .filter(|t| !t.is_deleted)
+            .collect())
+    }
+
+    /// Make a work item tracking request
+    ///
+    /// Unlike `api_request`, the endpoint may carry a query string and the
+    /// caller picks the API version and content type (work item updates are
+    /// JSON Patch documents).
+    async fn wit_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: reqwest::Method,
+        ctx: &AzureContext,
+        endpoint: &str,
+        api_version: &str,
+        content_type: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<T, PlatformError> {
+        let token = self.get_token().await?;
+        let separator = if endpoint.contains('?') { '&' } else { '?' };
+        let url = format!(
+            "{}/{}/{}/_apis/wit{}{}api-version={}",
+            self.base_url, ctx.organization, ctx.project, endpoint, separator, api_version
+        );
+
+        let auth = STANDARD.encode(format!(":{}", token));
+
+        let mut request = self
+            .http_client
+            .request(method, &url)
+            .header("Authorization", format!("Basic {}", auth))
+            .header("Content-Type", content_type);
+
+        if let Some(b) = body {
+            request = request.body(b.to_string());
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| PlatformError::NetworkError(e.to_string()))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(PlatformError::NotFound(format!("work item {}", endpoint)));
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(PlatformError::ApiError(format!(
+                "Azure DevOps API error ({}): {}",
+                status, error_text
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| PlatformError::ParseError(e.to_string()))
+    }
+
+    /// Apply a JSON Patch document to a work item
+    async fn patch_work_item(
+        &self,
+        ctx: &AzureContext,
+        id: u64,
+        operations: serde_json::Value,
+    ) -> Result<AzureWorkItem, PlatformError> {
+        self.wit_request(
+            reqwest::Method::PATCH,
+            ctx,
+            &format!("/workitems/{}", id),
+            "7.0",
+            "application/json-patch+json",
+            Some(operations),
+        )
+        .await
+    }
+
+    /// States of a work item type
+    async fn work_item_states(
+        &self,
+        ctx: &AzureContext,
+        work_item_type: &str,
+    ) -> Result<Vec<AzureWorkItemState>, PlatformError> {
+        let states: ListResponse<AzureWorkItemState> = self
+            .wit_request(
+                reqwest::Method::GET,
+                ctx,
+                &format!(
+                    "/workitemtypes/{}/states",
+                    urlencoding::encode(work_item_type)
+                ),
+                "7.0",
+                "application/json",
+                None,
+            )
+            .await?;
+        Ok(states.value)
+    }
+
+    /// Build work item web URL
+    fn build_work_item_url(&self, ctx: &AzureContext, id: u64) -> String {
+        format!(
+            "{}/{}/{}/_workitems/edit/{}",
+            self.base_url, ctx.organization, ctx.project, id
+        )
+    }
+
+    fn to_issue(&self, ctx: &AzureContext, item: AzureWorkItem, completed: &[String]) -> Issue {
+        let labels = item.fields.tag_list();
+        Issue {
+            number: item.id,
+            url: self.build_work_item_url(ctx, item.id),
+            state: if completed.contains(&item.fields.state) {
+                IssueState::Closed
+            } else {
+                IssueState::Open
+            },
+            title: item.fields.title,
+            body: item.fields.description.unwrap_or_default(),
+            labels,
+        }
+    }
+
+    /// Names of the states in `category` for a work item type
+    async fn states_in_category(
+        &self,
+        ctx: &AzureContext,
+        work_item_type: &str,
+        categories: &[&str],
+    ) -> Result<Vec<String>, PlatformError> {
+        Ok(self
+            .work_item_states(ctx, work_item_type)
+            .await?
+            .into_iter()
+            .filter(|s| categories.contains(&s.category.as_str()))
+            .map(|s| s.name)
+            .collect())
+    }
+
     /// Build PR web URL
     fn build_pr_url(&self, ctx: &AzureContext, pr_id: u64) -> String {
         format!(
@@ -560,6 +885,123 @@ impl HostingPlatform for AzureDevOpsAdapter {
             .collect())
     }
 
+    /// General comments are threads not attached to a file
+    /// Azure DevOps PRs have no assignees or milestones; teams are added as
+    /// group reviewers
+    async fn set_pull_request_metadata(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        options: &PRCreateOptions,
+    ) -> Result<(), PlatformError> {
+        if !options.assignees.is_empty() || options.milestone.is_some() {
+            return Err(PlatformError::ApiError(
+                "Assignees and milestones not supported on Azure DevOps PRs".to_string(),
+            ));
+        }
+        let ctx = self.parse_context(owner, repo);
+
+        for name in options.reviewers.iter().chain(&options.team_reviewers) {
+            let id = self.identity_id(&ctx, name).await?;
+            let _: serde_json::Value = self
+                .api_request(
+                    reqwest::Method::PUT,
+                    &ctx,
+                    &format!(
+                        "/git/repositories/{}/pullRequests/{}/reviewers/{}",
+                        ctx.repository, pull_number, id
+                    ),
+                    Some(serde_json::json!({ "vote": 0 })),
+                )
+                .await?;
+        }
+
+        for label in &options.labels {
+            let _: serde_json::Value = self
+                .api_request(
+                    reqwest::Method::POST,
+                    &ctx,
+                    &format!(
+                        "/git/repositories/{}/pullRequests/{}/labels",
+                        ctx.repository, pull_number
+                    ),
+                    Some(serde_json::json!({ "name": label })),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_pull_request_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<PRComment>, PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+
+        Ok(self
+            .get_threads(&ctx, pull_number)
+            .await?
+            .into_iter()
+            .filter(|t| t.thread_context.is_none())
+            .flat_map(AzureThread::text_comments)
+            .collect())
+    }
+
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+
+        let _: serde_json::Value = self
+            .api_request(
+                reqwest::Method::POST,
+                &ctx,
+                &format!(
+                    "/git/repositories/{}/pullRequests/{}/threads",
+                    ctx.repository, pull_number
+                ),
+                Some(serde_json::json!({
+                    "comments": [{ "parentCommentId": 0, "content": body, "commentType": 1 }],
+                })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Review threads are the threads attached to a file; `active` and
+    /// `pending` ones are unresolved
+    async fn get_review_threads(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<ReviewThread>, PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+
+        Ok(self
+            .get_threads(&ctx, pull_number)
+            .await?
+            .into_iter()
+            .filter_map(|mut t| {
+                let context = t.thread_context.take()?;
+                let resolved = !matches!(t.status.as_deref(), Some("active") | Some("pending"));
+                Some(ReviewThread {
+                    resolved,
+                    path: context.file_path,
+                    line: context.right_file_start.map(|p| p.line),
+                    comments: t.text_comments(),
+                })
+            })
+            .collect())
+    }
+
     async fn get_status_checks(
         &self,
         owner: &str,
@@ -864,6 +1306,282 @@ impl HostingPlatform for AzureDevOpsAdapter {
         Ok(())
     }
 
+    /// Issues are Azure Boards work items of type "Issue" in the repo's
+    /// project; the repository itself is not used.
+    async fn create_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: Option<&str>,
+        labels: &[String],
+    ) -> Result<IssueCreateResult, PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+
+        let mut operations = vec![serde_json::json!({
+            "op": "add",
+            "path": "/fields/System.Title",
+            "value": title,
+        })];
+        if let Some(body) = body {
+            operations.push(serde_json::json!({
+                "op": "add",
+                "path": "/fields/System.Description",
+                "value": body,
+            }));
+        }
+        if !labels.is_empty() {
+            operations.push(serde_json::json!({
+                "op": "add",
+                "path": "/fields/System.Tags",
+                "value": labels.join("; "),
+            }));
+        }
+
+        let item: AzureWorkItem = self
+            .wit_request(
+                reqwest::Method::POST,
+                &ctx,
+                &format!("/workitems/${}", ISSUE_WORK_ITEM_TYPE),
+                "7.0",
+                "application/json-patch+json",
+                Some(serde_json::Value::Array(operations)),
+            )
+            .await?;
+
+        Ok(IssueCreateResult {
+            number: item.id,
+            url: self.build_work_item_url(&ctx, item.id),
+        })
+    }
+
+    async fn get_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+    ) -> Result<Issue, PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+
+        let item: AzureWorkItem = self
+            .wit_request(
+                reqwest::Method::GET,
+                &ctx,
+                &format!("/workitems/{}", number),
+                "7.0",
+                "application/json",
+                None,
+            )
+            .await?;
+        let completed = self
+            .states_in_category(&ctx, &item.fields.work_item_type, &["Completed"])
+            .await?;
+
+        Ok(self.to_issue(&ctx, item, &completed))
+    }
+
+    async fn list_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: IssueState,
+        labels: &[String],
+    ) -> Result<Vec<Issue>, PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+        let completed = self
+            .states_in_category(&ctx, ISSUE_WORK_ITEM_TYPE, &["Completed"])
+            .await?;
+        let closed = self
+            .states_in_category(&ctx, ISSUE_WORK_ITEM_TYPE, &["Completed", "Removed"])
+            .await?;
+
+        let quote = |value: &str| format!("'{}'", value.replace('\'', "''"));
+        let mut query = format!(
+            "SELECT [System.Id] FROM WorkItems WHERE [System.TeamProject] = @project \
+             AND [System.WorkItemType] = {}",
+            quote(ISSUE_WORK_ITEM_TYPE)
+        );
+        let (operator, states) = match state {
+            IssueState::Open => ("NOT IN", &closed),
+            IssueState::Closed => ("IN", &completed),
+        };
+        if !states.is_empty() {
+            let states: Vec<String> = states.iter().map(|s| quote(s)).collect();
+            query.push_str(&format!(
+                " AND [System.State] {} ({})",
+                operator,
+                states.join(", ")
+            ));
+        }
+        for label in labels {
+            query.push_str(&format!(" AND [System.Tags] CONTAINS {}", quote(label)));
+        }
+        query.push_str(" ORDER BY [System.Id] DESC");
+
+        let result: AzureWiqlResult = self
+            .wit_request(
+                reqwest::Method::POST,
+                &ctx,
+                "/wiql?$top=100",
+                "7.0",
+                "application/json",
+                Some(serde_json::json!({ "query": query })),
+            )
+            .await?;
+        if result.work_items.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids: Vec<String> = result.work_items.iter().map(|w| w.id.to_string()).collect();
+        let items: ListResponse<AzureWorkItem> = self
+            .wit_request(
+                reqwest::Method::GET,
+                &ctx,
+                &format!("/workitems?ids={}", ids.join(",")),
+                "7.0",
+                "application/json",
+                None,
+            )
+            .await?;
+
+        Ok(items
+            .value
+            .into_iter()
+            .map(|item| self.to_issue(&ctx, item, &completed))
+            .collect())
+    }
+
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+
+        let _: serde_json::Value = self
+            .wit_request(
+                reqwest::Method::POST,
+                &ctx,
+                &format!("/workItems/{}/comments", number),
+                "7.0-preview.3",
+                "application/json",
+                Some(serde_json::json!({ "text": body })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn update_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<(), PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+
+        let mut operations = Vec::new();
+        if let Some(title) = title {
+            operations.push(serde_json::json!({
+                "op": "add",
+                "path": "/fields/System.Title",
+                "value": title,
+            }));
+        }
+        if let Some(body) = body {
+            operations.push(serde_json::json!({
+                "op": "add",
+                "path": "/fields/System.Description",
+                "value": body,
+            }));
+        }
+        if operations.is_empty() {
+            return Ok(());
+        }
+
+        self.patch_work_item(&ctx, number, serde_json::Value::Array(operations))
+            .await?;
+        Ok(())
+    }
+
+    async fn close_issue(&self, owner: &str, repo: &str, number: u64) -> Result<(), PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+
+        let item: AzureWorkItem = self
+            .wit_request(
+                reqwest::Method::GET,
+                &ctx,
+                &format!("/workitems/{}", number),
+                "7.0",
+                "application/json",
+                None,
+            )
+            .await?;
+        let completed = self
+            .states_in_category(&ctx, &item.fields.work_item_type, &["Completed"])
+            .await?;
+        let Some(state) = completed.first() else {
+            return Err(PlatformError::ApiError(format!(
+                "Work item type '{}' has no completed state",
+                item.fields.work_item_type
+            )));
+        };
+
+        self.patch_work_item(
+            &ctx,
+            number,
+            serde_json::json!([{
+                "op": "add",
+                "path": "/fields/System.State",
+                "value": state,
+            }]),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn add_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        labels: &[String],
+    ) -> Result<(), PlatformError> {
+        let ctx = self.parse_context(owner, repo);
+
+        let item: AzureWorkItem = self
+            .wit_request(
+                reqwest::Method::GET,
+                &ctx,
+                &format!("/workitems/{}", number),
+                "7.0",
+                "application/json",
+                None,
+            )
+            .await?;
+        let mut tags = item.fields.tag_list();
+        for label in labels {
+            if !tags.contains(label) {
+                tags.push(label.clone());
+            }
+        }
+
+        self.patch_work_item(
+            &ctx,
+            number,
+            serde_json::json!([{
+                "op": "add",
+                "path": "/fields/System.Tags",
+                "value": tags.join("; "),
+            }]),
+        )
+        .await?;
+        Ok(())
+    }
+
     fn generate_linked_pr_comment(&self, links: &[LinkedPRRef]) -> String {
         if links.is_empty() {
             return String::new();
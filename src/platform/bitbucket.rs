@@ -41,8 +41,85 @@ impl BitbucketAdapter {
             .build()
             .unwrap_or_else(|_| Client::new())
     }
+
+    /// Send an authenticated API request, failing with `{action} failed`
+    async fn send_request(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<serde_json::Value>,
+        action: &str,
+    ) -> Result<reqwest::Response, PlatformError> {
+        let token = self.get_token().await?;
+
+        let mut request = Self::http_client()
+            .request(method, url)
+            .header("Authorization", format!("Bearer {}", token));
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| PlatformError::NetworkError(e.to_string()))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(PlatformError::NotFound(url.to_string()));
+        }
+        if !response.status().is_success() {
+            let error = response.text().await.unwrap_or_default();
+            return Err(PlatformError::ApiError(format!(
+                "{} failed: {}",
+                action, error
+            )));
+        }
+
+        Ok(response)
+    }
+
+    /// GET every page of a paginated list, following the `next` links
+    async fn get_all<T: for<'de> Deserialize<'de>>(
+        &self,
+        url: &str,
+        action: &str,
+    ) -> Result<Vec<T>, PlatformError> {
+        let mut items = Vec::new();
+        let mut next = Some(url.to_string());
+        while let Some(url) = next {
+            let page: PagedList<T> = self
+                .send_request(reqwest::Method::GET, &url, None, action)
+                .await?
+                .json()
+                .await
+                .map_err(|e| PlatformError::ParseError(format!("{}: {}", action, e)))?;
+            items.extend(page.values);
+            next = page.next;
+        }
+        Ok(items)
+    }
+
+    /// All non-deleted comments on a pull request, oldest first
+    async fn get_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<BbComment>, PlatformError> {
+        let url = format!(
+            "{}/pullrequests/{}/comments?pagelen=100",
+            self.api_base_url(owner, repo),
+            pull_number
+        );
+        let comments: Vec<BbComment> = self.get_all(&url, "Get PR comments").await?;
+
+        Ok(comments.into_iter().filter(|c| !c.deleted).collect())
+    }
 }
 
+/// Bitbucket issue states that count as open
+const OPEN_ISSUE_STATES: &[&str] = &["new", "open", "on hold"];
+
 // Bitbucket API response structures
 #[derive(Debug, Deserialize)]
 struct BitbucketPR {
@@ -84,6 +161,91 @@ struct BbHtmlLink {
 #[derive(Debug, Deserialize)]
 struct PagedList<T> {
     values: Vec<T>,
+    /// URL of the next page, absent on the last one
+    next: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbComment {
+    id: u64,
+    content: Option<BbContent>,
+    user: Option<BbUser>,
+    created_on: Option<String>,
+    inline: Option<BbInline>,
+    parent: Option<BbParent>,
+    #[serde(default)]
+    deleted: bool,
+    /// Set when the conversation has been resolved
+    resolution: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbUser {
+    display_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbInline {
+    path: Option<String>,
+    to: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbParent {
+    id: u64,
+}
+
+impl From<&BbComment> for PRComment {
+    fn from(comment: &BbComment) -> Self {
+        PRComment {
+            author: comment
+                .user
+                .as_ref()
+                .map(|u| u.display_name.clone())
+                .unwrap_or_default(),
+            body: comment
+                .content
+                .as_ref()
+                .and_then(|c| c.raw.clone())
+                .unwrap_or_default(),
+            created_at: comment.created_on.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketIssue {
+    id: u64,
+    title: String,
+    content: Option<BbContent>,
+    state: String,
+    links: BbLinks,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbContent {
+    raw: Option<String>,
+}
+
+impl From<BitbucketIssue> for Issue {
+    fn from(issue: BitbucketIssue) -> Self {
+        Issue {
+            number: issue.id,
+            url: issue.links.html_link.href,
+            title: issue.title,
+            body: issue.content.and_then(|c| c.raw).unwrap_or_default(),
+            state: if OPEN_ISSUE_STATES.contains(&issue.state.as_str()) {
+                IssueState::Open
+            } else {
+                IssueState::Closed
+            },
+            labels: Vec::new(),
+        }
+    }
+}
+
+fn labels_not_supported() -> PlatformError {
+    PlatformError::ApiError("Labels are not supported on Bitbucket issues".to_string())
 }
 
 #[async_trait]
@@ -380,6 +542,143 @@ impl HostingPlatform for BitbucketAdapter {
         Ok(vec![])
     }
 
+    async fn get_pull_request_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<PRComment>, PlatformError> {
+        Ok(self
+            .get_comments(owner, repo, pull_number)
+            .await?
+            .iter()
+            .filter(|c| c.inline.is_none())
+            .map(PRComment::from)
+            .collect())
+    }
+
+    /// Bitbucket PRs only take reviewers, given as account IDs or `{uuid}`s
+    async fn set_pull_request_metadata(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        options: &PRCreateOptions,
+    ) -> Result<(), PlatformError> {
+        if !options.team_reviewers.is_empty()
+            || !options.labels.is_empty()
+            || !options.assignees.is_empty()
+            || options.milestone.is_some()
+        {
+            return Err(PlatformError::ApiError(
+                "Bitbucket PRs only support reviewers (no teams, labels, assignees or milestones)"
+                    .to_string(),
+            ));
+        }
+        if options.reviewers.is_empty() {
+            return Ok(());
+        }
+
+        let url = format!(
+            "{}/pullrequests/{}",
+            self.api_base_url(owner, repo),
+            pull_number
+        );
+        let pr: BitbucketPR = self
+            .send_request(reqwest::Method::GET, &url, None, "Get PR")
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::ParseError(e.to_string()))?;
+
+        let reviewers: Vec<serde_json::Value> = options
+            .reviewers
+            .iter()
+            .map(|id| {
+                if id.starts_with('{') {
+                    serde_json::json!({ "uuid": id })
+                } else {
+                    serde_json::json!({ "account_id": id })
+                }
+            })
+            .collect();
+        self.send_request(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::json!({ "title": pr.title, "reviewers": reviewers })),
+            "Add reviewers",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let url = format!(
+            "{}/pullrequests/{}/comments",
+            self.api_base_url(owner, repo),
+            pull_number
+        );
+        self.send_request(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::json!({ "content": { "raw": body } })),
+            "Comment on PR",
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Review threads are inline comments with their replies
+    async fn get_review_threads(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<ReviewThread>, PlatformError> {
+        let comments = self.get_comments(owner, repo, pull_number).await?;
+        let parents: std::collections::HashMap<u64, u64> = comments
+            .iter()
+            .filter_map(|c| Some((c.id, c.parent.as_ref()?.id)))
+            .collect();
+        let root_of = |mut id: u64| {
+            while let Some(parent) = parents.get(&id) {
+                id = *parent;
+            }
+            id
+        };
+
+        let mut threads: Vec<(u64, ReviewThread)> = comments
+            .iter()
+            .filter(|c| c.parent.is_none())
+            .filter_map(|c| {
+                let inline = c.inline.as_ref()?;
+                Some((
+                    c.id,
+                    ReviewThread {
+                        resolved: c.resolution.as_ref().is_some_and(|r| !r.is_null()),
+                        path: inline.path.clone(),
+                        line: inline.to,
+                        comments: Vec::new(),
+                    },
+                ))
+            })
+            .collect();
+        for comment in &comments {
+            let root = root_of(comment.id);
+            if let Some((_, thread)) = threads.iter_mut().find(|(id, _)| *id == root) {
+                thread.comments.push(PRComment::from(comment));
+            }
+        }
+
+        Ok(threads.into_iter().map(|(_, thread)| thread).collect())
+    }
+
     async fn get_status_checks(
         &self,
         owner: &str,
@@ -497,6 +796,165 @@ impl HostingPlatform for BitbucketAdapter {
         url.contains("bitbucket.org") || url.contains("bitbucket.")
     }
 
+    async fn create_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: Option<&str>,
+        labels: &[String],
+    ) -> Result<IssueCreateResult, PlatformError> {
+        if !labels.is_empty() {
+            return Err(labels_not_supported());
+        }
+
+        let url = format!("{}/issues", self.api_base_url(owner, repo));
+        let issue: BitbucketIssue = self
+            .send_request(
+                reqwest::Method::POST,
+                &url,
+                Some(serde_json::json!({
+                    "title": title,
+                    "content": { "raw": body.unwrap_or("") },
+                })),
+                "Create issue",
+            )
+            .await?
+            .json()
+            .await
+            .map_err(|e| {
+                PlatformError::ParseError(format!("Failed to parse issue response: {}", e))
+            })?;
+
+        Ok(IssueCreateResult {
+            number: issue.id,
+            url: issue.links.html_link.href,
+        })
+    }
+
+    async fn get_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+    ) -> Result<Issue, PlatformError> {
+        let url = format!("{}/issues/{}", self.api_base_url(owner, repo), number);
+        let issue: BitbucketIssue = self
+            .send_request(reqwest::Method::GET, &url, None, "Get issue")
+            .await?
+            .json()
+            .await
+            .map_err(|e| {
+                PlatformError::ParseError(format!("Failed to parse issue response: {}", e))
+            })?;
+
+        Ok(issue.into())
+    }
+
+    async fn list_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: IssueState,
+        labels: &[String],
+    ) -> Result<Vec<Issue>, PlatformError> {
+        if !labels.is_empty() {
+            return Err(labels_not_supported());
+        }
+
+        let clauses: Vec<String> = OPEN_ISSUE_STATES
+            .iter()
+            .map(|s| match state {
+                IssueState::Open => format!("state=\"{}\"", s),
+                IssueState::Closed => format!("state!=\"{}\"", s),
+            })
+            .collect();
+        let query = match state {
+            IssueState::Open => clauses.join(" OR "),
+            IssueState::Closed => clauses.join(" AND "),
+        };
+        let url = format!(
+            "{}/issues?pagelen=100&q={}",
+            self.api_base_url(owner, repo),
+            urlencoding::encode(&query)
+        );
+
+        let issues: Vec<BitbucketIssue> = self.get_all(&url, "List issues").await?;
+
+        Ok(issues.into_iter().map(Issue::from).collect())
+    }
+
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let url = format!(
+            "{}/issues/{}/comments",
+            self.api_base_url(owner, repo),
+            number
+        );
+        self.send_request(
+            reqwest::Method::POST,
+            &url,
+            Some(serde_json::json!({ "content": { "raw": body } })),
+            "Comment on issue",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<(), PlatformError> {
+        let mut update = serde_json::Map::new();
+        if let Some(title) = title {
+            update.insert("title".to_string(), title.into());
+        }
+        if let Some(body) = body {
+            update.insert("content".to_string(), serde_json::json!({ "raw": body }));
+        }
+
+        let url = format!("{}/issues/{}", self.api_base_url(owner, repo), number);
+        self.send_request(
+            reqwest::Method::PUT,
+            &url,
+            Some(update.into()),
+            "Update issue",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn close_issue(&self, owner: &str, repo: &str, number: u64) -> Result<(), PlatformError> {
+        let url = format!("{}/issues/{}", self.api_base_url(owner, repo), number);
+        self.send_request(
+            reqwest::Method::PUT,
+            &url,
+            Some(serde_json::json!({ "state": "resolved" })),
+            "Close issue",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn add_issue_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        _labels: &[String],
+    ) -> Result<(), PlatformError> {
+        Err(labels_not_supported())
+    }
+
     fn generate_linked_pr_comment(&self, links: &[LinkedPRRef]) -> String {
         let links_str: Vec<String> = links
             .iter()
@@ -0,0 +1,855 @@
+//! Gitea / Forgejo platform adapter
+//!
+//! Forgejo is a Gitea fork and keeps its `/api/v1` REST API, so one adapter
+//! serves both (including Codeberg).
+
+use async_trait::async_trait;
+use reqwest::{Client, Method, Response};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::time::Duration;
+
+use super::traits::{HostingPlatform, PlatformError};
+use super::types::*;
+use crate::core::manifest::PlatformType;
+
+/// Default connection timeout in seconds
+const CONNECT_TIMEOUT_SECS: u64 = 10;
+/// Default request timeout in seconds
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+/// Page size when listing pull requests
+const PAGE_LIMIT: usize = 50;
+
+/// Gitea pull request response
+#[derive(Debug, Deserialize)]
+struct GiteaPullRequest {
+    number: u64,
+    html_url: String,
+    title: String,
+    body: Option<String>,
+    state: String, // open, closed
+    #[serde(default)]
+    merged: bool,
+    mergeable: Option<bool>,
+    head: GiteaBranchRef,
+    base: GiteaBranchRef,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaBranchRef {
+    #[serde(rename = "ref")]
+    ref_name: String,
+    #[serde(default)]
+    sha: String,
+}
+
+/// Gitea pull request review
+#[derive(Debug, Deserialize)]
+struct GiteaReview {
+    #[serde(default)]
+    id: u64,
+    state: String, // APPROVED, REQUEST_CHANGES, COMMENT, PENDING, REQUEST_REVIEW
+    #[serde(default)]
+    comments_count: u64,
+    user: Option<GiteaUser>,
+    #[serde(default)]
+    stale: bool,
+    #[serde(default)]
+    dismissed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaUser {
+    login: String,
+}
+
+/// Gitea issue/PR comment, or a review comment on the code
+#[derive(Debug, Deserialize)]
+struct GiteaComment {
+    #[serde(default)]
+    body: String,
+    user: Option<GiteaUser>,
+    created_at: Option<String>,
+    path: Option<String>,
+    position: Option<u64>,
+    /// Who resolved the conversation, if anyone
+    resolver: Option<GiteaUser>,
+}
+
+impl From<GiteaComment> for PRComment {
+    fn from(comment: GiteaComment) -> Self {
+        PRComment {
+            author: comment.user.map(|u| u.login).unwrap_or_default(),
+            body: comment.body,
+            created_at: comment.created_at,
+        }
+    }
+}
+
+/// Gitea combined commit status
+#[derive(Debug, Deserialize)]
+struct GiteaCombinedStatus {
+    #[serde(default)]
+    statuses: Vec<GiteaStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaStatus {
+    context: String,
+    status: String, // pending, success, error, failure, warning
+}
+
+/// Gitea repository settings relevant to merging
+#[derive(Debug, Deserialize)]
+struct GiteaRepository {
+    #[serde(default = "default_true")]
+    allow_merge_commits: bool,
+    #[serde(default = "default_true")]
+    allow_squash_merge: bool,
+    #[serde(default = "default_true")]
+    allow_rebase: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Gitea release response
+#[derive(Debug, Deserialize)]
+struct GiteaRelease {
+    id: u64,
+    tag_name: String,
+    html_url: String,
+}
+
+/// Gitea / Forgejo API adapter
+pub struct GiteaAdapter {
+    base_url: String,
+    http_client: Client,
+}
+
+impl GiteaAdapter {
+    /// Create a new Gitea adapter for the instance at `base_url`
+    /// (default: Codeberg)
+    pub fn new(base_url: Option<&str>) -> Self {
+        let http_client = Client::builder()
+            .connect_timeout(Duration::from_secs(CONNECT_TIMEOUT_SECS))
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_else(|_| Client::new());
+
+        Self {
+            base_url: base_url
+                .unwrap_or("https://codeberg.org")
+                .trim_end_matches('/')
+                .to_string(),
+            http_client,
+        }
+    }
+
+    /// Send an authenticated API request
+    async fn send(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<impl Serialize>,
+    ) -> Result<Response, PlatformError> {
+        let token = self.get_token().await?;
+        let url = format!("{}/api/v1{}", self.base_url, endpoint);
+
+        let mut request = self
+            .http_client
+            .request(method, &url)
+            .header("Authorization", format!("token {}", token))
+            .header("Accept", "application/json");
+
+        if let Some(b) = body {
+            request = request.json(&b);
+        }
+
+        request
+            .send()
+            .await
+            .map_err(|e| PlatformError::NetworkError(e.to_string()))
+    }
+
+    /// Make an authenticated API request and parse the JSON response
+    async fn api_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<impl Serialize>,
+    ) -> Result<T, PlatformError> {
+        let response = self.send(method, endpoint, body).await?;
+        let status = response.status();
+
+        if status == 404 {
+            return Err(PlatformError::NotFound(endpoint.to_string()));
+        }
+        if status == 401 {
+            return Err(PlatformError::AuthError(
+                "Gitea rejected the token (check GITEA_TOKEN)".to_string(),
+            ));
+        }
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(PlatformError::ApiError(format!(
+                "Gitea API error ({}): {}",
+                status, error_text
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| PlatformError::ParseError(e.to_string()))
+    }
+
+    fn repo_endpoint(owner: &str, repo: &str) -> String {
+        format!(
+            "/repos/{}/{}",
+            urlencoding::encode(owner),
+            urlencoding::encode(repo)
+        )
+    }
+
+    /// Latest review state per reviewer, ignoring dismissed and stale reviews
+    fn latest_reviews(reviews: Vec<GiteaReview>) -> Vec<PRReview> {
+        let mut latest: HashMap<String, String> = HashMap::new();
+        let mut order = Vec::new();
+        for review in reviews {
+            if review.dismissed || review.stale {
+                continue;
+            }
+            if !matches!(review.state.as_str(), "APPROVED" | "REQUEST_CHANGES") {
+                continue;
+            }
+            let user = review.user.map(|u| u.login).unwrap_or_default();
+            if !latest.contains_key(&user) {
+                order.push(user.clone());
+            }
+            latest.insert(user, review.state);
+        }
+        order
+            .into_iter()
+            .map(|user| PRReview {
+                state: match latest[&user].as_str() {
+                    "REQUEST_CHANGES" => "CHANGES_REQUESTED".to_string(),
+                    state => state.to_string(),
+                },
+                user,
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl HostingPlatform for GiteaAdapter {
+    fn platform_type(&self) -> PlatformType {
+        PlatformType::Gitea
+    }
+
+    async fn get_token(&self) -> Result<String, PlatformError> {
+        env::var("GITEA_TOKEN")
+            .or_else(|_| env::var("FORGEJO_TOKEN"))
+            .map_err(|_| {
+                PlatformError::AuthError("GITEA_TOKEN (or FORGEJO_TOKEN) not set".to_string())
+            })
+    }
+
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: Option<&str>,
+        draft: bool,
+    ) -> Result<PRCreateResult, PlatformError> {
+        // Gitea marks pull requests as work in progress by title prefix
+        let title = if draft {
+            format!("WIP: {}", title)
+        } else {
+            title.to_string()
+        };
+
+        let body_json = serde_json::json!({
+            "head": head,
+            "base": base,
+            "title": title,
+            "body": body.unwrap_or(""),
+        });
+
+        let pr: GiteaPullRequest = self
+            .api_request(
+                Method::POST,
+                &format!("{}/pulls", Self::repo_endpoint(owner, repo)),
+                Some(body_json),
+            )
+            .await?;
+
+        Ok(PRCreateResult {
+            number: pr.number,
+            url: pr.html_url,
+        })
+    }
+
+    async fn get_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<PullRequest, PlatformError> {
+        let pr: GiteaPullRequest = self
+            .api_request(
+                Method::GET,
+                &format!("{}/pulls/{}", Self::repo_endpoint(owner, repo), pull_number),
+                None::<()>,
+            )
+            .await
+            .map_err(|e| match e {
+                PlatformError::NotFound(_) => PlatformError::NotFound(format!(
+                    "PR #{} not found in {}/{}",
+                    pull_number, owner, repo
+                )),
+                e => e,
+            })?;
+
+        let state = if pr.merged {
+            PRState::Merged
+        } else if pr.state == "closed" {
+            PRState::Closed
+        } else {
+            PRState::Open
+        };
+
+        Ok(PullRequest {
+            number: pr.number,
+            url: pr.html_url,
+            title: pr.title,
+            body: pr.body.unwrap_or_default(),
+            state,
+            merged: pr.merged,
+            mergeable: pr.mergeable,
+            head: PRHead {
+                ref_name: pr.head.ref_name,
+                sha: pr.head.sha,
+            },
+            base: PRBase {
+                ref_name: pr.base.ref_name,
+            },
+        })
+    }
+
+    async fn update_pull_request_body(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let _: serde_json::Value = self
+            .api_request(
+                Method::PATCH,
+                &format!("{}/pulls/{}", Self::repo_endpoint(owner, repo), pull_number),
+                Some(serde_json::json!({ "body": body })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn merge_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        method: Option<MergeMethod>,
+        delete_branch: bool,
+    ) -> Result<bool, PlatformError> {
+        let merge_style = match method.unwrap_or_default() {
+            MergeMethod::Merge => "merge",
+            MergeMethod::Squash => "squash",
+            MergeMethod::Rebase => "rebase",
+        };
+        let body_json = serde_json::json!({
+            "Do": merge_style,
+            "delete_branch_after_merge": delete_branch,
+        });
+
+        let response = self
+            .send(
+                Method::POST,
+                &format!(
+                    "{}/pulls/{}/merge",
+                    Self::repo_endpoint(owner, repo),
+                    pull_number
+                ),
+                Some(body_json),
+            )
+            .await?;
+
+        let status = response.status().as_u16();
+        let body_text = response.text().await.unwrap_or_default();
+        let body_lower = body_text.to_lowercase();
+
+        match status {
+            200 | 204 => Ok(true),
+            409 if body_lower.contains("out of date") || body_lower.contains("behind") => {
+                Err(PlatformError::BranchBehind(format!(
+                    "PR #{} branch is behind base branch",
+                    pull_number
+                )))
+            }
+            403 | 405 if body_lower.contains("protect") || body_lower.contains("required") => {
+                Err(PlatformError::BranchProtected(format!(
+                    "PR #{} is blocked by branch protection rules",
+                    pull_number
+                )))
+            }
+            _ => Err(PlatformError::ApiError(format!(
+                "Failed to merge PR ({}): {}",
+                status, body_text
+            ))),
+        }
+    }
+
+    async fn find_pr_by_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Option<PRCreateResult>, PlatformError> {
+        // The API cannot filter open pull requests by head branch
+        for page in 1.. {
+            let prs: Vec<GiteaPullRequest> = self
+                .api_request(
+                    Method::GET,
+                    &format!(
+                        "{}/pulls?state=open&page={}&limit={}",
+                        Self::repo_endpoint(owner, repo),
+                        page,
+                        PAGE_LIMIT
+                    ),
+                    None::<()>,
+                )
+                .await?;
+
+            let count = prs.len();
+            if let Some(pr) = prs.into_iter().find(|pr| pr.head.ref_name == branch) {
+                return Ok(Some(PRCreateResult {
+                    number: pr.number,
+                    url: pr.html_url,
+                }));
+            }
+            if count < PAGE_LIMIT {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    async fn is_pull_request_approved(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<bool, PlatformError> {
+        let reviews = self
+            .get_pull_request_reviews(owner, repo, pull_number)
+            .await?;
+        Ok(reviews.iter().any(|r| r.state == "APPROVED")
+            && !reviews.iter().any(|r| r.state == "CHANGES_REQUESTED"))
+    }
+
+    async fn get_pull_request_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<PRReview>, PlatformError> {
+        let reviews: Vec<GiteaReview> = self
+            .api_request(
+                Method::GET,
+                &format!(
+                    "{}/pulls/{}/reviews",
+                    Self::repo_endpoint(owner, repo),
+                    pull_number
+                ),
+                None::<()>,
+            )
+            .await?;
+        Ok(Self::latest_reviews(reviews))
+    }
+
+    async fn set_pull_request_metadata(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        options: &PRCreateOptions,
+    ) -> Result<(), PlatformError> {
+        let endpoint = Self::repo_endpoint(owner, repo);
+
+        if !options.reviewers.is_empty() || !options.team_reviewers.is_empty() {
+            let _: serde_json::Value = self
+                .api_request(
+                    Method::POST,
+                    &format!("{}/pulls/{}/requested_reviewers", endpoint, pull_number),
+                    Some(serde_json::json!({
+                        "reviewers": options.reviewers,
+                        "team_reviewers": options.team_reviewers,
+                    })),
+                )
+                .await?;
+        }
+
+        #[derive(Deserialize)]
+        struct Named {
+            id: u64,
+            #[serde(alias = "title")]
+            name: String,
+        }
+
+        // Labels and milestones are set by ID
+        let mut update = serde_json::Map::new();
+        if !options.labels.is_empty() {
+            let labels: Vec<Named> = self
+                .api_request(
+                    Method::GET,
+                    &format!("{}/labels?limit=100", endpoint),
+                    None::<()>,
+                )
+                .await?;
+            let mut ids = Vec::new();
+            for name in &options.labels {
+                let label = labels.iter().find(|l| &l.name == name).ok_or_else(|| {
+                    PlatformError::NotFound(format!("Label '{}' in {}/{}", name, owner, repo))
+                })?;
+                ids.push(label.id);
+            }
+            update.insert("labels".into(), serde_json::json!(ids));
+        }
+        if !options.assignees.is_empty() {
+            update.insert("assignees".into(), serde_json::json!(options.assignees));
+        }
+        if let Some(ref title) = options.milestone {
+            let milestones: Vec<Named> = self
+                .api_request(
+                    Method::GET,
+                    &format!(
+                        "{}/milestones?state=open&name={}",
+                        endpoint,
+                        urlencoding::encode(title)
+                    ),
+                    None::<()>,
+                )
+                .await?;
+            let milestone = milestones
+                .iter()
+                .find(|m| &m.name == title)
+                .ok_or_else(|| {
+                    PlatformError::NotFound(format!("Milestone '{}' in {}/{}", title, owner, repo))
+                })?;
+            update.insert("milestone".into(), serde_json::json!(milestone.id));
+        }
+
+        if !update.is_empty() {
+            let _: serde_json::Value = self
+                .api_request(
+                    Method::PATCH,
+                    &format!("{}/pulls/{}", endpoint, pull_number),
+                    Some(serde_json::Value::Object(update)),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_pull_request_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<PRComment>, PlatformError> {
+        let comments: Vec<GiteaComment> = self
+            .api_request(
+                Method::GET,
+                &format!(
+                    "{}/issues/{}/comments",
+                    Self::repo_endpoint(owner, repo),
+                    pull_number
+                ),
+                None::<()>,
+            )
+            .await?;
+        Ok(comments.into_iter().map(PRComment::from).collect())
+    }
+
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let _: serde_json::Value = self
+            .api_request(
+                Method::POST,
+                &format!(
+                    "{}/issues/{}/comments",
+                    Self::repo_endpoint(owner, repo),
+                    pull_number
+                ),
+                Some(serde_json::json!({ "body": body })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Gitea has no thread IDs: review comments on the same line form one
+    /// conversation, resolved once every comment has a resolver
+    async fn get_review_threads(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<ReviewThread>, PlatformError> {
+        let endpoint = format!(
+            "{}/pulls/{}/reviews",
+            Self::repo_endpoint(owner, repo),
+            pull_number
+        );
+        let reviews: Vec<GiteaReview> =
+            self.api_request(Method::GET, &endpoint, None::<()>).await?;
+
+        let mut threads: Vec<ReviewThread> = Vec::new();
+        for review in reviews.iter().filter(|r| r.comments_count > 0) {
+            let comments: Vec<GiteaComment> = self
+                .api_request(
+                    Method::GET,
+                    &format!("{}/{}/comments", endpoint, review.id),
+                    None::<()>,
+                )
+                .await?;
+            for comment in comments {
+                let resolved = comment.resolver.is_some();
+                let (path, line) = (comment.path.clone(), comment.position);
+                match threads
+                    .iter_mut()
+                    .find(|t| t.path == path && t.line == line)
+                {
+                    Some(thread) => {
+                        thread.resolved &= resolved;
+                        thread.comments.push(comment.into());
+                    }
+                    None => threads.push(ReviewThread {
+                        resolved,
+                        path,
+                        line,
+                        comments: vec![comment.into()],
+                    }),
+                }
+            }
+        }
+        Ok(threads)
+    }
+
+    async fn get_status_checks(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_name: &str,
+    ) -> Result<StatusCheckResult, PlatformError> {
+        let combined: GiteaCombinedStatus = self
+            .api_request(
+                Method::GET,
+                &format!(
+                    "{}/commits/{}/status",
+                    Self::repo_endpoint(owner, repo),
+                    urlencoding::encode(ref_name)
+                ),
+                None::<()>,
+            )
+            .await?;
+
+        let statuses: Vec<StatusCheck> = combined
+            .statuses
+            .into_iter()
+            .map(|s| StatusCheck {
+                context: s.context,
+                state: match s.status.as_str() {
+                    "success" | "warning" => "success",
+                    "failure" | "error" => "failure",
+                    _ => "pending",
+                }
+                .to_string(),
+            })
+            .collect();
+
+        let state = if statuses.iter().any(|s| s.state == "failure") {
+            CheckState::Failure
+        } else if statuses.is_empty() || statuses.iter().any(|s| s.state == "pending") {
+            CheckState::Pending
+        } else {
+            CheckState::Success
+        };
+
+        Ok(StatusCheckResult { state, statuses })
+    }
+
+    async fn get_allowed_merge_methods(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<AllowedMergeMethods, PlatformError> {
+        let repository: GiteaRepository = self
+            .api_request(Method::GET, &Self::repo_endpoint(owner, repo), None::<()>)
+            .await?;
+
+        Ok(AllowedMergeMethods {
+            merge: repository.allow_merge_commits,
+            squash: repository.allow_squash_merge,
+            rebase: repository.allow_rebase,
+        })
+    }
+
+    async fn get_pull_request_diff(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<String, PlatformError> {
+        let response = self
+            .send(
+                Method::GET,
+                &format!(
+                    "{}/pulls/{}.diff",
+                    Self::repo_endpoint(owner, repo),
+                    pull_number
+                ),
+                None::<()>,
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(PlatformError::ApiError(format!(
+                "Failed to get PR diff ({}): {}",
+                status, error_text
+            )));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| PlatformError::NetworkError(e.to_string()))
+    }
+
+    fn parse_repo_url(&self, url: &str) -> Option<ParsedRepoInfo> {
+        // Any host: the owner and repo are the last two path segments, which
+        // also covers instances served from a sub-path
+        let path = if let Some(rest) = url.split_once("://").map(|(_, rest)| rest) {
+            rest.split_once('/')?.1
+        } else {
+            url.split_once(':')?.1
+        };
+        let mut segments = path
+            .trim_end_matches('/')
+            .trim_end_matches(".git")
+            .rsplit('/');
+        let repo = segments.next().filter(|s| !s.is_empty())?;
+        let owner = segments.next().filter(|s| !s.is_empty())?;
+
+        Some(ParsedRepoInfo {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            project: None,
+            platform: Some(PlatformType::Gitea),
+        })
+    }
+
+    fn matches_url(&self, url: &str) -> bool {
+        url.contains("gitea.") || url.contains("forgejo.") || url.contains("codeberg.org")
+    }
+
+    async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+        name: &str,
+        body: Option<&str>,
+        target_commitish: &str,
+        draft: bool,
+        prerelease: bool,
+    ) -> Result<ReleaseResult, PlatformError> {
+        let body_json = serde_json::json!({
+            "tag_name": tag,
+            "target_commitish": target_commitish,
+            "name": name,
+            "body": body.unwrap_or(""),
+            "draft": draft,
+            "prerelease": prerelease,
+        });
+
+        let release: GiteaRelease = self
+            .api_request(
+                Method::POST,
+                &format!("{}/releases", Self::repo_endpoint(owner, repo)),
+                Some(body_json),
+            )
+            .await?;
+
+        Ok(ReleaseResult {
+            id: release.id,
+            tag: release.tag_name,
+            url: release.html_url,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repo_url() {
+        let adapter = GiteaAdapter::new(None);
+        for url in [
+            "git@codeberg.org:owner/repo.git",
+            "https://forgejo.example.com/owner/repo.git",
+            "ssh://git@gitea.example.com:2222/owner/repo.git",
+            "https://git.example.com/gitea/owner/repo",
+        ] {
+            let info = adapter.parse_repo_url(url).unwrap();
+            assert_eq!(info.owner, "owner", "{}", url);
+            assert_eq!(info.repo, "repo", "{}", url);
+        }
+        assert!(adapter.parse_repo_url("https://example.com/repo").is_none());
+    }
+
+    #[test]
+    fn test_latest_reviews() {
+        let review = |user: &str, state: &str| GiteaReview {
+            id: 0,
+            state: state.to_string(),
+            comments_count: 0,
+            user: Some(GiteaUser {
+                login: user.to_string(),
+            }),
+            stale: false,
+            dismissed: false,
+        };
+        let reviews = GiteaAdapter::latest_reviews(vec![
+            review("alice", "REQUEST_CHANGES"),
+            review("bob", "COMMENT"),
+            review("alice", "APPROVED"),
+        ]);
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews[0].user, "alice");
+        assert_eq!(reviews[0].state, "APPROVED");
+    }
+}
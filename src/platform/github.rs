@@ -77,6 +77,235 @@ impl GitHubAdapter {
             .build()
             .map_err(|e| PlatformError::ApiError(format!("Failed to create client: {}", e)))
     }
+
+    /// GraphQL endpoint: `/api/graphql` on GitHub Enterprise, `/graphql` otherwise
+    fn graphql_url(&self) -> String {
+        let base_url = self
+            .base_url
+            .as_deref()
+            .unwrap_or("https://api.github.com")
+            .trim_end_matches('/');
+        match base_url.strip_suffix("/api/v3") {
+            Some(host) => format!("{}/api/graphql", host),
+            None => format!("{}/graphql", base_url),
+        }
+    }
+
+    /// Run a GraphQL query and return its `data`
+    async fn graphql(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value, PlatformError> {
+        let token = self.get_token().await?;
+        let response = Self::http_client()
+            .post(self.graphql_url())
+            .header("Authorization", format!("Bearer {}", token))
+            .header("User-Agent", "gitgrip")
+            .json(&serde_json::json!({ "query": query, "variables": variables }))
+            .send()
+            .await
+            .map_err(|e| PlatformError::NetworkError(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(PlatformError::ApiError(format!(
+                "GraphQL request failed ({}): {}",
+                status, error_text
+            )));
+        }
+
+        let mut body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| PlatformError::ParseError(e.to_string()))?;
+        if let Some(errors) = body.get("errors").filter(|e| !e.is_null()) {
+            return Err(PlatformError::ApiError(format!(
+                "GraphQL error: {}",
+                errors
+            )));
+        }
+        Ok(body["data"].take())
+    }
+
+    /// Send an authenticated REST request to `{base}/repos/{owner}/{repo}{path}`
+    ///
+    /// Non-success responses become `ApiError("Failed to {action} (...)")`.
+    async fn repo_request(
+        &self,
+        method: reqwest::Method,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: Option<serde_json::Value>,
+        action: &str,
+    ) -> Result<reqwest::Response, PlatformError> {
+        let token = self.get_token().await?;
+        let base_url = self.base_url.as_deref().unwrap_or("https://api.github.com");
+        let url = format!("{}/repos/{}/{}{}", base_url, owner, repo, path);
+
+        let mut request = Self::http_client()
+            .request(method, &url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github.v3+json")
+            .header("User-Agent", "gitgrip");
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| PlatformError::NetworkError(e.to_string()))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(PlatformError::NotFound(format!(
+                "{}/{}{}",
+                owner, repo, path
+            )));
+        }
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(PlatformError::ApiError(format!(
+                "Failed to {} ({}): {}",
+                action, status, error_text
+            )));
+        }
+
+        Ok(response)
+    }
+
+    /// GET every page of a paginated list at `{base}/repos/{owner}/{repo}{path}`
+    ///
+    /// `path` must already carry a query string. Pages are requested until
+    /// the `Link` header no longer has a `rel="next"` entry.
+    async fn repo_get_all<T: serde::de::DeserializeOwned>(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        action: &str,
+    ) -> Result<Vec<T>, PlatformError> {
+        let mut items = Vec::new();
+        for page in 1.. {
+            let response = self
+                .repo_request(
+                    reqwest::Method::GET,
+                    owner,
+                    repo,
+                    &format!("{}&page={}", path, page),
+                    None,
+                    action,
+                )
+                .await?;
+            let has_next = response
+                .headers()
+                .get(reqwest::header::LINK)
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|link| link.contains("rel=\"next\""));
+            let batch: Vec<T> = response
+                .json()
+                .await
+                .map_err(|e| PlatformError::ParseError(e.to_string()))?;
+            items.extend(batch);
+            if !has_next {
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    /// Number of the open milestone titled `title`
+    async fn milestone_number(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+    ) -> Result<u64, PlatformError> {
+        let response = self
+            .repo_request(
+                reqwest::Method::GET,
+                owner,
+                repo,
+                "/milestones?state=open&per_page=100",
+                None,
+                "list milestones",
+            )
+            .await?;
+        let milestones: Vec<GitHubMilestone> = response
+            .json()
+            .await
+            .map_err(|e| PlatformError::ParseError(e.to_string()))?;
+
+        milestones
+            .into_iter()
+            .find(|m| m.title == title)
+            .map(|m| m.number)
+            .ok_or_else(|| {
+                PlatformError::NotFound(format!(
+                    "Milestone '{}' not found in {}/{}",
+                    title, owner, repo
+                ))
+            })
+    }
+}
+
+/// Comment as returned by the GitHub REST API
+#[derive(serde::Deserialize)]
+struct GitHubComment {
+    user: Option<GitHubUser>,
+    #[serde(default)]
+    body: String,
+    created_at: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct GitHubUser {
+    login: String,
+}
+
+/// Issue as returned by the GitHub REST API
+#[derive(serde::Deserialize)]
+struct GitHubIssue {
+    number: u64,
+    html_url: String,
+    title: String,
+    body: Option<String>,
+    state: String,
+    #[serde(default)]
+    labels: Vec<GitHubLabel>,
+    /// Present when the "issue" is a pull request
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(serde::Deserialize)]
+struct GitHubLabel {
+    name: String,
+}
+
+#[derive(serde::Deserialize)]
+struct GitHubMilestone {
+    number: u64,
+    title: String,
+}
+
+impl From<GitHubIssue> for Issue {
+    fn from(issue: GitHubIssue) -> Self {
+        Issue {
+            number: issue.number,
+            url: issue.html_url,
+            title: issue.title,
+            body: issue.body.unwrap_or_default(),
+            state: if issue.state == "closed" {
+                IssueState::Closed
+            } else {
+                IssueState::Open
+            },
+            labels: issue.labels.into_iter().map(|l| l.name).collect(),
+        }
+    }
 }
 
 #[async_trait]
@@ -656,6 +885,247 @@ impl HostingPlatform for GitHubAdapter {
         Ok(StatusCheckResult { state, statuses })
     }
 
+    async fn set_pull_request_metadata(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        options: &PRCreateOptions,
+    ) -> Result<(), PlatformError> {
+        if !options.reviewers.is_empty() || !options.team_reviewers.is_empty() {
+            self.repo_request(
+                reqwest::Method::POST,
+                owner,
+                repo,
+                &format!("/pulls/{}/requested_reviewers", pull_number),
+                Some(serde_json::json!({
+                    "reviewers": options.reviewers,
+                    "team_reviewers": options.team_reviewers,
+                })),
+                "request reviewers",
+            )
+            .await?;
+        }
+
+        // Labels, assignees and milestone live on the PR's issue
+        let mut update = serde_json::Map::new();
+        if !options.labels.is_empty() {
+            update.insert("labels".into(), serde_json::json!(options.labels));
+        }
+        if !options.assignees.is_empty() {
+            update.insert("assignees".into(), serde_json::json!(options.assignees));
+        }
+        if let Some(ref title) = options.milestone {
+            let number = self.milestone_number(owner, repo, title).await?;
+            update.insert("milestone".into(), serde_json::json!(number));
+        }
+        if !update.is_empty() {
+            self.repo_request(
+                reqwest::Method::PATCH,
+                owner,
+                repo,
+                &format!("/issues/{}", pull_number),
+                Some(serde_json::Value::Object(update)),
+                "update PR labels and assignees",
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_pull_request_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<PRComment>, PlatformError> {
+        // Conversation comments are issue comments on the PR
+        let comments: Vec<GitHubComment> = self
+            .repo_get_all(
+                owner,
+                repo,
+                &format!("/issues/{}/comments?per_page=100", pull_number),
+                "get PR comments",
+            )
+            .await?;
+
+        Ok(comments
+            .into_iter()
+            .map(|c| PRComment {
+                author: c.user.map(|u| u.login).unwrap_or_default(),
+                body: c.body,
+                created_at: c.created_at,
+            })
+            .collect())
+    }
+
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        self.repo_request(
+            reqwest::Method::POST,
+            owner,
+            repo,
+            &format!("/issues/{}/comments", pull_number),
+            Some(serde_json::json!({ "body": body })),
+            "comment on PR",
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Review threads and their resolution are only exposed by GraphQL
+    async fn get_review_threads(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<ReviewThread>, PlatformError> {
+        const QUERY: &str =
+            "query($owner: String!, $repo: String!, $number: Int!, $after: String) {
+  repository(owner: $owner, name: $repo) {
+    pullRequest(number: $number) {
+      reviewThreads(first: 100, after: $after) {
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          id
+          isResolved
+          path
+          line
+          comments(first: 100) {
+            pageInfo { hasNextPage endCursor }
+            nodes { author { login } body createdAt }
+          }
+        }
+      }
+    }
+  }
+}";
+        // Further comments of a thread with more than one page of them
+        const COMMENTS_QUERY: &str = "query($id: ID!, $after: String) {
+  node(id: $id) {
+    ... on PullRequestReviewThread {
+      comments(first: 100, after: $after) {
+        pageInfo { hasNextPage endCursor }
+        nodes { author { login } body createdAt }
+      }
+    }
+  }
+}";
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ThreadNode {
+            id: String,
+            is_resolved: bool,
+            path: Option<String>,
+            line: Option<u64>,
+            comments: Page<CommentNode>,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommentNode {
+            author: Option<GitHubUser>,
+            #[serde(default)]
+            body: String,
+            created_at: Option<String>,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Page<T> {
+            nodes: Vec<T>,
+            #[serde(default)]
+            page_info: PageInfo,
+        }
+
+        #[derive(Default, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PageInfo {
+            has_next_page: bool,
+            end_cursor: Option<String>,
+        }
+
+        fn parse<T: serde::de::DeserializeOwned>(
+            value: serde_json::Value,
+        ) -> Result<T, PlatformError> {
+            serde_json::from_value(value).map_err(|e| PlatformError::ParseError(e.to_string()))
+        }
+
+        let mut nodes: Vec<ThreadNode> = Vec::new();
+        let mut after: Option<String> = None;
+        loop {
+            let data = self
+                .graphql(
+                    QUERY,
+                    serde_json::json!({
+                        "owner": owner,
+                        "repo": repo,
+                        "number": pull_number,
+                        "after": after,
+                    }),
+                )
+                .await?;
+            let threads = data["repository"]["pullRequest"]["reviewThreads"].clone();
+            if threads.is_null() {
+                return Err(PlatformError::NotFound(format!(
+                    "PR #{} not found in {}/{}",
+                    pull_number, owner, repo
+                )));
+            }
+            let page: Page<ThreadNode> = parse(threads)?;
+            nodes.extend(page.nodes);
+            match page.page_info {
+                PageInfo {
+                    has_next_page: true,
+                    end_cursor: Some(cursor),
+                } => after = Some(cursor),
+                _ => break,
+            }
+        }
+
+        for thread in &mut nodes {
+            while thread.comments.page_info.has_next_page {
+                let Some(cursor) = thread.comments.page_info.end_cursor.take() else {
+                    break;
+                };
+                let data = self
+                    .graphql(
+                        COMMENTS_QUERY,
+                        serde_json::json!({ "id": thread.id, "after": cursor }),
+                    )
+                    .await?;
+                let page: Page<CommentNode> = parse(data["node"]["comments"].clone())?;
+                thread.comments.nodes.extend(page.nodes);
+                thread.comments.page_info = page.page_info;
+            }
+        }
+
+        Ok(nodes
+            .into_iter()
+            .map(|t| ReviewThread {
+                resolved: t.is_resolved,
+                path: t.path,
+                line: t.line,
+                comments: t
+                    .comments
+                    .nodes
+                    .into_iter()
+                    .map(|c| PRComment {
+                        author: c.author.map(|a| a.login).unwrap_or_default(),
+                        body: c.body,
+                        created_at: c.created_at,
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+
     async fn get_allowed_merge_methods(
         &self,
         owner: &str,
@@ -946,6 +1416,167 @@ impl HostingPlatform for GitHubAdapter {
         })
     }
 
+    async fn create_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: Option<&str>,
+        labels: &[String],
+    ) -> Result<IssueCreateResult, PlatformError> {
+        let response = self
+            .repo_request(
+                reqwest::Method::POST,
+                owner,
+                repo,
+                "/issues",
+                Some(serde_json::json!({
+                    "title": title,
+                    "body": body.unwrap_or(""),
+                    "labels": labels,
+                })),
+                "create issue",
+            )
+            .await?;
+
+        let issue: GitHubIssue = response
+            .json()
+            .await
+            .map_err(|e| PlatformError::ParseError(e.to_string()))?;
+
+        Ok(IssueCreateResult {
+            number: issue.number,
+            url: issue.html_url,
+        })
+    }
+
+    async fn get_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+    ) -> Result<Issue, PlatformError> {
+        let response = self
+            .repo_request(
+                reqwest::Method::GET,
+                owner,
+                repo,
+                &format!("/issues/{}", number),
+                None,
+                "get issue",
+            )
+            .await?;
+
+        let issue: GitHubIssue = response
+            .json()
+            .await
+            .map_err(|e| PlatformError::ParseError(e.to_string()))?;
+
+        Ok(issue.into())
+    }
+
+    async fn list_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: IssueState,
+        labels: &[String],
+    ) -> Result<Vec<Issue>, PlatformError> {
+        let mut path = format!("/issues?state={}&per_page=100", state);
+        if !labels.is_empty() {
+            path.push_str(&format!(
+                "&labels={}",
+                urlencoding::encode(&labels.join(","))
+            ));
+        }
+        let issues: Vec<GitHubIssue> = self.repo_get_all(owner, repo, &path, "list issues").await?;
+
+        // The issues endpoint also returns pull requests
+        Ok(issues
+            .into_iter()
+            .filter(|i| i.pull_request.is_none())
+            .map(Issue::from)
+            .collect())
+    }
+
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        self.repo_request(
+            reqwest::Method::POST,
+            owner,
+            repo,
+            &format!("/issues/{}/comments", number),
+            Some(serde_json::json!({ "body": body })),
+            "comment on issue",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<(), PlatformError> {
+        let mut update = serde_json::Map::new();
+        if let Some(title) = title {
+            update.insert("title".to_string(), title.into());
+        }
+        if let Some(body) = body {
+            update.insert("body".to_string(), body.into());
+        }
+        self.repo_request(
+            reqwest::Method::PATCH,
+            owner,
+            repo,
+            &format!("/issues/{}", number),
+            Some(update.into()),
+            "update issue",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn close_issue(&self, owner: &str, repo: &str, number: u64) -> Result<(), PlatformError> {
+        self.repo_request(
+            reqwest::Method::PATCH,
+            owner,
+            repo,
+            &format!("/issues/{}", number),
+            Some(serde_json::json!({ "state": "closed" })),
+            "close issue",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn add_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        labels: &[String],
+    ) -> Result<(), PlatformError> {
+        self.repo_request(
+            reqwest::Method::POST,
+            owner,
+            repo,
+            &format!("/issues/{}/labels", number),
+            Some(serde_json::json!({ "labels": labels })),
+            "label issue",
+        )
+        .await?;
+        Ok(())
+    }
+
     fn generate_linked_pr_comment(&self, links: &[LinkedPRRef]) -> String {
         if links.is_empty() {
             return String::new();
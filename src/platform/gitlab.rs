@@ -54,6 +54,76 @@ struct GitLabPipeline {
     status: String, // success, failed, running, pending, canceled, skipped
 }
 
+/// GitLab merge request discussion (a single comment or a thread)
+#[derive(Debug, Deserialize)]
+struct GitLabDiscussion {
+    notes: Vec<GitLabNote>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabNote {
+    body: String,
+    author: GitLabAuthor,
+    created_at: Option<String>,
+    #[serde(default)]
+    system: bool,
+    #[serde(default)]
+    resolvable: bool,
+    #[serde(default)]
+    resolved: bool,
+    position: Option<GitLabPosition>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabAuthor {
+    username: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabPosition {
+    new_path: Option<String>,
+    new_line: Option<u64>,
+}
+
+impl From<GitLabNote> for PRComment {
+    fn from(note: GitLabNote) -> Self {
+        PRComment {
+            author: note.author.username,
+            body: note.body,
+            created_at: note.created_at,
+        }
+    }
+}
+
+/// GitLab issue
+#[derive(Debug, Deserialize)]
+struct GitLabIssue {
+    iid: u64,
+    web_url: String,
+    title: String,
+    description: Option<String>,
+    state: String, // opened, closed
+    #[serde(default)]
+    labels: Vec<String>,
+}
+
+impl From<GitLabIssue> for Issue {
+    fn from(issue: GitLabIssue) -> Self {
+        Issue {
+            number: issue.iid,
+            url: issue.web_url,
+            title: issue.title,
+            body: issue.description.unwrap_or_default(),
+            state: if issue.state == "closed" {
+                IssueState::Closed
+            } else {
+                IssueState::Open
+            },
+            labels: issue.labels,
+        }
+    }
+}
+
 /// GitLab API adapter
 pub struct GitLabAdapter {
     base_url: String,
@@ -157,6 +227,121 @@ impl GitLabAdapter {
             .map_err(|e| PlatformError::ParseError(e.to_string()))
     }
 
+    /// GET every page of a paginated list endpoint
+    ///
+    /// `endpoint` must already carry a query string. Pages are requested
+    /// until GitLab's `x-next-page` header comes back empty.
+    async fn api_get_all<T: for<'de> Deserialize<'de>>(
+        &self,
+        endpoint: &str,
+    ) -> Result<Vec<T>, PlatformError> {
+        let token = self.get_token().await?;
+        let mut items = Vec::new();
+        let mut page = "1".to_string();
+        loop {
+            let url = format!("{}/api/v4{}&page={}", self.base_url, endpoint, page);
+            let response = self
+                .http_client
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", token))
+                .send()
+                .await
+                .map_err(|e| PlatformError::NetworkError(e.to_string()))?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_default();
+                return Err(PlatformError::ApiError(format!(
+                    "GitLab API error ({}): {}",
+                    status, error_text
+                )));
+            }
+
+            let next = response
+                .headers()
+                .get("x-next-page")
+                .and_then(|v| v.to_str().ok())
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string);
+            let batch: Vec<T> = response
+                .json()
+                .await
+                .map_err(|e| PlatformError::ParseError(e.to_string()))?;
+            items.extend(batch);
+
+            match next {
+                Some(next) => page = next,
+                None => return Ok(items),
+            }
+        }
+    }
+
+    /// All non-system discussions on a merge request
+    async fn get_discussions(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<GitLabDiscussion>, PlatformError> {
+        let project_id = self.encode_project(owner, repo);
+        let discussions: Vec<GitLabDiscussion> = self
+            .api_get_all(&format!(
+                "/projects/{}/merge_requests/{}/discussions?per_page=100",
+                project_id, pull_number
+            ))
+            .await?;
+
+        Ok(discussions
+            .into_iter()
+            .filter(|d| d.notes.first().is_some_and(|n| !n.system))
+            .collect())
+    }
+
+    /// ID of the user with `username`
+    async fn user_id(&self, username: &str) -> Result<u64, PlatformError> {
+        #[derive(Deserialize)]
+        struct User {
+            id: u64,
+        }
+
+        let users: Vec<User> = self
+            .api_request(
+                reqwest::Method::GET,
+                &format!("/users?username={}", urlencoding::encode(username)),
+                None::<()>,
+            )
+            .await?;
+        users
+            .first()
+            .map(|u| u.id)
+            .ok_or_else(|| PlatformError::NotFound(format!("GitLab user '{}'", username)))
+    }
+
+    /// ID of the project milestone titled `title`
+    async fn milestone_id(&self, project_id: &str, title: &str) -> Result<u64, PlatformError> {
+        #[derive(Deserialize)]
+        struct Milestone {
+            id: u64,
+        }
+
+        let milestones: Vec<Milestone> = self
+            .api_request(
+                reqwest::Method::GET,
+                &format!(
+                    "/projects/{}/milestones?title={}",
+                    project_id,
+                    urlencoding::encode(title)
+                ),
+                None::<()>,
+            )
+            .await?;
+        milestones
+            .first()
+            .map(|m| m.id)
+            .ok_or_else(|| PlatformError::NotFound(format!("Milestone '{}'", title)))
+    }
+
     /// Make PUT request
     async fn api_put(
         &self,
@@ -534,6 +719,125 @@ impl HostingPlatform for GitLabAdapter {
         }
     }
 
+    /// GitLab has no team reviewers; `team_reviewers` must be empty
+    async fn set_pull_request_metadata(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        options: &PRCreateOptions,
+    ) -> Result<(), PlatformError> {
+        if !options.team_reviewers.is_empty() {
+            return Err(PlatformError::ApiError(
+                "Team reviewers not supported on GitLab".to_string(),
+            ));
+        }
+        if !options.has_metadata() {
+            return Ok(());
+        }
+
+        let project_id = self.encode_project(owner, repo);
+        let mut update = serde_json::Map::new();
+        if !options.reviewers.is_empty() {
+            let mut ids = Vec::new();
+            for username in &options.reviewers {
+                ids.push(self.user_id(username).await?);
+            }
+            update.insert("reviewer_ids".into(), serde_json::json!(ids));
+        }
+        if !options.assignees.is_empty() {
+            let mut ids = Vec::new();
+            for username in &options.assignees {
+                ids.push(self.user_id(username).await?);
+            }
+            update.insert("assignee_ids".into(), serde_json::json!(ids));
+        }
+        if !options.labels.is_empty() {
+            update.insert(
+                "add_labels".into(),
+                serde_json::json!(options.labels.join(",")),
+            );
+        }
+        if let Some(ref title) = options.milestone {
+            let id = self.milestone_id(&project_id, title).await?;
+            update.insert("milestone_id".into(), serde_json::json!(id));
+        }
+
+        self.api_put(
+            &format!("/projects/{}/merge_requests/{}", project_id, pull_number),
+            Some(serde_json::Value::Object(update)),
+        )
+        .await
+    }
+
+    async fn get_pull_request_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<PRComment>, PlatformError> {
+        Ok(self
+            .get_discussions(owner, repo, pull_number)
+            .await?
+            .into_iter()
+            .filter(|d| !d.notes[0].resolvable)
+            .flat_map(|d| d.notes.into_iter().map(PRComment::from))
+            .collect())
+    }
+
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let project_id = self.encode_project(owner, repo);
+
+        #[derive(Serialize)]
+        struct CreateNote {
+            body: String,
+        }
+
+        let _: serde_json::Value = self
+            .api_request(
+                reqwest::Method::POST,
+                &format!(
+                    "/projects/{}/merge_requests/{}/notes",
+                    project_id, pull_number
+                ),
+                Some(CreateNote {
+                    body: body.to_string(),
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn get_review_threads(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<ReviewThread>, PlatformError> {
+        Ok(self
+            .get_discussions(owner, repo, pull_number)
+            .await?
+            .into_iter()
+            .filter(|d| d.notes[0].resolvable)
+            .map(|d| {
+                let first = &d.notes[0];
+                let position = first.position.as_ref();
+                ReviewThread {
+                    resolved: d.notes.iter().filter(|n| n.resolvable).all(|n| n.resolved),
+                    path: position.and_then(|p| p.new_path.clone()),
+                    line: position.and_then(|p| p.new_line),
+                    comments: d.notes.into_iter().map(PRComment::from).collect(),
+                }
+            })
+            .collect())
+    }
+
     async fn get_allowed_merge_methods(
         &self,
         _owner: &str,
@@ -756,6 +1060,168 @@ impl HostingPlatform for GitLabAdapter {
         Ok(())
     }
 
+    async fn create_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: Option<&str>,
+        labels: &[String],
+    ) -> Result<IssueCreateResult, PlatformError> {
+        let project_id = self.encode_project(owner, repo);
+
+        #[derive(Serialize)]
+        struct CreateIssue {
+            title: String,
+            description: String,
+            labels: String,
+        }
+
+        let issue: GitLabIssue = self
+            .api_request(
+                reqwest::Method::POST,
+                &format!("/projects/{}/issues", project_id),
+                Some(CreateIssue {
+                    title: title.to_string(),
+                    description: body.unwrap_or("").to_string(),
+                    labels: labels.join(","),
+                }),
+            )
+            .await?;
+
+        Ok(IssueCreateResult {
+            number: issue.iid,
+            url: issue.web_url,
+        })
+    }
+
+    async fn get_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+    ) -> Result<Issue, PlatformError> {
+        let project_id = self.encode_project(owner, repo);
+
+        let issue: GitLabIssue = self
+            .api_request(
+                reqwest::Method::GET,
+                &format!("/projects/{}/issues/{}", project_id, number),
+                None::<()>,
+            )
+            .await?;
+
+        Ok(issue.into())
+    }
+
+    async fn list_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: IssueState,
+        labels: &[String],
+    ) -> Result<Vec<Issue>, PlatformError> {
+        let project_id = self.encode_project(owner, repo);
+        let state = match state {
+            IssueState::Open => "opened",
+            IssueState::Closed => "closed",
+        };
+        let mut endpoint = format!(
+            "/projects/{}/issues?state={}&per_page=100",
+            project_id, state
+        );
+        if !labels.is_empty() {
+            endpoint.push_str(&format!(
+                "&labels={}",
+                urlencoding::encode(&labels.join(","))
+            ));
+        }
+
+        let issues: Vec<GitLabIssue> = self.api_get_all(&endpoint).await?;
+
+        Ok(issues.into_iter().map(Issue::from).collect())
+    }
+
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let project_id = self.encode_project(owner, repo);
+
+        #[derive(Serialize)]
+        struct CreateNote {
+            body: String,
+        }
+
+        let _: serde_json::Value = self
+            .api_request(
+                reqwest::Method::POST,
+                &format!("/projects/{}/issues/{}/notes", project_id, number),
+                Some(CreateNote {
+                    body: body.to_string(),
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn update_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<(), PlatformError> {
+        let project_id = self.encode_project(owner, repo);
+
+        #[derive(Serialize)]
+        struct UpdateIssue<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            title: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            description: Option<&'a str>,
+        }
+
+        self.api_put(
+            &format!("/projects/{}/issues/{}", project_id, number),
+            Some(UpdateIssue {
+                title,
+                description: body,
+            }),
+        )
+        .await
+    }
+
+    async fn close_issue(&self, owner: &str, repo: &str, number: u64) -> Result<(), PlatformError> {
+        let project_id = self.encode_project(owner, repo);
+
+        self.api_put(
+            &format!("/projects/{}/issues/{}", project_id, number),
+            Some(serde_json::json!({ "state_event": "close" })),
+        )
+        .await
+    }
+
+    async fn add_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        labels: &[String],
+    ) -> Result<(), PlatformError> {
+        let project_id = self.encode_project(owner, repo);
+
+        self.api_put(
+            &format!("/projects/{}/issues/{}", project_id, number),
+            Some(serde_json::json!({ "add_labels": labels.join(",") })),
+        )
+        .await
+    }
+
     fn generate_linked_pr_comment(&self, links: &[LinkedPRRef]) -> String {
         if links.is_empty() {
             return String::new();
@@ -0,0 +1,1072 @@
+//! Local file-backed platform adapter
+//!
+//! Serves pull requests for bare repos on disk, with no network. `base_url`
+//! is the directory holding the bare repos (`<repo>.git` or `<repo>`);
+//! platform data lives beside them as JSON:
+//!
+//! ```text
+//! <base>/.gitgrip-platform/<repo>/pulls/<number>.json
+//! <base>/.gitgrip-platform/<repo>/checks/<sha>.json
+//! <base>/.gitgrip-platform/<repo>/releases/<tag>.json
+//! <base>/.gitgrip-platform/<repo>/issues/<number>.json
+//! ```
+//!
+//! Merges are real commits on the bare repo's base branch.
+
+use async_trait::async_trait;
+use git2::{BranchType, Commit, DiffFormat, Oid, Repository, Signature, Sort};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::traits::{HostingPlatform, PlatformError};
+use super::types::*;
+use crate::core::manifest::PlatformType;
+
+/// Directory (beside the bare repos) holding platform data
+const DATA_DIR: &str = ".gitgrip-platform";
+
+/// A stored pull request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LocalPullRequest {
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: String,
+    state: PRState,
+    #[serde(default)]
+    draft: bool,
+    /// Head branch
+    head: String,
+    /// Base branch
+    base: String,
+    /// Head commit when the PR was merged or closed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    head_sha: Option<String>,
+    /// Commit the base branch moved to on merge
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    merge_commit: Option<String>,
+    /// Reviews, oldest first
+    #[serde(default)]
+    reviews: Vec<PRReview>,
+    /// Conversation comments, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    comments: Vec<PRComment>,
+    /// Review threads, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    threads: Vec<ReviewThread>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    reviewers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    team_reviewers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    assignees: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    milestone: Option<String>,
+}
+
+/// A stored release
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LocalRelease {
+    id: u64,
+    tag: String,
+    name: String,
+    #[serde(default)]
+    body: String,
+    target_commitish: String,
+    draft: bool,
+    prerelease: bool,
+}
+
+/// A stored issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LocalIssue {
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: String,
+    state: IssueState,
+    #[serde(default)]
+    labels: Vec<String>,
+    /// Comment bodies, oldest first
+    #[serde(default)]
+    comments: Vec<String>,
+}
+
+/// Local file-backed platform adapter
+pub struct LocalAdapter {
+    base_dir: Option<PathBuf>,
+}
+
+impl LocalAdapter {
+    /// Create a local adapter for the bare repos in `base_url` (a directory
+    /// path or `file://` URL)
+    pub fn new(base_url: Option<&str>) -> Self {
+        Self {
+            base_dir: base_url.map(|url| PathBuf::from(url.trim_start_matches("file://"))),
+        }
+    }
+
+    fn base_dir(&self) -> Result<&Path, PlatformError> {
+        self.base_dir.as_deref().ok_or_else(|| {
+            PlatformError::ApiError(
+                "Local platform needs a base_url (the directory holding the bare repos)"
+                    .to_string(),
+            )
+        })
+    }
+
+    fn data_dir(&self, repo: &str) -> Result<PathBuf, PlatformError> {
+        Ok(self.base_dir()?.join(DATA_DIR).join(repo))
+    }
+
+    fn open_remote(&self, repo: &str) -> Result<Repository, PlatformError> {
+        let base = self.base_dir()?;
+        let path = [base.join(format!("{}.git", repo)), base.join(repo)]
+            .into_iter()
+            .find(|p| p.is_dir())
+            .ok_or_else(|| {
+                PlatformError::NotFound(format!("No bare repo for {} in {}", repo, base.display()))
+            })?;
+        Repository::open_bare(&path).map_err(git_error)
+    }
+
+    fn pull_path(&self, repo: &str, number: u64) -> Result<PathBuf, PlatformError> {
+        Ok(self
+            .data_dir(repo)?
+            .join("pulls")
+            .join(format!("{}.json", number)))
+    }
+
+    fn load_pull(&self, repo: &str, number: u64) -> Result<LocalPullRequest, PlatformError> {
+        let path = self.pull_path(repo, number)?;
+        if !path.exists() {
+            return Err(PlatformError::NotFound(format!(
+                "PR #{} not found in {}",
+                number, repo
+            )));
+        }
+        read_json(&path)
+    }
+
+    fn save_pull(&self, repo: &str, pr: &LocalPullRequest) -> Result<(), PlatformError> {
+        write_json(&self.pull_path(repo, pr.number)?, pr)
+    }
+
+    /// All stored pull requests, by number
+    fn load_pulls(&self, repo: &str) -> Result<Vec<LocalPullRequest>, PlatformError> {
+        let dir = self.data_dir(repo)?.join("pulls");
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            return Ok(Vec::new());
+        };
+        let mut pulls = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(io_error)?.path();
+            if path.extension().is_some_and(|e| e == "json") {
+                pulls.push(read_json::<LocalPullRequest>(&path)?);
+            }
+        }
+        pulls.sort_by_key(|pr| pr.number);
+        Ok(pulls)
+    }
+
+    fn pull_url(&self, repo: &str, number: u64) -> Result<String, PlatformError> {
+        Ok(format!(
+            "file://{}",
+            self.pull_path(repo, number)?.display()
+        ))
+    }
+
+    fn issue_path(&self, repo: &str, number: u64) -> Result<PathBuf, PlatformError> {
+        Ok(self
+            .data_dir(repo)?
+            .join("issues")
+            .join(format!("{}.json", number)))
+    }
+
+    fn load_issue(&self, repo: &str, number: u64) -> Result<LocalIssue, PlatformError> {
+        let path = self.issue_path(repo, number)?;
+        if !path.exists() {
+            return Err(PlatformError::NotFound(format!(
+                "Issue #{} not found in {}",
+                number, repo
+            )));
+        }
+        read_json(&path)
+    }
+
+    fn save_issue(&self, repo: &str, issue: &LocalIssue) -> Result<(), PlatformError> {
+        write_json(&self.issue_path(repo, issue.number)?, issue)
+    }
+
+    /// All stored issues, by number
+    fn load_issues(&self, repo: &str) -> Result<Vec<LocalIssue>, PlatformError> {
+        let dir = self.data_dir(repo)?.join("issues");
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            return Ok(Vec::new());
+        };
+        let mut issues = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(io_error)?.path();
+            if path.extension().is_some_and(|e| e == "json") {
+                issues.push(read_json::<LocalIssue>(&path)?);
+            }
+        }
+        issues.sort_by_key(|issue| issue.number);
+        Ok(issues)
+    }
+
+    fn to_issue(&self, repo: &str, issue: LocalIssue) -> Result<Issue, PlatformError> {
+        Ok(Issue {
+            url: format!("file://{}", self.issue_path(repo, issue.number)?.display()),
+            number: issue.number,
+            title: issue.title,
+            body: issue.body,
+            state: issue.state,
+            labels: issue.labels,
+        })
+    }
+
+    /// Title, body, draft flag, reviewers, labels, assignees and milestone of
+    /// a pull request
+    pub fn pull_request_options(
+        &self,
+        repo: &str,
+        number: u64,
+    ) -> Result<PRCreateOptions, PlatformError> {
+        let pr = self.load_pull(repo, number)?;
+        Ok(PRCreateOptions {
+            title: pr.title,
+            body: Some(pr.body),
+            base: Some(pr.base),
+            draft: Some(pr.draft),
+            reviewers: pr.reviewers,
+            team_reviewers: pr.team_reviewers,
+            labels: pr.labels,
+            assignees: pr.assignees,
+            milestone: pr.milestone,
+        })
+    }
+
+    /// Comments on an issue, oldest first
+    pub fn issue_comments(&self, repo: &str, number: u64) -> Result<Vec<String>, PlatformError> {
+        Ok(self.load_issue(repo, number)?.comments)
+    }
+
+    /// Record a review on a pull request (state `APPROVED`,
+    /// `CHANGES_REQUESTED` or `COMMENTED`)
+    pub fn add_review(
+        &self,
+        repo: &str,
+        pull_number: u64,
+        user: &str,
+        state: &str,
+    ) -> Result<(), PlatformError> {
+        let mut pr = self.load_pull(repo, pull_number)?;
+        pr.reviews.push(PRReview {
+            state: state.to_string(),
+            user: user.to_string(),
+        });
+        self.save_pull(repo, &pr)
+    }
+
+    /// Start a review thread on `path`:`line` of a pull request
+    pub fn add_review_thread(
+        &self,
+        repo: &str,
+        pull_number: u64,
+        user: &str,
+        path: &str,
+        line: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let mut pr = self.load_pull(repo, pull_number)?;
+        pr.threads.push(ReviewThread {
+            resolved: false,
+            path: Some(path.to_string()),
+            line: Some(line),
+            comments: vec![PRComment {
+                author: user.to_string(),
+                body: body.to_string(),
+                created_at: Some(chrono::Utc::now().to_rfc3339()),
+            }],
+        });
+        self.save_pull(repo, &pr)
+    }
+
+    /// Mark the review thread at `index` (oldest first) resolved
+    pub fn resolve_review_thread(
+        &self,
+        repo: &str,
+        pull_number: u64,
+        index: usize,
+    ) -> Result<(), PlatformError> {
+        let mut pr = self.load_pull(repo, pull_number)?;
+        let thread = pr.threads.get_mut(index).ok_or_else(|| {
+            PlatformError::NotFound(format!(
+                "Review thread {} not found on PR #{}",
+                index, pull_number
+            ))
+        })?;
+        thread.resolved = true;
+        self.save_pull(repo, &pr)
+    }
+
+    /// Record the result of a check on the commit `ref_name` resolves to
+    pub fn set_status_check(
+        &self,
+        repo: &str,
+        ref_name: &str,
+        context: &str,
+        state: CheckState,
+    ) -> Result<(), PlatformError> {
+        let path = self.checks_path(repo, ref_name)?;
+        let mut checks: Vec<StatusCheck> = if path.exists() {
+            read_json(&path)?
+        } else {
+            Vec::new()
+        };
+        checks.retain(|c| c.context != context);
+        checks.push(StatusCheck {
+            context: context.to_string(),
+            state: state.to_string(),
+        });
+        write_json(&path, &checks)
+    }
+
+    fn checks_path(&self, repo: &str, ref_name: &str) -> Result<PathBuf, PlatformError> {
+        let remote = self.open_remote(repo)?;
+        let sha = resolve(&remote, ref_name)?.id();
+        Ok(self
+            .data_dir(repo)?
+            .join("checks")
+            .join(format!("{}.json", sha)))
+    }
+
+    /// Whether `head` merges into `base` without conflicts
+    fn is_mergeable(remote: &Repository, base: &Commit, head: &Commit) -> bool {
+        remote
+            .merge_commits(base, head, None)
+            .map(|index| !index.has_conflicts())
+            .unwrap_or(false)
+    }
+
+    /// Merge `pr` into its base branch and return the new base commit
+    fn merge_into_base(
+        remote: &Repository,
+        pr: &LocalPullRequest,
+        method: MergeMethod,
+    ) -> Result<Oid, PlatformError> {
+        let base_ref = format!("refs/heads/{}", pr.base);
+        let base = resolve(remote, &pr.base)?;
+        let head = resolve(remote, &pr.head)?;
+        let signature = signature(remote)?;
+        let conflict =
+            || PlatformError::ApiError(format!("PR #{} has conflicts with {}", pr.number, pr.base));
+
+        let new_base = match method {
+            MergeMethod::Merge | MergeMethod::Squash => {
+                let mut index = remote
+                    .merge_commits(&base, &head, None)
+                    .map_err(git_error)?;
+                if index.has_conflicts() {
+                    return Err(conflict());
+                }
+                let tree = remote
+                    .find_tree(index.write_tree_to(remote).map_err(git_error)?)
+                    .map_err(git_error)?;
+                let (message, parents) = if method == MergeMethod::Merge {
+                    (
+                        format!(
+                            "Merge pull request #{} from {}\n\n{}",
+                            pr.number, pr.head, pr.title
+                        ),
+                        vec![&base, &head],
+                    )
+                } else {
+                    (format!("{} (#{})", pr.title, pr.number), vec![&base])
+                };
+                remote
+                    .commit(None, &signature, &signature, &message, &tree, &parents)
+                    .map_err(git_error)?
+            }
+            MergeMethod::Rebase => {
+                let mut walk = remote.revwalk().map_err(git_error)?;
+                walk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)
+                    .map_err(git_error)?;
+                walk.push(head.id()).map_err(git_error)?;
+                walk.hide(base.id()).map_err(git_error)?;
+
+                let mut onto = base.clone();
+                for oid in walk {
+                    let commit = remote
+                        .find_commit(oid.map_err(git_error)?)
+                        .map_err(git_error)?;
+                    if commit.parent_count() > 1 {
+                        continue;
+                    }
+                    let mut index = remote
+                        .cherrypick_commit(&commit, &onto, 0, None)
+                        .map_err(git_error)?;
+                    if index.has_conflicts() {
+                        return Err(conflict());
+                    }
+                    let tree = remote
+                        .find_tree(index.write_tree_to(remote).map_err(git_error)?)
+                        .map_err(git_error)?;
+                    let oid = remote
+                        .commit(
+                            None,
+                            &commit.author(),
+                            &signature,
+                            commit.message().unwrap_or_default(),
+                            &tree,
+                            &[&onto],
+                        )
+                        .map_err(git_error)?;
+                    onto = remote.find_commit(oid).map_err(git_error)?;
+                }
+                onto.id()
+            }
+        };
+
+        remote
+            .reference_matching(
+                &base_ref,
+                new_base,
+                true,
+                base.id(),
+                &format!("gitgrip: merge PR #{}", pr.number),
+            )
+            .map_err(git_error)?;
+        Ok(new_base)
+    }
+}
+
+fn git_error(e: git2::Error) -> PlatformError {
+    PlatformError::ApiError(e.message().to_string())
+}
+
+fn io_error(e: std::io::Error) -> PlatformError {
+    PlatformError::ApiError(e.to_string())
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, PlatformError> {
+    let content = std::fs::read_to_string(path).map_err(io_error)?;
+    serde_json::from_str(&content)
+        .map_err(|e| PlatformError::ParseError(format!("{}: {}", path.display(), e)))
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), PlatformError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| PlatformError::ParseError(e.to_string()))?;
+    std::fs::write(path, content).map_err(io_error)
+}
+
+/// Resolve a branch name or revision in the bare repo to a commit
+fn resolve<'r>(remote: &'r Repository, ref_name: &str) -> Result<Commit<'r>, PlatformError> {
+    remote
+        .find_branch(ref_name, BranchType::Local)
+        .map(|b| b.into_reference())
+        .and_then(|r| r.peel_to_commit())
+        .or_else(|_| remote.revparse_single(ref_name)?.peel_to_commit())
+        .map_err(|_| PlatformError::NotFound(format!("{} not found", ref_name)))
+}
+
+/// Committer for merges: the bare repo's git identity, or a fixed one
+fn signature(remote: &Repository) -> Result<Signature<'static>, PlatformError> {
+    remote
+        .signature()
+        .or_else(|_| Signature::now("gitgrip", "gitgrip@localhost"))
+        .map_err(git_error)
+}
+
+#[async_trait]
+impl HostingPlatform for LocalAdapter {
+    fn platform_type(&self) -> PlatformType {
+        PlatformType::Local
+    }
+
+    async fn get_token(&self) -> Result<String, PlatformError> {
+        // Nothing to authenticate against
+        Ok(String::new())
+    }
+
+    async fn create_pull_request(
+        &self,
+        _owner: &str,
+        repo: &str,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: Option<&str>,
+        draft: bool,
+    ) -> Result<PRCreateResult, PlatformError> {
+        let remote = self.open_remote(repo)?;
+        resolve(&remote, head)?;
+        resolve(&remote, base)?;
+
+        let pulls = self.load_pulls(repo)?;
+        if let Some(pr) = pulls
+            .iter()
+            .find(|pr| pr.state == PRState::Open && pr.head == head && pr.base == base)
+        {
+            return Err(PlatformError::ApiError(format!(
+                "PR #{} for {} is already open",
+                pr.number, head
+            )));
+        }
+
+        let pr = LocalPullRequest {
+            number: pulls.last().map(|pr| pr.number).unwrap_or(0) + 1,
+            title: title.to_string(),
+            body: body.unwrap_or_default().to_string(),
+            state: PRState::Open,
+            draft,
+            head: head.to_string(),
+            base: base.to_string(),
+            head_sha: None,
+            merge_commit: None,
+            reviews: Vec::new(),
+            comments: Vec::new(),
+            threads: Vec::new(),
+            reviewers: Vec::new(),
+            team_reviewers: Vec::new(),
+            labels: Vec::new(),
+            assignees: Vec::new(),
+            milestone: None,
+        };
+        self.save_pull(repo, &pr)?;
+
+        Ok(PRCreateResult {
+            number: pr.number,
+            url: self.pull_url(repo, pr.number)?,
+        })
+    }
+
+    async fn get_pull_request(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<PullRequest, PlatformError> {
+        let pr = self.load_pull(repo, pull_number)?;
+        let remote = self.open_remote(repo)?;
+
+        let head = resolve(&remote, &pr.head).ok();
+        let mergeable = match (pr.state, &head, resolve(&remote, &pr.base)) {
+            (PRState::Open, Some(head), Ok(base)) => Some(Self::is_mergeable(&remote, &base, head)),
+            _ => None,
+        };
+        let sha = pr
+            .head_sha
+            .clone()
+            .or_else(|| head.map(|c| c.id().to_string()))
+            .unwrap_or_default();
+
+        Ok(PullRequest {
+            number: pr.number,
+            url: self.pull_url(repo, pr.number)?,
+            title: pr.title,
+            body: pr.body,
+            state: pr.state,
+            merged: pr.state == PRState::Merged,
+            mergeable,
+            head: PRHead {
+                ref_name: pr.head,
+                sha,
+            },
+            base: PRBase { ref_name: pr.base },
+        })
+    }
+
+    async fn update_pull_request_body(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let mut pr = self.load_pull(repo, pull_number)?;
+        pr.body = body.to_string();
+        self.save_pull(repo, &pr)
+    }
+
+    async fn merge_pull_request(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+        method: Option<MergeMethod>,
+        delete_branch: bool,
+    ) -> Result<bool, PlatformError> {
+        let mut pr = self.load_pull(repo, pull_number)?;
+        if pr.state != PRState::Open {
+            return Err(PlatformError::ApiError(format!(
+                "PR #{} is {}",
+                pull_number, pr.state
+            )));
+        }
+
+        let remote = self.open_remote(repo)?;
+        let head_sha = resolve(&remote, &pr.head)?.id().to_string();
+        let merge_commit = Self::merge_into_base(&remote, &pr, method.unwrap_or_default())?;
+
+        if delete_branch {
+            if let Ok(mut branch) = remote.find_branch(&pr.head, BranchType::Local) {
+                branch.delete().map_err(git_error)?;
+            }
+        }
+
+        pr.state = PRState::Merged;
+        pr.head_sha = Some(head_sha);
+        pr.merge_commit = Some(merge_commit.to_string());
+        self.save_pull(repo, &pr)?;
+        Ok(true)
+    }
+
+    async fn find_pr_by_branch(
+        &self,
+        _owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Option<PRCreateResult>, PlatformError> {
+        let Some(pr) = self
+            .load_pulls(repo)?
+            .into_iter()
+            .find(|pr| pr.state == PRState::Open && pr.head == branch)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(PRCreateResult {
+            number: pr.number,
+            url: self.pull_url(repo, pr.number)?,
+        }))
+    }
+
+    async fn is_pull_request_approved(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<bool, PlatformError> {
+        let reviews = self
+            .get_pull_request_reviews(owner, repo, pull_number)
+            .await?;
+
+        // Latest review per user counts
+        let mut latest: HashMap<&str, &str> = HashMap::new();
+        for review in &reviews {
+            if matches!(review.state.as_str(), "APPROVED" | "CHANGES_REQUESTED") {
+                latest.insert(&review.user, &review.state);
+            }
+        }
+        Ok(latest.values().any(|s| *s == "APPROVED")
+            && !latest.values().any(|s| *s == "CHANGES_REQUESTED"))
+    }
+
+    async fn get_pull_request_reviews(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<PRReview>, PlatformError> {
+        Ok(self.load_pull(repo, pull_number)?.reviews)
+    }
+
+    async fn set_pull_request_metadata(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+        options: &PRCreateOptions,
+    ) -> Result<(), PlatformError> {
+        let mut pr = self.load_pull(repo, pull_number)?;
+        for (list, add) in [
+            (&mut pr.reviewers, &options.reviewers),
+            (&mut pr.team_reviewers, &options.team_reviewers),
+            (&mut pr.labels, &options.labels),
+            (&mut pr.assignees, &options.assignees),
+        ] {
+            for name in add {
+                if !list.contains(name) {
+                    list.push(name.clone());
+                }
+            }
+        }
+        if options.milestone.is_some() {
+            pr.milestone = options.milestone.clone();
+        }
+        self.save_pull(repo, &pr)
+    }
+
+    async fn get_pull_request_comments(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<PRComment>, PlatformError> {
+        Ok(self.load_pull(repo, pull_number)?.comments)
+    }
+
+    async fn create_pull_request_comment(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let author = signature(&self.open_remote(repo)?)?
+            .name()
+            .unwrap_or_default()
+            .to_string();
+        let mut pr = self.load_pull(repo, pull_number)?;
+        pr.comments.push(PRComment {
+            author,
+            body: body.to_string(),
+            created_at: Some(chrono::Utc::now().to_rfc3339()),
+        });
+        self.save_pull(repo, &pr)
+    }
+
+    async fn get_review_threads(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<Vec<ReviewThread>, PlatformError> {
+        Ok(self.load_pull(repo, pull_number)?.threads)
+    }
+
+    async fn get_status_checks(
+        &self,
+        _owner: &str,
+        repo: &str,
+        ref_name: &str,
+    ) -> Result<StatusCheckResult, PlatformError> {
+        let path = self.checks_path(repo, ref_name)?;
+        let statuses: Vec<StatusCheck> = if path.exists() {
+            read_json(&path)?
+        } else {
+            Vec::new()
+        };
+
+        let state = if statuses.iter().any(|s| s.state == "failure") {
+            CheckState::Failure
+        } else if statuses.iter().any(|s| s.state == "pending") {
+            CheckState::Pending
+        } else {
+            // No checks recorded counts as passing
+            CheckState::Success
+        };
+
+        Ok(StatusCheckResult { state, statuses })
+    }
+
+    async fn get_allowed_merge_methods(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<AllowedMergeMethods, PlatformError> {
+        Ok(AllowedMergeMethods::default())
+    }
+
+    async fn get_pull_request_diff(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<String, PlatformError> {
+        let pr = self.load_pull(repo, pull_number)?;
+        let remote = self.open_remote(repo)?;
+        let head = match &pr.head_sha {
+            Some(sha) => resolve(&remote, sha)?,
+            None => resolve(&remote, &pr.head)?,
+        };
+        let base = resolve(&remote, &pr.base)?;
+
+        let merge_base = remote.merge_base(base.id(), head.id()).map_err(git_error)?;
+        let old_tree = remote
+            .find_commit(merge_base)
+            .and_then(|c| c.tree())
+            .map_err(git_error)?;
+        let new_tree = head.tree().map_err(git_error)?;
+        let diff = remote
+            .diff_tree_to_tree(Some(&old_tree), Some(&new_tree), None)
+            .map_err(git_error)?;
+
+        let mut patch = String::new();
+        diff.print(DiffFormat::Patch, |_, _, line| {
+            if matches!(line.origin(), '+' | '-' | ' ') {
+                patch.push(line.origin());
+            }
+            patch.push_str(&String::from_utf8_lossy(line.content()));
+            true
+        })
+        .map_err(git_error)?;
+        Ok(patch)
+    }
+
+    fn parse_repo_url(&self, url: &str) -> Option<ParsedRepoInfo> {
+        let path = url.strip_prefix("file://")?.trim_end_matches('/');
+        let repo = path.rsplit('/').next()?.trim_end_matches(".git");
+        if repo.is_empty() {
+            return None;
+        }
+
+        Some(ParsedRepoInfo {
+            owner: "local".to_string(),
+            repo: repo.to_string(),
+            project: None,
+            platform: Some(PlatformType::Local),
+        })
+    }
+
+    fn matches_url(&self, url: &str) -> bool {
+        url.starts_with("file://")
+    }
+
+    async fn update_branch(
+        &self,
+        _owner: &str,
+        repo: &str,
+        pull_number: u64,
+    ) -> Result<bool, PlatformError> {
+        let pr = self.load_pull(repo, pull_number)?;
+        let remote = self.open_remote(repo)?;
+        let base = resolve(&remote, &pr.base)?;
+        let head = resolve(&remote, &pr.head)?;
+
+        if head.id() == base.id()
+            || remote
+                .graph_descendant_of(head.id(), base.id())
+                .map_err(git_error)?
+        {
+            return Ok(false);
+        }
+
+        let mut index = remote
+            .merge_commits(&head, &base, None)
+            .map_err(git_error)?;
+        if index.has_conflicts() {
+            return Err(PlatformError::ApiError(format!(
+                "{} has conflicts with {}",
+                pr.head, pr.base
+            )));
+        }
+        let tree = remote
+            .find_tree(index.write_tree_to(&remote).map_err(git_error)?)
+            .map_err(git_error)?;
+        let signature = signature(&remote)?;
+        let oid = remote
+            .commit(
+                None,
+                &signature,
+                &signature,
+                &format!("Merge branch '{}' into {}", pr.base, pr.head),
+                &tree,
+                &[&head, &base],
+            )
+            .map_err(git_error)?;
+        remote
+            .reference_matching(
+                &format!("refs/heads/{}", pr.head),
+                oid,
+                true,
+                head.id(),
+                "gitgrip: update branch",
+            )
+            .map_err(git_error)?;
+        Ok(true)
+    }
+
+    async fn create_release(
+        &self,
+        _owner: &str,
+        repo: &str,
+        tag: &str,
+        name: &str,
+        body: Option<&str>,
+        target_commitish: &str,
+        draft: bool,
+        prerelease: bool,
+    ) -> Result<ReleaseResult, PlatformError> {
+        let remote = self.open_remote(repo)?;
+        if remote
+            .find_reference(&format!("refs/tags/{}", tag))
+            .is_err()
+        {
+            let target = resolve(&remote, target_commitish)?;
+            remote
+                .tag_lightweight(tag, target.as_object(), false)
+                .map_err(git_error)?;
+        }
+
+        let dir = self.data_dir(repo)?.join("releases");
+        let path = dir.join(format!("{}.json", tag));
+        let id = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries.count() as u64 + 1,
+            Err(_) => 1,
+        };
+        let release = LocalRelease {
+            id,
+            tag: tag.to_string(),
+            name: name.to_string(),
+            body: body.unwrap_or_default().to_string(),
+            target_commitish: target_commitish.to_string(),
+            draft,
+            prerelease,
+        };
+        write_json(&path, &release)?;
+
+        Ok(ReleaseResult {
+            id: release.id,
+            tag: release.tag,
+            url: format!("file://{}", path.display()),
+        })
+    }
+
+    async fn create_issue(
+        &self,
+        _owner: &str,
+        repo: &str,
+        title: &str,
+        body: Option<&str>,
+        labels: &[String],
+    ) -> Result<IssueCreateResult, PlatformError> {
+        self.open_remote(repo)?;
+        let issue = LocalIssue {
+            number: self
+                .load_issues(repo)?
+                .last()
+                .map(|i| i.number)
+                .unwrap_or(0)
+                + 1,
+            title: title.to_string(),
+            body: body.unwrap_or_default().to_string(),
+            state: IssueState::Open,
+            labels: labels.to_vec(),
+            comments: Vec::new(),
+        };
+        self.save_issue(repo, &issue)?;
+
+        Ok(IssueCreateResult {
+            number: issue.number,
+            url: format!("file://{}", self.issue_path(repo, issue.number)?.display()),
+        })
+    }
+
+    async fn get_issue(
+        &self,
+        _owner: &str,
+        repo: &str,
+        number: u64,
+    ) -> Result<Issue, PlatformError> {
+        let issue = self.load_issue(repo, number)?;
+        self.to_issue(repo, issue)
+    }
+
+    async fn list_issues(
+        &self,
+        _owner: &str,
+        repo: &str,
+        state: IssueState,
+        labels: &[String],
+    ) -> Result<Vec<Issue>, PlatformError> {
+        self.load_issues(repo)?
+            .into_iter()
+            .filter(|i| i.state == state && labels.iter().all(|l| i.labels.contains(l)))
+            .map(|i| self.to_issue(repo, i))
+            .collect()
+    }
+
+    async fn create_issue_comment(
+        &self,
+        _owner: &str,
+        repo: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<(), PlatformError> {
+        let mut issue = self.load_issue(repo, number)?;
+        issue.comments.push(body.to_string());
+        self.save_issue(repo, &issue)
+    }
+
+    async fn update_issue(
+        &self,
+        _owner: &str,
+        repo: &str,
+        number: u64,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<(), PlatformError> {
+        let mut issue = self.load_issue(repo, number)?;
+        if let Some(title) = title {
+            issue.title = title.to_string();
+        }
+        if let Some(body) = body {
+            issue.body = body.to_string();
+        }
+        self.save_issue(repo, &issue)
+    }
+
+    async fn close_issue(
+        &self,
+        _owner: &str,
+        repo: &str,
+        number: u64,
+    ) -> Result<(), PlatformError> {
+        let mut issue = self.load_issue(repo, number)?;
+        issue.state = IssueState::Closed;
+        self.save_issue(repo, &issue)
+    }
+
+    async fn add_issue_labels(
+        &self,
+        _owner: &str,
+        repo: &str,
+        number: u64,
+        labels: &[String],
+    ) -> Result<(), PlatformError> {
+        let mut issue = self.load_issue(repo, number)?;
+        for label in labels {
+            if !issue.labels.contains(label) {
+                issue.labels.push(label.clone());
+            }
+        }
+        self.save_issue(repo, &issue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repo_url() {
+        let adapter = LocalAdapter::new(None);
+        let info = adapter
+            .parse_repo_url("file:///srv/remotes/app.git")
+            .unwrap();
+        assert_eq!(info.repo, "app");
+        assert_eq!(info.platform, Some(PlatformType::Local));
+        assert!(adapter
+            .parse_repo_url("git@github.com:org/app.git")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_requires_base_url() {
+        let adapter = LocalAdapter::new(None);
+        let result = adapter.get_pull_request("local", "app", 1).await;
+        assert!(matches!(result, Err(PlatformError::ApiError(_))));
+    }
+}
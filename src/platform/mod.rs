@@ -1,20 +1,23 @@
 //! Hosting platform adapters
 //!
-//! Provides a unified interface for GitHub, GitLab, and Azure DevOps.
+//! Provides a unified interface for GitHub, GitLab, Azure DevOps, Bitbucket,
+//! Gitea/Forgejo and local bare repos.
 
 pub mod azure;
 pub mod bitbucket;
+pub mod gitea;
 pub mod github;
 pub mod gitlab;
+pub mod local;
 pub mod rate_limit;
 pub mod traits;
 pub mod types;
 
 pub use traits::HostingPlatform;
 pub use types::{
-    AllowedMergeMethods, CheckState, CheckStatusDetails, MergeMethod, PRBase, PRCreateResult,
-    PRHead, PRReview, PRState, ParsedRepoInfo, PullRequest, ReleaseResult, StatusCheck,
-    StatusCheckResult,
+    AllowedMergeMethods, CheckState, CheckStatusDetails, Issue, IssueCreateResult, IssueState,
+    MergeMethod, PRBase, PRComment, PRCreateOptions, PRCreateResult, PRHead, PRReview, PRState,
+    ParsedRepoInfo, PullRequest, ReleaseResult, ReviewThread, StatusCheck, StatusCheckResult,
 };
 
 use crate::core::manifest::PlatformType;
@@ -30,6 +33,8 @@ pub fn get_platform_adapter(
         PlatformType::GitLab => Arc::new(gitlab::GitLabAdapter::new(base_url)),
         PlatformType::AzureDevOps => Arc::new(azure::AzureDevOpsAdapter::new(base_url)),
         PlatformType::Bitbucket => Arc::new(bitbucket::BitbucketAdapter::new(base_url)),
+        PlatformType::Gitea => Arc::new(gitea::GiteaAdapter::new(base_url)),
+        PlatformType::Local => Arc::new(local::LocalAdapter::new(base_url)),
     }
 }
 
@@ -55,10 +60,26 @@ pub fn detect_platform(url: &str) -> PlatformType {
         return PlatformType::GitLab;
     }
 
+    // Gitea/Forgejo instances are recognised by hostname only
+    let host = url_host(url);
+    if host.contains("gitea.") || host.contains("forgejo.") || host == "codeberg.org" {
+        return PlatformType::Gitea;
+    }
+
     // Default to GitHub for backward compatibility
     PlatformType::GitHub
 }
 
+/// Host of a git URL (`git@host:path` or `scheme://[user@]host[:port]/path`)
+pub(crate) fn url_host(url: &str) -> &str {
+    let authority = match url.split_once("://") {
+        Some((_, rest)) => rest.split('/').next().unwrap_or(rest),
+        None => url.split(':').next().unwrap_or(url),
+    };
+    let host = authority.rsplit('@').next().unwrap_or(authority);
+    host.split(':').next().unwrap_or(host)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,6 +116,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_gitea() {
+        assert_eq!(
+            detect_platform("git@codeberg.org:user/repo.git"),
+            PlatformType::Gitea
+        );
+        assert_eq!(
+            detect_platform("https://forgejo.example.com/org/repo.git"),
+            PlatformType::Gitea
+        );
+        assert_eq!(
+            detect_platform("ssh://git@gitea.internal:2222/org/repo.git"),
+            PlatformType::Gitea
+        );
+    }
+
+    #[test]
+    fn test_detect_gitlab_with_gitea_in_path() {
+        assert_eq!(
+            detect_platform("git@gitlab.com:org/gitea.chart.git"),
+            PlatformType::GitLab
+        );
+        assert_eq!(
+            detect_platform("https://gitlab.com/x/forgejo.nvim"),
+            PlatformType::GitLab
+        );
+        assert_eq!(
+            detect_platform("git@example.com:org/gitea.chart.git"),
+            PlatformType::GitHub
+        );
+    }
+
     #[test]
     fn test_default_to_github() {
         assert_eq!(
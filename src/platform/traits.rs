@@ -41,6 +41,13 @@ pub struct LinkedPRRef {
     pub number: u64,
 }
 
+/// Linked issue reference for cross-repo tracking issues
+#[derive(Debug, Clone)]
+pub struct LinkedIssueRef {
+    pub repo_name: String,
+    pub number: u64,
+}
+
 /// Interface for hosting platform adapters
 /// Each platform (GitHub, GitLab, Azure DevOps) implements this trait
 #[async_trait]
@@ -224,59 +231,232 @@ pub trait HostingPlatform: Send + Sync {
         ))
     }
 
-    /// Generate HTML comment for linked PR tracking
-    fn generate_linked_pr_comment(&self, links: &[LinkedPRRef]) -> String {
-        if links.is_empty() {
-            return String::new();
+    /// Request reviewers and add labels, assignees and milestone from
+    /// `options` to an existing pull request
+    async fn set_pull_request_metadata(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pull_number: u64,
+        options: &PRCreateOptions,
+    ) -> Result<(), PlatformError> {
+        if !options.has_metadata() {
+            return Ok(());
         }
+        Err(PlatformError::ApiError(
+            "PR reviewers, labels and assignees not supported on this platform".to_string(),
+        ))
+    }
 
-        let mut comment = String::from("<!-- gitgrip-linked-prs\n");
-        for link in links {
-            comment.push_str(&format!("{}:{}\n", link.repo_name, link.number));
-        }
-        comment.push_str("-->");
-        comment
+    /// Get the conversation comments on a pull request (not those in review threads)
+    async fn get_pull_request_comments(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pull_number: u64,
+    ) -> Result<Vec<PRComment>, PlatformError> {
+        Err(comments_not_supported())
+    }
+
+    /// Add a conversation comment to a pull request
+    async fn create_pull_request_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pull_number: u64,
+        _body: &str,
+    ) -> Result<(), PlatformError> {
+        Err(comments_not_supported())
+    }
+
+    /// Get the review threads on a pull request
+    async fn get_review_threads(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pull_number: u64,
+    ) -> Result<Vec<ReviewThread>, PlatformError> {
+        Err(comments_not_supported())
+    }
+
+    /// Create an issue
+    ///
+    /// Returns the issue number and URL.
+    async fn create_issue(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _title: &str,
+        _body: Option<&str>,
+        _labels: &[String],
+    ) -> Result<IssueCreateResult, PlatformError> {
+        Err(issues_not_supported())
+    }
+
+    /// Get issue details
+    async fn get_issue(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<Issue, PlatformError> {
+        Err(issues_not_supported())
+    }
+
+    /// List issues in `state`, keeping only those that carry all `labels`
+    async fn list_issues(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _state: IssueState,
+        _labels: &[String],
+    ) -> Result<Vec<Issue>, PlatformError> {
+        Err(issues_not_supported())
+    }
+
+    /// Add a comment to an issue
+    async fn create_issue_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        _body: &str,
+    ) -> Result<(), PlatformError> {
+        Err(issues_not_supported())
+    }
+
+    /// Update an issue's title and/or body
+    async fn update_issue(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        _title: Option<&str>,
+        _body: Option<&str>,
+    ) -> Result<(), PlatformError> {
+        Err(issues_not_supported())
+    }
+
+    /// Close an issue
+    async fn close_issue(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<(), PlatformError> {
+        Err(issues_not_supported())
+    }
+
+    /// Add labels to an issue (existing labels are kept)
+    async fn add_issue_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        _labels: &[String],
+    ) -> Result<(), PlatformError> {
+        Err(issues_not_supported())
+    }
+
+    /// Generate HTML comment for linked PR tracking
+    fn generate_linked_pr_comment(&self, links: &[LinkedPRRef]) -> String {
+        let refs: Vec<(&str, u64)> = links
+            .iter()
+            .map(|l| (l.repo_name.as_str(), l.number))
+            .collect();
+        linked_refs_comment("gitgrip-linked-prs", &refs)
     }
 
     /// Parse linked PR references from PR body
     fn parse_linked_pr_comment(&self, body: &str) -> Vec<LinkedPRRef> {
-        let start_marker = "<!-- gitgrip-linked-prs";
-        let end_marker = "-->";
-
-        let Some(start) = body.find(start_marker) else {
-            return Vec::new();
-        };
-
-        let content_start = start + start_marker.len();
-        let Some(end) = body[content_start..].find(end_marker) else {
-            return Vec::new();
-        };
-
-        let content = &body[content_start..content_start + end];
-
-        content
-            .lines()
-            .filter_map(|line| {
-                let line = line.trim();
-                if line.is_empty() {
-                    return None;
-                }
-
-                let parts: Vec<&str> = line.splitn(2, ':').collect();
-                if parts.len() != 2 {
-                    return None;
-                }
-
-                let number = parts[1].parse().ok()?;
-                Some(LinkedPRRef {
-                    repo_name: parts[0].to_string(),
-                    number,
-                })
-            })
+        parse_linked_refs("gitgrip-linked-prs", body)
+            .into_iter()
+            .map(|(repo_name, number)| LinkedPRRef { repo_name, number })
+            .collect()
+    }
+
+    /// Generate HTML comment linking the issues of a cross-repo tracking issue
+    fn generate_linked_issue_comment(&self, links: &[LinkedIssueRef]) -> String {
+        let refs: Vec<(&str, u64)> = links
+            .iter()
+            .map(|l| (l.repo_name.as_str(), l.number))
+            .collect();
+        linked_refs_comment("gitgrip-linked-issues", &refs)
+    }
+
+    /// Parse linked issue references from an issue body
+    fn parse_linked_issue_comment(&self, body: &str) -> Vec<LinkedIssueRef> {
+        parse_linked_refs("gitgrip-linked-issues", body)
+            .into_iter()
+            .map(|(repo_name, number)| LinkedIssueRef { repo_name, number })
             .collect()
     }
 }
 
+const COMMENTS_NOT_SUPPORTED: &str = "PR comments not supported on this platform";
+
+fn comments_not_supported() -> PlatformError {
+    PlatformError::ApiError(COMMENTS_NOT_SUPPORTED.to_string())
+}
+
+/// Whether `error` is the default returned by platforms without PR comments
+pub(crate) fn is_comments_not_supported(error: &PlatformError) -> bool {
+    matches!(error, PlatformError::ApiError(msg) if msg == COMMENTS_NOT_SUPPORTED)
+}
+
+fn issues_not_supported() -> PlatformError {
+    PlatformError::ApiError("Issues not supported on this platform".to_string())
+}
+
+/// `<!-- marker` comment with one `repo:number` line per reference
+fn linked_refs_comment(marker: &str, refs: &[(&str, u64)]) -> String {
+    if refs.is_empty() {
+        return String::new();
+    }
+
+    let mut comment = format!("<!-- {}\n", marker);
+    for (repo_name, number) in refs {
+        comment.push_str(&format!("{}:{}\n", repo_name, number));
+    }
+    comment.push_str("-->");
+    comment
+}
+
+/// Parse the `repo:number` lines of a `<!-- marker` comment
+fn parse_linked_refs(marker: &str, body: &str) -> Vec<(String, u64)> {
+    let start_marker = format!("<!-- {}", marker);
+    let end_marker = "-->";
+
+    let Some(start) = body.find(&start_marker) else {
+        return Vec::new();
+    };
+
+    let content_start = start + start_marker.len();
+    let Some(end) = body[content_start..].find(end_marker) else {
+        return Vec::new();
+    };
+
+    let content = &body[content_start..content_start + end];
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() {
+                return None;
+            }
+
+            let parts: Vec<&str> = line.splitn(2, ':').collect();
+            if parts.len() != 2 {
+                return None;
+            }
+
+            let number = parts[1].parse().ok()?;
+            Some((parts[0].to_string(), number))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -445,4 +625,40 @@ More content
         let links = platform.parse_linked_pr_comment("No linked PRs here");
         assert!(links.is_empty());
     }
+
+    #[test]
+    fn test_linked_issue_roundtrip() {
+        let platform = MockPlatform;
+        let links = vec![
+            LinkedIssueRef {
+                repo_name: "app".to_string(),
+                number: 12,
+            },
+            LinkedIssueRef {
+                repo_name: "lib".to_string(),
+                number: 3,
+            },
+        ];
+        let comment = platform.generate_linked_issue_comment(&links);
+        assert!(comment.starts_with("<!-- gitgrip-linked-issues"));
+
+        let parsed = platform.parse_linked_issue_comment(&format!("Tracking\n\n{}", comment));
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].repo_name, "lib");
+        assert_eq!(parsed[1].number, 3);
+        assert!(platform.parse_linked_pr_comment(&comment).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_comments_not_supported_is_recognised() {
+        let err = MockPlatform
+            .get_review_threads("owner", "repo", 1)
+            .await
+            .unwrap_err();
+        assert!(is_comments_not_supported(&err));
+        assert!(!is_comments_not_supported(&PlatformError::RateLimited));
+        assert!(!is_comments_not_supported(&PlatformError::ApiError(
+            "Bad credentials".to_string()
+        )));
+    }
 }
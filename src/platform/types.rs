@@ -76,6 +76,27 @@ pub struct PRCreateOptions {
     pub base: Option<String>,
     /// Create as draft PR
     pub draft: Option<bool>,
+    /// Users to request reviews from
+    pub reviewers: Vec<String>,
+    /// Teams to request reviews from
+    pub team_reviewers: Vec<String>,
+    /// Labels to add
+    pub labels: Vec<String>,
+    /// Users to assign
+    pub assignees: Vec<String>,
+    /// Milestone title
+    pub milestone: Option<String>,
+}
+
+impl PRCreateOptions {
+    /// True when any reviewer, label, assignee or milestone is set
+    pub fn has_metadata(&self) -> bool {
+        !self.reviewers.is_empty()
+            || !self.team_reviewers.is_empty()
+            || !self.labels.is_empty()
+            || !self.assignees.is_empty()
+            || self.milestone.is_some()
+    }
 }
 
 /// Merge method for PRs
@@ -124,6 +145,35 @@ pub struct PRReview {
     pub user: String,
 }
 
+/// A comment on a pull request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PRComment {
+    /// Comment author
+    pub author: String,
+    /// Comment text
+    pub body: String,
+    /// Creation time as reported by the platform (RFC 3339)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+/// A review thread: a resolvable conversation on the PR's code
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewThread {
+    /// Whether the conversation has been resolved
+    pub resolved: bool,
+    /// File the thread is attached to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Line in the new version of the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u64>,
+    /// Comments, oldest first
+    pub comments: Vec<PRComment>,
+}
+
 /// Status check state
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -224,6 +274,50 @@ pub struct ReleaseResult {
     pub url: String,
 }
 
+/// Issue state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueState {
+    #[default]
+    Open,
+    Closed,
+}
+
+impl std::fmt::Display for IssueState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueState::Open => write!(f, "open"),
+            IssueState::Closed => write!(f, "closed"),
+        }
+    }
+}
+
+/// Normalized issue data across platforms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Issue {
+    /// Issue number (work item ID on Azure DevOps)
+    pub number: u64,
+    /// Issue URL
+    pub url: String,
+    /// Issue title
+    pub title: String,
+    /// Issue body/description
+    pub body: String,
+    /// Issue state
+    pub state: IssueState,
+    /// Labels (tags on Azure DevOps)
+    pub labels: Vec<String>,
+}
+
+/// Result of creating an issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCreateResult {
+    /// Issue number
+    pub number: u64,
+    /// Issue URL
+    pub url: String,
+}
+
 /// Azure DevOps specific context
 #[derive(Debug, Clone)]
 pub struct AzureDevOpsContext {
@@ -294,6 +388,7 @@ mod tests {
         assert!(opts.body.is_none());
         assert!(opts.base.is_none());
         assert!(opts.draft.is_none());
+        assert!(!opts.has_metadata());
     }
 
     #[test]
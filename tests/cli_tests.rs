@@ -4,7 +4,7 @@
 
 mod common;
 
-use assert_cmd::Command;
+use assert_cmd::cargo::cargo_bin_cmd;
 use gitgrip::core::griptree::GriptreeConfig;
 use predicates::prelude::*;
 use tempfile::TempDir;
@@ -15,7 +15,7 @@ use common::git_helpers;
 /// Test that `gr --help` works
 #[test]
 fn test_help() {
-    let mut cmd = Command::cargo_bin("gr").unwrap();
+    let mut cmd = cargo_bin_cmd!("gr");
     cmd.arg("--help")
         .assert()
         .success()
@@ -25,7 +25,7 @@ fn test_help() {
 /// Test that `gr --version` works
 #[test]
 fn test_version() {
-    let mut cmd = Command::cargo_bin("gr").unwrap();
+    let mut cmd = cargo_bin_cmd!("gr");
     cmd.arg("--version")
         .assert()
         .success()
@@ -37,7 +37,7 @@ fn test_version() {
 fn test_status_outside_workspace() {
     let temp = TempDir::new().unwrap();
 
-    let mut cmd = Command::cargo_bin("gr").unwrap();
+    let mut cmd = cargo_bin_cmd!("gr");
     cmd.current_dir(temp.path())
         .arg("status")
         .assert()
@@ -48,7 +48,7 @@ fn test_status_outside_workspace() {
 /// Test that `gr bench --list` works
 #[test]
 fn test_bench_list() {
-    let mut cmd = Command::cargo_bin("gr").unwrap();
+    let mut cmd = cargo_bin_cmd!("gr");
     cmd.arg("bench")
         .arg("--list")
         .assert()
@@ -59,7 +59,7 @@ fn test_bench_list() {
 /// Test that `gr bench` runs benchmarks
 #[test]
 fn test_bench_run() {
-    let mut cmd = Command::cargo_bin("gr").unwrap();
+    let mut cmd = cargo_bin_cmd!("gr");
     cmd.arg("bench")
         .arg("-n")
         .arg("1")
@@ -71,7 +71,7 @@ fn test_bench_run() {
 /// Test that `gr bench --json` outputs JSON
 #[test]
 fn test_bench_json() {
-    let mut cmd = Command::cargo_bin("gr").unwrap();
+    let mut cmd = cargo_bin_cmd!("gr");
     cmd.arg("bench")
         .arg("-n")
         .arg("1")
@@ -93,11 +93,11 @@ fn test_checkout_base_uses_griptree_config() {
     git_helpers::create_branch(&ws.repo_path("lib"), "feat/base");
     git_helpers::checkout(&ws.repo_path("lib"), "main");
 
-    let mut config = GriptreeConfig::new("feat/base", &ws.workspace_root.to_string_lossy());
+    let config = GriptreeConfig::new("feat/base", &ws.workspace_root.to_string_lossy());
     let config_path = ws.workspace_root.join(".gitgrip").join("griptree.json");
     config.save(&config_path).unwrap();
 
-    let mut cmd = Command::cargo_bin("gr").unwrap();
+    let mut cmd = cargo_bin_cmd!("gr");
     cmd.current_dir(&ws.workspace_root)
         .arg("checkout")
         .arg("--base")
@@ -113,3 +113,77 @@ fn test_checkout_base_uses_griptree_config() {
         "feat/base"
     );
 }
+
+#[test]
+fn test_manifest_export_repo_xml() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .build();
+
+    let mut cmd = cargo_bin_cmd!("gr");
+    cmd.current_dir(&ws.workspace_root)
+        .args(["manifest", "export", "--format", "repo-xml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("<manifest>"))
+        .stdout(predicate::str::contains(r#"<project name="app" />"#))
+        .stdout(predicate::str::contains(r#"<project name="lib" />"#));
+}
+
+#[test]
+fn test_affected_filter_limits_forall() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .build();
+    std::fs::write(ws.repo_path("lib").join("new.txt"), "change").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("gr");
+    cmd.current_dir(&ws.workspace_root)
+        .args(["forall", "--all", "-c", "echo in-$REPO_NAME", "--affected"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("in-lib"))
+        .stdout(predicate::str::contains("in-app").not());
+}
+
+#[test]
+fn test_affected_filter_rejected_for_unsupported_command() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+
+    let mut cmd = cargo_bin_cmd!("gr");
+    cmd.current_dir(&ws.workspace_root)
+        .args(["status", "--affected=origin/main"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--affected is only supported"));
+}
+
+#[test]
+fn test_manifest_diff_json_reports_added_repo() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .with_manifest_repo()
+        .build();
+    let manifest_path = ws.workspace_root.join(".gitgrip/spaces/main/gripspace.yml");
+    let content = std::fs::read_to_string(&manifest_path).unwrap().replacen(
+        "repos:\n",
+        "repos:\n  lib:\n    url: git@github.com:org/lib.git\n    path: lib\n",
+        1,
+    );
+    std::fs::write(&manifest_path, content).unwrap();
+
+    let mut cmd = cargo_bin_cmd!("gr");
+    let output = cmd
+        .current_dir(&ws.workspace_root)
+        .args(["manifest", "diff", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["from"], "HEAD");
+    assert_eq!(json["added_repos"][0]["name"], "lib");
+    assert!(json["removed_repos"].as_array().unwrap().is_empty());
+}
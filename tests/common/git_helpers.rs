@@ -33,12 +33,42 @@ pub fn init_repo(path: &Path) {
 
 /// Create a file, stage, and commit it. Returns the commit hash.
 pub fn commit_file(repo_path: &Path, filename: &str, content: &str, message: &str) -> String {
-    fs::write(repo_path.join(filename), content).unwrap();
+    let path = repo_path.join(filename);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).unwrap();
+    }
+    fs::write(path, content).unwrap();
     git(repo_path, &["add", filename]);
     git(repo_path, &["commit", "-m", message]);
     get_head_sha(repo_path)
 }
 
+/// Like [`commit_file`], but with the author and committer date set to `date`.
+/// The file's content is the commit message. Returns the commit hash.
+pub fn commit_at(repo_path: &Path, filename: &str, message: &str, date: &str) -> String {
+    fs::write(repo_path.join(filename), message).unwrap();
+    git(repo_path, &["add", filename]);
+    let output = Command::new("git")
+        .args(["commit", "-q", "-m", message])
+        .env("GIT_AUTHOR_DATE", date)
+        .env("GIT_COMMITTER_DATE", date)
+        .current_dir(repo_path)
+        .output()
+        .expect("failed to run git commit");
+    assert!(
+        output.status.success(),
+        "git commit failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    get_head_sha(repo_path)
+}
+
+/// Write a file and stage it without committing.
+pub fn stage_file(repo_path: &Path, filename: &str, content: &str) {
+    fs::write(repo_path.join(filename), content).unwrap();
+    git(repo_path, &["add", filename]);
+}
+
 /// Create and checkout a new branch.
 pub fn create_branch(repo_path: &Path, branch_name: &str) {
     git(repo_path, &["checkout", "-b", branch_name]);
@@ -117,6 +147,31 @@ pub fn get_head_sha(repo_path: &Path) -> String {
     git_output(repo_path, &["rev-parse", "HEAD"])
 }
 
+/// Full message (subject and body) of the HEAD commit.
+pub fn head_message(repo_path: &Path) -> String {
+    git_output(repo_path, &["log", "-1", "--format=%B"])
+}
+
+/// Check if the worktree and index have no changes (untracked files included).
+pub fn is_clean(repo_path: &Path) -> bool {
+    git_output(repo_path, &["status", "--porcelain"]).is_empty()
+}
+
+/// Check if a tag exists.
+pub fn has_tag(repo_path: &Path, name: &str) -> bool {
+    Command::new("git")
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/tags/{}", name),
+        ])
+        .current_dir(repo_path)
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
 /// Check if a local branch exists.
 pub fn branch_exists(repo_path: &Path, branch_name: &str) -> bool {
     Command::new("git")
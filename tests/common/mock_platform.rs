@@ -4,7 +4,9 @@
 //! testing of platform adapter methods.
 
 use serde_json::{json, Map, Value};
-use wiremock::matchers::{header, method, path};
+use wiremock::matchers::{
+    body_partial_json, header, method, path, query_param, query_param_is_missing,
+};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Start a wiremock server and configure GITHUB_TOKEN env var.
@@ -194,7 +196,7 @@ fn github_pr_json(
     body: &str,
 ) -> Value {
     let repo = github_repo_json("owner", "repo");
-    let api_base = format!("https://api.github.com/repos/owner/repo");
+    let api_base = "https://api.github.com/repos/owner/repo".to_string();
 
     let mut m = Map::new();
     m.insert("id".into(), json!(number));
@@ -616,6 +618,318 @@ pub async fn mock_repo_info(server: &MockServer, owner: &str, repo: &str) {
         .await;
 }
 
+/// Generate a GitHub issue JSON object.
+fn github_issue_json(number: u64, title: &str, state: &str, labels: &[&str]) -> Value {
+    json!({
+        "number": number,
+        "html_url": format!("https://github.com/owner/repo/issues/{}", number),
+        "title": title,
+        "body": "Issue body",
+        "state": state,
+        "labels": labels.iter().map(|l| json!({ "name": l })).collect::<Vec<_>>(),
+    })
+}
+
+/// GitHub API response for creating an issue (POST /repos/:owner/:repo/issues).
+pub async fn mock_create_issue(server: &MockServer, number: u64) {
+    let body = github_issue_json(number, "Test issue", "open", &[]);
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// GitHub API response for getting an issue (GET /repos/:owner/:repo/issues/:number).
+pub async fn mock_get_issue(server: &MockServer, number: u64, state: &str, labels: &[&str]) {
+    let body = github_issue_json(number, "Test issue", state, labels);
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/owner/repo/issues/{}", number)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// GitHub API response for listing issues (GET /repos/:owner/:repo/issues).
+/// Numbers in `pull_requests` are returned as pull requests, like the real API.
+pub async fn mock_list_issues(server: &MockServer, issues: Vec<u64>, pull_requests: Vec<u64>) {
+    let mut items: Vec<Value> = issues
+        .iter()
+        .map(|n| github_issue_json(*n, &format!("Issue {}", n), "open", &["bug"]))
+        .collect();
+    for n in pull_requests {
+        let mut pr = github_issue_json(n, &format!("PR {}", n), "open", &[]);
+        pr["pull_request"] =
+            json!({ "url": format!("https://api.github.com/repos/owner/repo/pulls/{}", n) });
+        items.push(pr);
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(items))
+        .mount(server)
+        .await;
+}
+
+/// GitHub API response for listing issues split over two pages, linked with
+/// a `Link: <...>; rel="next"` header.
+pub async fn mock_paginated_list_issues(server: &MockServer) {
+    let next = format!(
+        "<{}/repos/owner/repo/issues?state=open&per_page=100&page=2>; rel=\"next\"",
+        server.uri()
+    );
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("link", next.as_str())
+                .set_body_json(json!([github_issue_json(1, "Issue 1", "open", &[])])),
+        )
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([github_issue_json(
+                2,
+                "Issue 2",
+                "open",
+                &[]
+            )])),
+        )
+        .mount(server)
+        .await;
+}
+
+/// GitHub API response for updating an issue (PATCH /repos/:owner/:repo/issues/:number).
+pub async fn mock_update_issue(server: &MockServer, number: u64) {
+    let body = github_issue_json(number, "Test issue", "closed", &[]);
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/owner/repo/issues/{}", number)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// GitHub API response for commenting on an issue
+/// (POST /repos/:owner/:repo/issues/:number/comments).
+pub async fn mock_issue_comment(server: &MockServer, number: u64) {
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/owner/repo/issues/{}/comments",
+            number
+        )))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "id": 1 })))
+        .mount(server)
+        .await;
+}
+
+/// GitHub API response for PR conversation comments
+/// (GET /repos/:owner/:repo/issues/:number/comments).
+pub async fn mock_pr_comments(server: &MockServer, number: u64, comments: Vec<(&str, &str)>) {
+    let body: Vec<Value> = comments
+        .iter()
+        .map(|(login, text)| {
+            json!({
+                "id": 1,
+                "user": { "login": login },
+                "body": text,
+                "created_at": "2024-01-01T00:00:00Z"
+            })
+        })
+        .collect();
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/owner/repo/issues/{}/comments",
+            number
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// GitHub API response for PR conversation comments split over two pages,
+/// linked with a `Link: <...>; rel="next"` header.
+pub async fn mock_paginated_pr_comments(server: &MockServer, number: u64) {
+    let comments_path = format!("/repos/owner/repo/issues/{}/comments", number);
+    let comment = |login: &str, text: &str| {
+        json!({
+            "id": 1,
+            "user": { "login": login },
+            "body": text,
+            "created_at": "2024-01-01T00:00:00Z"
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path(comments_path.as_str()))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "link",
+                    format!(
+                        "<{}{}?per_page=100&page=2>; rel=\"next\"",
+                        server.uri(),
+                        comments_path
+                    )
+                    .as_str(),
+                )
+                .set_body_json(json!([comment("alice", "First page")])),
+        )
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(comments_path.as_str()))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([comment("bob", "Second page")])),
+        )
+        .mount(server)
+        .await;
+}
+
+/// GitHub GraphQL response for PR review threads (POST /graphql).
+/// Each thread is (resolved, path, line, first comment body).
+pub async fn mock_review_threads(server: &MockServer, threads: Vec<(bool, &str, u64, &str)>) {
+    let nodes: Vec<Value> = threads
+        .iter()
+        .enumerate()
+        .map(|(i, (resolved, file, line, text))| {
+            json!({
+                "id": format!("thread-{}", i),
+                "isResolved": resolved,
+                "path": file,
+                "line": line,
+                "comments": {
+                    "pageInfo": { "hasNextPage": false, "endCursor": null },
+                    "nodes": [review_comment(text)]
+                }
+            })
+        })
+        .collect();
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "repository": { "pullRequest": {
+                "reviewThreads": {
+                    "pageInfo": { "hasNextPage": false, "endCursor": null },
+                    "nodes": nodes
+                }
+            } } }
+        })))
+        .mount(server)
+        .await;
+}
+
+/// GitHub GraphQL review threads split over pages: two pages of threads,
+/// the second thread holding a second page of comments.
+pub async fn mock_paginated_review_threads(server: &MockServer) {
+    let page = |threads: Value, next: Option<&str>| {
+        json!({
+            "data": { "repository": { "pullRequest": {
+                "reviewThreads": {
+                    "pageInfo": { "hasNextPage": next.is_some(), "endCursor": next },
+                    "nodes": threads
+                }
+            } } }
+        })
+    };
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({ "variables": { "after": null } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(
+            json!([{
+                "id": "thread-1",
+                "isResolved": true,
+                "path": "src/lib.rs",
+                "line": 1,
+                "comments": {
+                    "pageInfo": { "hasNextPage": false, "endCursor": null },
+                    "nodes": [review_comment("First")]
+                }
+            }]),
+            Some("threads-1"),
+        )))
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(
+            json!({ "variables": { "after": "threads-1" } }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(
+            json!([{
+                "id": "thread-2",
+                "isResolved": false,
+                "path": "src/main.rs",
+                "line": 2,
+                "comments": {
+                    "pageInfo": { "hasNextPage": true, "endCursor": "comments-1" },
+                    "nodes": [review_comment("Second")]
+                }
+            }]),
+            None,
+        )))
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(
+            json!({ "variables": { "id": "thread-2", "after": "comments-1" } }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "node": { "comments": {
+                "pageInfo": { "hasNextPage": false, "endCursor": null },
+                "nodes": [review_comment("Reply")]
+            } } }
+        })))
+        .mount(server)
+        .await;
+}
+
+fn review_comment(text: &str) -> Value {
+    json!({
+        "author": { "login": "reviewer" },
+        "body": text,
+        "createdAt": "2024-01-01T00:00:00Z"
+    })
+}
+
+/// GitHub API responses for requesting reviewers
+/// (POST /repos/:owner/:repo/pulls/:number/requested_reviewers) and listing
+/// milestones (GET /repos/:owner/:repo/milestones).
+pub async fn mock_pr_metadata(server: &MockServer, number: u64, milestones: Vec<(u64, &str)>) {
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/owner/repo/pulls/{}/requested_reviewers",
+            number
+        )))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "number": number })))
+        .mount(server)
+        .await;
+
+    let body: Vec<Value> = milestones
+        .iter()
+        .map(|(number, title)| json!({ "number": number, "title": title }))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/milestones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
 // ── Bitbucket mock helpers ──────────────────────────────────────────────────
 
 /// Start a wiremock server and configure BITBUCKET_TOKEN env var.
@@ -749,3 +1063,364 @@ pub async fn mock_bb_reviewers(server: &MockServer, id: u64, reviewers: Vec<bool
         .mount(server)
         .await;
 }
+
+// ── Gitea mock helpers ──────────────────────────────────────────────────────
+
+/// Start a wiremock server and configure GITEA_TOKEN env var.
+/// Returns the server and a GiteaAdapter pointed at it.
+pub async fn setup_gitea_mock() -> (MockServer, gitgrip::platform::gitea::GiteaAdapter) {
+    use std::sync::Once;
+    static SET_TOKEN: Once = Once::new();
+    SET_TOKEN.call_once(|| unsafe {
+        std::env::set_var("GITEA_TOKEN", "mock-gitea-token");
+    });
+
+    let server = MockServer::start().await;
+    let adapter = gitgrip::platform::gitea::GiteaAdapter::new(Some(&server.uri()));
+    (server, adapter)
+}
+
+/// Generate a Gitea PR JSON response.
+fn gitea_pr_json(number: u64, state: &str, merged: bool, head_branch: &str) -> Value {
+    json!({
+        "number": number,
+        "html_url": format!("https://codeberg.org/owner/repo/pulls/{}", number),
+        "title": "Test PR",
+        "body": "PR description",
+        "state": state,
+        "merged": merged,
+        "mergeable": true,
+        "head": { "ref": head_branch, "sha": "abc123" },
+        "base": { "ref": "main", "sha": "def456" }
+    })
+}
+
+/// Gitea API: create PR (POST /api/v1/repos/:owner/:repo/pulls).
+pub async fn mock_gitea_create_pr(server: &MockServer, number: u64) {
+    let body = gitea_pr_json(number, "open", false, "feat/test");
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/repos/owner/repo/pulls"))
+        .and(header("Authorization", "token mock-gitea-token"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// Gitea API: get PR (GET /api/v1/repos/:owner/:repo/pulls/:number).
+pub async fn mock_gitea_get_pr(server: &MockServer, number: u64, state: &str, merged: bool) {
+    let body = gitea_pr_json(number, state, merged, "feat/test");
+
+    Mock::given(method("GET"))
+        .and(path(format!("/api/v1/repos/owner/repo/pulls/{}", number)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// Gitea API: merge PR (POST /api/v1/repos/:owner/:repo/pulls/:number/merge).
+pub async fn mock_gitea_merge_pr(server: &MockServer, number: u64, status: u16, message: &str) {
+    let response = if status == 200 {
+        ResponseTemplate::new(200)
+    } else {
+        ResponseTemplate::new(status).set_body_json(json!({ "message": message }))
+    };
+
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/repos/owner/repo/pulls/{}/merge",
+            number
+        )))
+        .respond_with(response)
+        .mount(server)
+        .await;
+}
+
+/// Gitea API: list open PRs (GET /api/v1/repos/:owner/:repo/pulls?state=open).
+pub async fn mock_gitea_list_prs(server: &MockServer, prs: Vec<(u64, &str)>) {
+    let body: Vec<Value> = prs
+        .iter()
+        .map(|(number, branch)| gitea_pr_json(*number, "open", false, branch))
+        .collect();
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/repos/owner/repo/pulls"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// Gitea API: PR reviews (GET /api/v1/repos/:owner/:repo/pulls/:number/reviews).
+pub async fn mock_gitea_reviews(server: &MockServer, number: u64, reviews: Vec<(&str, &str)>) {
+    let body: Vec<Value> = reviews
+        .iter()
+        .map(|(user, state)| {
+            json!({
+                "state": state,
+                "user": { "login": user },
+                "stale": false,
+                "dismissed": false
+            })
+        })
+        .collect();
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/repos/owner/repo/pulls/{}/reviews",
+            number
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// Gitea API: combined commit status (GET /api/v1/repos/:owner/:repo/commits/:ref/status).
+pub async fn mock_gitea_status(server: &MockServer, ref_name: &str, statuses: Vec<(&str, &str)>) {
+    let statuses: Vec<Value> = statuses
+        .iter()
+        .map(|(context, status)| json!({ "context": context, "status": status }))
+        .collect();
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/repos/owner/repo/commits/{}/status",
+            ref_name
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "statuses": statuses })))
+        .mount(server)
+        .await;
+}
+
+/// Gitea API: repository settings (GET /api/v1/repos/:owner/:repo).
+pub async fn mock_gitea_repo(server: &MockServer, merge: bool, squash: bool, rebase: bool) {
+    let body = json!({
+        "name": "repo",
+        "allow_merge_commits": merge,
+        "allow_squash_merge": squash,
+        "allow_rebase": rebase
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/repos/owner/repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// Gitea API: PR diff (GET /api/v1/repos/:owner/:repo/pulls/:number.diff).
+pub async fn mock_gitea_diff(server: &MockServer, number: u64, diff_content: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/repos/owner/repo/pulls/{}.diff",
+            number
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_string(diff_content))
+        .mount(server)
+        .await;
+}
+
+/// Gitea API: create release (POST /api/v1/repos/:owner/:repo/releases).
+pub async fn mock_gitea_create_release(server: &MockServer, id: u64, tag: &str) {
+    let body = json!({
+        "id": id,
+        "tag_name": tag,
+        "html_url": format!("https://codeberg.org/owner/repo/releases/tag/{}", tag)
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/repos/owner/repo/releases"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// Gitea API responses for requesting reviewers, listing labels and
+/// milestones, and patching a PR.
+pub async fn mock_gitea_pr_metadata(server: &MockServer, number: u64, labels: Vec<(u64, &str)>) {
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/repos/owner/repo/pulls/{}/requested_reviewers",
+            number
+        )))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!([])))
+        .mount(server)
+        .await;
+
+    let body: Vec<Value> = labels
+        .iter()
+        .map(|(id, name)| json!({ "id": id, "name": name }))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/api/v1/repos/owner/repo/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/repos/owner/repo/milestones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{ "id": 9, "title": "v2" }])))
+        .mount(server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/api/v1/repos/owner/repo/pulls/{}", number)))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "number": number })))
+        .mount(server)
+        .await;
+}
+
+/// Bitbucket API: PR comments split over two pages linked by `next`
+/// (GET /repositories/:owner/:repo/pullrequests/:id/comments).
+pub async fn mock_bb_paginated_comments(server: &MockServer, id: u64) {
+    let comments_path = format!("/repositories/owner/repo/pullrequests/{}/comments", id);
+    let comment = |id: u64, author: &str, text: &str| {
+        json!({
+            "id": id,
+            "content": { "raw": text },
+            "user": { "display_name": author },
+            "created_on": "2024-01-01T00:00:00Z",
+            "deleted": false
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path(comments_path.as_str()))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": [comment(1, "alice", "First page")],
+            "next": format!("{}{}?pagelen=100&page=2", server.uri(), comments_path)
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(comments_path.as_str()))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": [comment(2, "bob", "Second page")]
+        })))
+        .mount(server)
+        .await;
+}
+
+// ── GitLab ─────────────────────────────────────────────────────────
+
+/// Start a wiremock server and configure GITLAB_TOKEN env var.
+/// Returns the server and a GitLabAdapter pointed at it.
+pub async fn setup_gitlab_mock() -> (MockServer, gitgrip::platform::gitlab::GitLabAdapter) {
+    use std::sync::Once;
+    static SET_TOKEN: Once = Once::new();
+    SET_TOKEN.call_once(|| unsafe {
+        std::env::set_var("GITLAB_TOKEN", "mock-gitlab-token");
+    });
+
+    let server = MockServer::start().await;
+    let adapter = gitgrip::platform::gitlab::GitLabAdapter::new(Some(&server.uri()));
+    (server, adapter)
+}
+
+/// GitLab API: MR discussions split over two pages, linked with the
+/// `x-next-page` header (GET /projects/:id/merge_requests/:iid/discussions).
+pub async fn mock_gitlab_paginated_discussions(server: &MockServer, iid: u64) {
+    let discussions_path = format!(
+        "/api/v4/projects/owner%2Frepo/merge_requests/{}/discussions",
+        iid
+    );
+    let discussion = |author: &str, text: &str| {
+        json!({
+            "notes": [{
+                "body": text,
+                "author": { "username": author },
+                "created_at": "2024-01-01T00:00:00Z",
+                "system": false,
+                "resolvable": false
+            }]
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path(discussions_path.as_str()))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-next-page", "2")
+                .set_body_json(json!([discussion("alice", "First page")])),
+        )
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(discussions_path.as_str()))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-next-page", "")
+                .set_body_json(json!([discussion("bob", "Second page")])),
+        )
+        .mount(server)
+        .await;
+}
+
+/// GitLab API: issues split over two pages, linked with the `x-next-page`
+/// header (GET /projects/:id/issues).
+pub async fn mock_gitlab_paginated_issues(server: &MockServer) {
+    let issue = |iid: u64| {
+        json!({
+            "iid": iid,
+            "web_url": format!("https://gitlab.com/owner/repo/-/issues/{}", iid),
+            "title": format!("Issue {}", iid),
+            "description": "Issue body",
+            "state": "opened",
+            "labels": []
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/api/v4/projects/owner%2Frepo/issues"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-next-page", "2")
+                .set_body_json(json!([issue(1)])),
+        )
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v4/projects/owner%2Frepo/issues"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([issue(2)])))
+        .mount(server)
+        .await;
+}
+
+/// Bitbucket API: issues split over two pages linked by `next`
+/// (GET /repositories/:owner/:repo/issues).
+pub async fn mock_bb_paginated_issues(server: &MockServer) {
+    let issue = |id: u64| {
+        json!({
+            "id": id,
+            "title": format!("Issue {}", id),
+            "content": { "raw": "Issue body" },
+            "state": "new",
+            "links": {
+                "html": { "href": format!("https://bitbucket.org/owner/repo/issues/{}", id) }
+            }
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/repositories/owner/repo/issues"))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": [issue(1)],
+            "next": format!("{}/repositories/owner/repo/issues?pagelen=100&page=2", server.uri())
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repositories/owner/repo/issues"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "values": [issue(2)]
+        })))
+        .mount(server)
+        .await;
+}
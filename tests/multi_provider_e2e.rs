@@ -10,6 +10,7 @@
 //!
 //! Run with: cargo test --features integration-tests -- --ignored multi_provider
 
+#![cfg_attr(not(feature = "integration-tests"), allow(dead_code, unused_imports))]
 use std::fs;
 use std::path::Path;
 use std::process::Command;
@@ -1131,6 +1132,4 @@ mod forall_tests {
 
 // Non-feature-gated test to verify compilation
 #[test]
-fn test_multi_provider_e2e_module_compiles() {
-    assert!(true);
-}
+fn test_multi_provider_e2e_module_compiles() {}
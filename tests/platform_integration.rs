@@ -326,5 +326,4 @@ mod integration {
 #[test]
 fn test_platform_integration_test_module_compiles() {
     // This test just verifies the module compiles without the feature flag
-    assert!(true);
 }
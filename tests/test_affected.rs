@@ -0,0 +1,77 @@
+//! Integration tests for `gr affected` and the `--affected` filter.
+
+mod common;
+
+use common::fixtures::WorkspaceBuilder;
+use common::git_helpers;
+use gitgrip::cli::commands::affected::{affected_repo_names, run_affected};
+use std::collections::BTreeSet;
+
+fn names(list: &[&str]) -> BTreeSet<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+/// core <- api (Cargo path dependency) <- web (depends_on); docs is independent
+fn build_workspace() -> common::fixtures::WorkspaceFixture {
+    let ws = WorkspaceBuilder::new()
+        .add_repo_with_files("core", vec![("Cargo.toml", "[package]\nname = \"core\"\n")])
+        .add_repo_with_files(
+            "api",
+            vec![(
+                "Cargo.toml",
+                "[package]\nname = \"api\"\n\n[dependencies]\ncore = { path = \"../core\" }\n",
+            )],
+        )
+        .add_repo("web")
+        .add_repo("docs")
+        .build();
+
+    let manifest_path =
+        gitgrip::core::manifest_paths::resolve_gripspace_manifest_path(&ws.workspace_root).unwrap();
+    let content = std::fs::read_to_string(&manifest_path).unwrap().replacen(
+        "    path: web\n",
+        "    path: web\n    depends_on: [api]\n",
+        1,
+    );
+    std::fs::write(&manifest_path, content).unwrap();
+    ws
+}
+
+#[test]
+fn test_affected_includes_transitive_dependents() {
+    let ws = build_workspace();
+    let manifest = ws.load_manifest();
+
+    assert!(affected_repo_names(&ws.workspace_root, &manifest, None).is_empty());
+
+    git_helpers::commit_file(&ws.repo_path("core"), "lib.rs", "// change", "Change core");
+
+    assert_eq!(
+        affected_repo_names(&ws.workspace_root, &manifest, None),
+        names(&["core", "api", "web"])
+    );
+    run_affected(&ws.workspace_root, &manifest, None, false, false).unwrap();
+    run_affected(&ws.workspace_root, &manifest, None, true, false).unwrap();
+}
+
+#[test]
+fn test_affected_leaf_change_only_affects_leaf() {
+    let ws = build_workspace();
+    let manifest = ws.load_manifest();
+
+    std::fs::write(ws.repo_path("web").join("index.html"), "<html>").unwrap();
+
+    assert_eq!(
+        affected_repo_names(&ws.workspace_root, &manifest, Some("origin/main")),
+        names(&["web"])
+    );
+}
+
+#[test]
+fn test_affected_unknown_ref_assumes_changed() {
+    let ws = build_workspace();
+    let manifest = ws.load_manifest();
+
+    let affected = affected_repo_names(&ws.workspace_root, &manifest, Some("no-such-ref"));
+    assert_eq!(affected, names(&["core", "api", "web", "docs"]));
+}
@@ -61,6 +61,23 @@ fn add_workspace_agent_config(workspace_root: &Path, workspace_yaml: &str) {
     fs::write(&manifest_path, content).unwrap();
 }
 
+/// Make `repo_name` depend on `deps` by inserting a `depends_on` line after its path
+fn add_repo_depends_on(workspace_root: &Path, repo_name: &str, deps: &[&str]) {
+    let manifest_path = workspace_root
+        .join(".gitgrip")
+        .join("spaces")
+        .join("main")
+        .join("gripspace.yml");
+    let content = fs::read_to_string(&manifest_path).unwrap();
+    let needle = format!("    path: {}\n", repo_name);
+    let updated = content.replacen(
+        &needle,
+        &format!("{}    depends_on: [{}]\n", needle, deps.join(", ")),
+        1,
+    );
+    fs::write(&manifest_path, updated).unwrap();
+}
+
 // ── Context Tests ────────────────────────────────────────────────
 
 #[test]
@@ -598,6 +615,59 @@ fn test_agent_build_specific_repo_no_config_errors() {
     );
 }
 
+#[test]
+fn test_agent_build_follows_depends_on() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .add_repo("core")
+        .build();
+
+    let log = ws.workspace_root.join("build-order.log");
+    for name in ["app", "lib", "core"] {
+        let agent_yaml = format!("      build: echo {} >> \"{}\"\n", name, log.display());
+        add_repo_agent_config(&ws.workspace_root, name, &agent_yaml);
+    }
+    add_repo_depends_on(&ws.workspace_root, "app", &["lib"]);
+    add_repo_depends_on(&ws.workspace_root, "lib", &["core"]);
+
+    let manifest = ws.load_manifest();
+    let result =
+        gitgrip::cli::commands::agent::run_agent_build(&ws.workspace_root, &manifest, None);
+    assert!(result.is_ok(), "build should succeed: {:?}", result.err());
+
+    let order: Vec<String> = fs::read_to_string(&log)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect();
+    assert_eq!(order, vec!["core", "lib", "app"]);
+}
+
+#[test]
+fn test_agent_build_stops_dependents_after_failure() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .build();
+
+    let marker = ws.workspace_root.join("app").join("build-marker.txt");
+    add_repo_agent_config(&ws.workspace_root, "lib", "      build: exit 1\n");
+    add_repo_agent_config(
+        &ws.workspace_root,
+        "app",
+        &format!("      build: echo built > \"{}\"\n", marker.display()),
+    );
+    add_repo_depends_on(&ws.workspace_root, "app", &["lib"]);
+
+    let manifest = ws.load_manifest();
+    let result =
+        gitgrip::cli::commands::agent::run_agent_build(&ws.workspace_root, &manifest, None);
+    let err = result.expect_err("build should fail when a dependency fails");
+    assert!(err.to_string().contains("'lib'"), "{}", err);
+    assert!(!marker.exists(), "dependent repo should not be built");
+}
+
 // ── Test Tests ───────────────────────────────────────────────────
 
 #[test]
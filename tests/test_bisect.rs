@@ -0,0 +1,131 @@
+//! Integration tests for workspace bisect (`gr bisect`).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::str::contains;
+
+use common::fixtures::WorkspaceBuilder;
+use common::git_helpers;
+
+#[test]
+fn test_bisect_run_finds_cross_repo_regression() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+    let frontend = ws.repo_path("frontend");
+    let backend = ws.repo_path("backend");
+
+    git_helpers::commit_at(&frontend, "a.txt", "Frontend 1", "2099-01-01T10:00:00Z");
+    git_helpers::commit_at(&backend, "b.txt", "Backend 1", "2099-01-02T10:00:00Z");
+    // The frontend starts relying on the v2 API here...
+    git_helpers::commit_at(&frontend, "uses-v2", "Use v2 API", "2099-01-03T10:00:00Z");
+    git_helpers::commit_at(&frontend, "c.txt", "Frontend 2", "2099-01-04T10:00:00Z");
+    // ...which only breaks once the backend drops it
+    git_helpers::commit_at(
+        &backend,
+        "dropped-v2",
+        "Drop v2 API",
+        "2099-01-05T10:00:00Z",
+    );
+    git_helpers::commit_at(&backend, "d.txt", "Backend 2", "2099-01-06T10:00:00Z");
+    let frontend_head = git_helpers::get_head_sha(&frontend);
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["bisect", "start", "2099-01-10", "2098-12-31"])
+        .assert()
+        .success()
+        .stdout(contains("Bisecting 6 commit(s) across 2 repo(s)"));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args([
+            "bisect",
+            "run",
+            "sh",
+            "-c",
+            "! { test -f frontend/uses-v2 && test -f backend/dropped-v2; }",
+        ])
+        .assert()
+        .success()
+        .stdout(contains("First bad commit: backend"))
+        .stdout(contains("Drop v2 API"));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["bisect", "reset"])
+        .assert()
+        .success();
+    assert_eq!(git_helpers::current_branch(&frontend), "main");
+    assert_eq!(git_helpers::get_head_sha(&frontend), frontend_head);
+    assert!(!ws.workspace_root.join(".gitgrip/bisect.json").exists());
+}
+
+#[test]
+fn test_bisect_manual_marks() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let app = ws.repo_path("app");
+    git_helpers::commit_at(&app, "one", "One", "2099-01-01T10:00:00Z");
+    git_helpers::commit_at(&app, "two", "Two", "2099-01-02T10:00:00Z");
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["bisect", "good"])
+        .assert()
+        .failure()
+        .stderr(contains("No bisect in progress"));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["bisect", "start", "2099-01-03", "2098-12-31"])
+        .assert()
+        .success();
+    // State 1 ("One") is checked out
+    assert!(app.join("one").exists());
+    assert!(!app.join("two").exists());
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["bisect", "bad"])
+        .assert()
+        .success()
+        .stdout(contains("First bad commit: app"))
+        .stdout(contains("One"));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["bisect", "reset"])
+        .assert()
+        .success();
+    assert!(app.join("two").exists());
+}
+
+#[test]
+fn test_bisect_rejects_unknown_point() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["bisect", "start", "not-a-point", "2020-01-01"])
+        .assert()
+        .failure()
+        .stderr(contains("neither a date nor a commit"));
+}
+
+#[test]
+fn test_bisect_rejects_good_point_before_history() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let app = ws.repo_path("app");
+    git_helpers::commit_at(&app, "one", "One", "2099-01-01T10:00:00Z");
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["bisect", "start", "2099-01-02", "2000-01-01"])
+        .assert()
+        .failure()
+        .stderr(contains("No commit at or before"))
+        .stderr(contains("app"));
+    assert_eq!(git_helpers::current_branch(&app), "main");
+    assert!(!ws.workspace_root.join(".gitgrip/bisect.json").exists());
+}
@@ -0,0 +1,152 @@
+//! Integration tests for cross-repo change IDs (`gr commit`, `gr log --change`,
+//! `gr show`, `gr revert`).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::str::contains;
+use std::process::Command;
+
+use common::fixtures::WorkspaceBuilder;
+use common::git_helpers;
+
+fn change_id(message: &str) -> Option<String> {
+    message
+        .lines()
+        .find_map(|l| l.strip_prefix("Gitgrip-Change-Id: "))
+        .map(str::to_string)
+}
+
+#[test]
+fn test_commit_links_repos_with_change_id() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .add_repo("shared")
+        .build();
+
+    git_helpers::stage_file(&ws.repo_path("frontend"), "api.ts", "client");
+    git_helpers::stage_file(&ws.repo_path("backend"), "api.rs", "server");
+
+    let output = cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["commit", "-m", "Add API", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let id = result["change_id"].as_str().unwrap().to_string();
+
+    assert_eq!(
+        change_id(&git_helpers::head_message(&ws.repo_path("frontend"))).as_deref(),
+        Some(id.as_str())
+    );
+    assert_eq!(
+        change_id(&git_helpers::head_message(&ws.repo_path("backend"))).as_deref(),
+        Some(id.as_str())
+    );
+
+    let output = cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["log", "--json", "--change", &id[..8]])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let mut repos: Vec<&str> = entries
+        .iter()
+        .map(|e| e["repo"].as_str().unwrap())
+        .collect();
+    repos.sort();
+    assert_eq!(repos, ["backend", "frontend"]);
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["show", &id, "--stat"])
+        .assert()
+        .success()
+        .stdout(contains("api.ts"))
+        .stdout(contains("api.rs"));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["revert", &id])
+        .assert()
+        .success();
+    assert!(!ws.repo_path("frontend").join("api.ts").exists());
+    assert!(!ws.repo_path("backend").join("api.rs").exists());
+    let revert_message = git_helpers::head_message(&ws.repo_path("backend"));
+    assert!(revert_message.starts_with("Revert \"Add API\""));
+    let revert_id = change_id(&revert_message).unwrap();
+    assert_ne!(revert_id, id);
+    assert_eq!(
+        change_id(&git_helpers::head_message(&ws.repo_path("frontend"))),
+        Some(revert_id)
+    );
+}
+
+#[test]
+fn test_single_repo_commit_has_no_change_id() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    git_helpers::stage_file(&ws.repo_path("frontend"), "only.txt", "one repo");
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["commit", "-m", "Only frontend"])
+        .assert()
+        .success();
+    assert_eq!(
+        change_id(&git_helpers::head_message(&ws.repo_path("frontend"))),
+        None
+    );
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["show", "Iabcdef1234"])
+        .assert()
+        .failure()
+        .stderr(contains("No commits found"));
+}
+
+#[test]
+fn test_revert_rejects_ambiguous_prefix() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    for (repo, id) in [
+        ("frontend", "Iabcdef1111111111111111111111111111111111"),
+        ("backend", "Iabcdef2222222222222222222222222222222222"),
+    ] {
+        let path = ws.repo_path(repo);
+        git_helpers::stage_file(&path, "change.txt", id);
+        let message = format!("Unrelated change\n\nGitgrip-Change-Id: {}", id);
+        let status = Command::new("git")
+            .args(["commit", "-q", "-m", &message])
+            .current_dir(&path)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["revert", "Iabcdef"])
+        .assert()
+        .failure()
+        .stderr(contains("ambiguous"));
+    assert!(ws.repo_path("frontend").join("change.txt").exists());
+    assert!(ws.repo_path("backend").join("change.txt").exists());
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["revert", "Iabcdef1"])
+        .assert()
+        .success();
+    assert!(!ws.repo_path("frontend").join("change.txt").exists());
+    assert!(ws.repo_path("backend").join("change.txt").exists());
+}
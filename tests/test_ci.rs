@@ -99,8 +99,13 @@ fn test_ci_run_simple() {
     );
 
     let manifest = ws.load_manifest();
-    let result =
-        gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "build", false);
+    let result = gitgrip::cli::commands::ci::run_ci_run(
+        &ws.workspace_root,
+        &manifest,
+        "build",
+        false,
+        false,
+    );
     assert!(
         result.is_ok(),
         "ci run simple should succeed: {:?}",
@@ -131,6 +136,7 @@ fn test_ci_run_step_failure_stops() {
         &manifest,
         "fail-pipeline",
         false,
+        false,
     );
     assert!(result.is_err(), "ci run should fail when a step fails");
 }
@@ -154,8 +160,13 @@ fn test_ci_run_continue_on_error() {
     let manifest = ws.load_manifest();
     // Even though flaky fails, continue_on_error lets it proceed.
     // The pipeline still reports overall failure (the flaky step failed).
-    let result =
-        gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "resilient", false);
+    let result = gitgrip::cli::commands::ci::run_ci_run(
+        &ws.workspace_root,
+        &manifest,
+        "resilient",
+        false,
+        false,
+    );
     // Pipeline overall fails because at least one step failed
     assert!(
         result.is_err(),
@@ -177,8 +188,13 @@ fn test_ci_run_nonexistent_pipeline() {
     );
 
     let manifest = ws.load_manifest();
-    let result =
-        gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "nonexistent", false);
+    let result = gitgrip::cli::commands::ci::run_ci_run(
+        &ws.workspace_root,
+        &manifest,
+        "nonexistent",
+        false,
+        false,
+    );
     assert!(result.is_err(), "should error on nonexistent pipeline");
     let err_msg = result.unwrap_err().to_string();
     assert!(
@@ -207,8 +223,13 @@ fn test_ci_run_cwd_resolution() {
     );
 
     let manifest = ws.load_manifest();
-    let result =
-        gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "cwd-test", false);
+    let result = gitgrip::cli::commands::ci::run_ci_run(
+        &ws.workspace_root,
+        &manifest,
+        "cwd-test",
+        false,
+        false,
+    );
     assert!(
         result.is_ok(),
         "ci run with cwd should succeed: {:?}",
@@ -230,8 +251,13 @@ fn test_ci_run_json_output() {
     );
 
     let manifest = ws.load_manifest();
-    let result =
-        gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "json-test", true);
+    let result = gitgrip::cli::commands::ci::run_ci_run(
+        &ws.workspace_root,
+        &manifest,
+        "json-test",
+        false,
+        true,
+    );
     assert!(
         result.is_ok(),
         "ci run with json should succeed: {:?}",
@@ -239,6 +265,90 @@ fn test_ci_run_json_output() {
     );
 }
 
+#[test]
+fn test_ci_run_per_repo_follows_depends_on() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .build();
+
+    // app depends on lib
+    let manifest_path =
+        gitgrip::core::manifest_paths::resolve_gripspace_manifest_path(&ws.workspace_root).unwrap();
+    let content = fs::read_to_string(&manifest_path).unwrap();
+    fs::write(
+        &manifest_path,
+        content.replacen(
+            "    path: app\n",
+            "    path: app\n    depends_on: [lib]\n",
+            1,
+        ),
+    )
+    .unwrap();
+
+    let log = ws.workspace_root.join("order.log");
+    write_ci_manifest(
+        &ws,
+        &format!(
+            r#"      build:
+        steps:
+          - name: build
+            command: "echo $REPO_NAME >> {}"
+            per_repo: true
+"#,
+            log.display()
+        ),
+    );
+
+    let manifest = ws.load_manifest();
+    gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "build", false, true)
+        .unwrap();
+
+    assert_eq!(fs::read_to_string(&log).unwrap(), "lib\napp\n");
+
+    let saved: gitgrip::cli::commands::ci::PipelineResult = serde_json::from_str(
+        &fs::read_to_string(
+            ws.workspace_root
+                .join(".gitgrip")
+                .join("ci-results")
+                .join("build.json"),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let names: Vec<&str> = saved.steps.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["build (lib)", "build (app)"]);
+}
+
+#[test]
+fn test_ci_run_affected_skips_workspace_steps_without_affected_repos() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let marker = ws.workspace_root.join("ran.txt");
+    write_ci_manifest(
+        &ws,
+        &format!(
+            r#"      build:
+        steps:
+          - name: lint
+            command: "touch {}"
+"#,
+            marker.display()
+        ),
+    );
+
+    // `--affected` found nothing, so the manifest has no repos left
+    let mut manifest = ws.load_manifest();
+    manifest.repos.clear();
+    gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "build", true, true)
+        .unwrap();
+    assert!(!marker.exists());
+
+    let manifest = ws.load_manifest();
+    gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "build", true, true)
+        .unwrap();
+    assert!(marker.exists());
+}
+
 // ── ci result saved ──────────────────────────────────────────────
 
 #[test]
@@ -255,8 +365,14 @@ fn test_ci_result_saved() {
     );
 
     let manifest = ws.load_manifest();
-    gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "save-test", false)
-        .unwrap();
+    gitgrip::cli::commands::ci::run_ci_run(
+        &ws.workspace_root,
+        &manifest,
+        "save-test",
+        false,
+        false,
+    )
+    .unwrap();
 
     let result_path = ws
         .workspace_root
@@ -298,8 +414,14 @@ fn test_ci_status_after_run() {
     );
 
     let manifest = ws.load_manifest();
-    gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "status-test", false)
-        .unwrap();
+    gitgrip::cli::commands::ci::run_ci_run(
+        &ws.workspace_root,
+        &manifest,
+        "status-test",
+        false,
+        false,
+    )
+    .unwrap();
 
     // Now check status
     let result = gitgrip::cli::commands::ci::run_ci_status(&ws.workspace_root, false);
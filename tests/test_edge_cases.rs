@@ -159,8 +159,13 @@ fn test_ci_run_with_workspace_env() {
     );
 
     let manifest = ws.load_manifest();
-    let result =
-        gitgrip::cli::commands::ci::run_ci_run(&ws.workspace_root, &manifest, "env-test", false);
+    let result = gitgrip::cli::commands::ci::run_ci_run(
+        &ws.workspace_root,
+        &manifest,
+        "env-test",
+        false,
+        false,
+    );
     assert!(
         result.is_ok(),
         "CI with workspace env should succeed: {:?}",
@@ -191,6 +196,7 @@ fn test_ci_run_step_with_env_override() {
         &manifest,
         "env-override",
         false,
+        false,
     );
     assert!(
         result.is_ok(),
@@ -225,18 +225,23 @@ async fn test_sync_with_deleted_remote() {
 
     let manifest = ws.load_manifest();
     // Sync should handle missing remote gracefully (error or report per-repo failure)
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     // Whether it returns Ok (with per-repo error reports) or Err is acceptable,
     // but it must not panic. Verify we got a determinate result.
     match &result {
@@ -293,6 +298,7 @@ fn test_forall_with_nonexistent_command() {
         false, // changed_only
         false, // no_intercept
         None,
+        false, // ordered
     );
     // Forall should handle per-repo command failures gracefully
     match &result {
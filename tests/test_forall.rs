@@ -22,6 +22,7 @@ fn test_forall_all_repos() {
         false, // all repos (changed_only = false)
         false, // intercept enabled
         None,
+        false, // ordered
     );
     assert!(result.is_ok(), "forall should succeed: {:?}", result.err());
 }
@@ -47,6 +48,7 @@ fn test_forall_changed_only() {
         true, // changed_only
         false,
         None,
+        false, // ordered
     );
     assert!(
         result.is_ok(),
@@ -74,6 +76,7 @@ fn test_forall_parallel() {
         false,
         false,
         None,
+        false, // ordered
     );
     assert!(
         result.is_ok(),
@@ -97,6 +100,7 @@ fn test_forall_git_command_intercepted() {
         false,
         false, // intercept enabled
         None,
+        false, // ordered
     );
     assert!(
         result.is_ok(),
@@ -120,6 +124,7 @@ fn test_forall_no_intercept() {
         false,
         true, // no_intercept
         None,
+        false, // ordered
     );
     assert!(
         result.is_ok(),
@@ -127,3 +132,52 @@ fn test_forall_no_intercept() {
         result.err()
     );
 }
+
+#[test]
+fn test_forall_ordered_runs_dependencies_first() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("api")
+        .add_repo("core")
+        .build();
+
+    // app -> api -> core
+    let manifest_path =
+        gitgrip::core::manifest_paths::resolve_gripspace_manifest_path(&ws.workspace_root).unwrap();
+    let content = std::fs::read_to_string(&manifest_path)
+        .unwrap()
+        .replacen(
+            "    path: app\n",
+            "    path: app\n    depends_on: [api]\n",
+            1,
+        )
+        .replacen(
+            "    path: api\n",
+            "    path: api\n    depends_on: [core]\n",
+            1,
+        );
+    std::fs::write(&manifest_path, content).unwrap();
+    let manifest = ws.load_manifest();
+
+    let log = ws.workspace_root.join("order.log");
+    for parallel in [false, true] {
+        std::fs::write(&log, "").unwrap();
+        let result = gitgrip::cli::commands::forall::run_forall(
+            &ws.workspace_root,
+            &manifest,
+            &format!("echo $REPO_NAME >> {}", log.display()),
+            parallel,
+            false,
+            false,
+            None,
+            true, // ordered
+        );
+        assert!(result.is_ok(), "forall should succeed: {:?}", result.err());
+        assert_eq!(
+            std::fs::read_to_string(&log).unwrap(),
+            "core\napi\napp\n",
+            "parallel = {}",
+            parallel
+        );
+    }
+}
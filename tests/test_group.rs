@@ -158,3 +158,26 @@ fn test_group_add_updates_legacy_manifest_mirror() {
         "legacy manifest mirror should contain added group"
     );
 }
+
+#[test]
+fn test_group_add_and_remove_preserve_comments() {
+    let ws = WorkspaceBuilder::new().add_repo("frontend").build();
+    let manifest_path =
+        gitgrip::core::manifest_paths::resolve_gripspace_manifest_path(&ws.workspace_root).unwrap();
+    let content = fs::read_to_string(&manifest_path).unwrap().replacen(
+        "repos:\n",
+        "# Our repos\nrepos:\n  # Web frontend\n",
+        1,
+    );
+    fs::write(&manifest_path, &content).unwrap();
+    let repos = vec!["frontend".to_string()];
+
+    gitgrip::cli::commands::group::run_group_add(&ws.workspace_root, "ui", &repos).unwrap();
+    let updated = fs::read_to_string(&manifest_path).unwrap();
+    assert!(updated.contains("# Our repos\n"));
+    assert!(updated.contains("  # Web frontend\n  frontend:\n"));
+    assert!(updated.contains("groups: [ui]"));
+
+    gitgrip::cli::commands::group::run_group_remove(&ws.workspace_root, "ui", &repos).unwrap();
+    assert_eq!(fs::read_to_string(&manifest_path).unwrap(), content);
+}
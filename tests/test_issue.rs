@@ -0,0 +1,187 @@
+//! Integration tests for `gr issue`.
+//!
+//! Issues live on the local file-backed platform so the commands run offline.
+
+mod common;
+
+use common::fixtures::{WorkspaceBuilder, WorkspaceFixture};
+use gitgrip::cli::commands::issue;
+use gitgrip::core::manifest::{Manifest, PlatformConfig, PlatformType};
+use gitgrip::platform::local::LocalAdapter;
+use gitgrip::platform::traits::HostingPlatform;
+use gitgrip::platform::IssueState;
+
+/// Manifest with every repo on the local platform
+fn local_manifest(ws: &WorkspaceFixture) -> Manifest {
+    let mut manifest = ws.load_manifest();
+    for config in manifest.repos.values_mut() {
+        config.platform = Some(PlatformConfig {
+            platform_type: PlatformType::Local,
+            base_url: None,
+        });
+    }
+    manifest
+}
+
+fn adapter(ws: &WorkspaceFixture) -> LocalAdapter {
+    LocalAdapter::new(Some(ws.remotes_dir.to_str().unwrap()))
+}
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[tokio::test]
+async fn test_issue_tracking_create_and_close_linked() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .add_repo("docs")
+        .build();
+    let manifest = local_manifest(&ws);
+
+    issue::run_issue_create(
+        &ws.workspace_root,
+        &manifest,
+        "Upgrade TLS",
+        Some("Move everything to TLS 1.3"),
+        &names(&["security"]),
+        Some(&names(&["app", "lib"])),
+        None,
+        true,
+        false,
+    )
+    .await
+    .unwrap();
+
+    let adapter = adapter(&ws);
+    for repo in ["app", "lib"] {
+        let issue = adapter.get_issue("local", repo, 1).await.unwrap();
+        assert_eq!(issue.title, "Upgrade TLS");
+        assert_eq!(issue.labels, vec!["security"]);
+        assert!(issue.body.starts_with("Move everything to TLS 1.3"));
+        let links = adapter.parse_linked_issue_comment(&issue.body);
+        let linked: Vec<&str> = links.iter().map(|l| l.repo_name.as_str()).collect();
+        assert_eq!(linked, vec!["app", "lib"]);
+    }
+    assert!(
+        adapter.get_issue("local", "docs", 1).await.is_err(),
+        "unselected repo should have no issue"
+    );
+
+    issue::run_issue_close(
+        &ws.workspace_root,
+        &manifest,
+        "lib",
+        1,
+        Some("Done everywhere"),
+        true,
+    )
+    .await
+    .unwrap();
+
+    for repo in ["app", "lib"] {
+        let issue = adapter.get_issue("local", repo, 1).await.unwrap();
+        assert_eq!(issue.state, IssueState::Closed, "{} should be closed", repo);
+        assert_eq!(
+            adapter.issue_comments(repo, 1).unwrap(),
+            vec!["Done everywhere"]
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_issue_list_edit_and_label() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .build();
+    let manifest = local_manifest(&ws);
+    let adapter = adapter(&ws);
+
+    adapter
+        .create_issue("local", "app", "Crash on start", None, &names(&["bug"]))
+        .await
+        .unwrap();
+    adapter
+        .create_issue("local", "app", "Add dark mode", None, &[])
+        .await
+        .unwrap();
+
+    let bugs = adapter
+        .list_issues("local", "app", IssueState::Open, &names(&["bug"]))
+        .await
+        .unwrap();
+    assert_eq!(bugs.len(), 1);
+    assert_eq!(bugs[0].title, "Crash on start");
+
+    issue::run_issue_list(
+        &ws.workspace_root,
+        &manifest,
+        IssueState::Open,
+        &[],
+        None,
+        None,
+        true,
+    )
+    .await
+    .unwrap();
+
+    issue::run_issue_edit(
+        &ws.workspace_root,
+        &manifest,
+        "app",
+        2,
+        Some("Add dark and light modes"),
+        None,
+    )
+    .await
+    .unwrap();
+    issue::run_issue_label(
+        &ws.workspace_root,
+        &manifest,
+        "app",
+        2,
+        &names(&["feature", "ui"]),
+    )
+    .await
+    .unwrap();
+
+    let issue = adapter.get_issue("local", "app", 2).await.unwrap();
+    assert_eq!(issue.title, "Add dark and light modes");
+    assert_eq!(issue.labels, vec!["feature", "ui"]);
+}
+
+#[tokio::test]
+async fn test_issue_create_requires_repo_selection() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let manifest = local_manifest(&ws);
+
+    let result = issue::run_issue_create(
+        &ws.workspace_root,
+        &manifest,
+        "Title",
+        None,
+        &[],
+        None,
+        None,
+        false,
+        false,
+    )
+    .await;
+    assert!(result.is_err(), "create without --repo/--group should fail");
+
+    let result = issue::run_issue_create(
+        &ws.workspace_root,
+        &manifest,
+        "Title",
+        None,
+        &[],
+        Some(&names(&["missing"])),
+        None,
+        false,
+        false,
+    )
+    .await;
+    assert!(result.is_err(), "unknown repo should fail");
+}
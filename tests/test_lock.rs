@@ -0,0 +1,146 @@
+//! Integration tests for the workspace lockfile (manifest lock, sync --locked).
+
+mod common;
+
+use common::fixtures::WorkspaceBuilder;
+use common::git_helpers;
+use gitgrip::cli::commands::sync::{run_sync, SyncOptions};
+use gitgrip::core::lockfile::{lockfile_path, Lockfile};
+
+fn locked_sync_options<'a>(
+    workspace_root: &'a std::path::PathBuf,
+    manifest: &'a gitgrip::core::manifest::Manifest,
+) -> SyncOptions<'a> {
+    SyncOptions {
+        workspace_root,
+        manifest,
+        force: false,
+        quiet: true,
+        group_filter: None,
+        sequential: true,
+        reset_refs: false,
+        json: false,
+        no_hooks: true,
+        locked: true,
+        depth: None,
+        filter: None,
+        unshallow: false,
+    }
+}
+
+#[test]
+fn test_manifest_lock_records_repo_shas() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+    let manifest = ws.load_manifest();
+
+    gitgrip::cli::commands::manifest::run_manifest_lock(&ws.workspace_root, &manifest, true, false)
+        .expect("manifest lock should succeed");
+
+    let path = lockfile_path(&ws.workspace_root);
+    assert!(path.ends_with(".gitgrip/spaces/main/gripspace.lock"));
+
+    let lockfile = Lockfile::load(&path).unwrap();
+    assert_eq!(lockfile.repos.len(), 2);
+    for name in ["frontend", "backend"] {
+        assert_eq!(
+            lockfile.repo_sha(name),
+            Some(git_helpers::get_head_sha(&ws.repo_path(name)).as_str())
+        );
+        assert_eq!(lockfile.repos[name].path, name);
+    }
+}
+
+#[test]
+fn test_manifest_lock_fails_when_repo_not_cloned() {
+    let ws = WorkspaceBuilder::new().add_repo("frontend").build();
+    std::fs::remove_dir_all(ws.repo_path("frontend")).unwrap();
+    let manifest = ws.load_manifest();
+
+    let result = gitgrip::cli::commands::manifest::run_manifest_lock(
+        &ws.workspace_root,
+        &manifest,
+        true,
+        false,
+    );
+    assert!(result.is_err());
+    assert!(!lockfile_path(&ws.workspace_root).exists());
+}
+
+#[tokio::test]
+async fn test_sync_locked_checks_out_locked_sha() {
+    let ws = WorkspaceBuilder::new().add_repo("frontend").build();
+    let manifest = ws.load_manifest();
+    let repo = ws.repo_path("frontend");
+
+    let locked_sha = git_helpers::get_head_sha(&repo);
+    gitgrip::cli::commands::manifest::run_manifest_lock(&ws.workspace_root, &manifest, true, false)
+        .unwrap();
+
+    // Move the repo (and its remote) past the locked commit
+    git_helpers::commit_file(&repo, "new.txt", "new", "Advance past lock");
+    git_helpers::push_branch(&repo, "origin", "main");
+    assert_ne!(git_helpers::get_head_sha(&repo), locked_sha);
+
+    run_sync(locked_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("locked sync should succeed");
+
+    assert_eq!(git_helpers::get_head_sha(&repo), locked_sha);
+}
+
+#[tokio::test]
+async fn test_sync_locked_clones_missing_repo_at_locked_sha() {
+    let ws = WorkspaceBuilder::new().add_repo("frontend").build();
+    let manifest = ws.load_manifest();
+    let repo = ws.repo_path("frontend");
+
+    let locked_sha = git_helpers::get_head_sha(&repo);
+    gitgrip::cli::commands::manifest::run_manifest_lock(&ws.workspace_root, &manifest, true, false)
+        .unwrap();
+
+    git_helpers::commit_file(&repo, "new.txt", "new", "Advance past lock");
+    git_helpers::push_branch(&repo, "origin", "main");
+    std::fs::remove_dir_all(&repo).unwrap();
+
+    run_sync(locked_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("locked sync should succeed");
+
+    assert_eq!(git_helpers::get_head_sha(&repo), locked_sha);
+}
+
+#[tokio::test]
+async fn test_sync_locked_without_lockfile_fails() {
+    let ws = WorkspaceBuilder::new().add_repo("frontend").build();
+    let manifest = ws.load_manifest();
+
+    let result = run_sync(locked_sync_options(&ws.workspace_root, &manifest)).await;
+    let err = result.expect_err("locked sync without a lockfile should fail");
+    assert!(err.to_string().contains("gr manifest lock"));
+}
+
+#[tokio::test]
+async fn test_sync_locked_keeps_uncommitted_changes() {
+    let ws = WorkspaceBuilder::new().add_repo("frontend").build();
+    let manifest = ws.load_manifest();
+    let repo = ws.repo_path("frontend");
+
+    gitgrip::cli::commands::manifest::run_manifest_lock(&ws.workspace_root, &manifest, true, false)
+        .unwrap();
+    let advanced = git_helpers::commit_file(&repo, "new.txt", "new", "Advance past lock");
+    std::fs::write(repo.join("README.md"), "dirty\n").unwrap();
+
+    run_sync(locked_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .unwrap();
+
+    // The dirty repo is reported as failed and left untouched
+    assert_eq!(git_helpers::get_head_sha(&repo), advanced);
+    assert_eq!(
+        std::fs::read_to_string(repo.join("README.md")).unwrap(),
+        "dirty\n"
+    );
+}
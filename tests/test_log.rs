@@ -0,0 +1,102 @@
+//! Integration tests for the cross-repo log (`gr log`).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use std::path::Path;
+
+use common::fixtures::WorkspaceBuilder;
+use common::git_helpers;
+
+fn log_json(workspace_root: &Path, args: &[&str]) -> Vec<serde_json::Value> {
+    let output = cargo_bin_cmd!("gr")
+        .current_dir(workspace_root)
+        .args(["log", "--json"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+fn summary(entries: &[serde_json::Value]) -> Vec<(String, String)> {
+    entries
+        .iter()
+        .map(|e| {
+            (
+                e["repo"].as_str().unwrap().to_string(),
+                e["subject"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_log_merges_repos_by_date() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    git_helpers::commit_at(
+        &ws.repo_path("frontend"),
+        "one.txt",
+        "Frontend one",
+        "2030-01-01T10:00:00Z",
+    );
+    git_helpers::commit_at(
+        &ws.repo_path("backend"),
+        "one.txt",
+        "Backend one",
+        "2030-01-02T10:00:00Z",
+    );
+    git_helpers::commit_at(
+        &ws.repo_path("frontend"),
+        "two.txt",
+        "Frontend two",
+        "2030-01-03T10:00:00Z",
+    );
+
+    let entries = log_json(&ws.workspace_root, &["--since", "2029-12-31", "-n", "10"]);
+    assert_eq!(
+        summary(&entries),
+        [
+            ("frontend".to_string(), "Frontend two".to_string()),
+            ("backend".to_string(), "Backend one".to_string()),
+            ("frontend".to_string(), "Frontend one".to_string()),
+        ]
+    );
+
+    let entries = log_json(&ws.workspace_root, &["--grep", "Backend"]);
+    assert_eq!(
+        summary(&entries),
+        [("backend".to_string(), "Backend one".to_string())]
+    );
+
+    let entries = log_json(&ws.workspace_root, &["-n", "1"]);
+    assert_eq!(entries.len(), 1);
+}
+
+#[test]
+fn test_log_branch_only_shows_feature_commits() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    git_helpers::create_branch(&ws.repo_path("frontend"), "feat/log");
+    git_helpers::commit_file(
+        &ws.repo_path("frontend"),
+        "feature.txt",
+        "feature",
+        "Add feature",
+    );
+    // On its default branch: nothing to show even with local commits
+    git_helpers::commit_file(&ws.repo_path("backend"), "local.txt", "x", "Local");
+
+    let entries = log_json(&ws.workspace_root, &["--branch"]);
+    assert_eq!(
+        summary(&entries),
+        [("frontend".to_string(), "Add feature".to_string())]
+    );
+}
@@ -116,6 +116,51 @@ fn test_manifest_import_nonexistent() {
     );
 }
 
+#[test]
+fn test_manifest_import_vcstool_repos() {
+    let tmp = TempDir::new().unwrap();
+    let repos_path = tmp.path().join("ros2.repos");
+    let output_path = tmp.path().join("manifest.yaml");
+
+    fs::write(
+        &repos_path,
+        r#"repositories:
+  src/ros2/rclcpp:
+    type: git
+    url: https://github.com/ros2/rclcpp.git
+    version: rolling
+"#,
+    )
+    .unwrap();
+
+    let result = gitgrip::cli::commands::manifest::run_manifest_import(
+        repos_path.to_str().unwrap(),
+        Some(output_path.to_str().unwrap()),
+    );
+    assert!(result.is_ok(), "import should succeed: {:?}", result.err());
+
+    let manifest =
+        gitgrip::core::manifest::Manifest::parse(&fs::read_to_string(&output_path).unwrap())
+            .unwrap();
+    let repo = &manifest.repos["src-ros2-rclcpp"];
+    assert_eq!(repo.path, "src/ros2/rclcpp");
+    assert_eq!(repo.revision.as_deref(), Some("rolling"));
+}
+
+#[test]
+fn test_manifest_import_unknown_format() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("notes.txt");
+    fs::write(&path, "just some text").unwrap();
+
+    let result = gitgrip::cli::commands::manifest::run_manifest_import(
+        path.to_str().unwrap(),
+        Some(tmp.path().join("out.yaml").to_str().unwrap()),
+    );
+    let err_msg = result.unwrap_err().to_string();
+    assert!(err_msg.contains("Could not detect"), "{}", err_msg);
+}
+
 // ── manifest sync ──────────────────────────────────────────────
 
 #[test]
@@ -169,3 +214,91 @@ fn test_manifest_sync_no_repo_dir() {
         err_msg
     );
 }
+
+// ── manifest diff ────────────────────────────────────────────────
+
+#[test]
+fn test_manifest_diff_revisions_and_files() {
+    let ws = common::fixtures::WorkspaceBuilder::new()
+        .add_repo("app")
+        .with_manifest_repo()
+        .build();
+    let manifest_dir = ws.workspace_root.join(".gitgrip/spaces/main");
+    let original = fs::read_to_string(manifest_dir.join("gripspace.yml")).unwrap();
+
+    // No changes between HEAD and the working copy
+    gitgrip::cli::commands::manifest::run_manifest_diff(&ws.workspace_root, None, None, true)
+        .unwrap();
+
+    let updated = original.replacen(
+        "repos:\n",
+        "repos:\n  lib:\n    url: git@github.com:org/lib.git\n    path: lib\n",
+        1,
+    );
+    common::git_helpers::commit_file(&manifest_dir, "gripspace.yml", &updated, "Add lib");
+
+    let result = gitgrip::cli::commands::manifest::run_manifest_diff(
+        &ws.workspace_root,
+        Some("HEAD~1"),
+        Some("HEAD"),
+        false,
+    );
+    assert!(
+        result.is_ok(),
+        "diff between revisions failed: {:?}",
+        result
+    );
+
+    let old_file = ws.workspace_root.join("old.yml");
+    fs::write(&old_file, &original).unwrap();
+    let result = gitgrip::cli::commands::manifest::run_manifest_diff(
+        &ws.workspace_root,
+        Some(old_file.to_str().unwrap()),
+        None,
+        true,
+    );
+    assert!(result.is_ok(), "diff against a file failed: {:?}", result);
+
+    let result = gitgrip::cli::commands::manifest::run_manifest_diff(
+        &ws.workspace_root,
+        Some("no-such-rev"),
+        None,
+        false,
+    );
+    assert!(result.is_err(), "unknown revision should fail");
+}
+
+// ── manifest fmt ─────────────────────────────────────────────────
+
+#[test]
+fn test_manifest_fmt_keeps_comments() {
+    let ws = common::fixtures::WorkspaceBuilder::new()
+        .add_repo("app")
+        .build();
+    let manifest_path =
+        gitgrip::core::manifest_paths::resolve_gripspace_manifest_path(&ws.workspace_root).unwrap();
+    let original = fs::read_to_string(&manifest_path).unwrap();
+    let messy = format!(
+        "# Team workspace\n\nsettings:\n    pr_prefix: \"[x]\"  # keep me\n{}",
+        original
+            .replace("version: 1\n", "")
+            .replace("    url:", "    # app remote\n    url:")
+    ) + "version: 1\n";
+    fs::write(&manifest_path, &messy).unwrap();
+
+    let check = gitgrip::cli::commands::manifest::run_manifest_fmt(&ws.workspace_root, true, true);
+    assert!(
+        check.is_err(),
+        "--check should fail on an unformatted manifest"
+    );
+    assert_eq!(fs::read_to_string(&manifest_path).unwrap(), messy);
+
+    gitgrip::cli::commands::manifest::run_manifest_fmt(&ws.workspace_root, false, true).unwrap();
+    let formatted = fs::read_to_string(&manifest_path).unwrap();
+    assert!(formatted.starts_with("# Team workspace\n\nversion: 1\n"));
+    assert!(formatted.contains("  pr_prefix: \"[x]\"  # keep me\n"));
+    assert!(formatted.contains("    # app remote\n    url:"));
+    assert!(formatted.find("repos:").unwrap() < formatted.find("settings:").unwrap());
+
+    gitgrip::cli::commands::manifest::run_manifest_fmt(&ws.workspace_root, true, true).unwrap();
+}
@@ -0,0 +1,81 @@
+//! Integration tests for shared object mirrors (`gr mirror`).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::str::contains;
+
+use common::fixtures::WorkspaceBuilder;
+use common::git_helpers;
+
+#[test]
+fn test_mirror_sync_then_clone_borrows_objects() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let mirrors = ws._temp.path().join("mirrors");
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .env("GITGRIP_MIRROR_DIR", &mirrors)
+        .args(["mirror", "sync"])
+        .assert()
+        .success();
+
+    let mirror = gitgrip::git::mirror::mirror_path(&mirrors, &ws.remote_url("app"));
+    assert!(mirror.join("HEAD").is_file());
+
+    // Re-clone the repo: it should borrow objects from the mirror
+    std::fs::remove_dir_all(ws.repo_path("app")).unwrap();
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .env("GITGRIP_MIRROR_DIR", &mirrors)
+        .args(["sync", "--sequential"])
+        .assert()
+        .success();
+    let repo = gitgrip::git::open_repo(ws.repo_path("app")).unwrap();
+    assert!(gitgrip::git::mirror::uses_mirror(&repo, &mirror));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .env("GITGRIP_MIRROR_DIR", &mirrors)
+        .args(["mirror", "list"])
+        .assert()
+        .success()
+        .stdout(contains("shared with workspace"));
+}
+
+#[test]
+fn test_sync_refreshes_mirror_before_pulling() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let mirrors = ws._temp.path().join("mirrors");
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .env("GITGRIP_MIRROR_DIR", &mirrors)
+        .args(["mirror", "sync"])
+        .assert()
+        .success();
+
+    // Push upstream after the mirror was created
+    let staging = ws._temp.path().join("staging");
+    git_helpers::clone_repo(&ws.remote_url("app"), &staging);
+    let new_sha = git_helpers::commit_file(&staging, "new.txt", "new", "Add new file");
+    git_helpers::push_branch(&staging, "origin", "main");
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .env("GITGRIP_MIRROR_DIR", &mirrors)
+        .args(["sync", "--sequential"])
+        .assert()
+        .success();
+
+    assert_eq!(git_helpers::get_head_sha(&ws.repo_path("app")), new_sha);
+    let mirror = gitgrip::git::mirror::mirror_path(&mirrors, &ws.remote_url("app"));
+    let mirror_repo = git2::Repository::open_bare(&mirror).unwrap();
+    assert_eq!(
+        mirror_repo
+            .refname_to_id("refs/heads/main")
+            .unwrap()
+            .to_string(),
+        new_sha
+    );
+}
@@ -7,7 +7,7 @@ mod common;
 
 use common::mock_platform::*;
 use gitgrip::platform::traits::HostingPlatform;
-use gitgrip::platform::CheckState;
+use gitgrip::platform::{CheckState, IssueState};
 
 // ── PR Create ──────────────────────────────────────────────────────
 
@@ -135,6 +135,36 @@ async fn test_bb_is_approved() {
     assert!(result.unwrap(), "PR with all approvals should be approved");
 }
 
+// ── Comments ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_bb_pr_comments_follow_pagination() {
+    let (server, adapter) = setup_bitbucket_mock().await;
+    mock_bb_paginated_comments(&server, 10).await;
+
+    let comments = adapter
+        .get_pull_request_comments("owner", "repo", 10)
+        .await
+        .unwrap();
+    let bodies: Vec<&str> = comments.iter().map(|c| c.body.as_str()).collect();
+    assert_eq!(bodies, ["First page", "Second page"]);
+}
+
+// ── Issues ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_bb_list_issues_follows_pagination() {
+    let (server, adapter) = setup_bitbucket_mock().await;
+    mock_bb_paginated_issues(&server).await;
+
+    let issues = adapter
+        .list_issues("owner", "repo", IssueState::Open, &[])
+        .await
+        .unwrap();
+    let numbers: Vec<u64> = issues.iter().map(|i| i.number).collect();
+    assert_eq!(numbers, [1, 2]);
+}
+
 // ── URL Parsing ──────────────────────────────────────────────────
 
 #[test]
@@ -0,0 +1,334 @@
+//! Integration tests for the Gitea/Forgejo platform adapter using wiremock.
+//!
+//! Tests the GiteaAdapter against mock HTTP responses, verifying correct
+//! API interaction without requiring a real Gitea instance or network access.
+
+mod common;
+
+use common::mock_platform::*;
+use gitgrip::platform::traits::{HostingPlatform, PlatformError};
+use gitgrip::platform::{CheckState, PRCreateOptions, PRState};
+
+// ── PR Create ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_create_pr() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_create_pr(&server, 7).await;
+
+    let result = adapter
+        .create_pull_request("owner", "repo", "feat/test", "main", "Test PR", None, false)
+        .await;
+
+    assert!(result.is_ok(), "create PR should succeed: {:?}", result);
+    let pr = result.unwrap();
+    assert_eq!(pr.number, 7);
+    assert!(pr.url.contains("/pulls/7"));
+}
+
+// ── PR Get ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_get_pr_open() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_get_pr(&server, 7, "open", false).await;
+
+    let result = adapter.get_pull_request("owner", "repo", 7).await;
+
+    assert!(result.is_ok(), "get PR should succeed: {:?}", result);
+    let pr = result.unwrap();
+    assert_eq!(pr.number, 7);
+    assert_eq!(pr.state, PRState::Open);
+    assert_eq!(pr.head.ref_name, "feat/test");
+    assert_eq!(pr.head.sha, "abc123");
+    assert_eq!(pr.base.ref_name, "main");
+}
+
+#[tokio::test]
+async fn test_gitea_get_pr_merged() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_get_pr(&server, 7, "closed", true).await;
+
+    let pr = adapter.get_pull_request("owner", "repo", 7).await.unwrap();
+
+    assert_eq!(pr.state, PRState::Merged);
+    assert!(pr.merged);
+}
+
+#[tokio::test]
+async fn test_gitea_get_pr_not_found() {
+    let (_server, adapter) = setup_gitea_mock().await;
+
+    let result = adapter.get_pull_request("owner", "repo", 404).await;
+
+    assert!(
+        matches!(result, Err(PlatformError::NotFound(_))),
+        "should be NotFound: {:?}",
+        result
+    );
+}
+
+// ── PR Merge ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_merge_pr() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_merge_pr(&server, 7, 200, "").await;
+
+    let result = adapter
+        .merge_pull_request("owner", "repo", 7, None, false)
+        .await;
+
+    assert!(result.is_ok(), "merge should succeed: {:?}", result);
+    assert!(result.unwrap(), "PR should be merged");
+}
+
+#[tokio::test]
+async fn test_gitea_merge_pr_branch_behind() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_merge_pr(&server, 7, 409, "head branch is behind base branch").await;
+
+    let result = adapter
+        .merge_pull_request("owner", "repo", 7, None, false)
+        .await;
+
+    assert!(
+        matches!(result, Err(PlatformError::BranchBehind(_))),
+        "should fail with BranchBehind: {:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_gitea_merge_pr_branch_protected() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_merge_pr(&server, 7, 405, "Not enough approvals for protected branch").await;
+
+    let result = adapter
+        .merge_pull_request("owner", "repo", 7, None, false)
+        .await;
+
+    assert!(
+        matches!(result, Err(PlatformError::BranchProtected(_))),
+        "should fail with BranchProtected: {:?}",
+        result
+    );
+}
+
+// ── Find PR by Branch ──────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_find_pr_by_branch() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_list_prs(&server, vec![(6, "feat/other"), (7, "feat/test")]).await;
+
+    let result = adapter
+        .find_pr_by_branch("owner", "repo", "feat/test")
+        .await;
+
+    assert!(result.is_ok(), "find PR should succeed: {:?}", result);
+    let pr = result.unwrap();
+    assert!(pr.is_some(), "should find PR for branch");
+    assert_eq!(pr.unwrap().number, 7);
+}
+
+#[tokio::test]
+async fn test_gitea_find_pr_not_found() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_list_prs(&server, vec![(6, "feat/other")]).await;
+
+    let result = adapter
+        .find_pr_by_branch("owner", "repo", "feat/nonexistent")
+        .await;
+
+    assert!(result.is_ok());
+    assert!(result.unwrap().is_none(), "should not find PR");
+}
+
+// ── Reviews ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_is_approved() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_reviews(
+        &server,
+        7,
+        vec![("alice", "REQUEST_CHANGES"), ("alice", "APPROVED")],
+    )
+    .await;
+
+    let result = adapter.is_pull_request_approved("owner", "repo", 7).await;
+
+    assert!(result.is_ok());
+    assert!(result.unwrap(), "latest review is an approval");
+}
+
+#[tokio::test]
+async fn test_gitea_changes_requested() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_reviews(
+        &server,
+        7,
+        vec![("alice", "APPROVED"), ("bob", "REQUEST_CHANGES")],
+    )
+    .await;
+
+    let reviews = adapter
+        .get_pull_request_reviews("owner", "repo", 7)
+        .await
+        .unwrap();
+    assert_eq!(reviews.len(), 2);
+    assert_eq!(reviews[1].state, "CHANGES_REQUESTED");
+
+    let approved = adapter
+        .is_pull_request_approved("owner", "repo", 7)
+        .await
+        .unwrap();
+    assert!(!approved, "requested changes block approval");
+}
+
+// ── Status Checks ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_status_checks() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_status(
+        &server,
+        "abc123",
+        vec![("ci", "success"), ("lint", "pending")],
+    )
+    .await;
+
+    let result = adapter.get_status_checks("owner", "repo", "abc123").await;
+
+    assert!(result.is_ok(), "should get checks: {:?}", result);
+    let checks = result.unwrap();
+    assert_eq!(checks.state, CheckState::Pending);
+    assert_eq!(checks.statuses.len(), 2);
+}
+
+#[tokio::test]
+async fn test_gitea_status_checks_failure() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_status(
+        &server,
+        "abc123",
+        vec![("ci", "success"), ("lint", "error")],
+    )
+    .await;
+
+    let checks = adapter
+        .get_status_checks("owner", "repo", "abc123")
+        .await
+        .unwrap();
+
+    assert_eq!(checks.state, CheckState::Failure);
+}
+
+// ── Merge Methods ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_allowed_merge_methods() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_repo(&server, false, true, true).await;
+
+    let methods = adapter
+        .get_allowed_merge_methods("owner", "repo")
+        .await
+        .unwrap();
+
+    assert!(!methods.merge);
+    assert!(methods.squash);
+    assert!(methods.rebase);
+}
+
+// ── Diff ──────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_pr_diff() {
+    let (server, adapter) = setup_gitea_mock().await;
+    let diff = "diff --git a/file.txt b/file.txt\n+added line\n";
+    mock_gitea_diff(&server, 7, diff).await;
+
+    let result = adapter.get_pull_request_diff("owner", "repo", 7).await;
+
+    assert!(result.is_ok(), "should get diff: {:?}", result);
+    assert_eq!(result.unwrap(), diff);
+}
+
+// ── Releases ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_create_release() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_create_release(&server, 3, "v1.0.0").await;
+
+    let release = adapter
+        .create_release(
+            "owner", "repo", "v1.0.0", "v1.0.0", None, "main", false, false,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(release.id, 3);
+    assert_eq!(release.tag, "v1.0.0");
+    assert!(release.url.contains("/releases/tag/v1.0.0"));
+}
+
+// ── PR Metadata ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitea_set_pr_metadata_resolves_ids() {
+    let (server, adapter) = setup_gitea_mock().await;
+    mock_gitea_pr_metadata(&server, 5, vec![(11, "bug"), (12, "cross-repo")]).await;
+
+    let options = PRCreateOptions {
+        reviewers: vec!["alice".to_string()],
+        labels: vec!["cross-repo".to_string()],
+        assignees: vec!["bob".to_string()],
+        milestone: Some("v2".to_string()),
+        ..Default::default()
+    };
+    adapter
+        .set_pull_request_metadata("owner", "repo", 5, &options)
+        .await
+        .unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let update = requests.last().unwrap();
+    assert_eq!(update.method.as_str(), "PATCH");
+    let update: serde_json::Value = serde_json::from_slice(&update.body).unwrap();
+    assert_eq!(update["labels"], serde_json::json!([12]));
+    assert_eq!(update["assignees"][0], "bob");
+    assert_eq!(update["milestone"], 9);
+
+    let options = PRCreateOptions {
+        labels: vec!["missing".to_string()],
+        ..Default::default()
+    };
+    let err = adapter
+        .set_pull_request_metadata("owner", "repo", 5, &options)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PlatformError::NotFound(_)), "error: {}", err);
+}
+
+// ── URL Parsing ──────────────────────────────────────────────────
+
+#[test]
+fn test_gitea_parse_repo_url_ssh() {
+    let adapter = gitgrip::platform::gitea::GiteaAdapter::new(None);
+    let info = adapter
+        .parse_repo_url("git@codeberg.org:myorg/my-repo.git")
+        .expect("should parse SSH URL");
+    assert_eq!(info.owner, "myorg");
+    assert_eq!(info.repo, "my-repo");
+}
+
+#[test]
+fn test_gitea_matches_url() {
+    let adapter = gitgrip::platform::gitea::GiteaAdapter::new(None);
+    assert!(adapter.matches_url("https://codeberg.org/myorg/my-repo.git"));
+    assert!(adapter.matches_url("git@gitea.example.com:myorg/my-repo.git"));
+    assert!(!adapter.matches_url("git@github.com:myorg/my-repo.git"));
+}
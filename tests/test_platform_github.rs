@@ -7,7 +7,7 @@ mod common;
 
 use common::mock_platform::*;
 use gitgrip::platform::traits::HostingPlatform;
-use gitgrip::platform::{CheckState, MergeMethod};
+use gitgrip::platform::{CheckState, IssueState, MergeMethod, PRCreateOptions};
 
 // ── PR Create ──────────────────────────────────────────────────────
 
@@ -513,6 +513,235 @@ async fn test_github_server_error_on_checks() {
     assert!(result.is_err(), "should fail on server error");
 }
 
+// ── Issues ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_github_create_issue() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_create_issue(&server, 7).await;
+
+    let result = adapter
+        .create_issue("owner", "repo", "Test issue", None, &["bug".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(result.number, 7);
+    assert_eq!(result.url, "https://github.com/owner/repo/issues/7");
+
+    let requests = server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["title"], "Test issue");
+    assert_eq!(body["labels"][0], "bug");
+}
+
+#[tokio::test]
+async fn test_github_get_issue() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_get_issue(&server, 7, "closed", &["bug", "cross-repo"]).await;
+
+    let issue = adapter.get_issue("owner", "repo", 7).await.unwrap();
+    assert_eq!(issue.title, "Test issue");
+    assert_eq!(issue.state, IssueState::Closed);
+    assert_eq!(issue.labels, vec!["bug", "cross-repo"]);
+}
+
+#[tokio::test]
+async fn test_github_get_issue_not_found() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_not_found(&server, "/repos/owner/repo/issues/99").await;
+
+    let result = adapter.get_issue("owner", "repo", 99).await;
+    assert!(result.is_err(), "should fail for missing issue");
+}
+
+#[tokio::test]
+async fn test_github_list_issues_skips_pull_requests() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_list_issues(&server, vec![1, 3], vec![2]).await;
+
+    let issues = adapter
+        .list_issues("owner", "repo", IssueState::Open, &["bug".to_string()])
+        .await
+        .unwrap();
+    let numbers: Vec<u64> = issues.iter().map(|i| i.number).collect();
+    assert_eq!(numbers, vec![1, 3]);
+
+    let requests = server.received_requests().await.unwrap();
+    let query = requests[0].url.query().unwrap_or_default();
+    assert!(query.contains("state=open"), "query: {}", query);
+    assert!(query.contains("labels=bug"), "query: {}", query);
+}
+
+#[tokio::test]
+async fn test_github_list_issues_follows_pagination() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_paginated_list_issues(&server).await;
+
+    let issues = adapter
+        .list_issues("owner", "repo", IssueState::Open, &[])
+        .await
+        .unwrap();
+    let numbers: Vec<u64> = issues.iter().map(|i| i.number).collect();
+    assert_eq!(numbers, [1, 2]);
+}
+
+#[tokio::test]
+async fn test_github_comment_and_close_issue() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_issue_comment(&server, 7).await;
+    mock_update_issue(&server, 7).await;
+
+    adapter
+        .create_issue_comment("owner", "repo", 7, "Fixed in all repos")
+        .await
+        .unwrap();
+    adapter.close_issue("owner", "repo", 7).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let close: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(close["state"], "closed");
+}
+
+// ── PR Comments ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_github_get_and_create_pr_comments() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_pr_comments(
+        &server,
+        42,
+        vec![("alice", "Looks good"), ("bob", "One nit")],
+    )
+    .await;
+    mock_issue_comment(&server, 42).await;
+
+    let comments = adapter
+        .get_pull_request_comments("owner", "repo", 42)
+        .await
+        .unwrap();
+    assert_eq!(comments.len(), 2);
+    assert_eq!(comments[0].author, "alice");
+    assert_eq!(comments[1].body, "One nit");
+
+    adapter
+        .create_pull_request_comment("owner", "repo", 42, "Addressed")
+        .await
+        .unwrap();
+    let requests = server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(body["body"], "Addressed");
+}
+
+#[tokio::test]
+async fn test_github_pr_comments_follow_pagination() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_paginated_pr_comments(&server, 42).await;
+
+    let comments = adapter
+        .get_pull_request_comments("owner", "repo", 42)
+        .await
+        .unwrap();
+    let bodies: Vec<&str> = comments.iter().map(|c| c.body.as_str()).collect();
+    assert_eq!(bodies, ["First page", "Second page"]);
+}
+
+#[tokio::test]
+async fn test_github_review_threads_via_graphql() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_review_threads(
+        &server,
+        vec![
+            (true, "src/lib.rs", 10, "Fixed"),
+            (false, "src/main.rs", 3, "Please handle the error"),
+        ],
+    )
+    .await;
+
+    let threads = adapter
+        .get_review_threads("owner", "repo", 42)
+        .await
+        .unwrap();
+    assert_eq!(threads.len(), 2);
+    assert!(threads[0].resolved);
+    assert!(!threads[1].resolved);
+    assert_eq!(threads[1].path.as_deref(), Some("src/main.rs"));
+    assert_eq!(threads[1].line, Some(3));
+    assert_eq!(threads[1].comments[0].body, "Please handle the error");
+
+    let requests = server.received_requests().await.unwrap();
+    let query: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(query["variables"]["number"], 42);
+}
+
+#[tokio::test]
+async fn test_github_review_threads_follow_pages() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_paginated_review_threads(&server).await;
+
+    let threads = adapter
+        .get_review_threads("owner", "repo", 42)
+        .await
+        .unwrap();
+    assert_eq!(threads.len(), 2);
+    assert_eq!(threads[0].comments[0].body, "First");
+    let bodies: Vec<&str> = threads[1]
+        .comments
+        .iter()
+        .map(|c| c.body.as_str())
+        .collect();
+    assert_eq!(bodies, ["Second", "Reply"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+// ── PR Metadata ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_github_set_pr_reviewers_labels_and_milestone() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_pr_metadata(&server, 42, vec![(1, "v1"), (3, "v2")]).await;
+    mock_update_issue(&server, 42).await;
+
+    let options = PRCreateOptions {
+        reviewers: vec!["alice".to_string()],
+        team_reviewers: vec!["platform".to_string()],
+        labels: vec!["cross-repo".to_string()],
+        assignees: vec!["bob".to_string()],
+        milestone: Some("v2".to_string()),
+        ..Default::default()
+    };
+    adapter
+        .set_pull_request_metadata("owner", "repo", 42, &options)
+        .await
+        .unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let reviewers: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(reviewers["reviewers"][0], "alice");
+    assert_eq!(reviewers["team_reviewers"][0], "platform");
+
+    let update = requests.last().unwrap();
+    assert_eq!(update.method.as_str(), "PATCH");
+    let update: serde_json::Value = serde_json::from_slice(&update.body).unwrap();
+    assert_eq!(update["labels"][0], "cross-repo");
+    assert_eq!(update["assignees"][0], "bob");
+    assert_eq!(update["milestone"], 3);
+}
+
+#[tokio::test]
+async fn test_github_set_pr_metadata_unknown_milestone() {
+    let (server, adapter) = setup_github_mock().await;
+    mock_pr_metadata(&server, 42, vec![(1, "v1")]).await;
+
+    let options = PRCreateOptions {
+        milestone: Some("v9".to_string()),
+        ..Default::default()
+    };
+    let err = adapter
+        .set_pull_request_metadata("owner", "repo", 42, &options)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("v9"), "error: {}", err);
+}
+
 // ── URL Parsing ──────────────────────────────────────────────────
 
 #[test]
@@ -0,0 +1,40 @@
+//! Integration tests for the GitLab platform adapter using wiremock.
+//!
+//! Tests the GitLabAdapter against mock HTTP responses, verifying correct
+//! API interaction without requiring real GitLab credentials or network access.
+
+mod common;
+
+use common::mock_platform::*;
+use gitgrip::platform::traits::HostingPlatform;
+use gitgrip::platform::IssueState;
+
+// ── Comments ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitlab_pr_comments_follow_pagination() {
+    let (server, adapter) = setup_gitlab_mock().await;
+    mock_gitlab_paginated_discussions(&server, 7).await;
+
+    let comments = adapter
+        .get_pull_request_comments("owner", "repo", 7)
+        .await
+        .unwrap();
+    let bodies: Vec<&str> = comments.iter().map(|c| c.body.as_str()).collect();
+    assert_eq!(bodies, ["First page", "Second page"]);
+}
+
+// ── Issues ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_gitlab_list_issues_follows_pagination() {
+    let (server, adapter) = setup_gitlab_mock().await;
+    mock_gitlab_paginated_issues(&server).await;
+
+    let issues = adapter
+        .list_issues("owner", "repo", IssueState::Open, &[])
+        .await
+        .unwrap();
+    let numbers: Vec<u64> = issues.iter().map(|i| i.number).collect();
+    assert_eq!(numbers, [1, 2]);
+}
@@ -0,0 +1,389 @@
+//! Integration tests for the local file-backed platform adapter.
+//!
+//! Runs the PR workflow against bare repos on disk: PRs, reviews and checks
+//! are JSON files beside the remotes and merges move the bare repo's branches.
+
+mod common;
+
+use common::fixtures::{WorkspaceBuilder, WorkspaceFixture};
+use common::git_helpers;
+use gitgrip::core::manifest::{Manifest, PlatformConfig, PlatformType, PrDefaults};
+use gitgrip::platform::local::LocalAdapter;
+use gitgrip::platform::traits::HostingPlatform;
+use gitgrip::platform::{CheckState, MergeMethod, PRState};
+
+/// Manifest with every repo on the local platform
+fn local_manifest(ws: &WorkspaceFixture) -> Manifest {
+    let mut manifest = ws.load_manifest();
+    for config in manifest.repos.values_mut() {
+        config.platform = Some(PlatformConfig {
+            platform_type: PlatformType::Local,
+            base_url: None,
+        });
+    }
+    manifest
+}
+
+fn adapter(ws: &WorkspaceFixture) -> LocalAdapter {
+    LocalAdapter::new(Some(ws.remotes_dir.to_str().unwrap()))
+}
+
+/// Commit `file` on `branch` in `repo` and push the branch
+fn push_feature(ws: &WorkspaceFixture, repo: &str, branch: &str, file: &str) {
+    let path = ws.repo_path(repo);
+    git_helpers::create_branch(&path, branch);
+    git_helpers::commit_file(&path, file, "feature", &format!("Add {}", file));
+    git_helpers::push_branch(&path, "origin", branch);
+}
+
+// ── Full workflow ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_local_create_review_check_merge() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .build();
+    let manifest = local_manifest(&ws);
+    for repo in ["app", "lib"] {
+        git_helpers::create_branch(&ws.repo_path(repo), "feat/local");
+        git_helpers::commit_file(&ws.repo_path(repo), "feature.txt", "x", "Add feature");
+    }
+
+    gitgrip::cli::commands::pr::run_pr_create(
+        &ws.workspace_root,
+        &manifest,
+        Some("Local feature"),
+        None,
+        &Default::default(),
+        false,
+        true, // push first
+        false,
+        false,
+    )
+    .await
+    .unwrap();
+
+    let adapter = adapter(&ws);
+    for repo in ["app", "lib"] {
+        let pr = adapter
+            .find_pr_by_branch("local", repo, "feat/local")
+            .await
+            .unwrap()
+            .expect("PR should have been created");
+        assert_eq!(pr.number, 1);
+        assert!(pr.url.starts_with("file://"));
+
+        adapter.add_review(repo, 1, "alice", "APPROVED").unwrap();
+        adapter
+            .set_status_check(repo, "feat/local", "ci", CheckState::Success)
+            .unwrap();
+    }
+
+    gitgrip::cli::commands::pr::run_pr_merge(
+        &ws.workspace_root,
+        &manifest,
+        None,
+        false, // force
+        false,
+        false,
+        false,
+        false,
+        600,
+    )
+    .await
+    .unwrap();
+
+    for repo in ["app", "lib"] {
+        let pr = adapter.get_pull_request("local", repo, 1).await.unwrap();
+        assert_eq!(pr.state, PRState::Merged);
+        assert!(git_helpers::log_contains(
+            &ws.remote_path(repo),
+            "Merge pull request #1 from feat/local"
+        ));
+        assert!(!git_helpers::branch_exists(
+            &ws.remote_path(repo),
+            "feat/local"
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_local_merge_blocked_without_approval() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let manifest = local_manifest(&ws);
+    push_feature(&ws, "app", "feat/x", "x.txt");
+
+    let adapter = adapter(&ws);
+    adapter
+        .create_pull_request("local", "app", "feat/x", "main", "X", None, false)
+        .await
+        .unwrap();
+    adapter
+        .set_status_check("app", "feat/x", "ci", CheckState::Pending)
+        .unwrap();
+
+    gitgrip::cli::commands::pr::run_pr_merge(
+        &ws.workspace_root,
+        &manifest,
+        None,
+        false,
+        false,
+        false,
+        false,
+        false,
+        600,
+    )
+    .await
+    .unwrap();
+
+    let pr = adapter.get_pull_request("local", "app", 1).await.unwrap();
+    assert_eq!(pr.state, PRState::Open, "unapproved PR should stay open");
+    assert_eq!(pr.mergeable, Some(true));
+}
+
+// ── Adapter ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_local_squash_and_rebase() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let adapter = adapter(&ws);
+
+    push_feature(&ws, "app", "feat/squash", "a.txt");
+    adapter
+        .create_pull_request(
+            "local",
+            "app",
+            "feat/squash",
+            "main",
+            "Squash me",
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+    adapter
+        .merge_pull_request("local", "app", 1, Some(MergeMethod::Squash), false)
+        .await
+        .unwrap();
+    assert!(git_helpers::log_contains(
+        &ws.remote_path("app"),
+        "Squash me (#1)"
+    ));
+
+    git_helpers::checkout(&ws.repo_path("app"), "main");
+    push_feature(&ws, "app", "feat/rebase", "b.txt");
+    adapter
+        .create_pull_request("local", "app", "feat/rebase", "main", "Rebase", None, false)
+        .await
+        .unwrap();
+    adapter
+        .merge_pull_request("local", "app", 2, Some(MergeMethod::Rebase), false)
+        .await
+        .unwrap();
+
+    let remote = ws.remote_path("app");
+    assert!(git_helpers::log_contains(&remote, "Add b.txt"));
+    assert!(git_helpers::log_contains(&remote, "Squash me (#1)"));
+    assert!(!git_helpers::log_contains(&remote, "Merge"));
+}
+
+#[tokio::test]
+async fn test_local_conflict_and_update_branch() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let adapter = adapter(&ws);
+    let path = ws.repo_path("app");
+
+    push_feature(&ws, "app", "feat/one", "shared.txt");
+    adapter
+        .create_pull_request("local", "app", "feat/one", "main", "One", None, false)
+        .await
+        .unwrap();
+
+    // Advance main with an unrelated change: PR is behind but mergeable
+    git_helpers::checkout(&path, "main");
+    git_helpers::commit_file(&path, "other.txt", "other", "Other change");
+    git_helpers::push_branch(&path, "origin", "main");
+    assert!(adapter.update_branch("local", "app", 1).await.unwrap());
+    assert!(!adapter.update_branch("local", "app", 1).await.unwrap());
+
+    // Conflicting change on main
+    git_helpers::commit_file(&path, "shared.txt", "conflict", "Conflicting change");
+    git_helpers::push_branch(&path, "origin", "main");
+    let pr = adapter.get_pull_request("local", "app", 1).await.unwrap();
+    assert_eq!(pr.mergeable, Some(false));
+    let result = adapter
+        .merge_pull_request("local", "app", 1, None, false)
+        .await;
+    assert!(result.is_err(), "conflicting merge should fail");
+}
+
+#[tokio::test]
+async fn test_local_reviews_checks_diff_release() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let adapter = adapter(&ws);
+    push_feature(&ws, "app", "feat/y", "y.txt");
+    adapter
+        .create_pull_request("local", "app", "feat/y", "main", "Y", Some("Body"), true)
+        .await
+        .unwrap();
+
+    adapter.add_review("app", 1, "alice", "APPROVED").unwrap();
+    adapter
+        .add_review("app", 1, "bob", "CHANGES_REQUESTED")
+        .unwrap();
+    assert!(!adapter
+        .is_pull_request_approved("local", "app", 1)
+        .await
+        .unwrap());
+    adapter.add_review("app", 1, "bob", "APPROVED").unwrap();
+    assert!(adapter
+        .is_pull_request_approved("local", "app", 1)
+        .await
+        .unwrap());
+
+    let checks = adapter
+        .get_status_checks("local", "app", "feat/y")
+        .await
+        .unwrap();
+    assert_eq!(checks.state, CheckState::Success, "no checks is passing");
+    adapter
+        .set_status_check("app", "feat/y", "ci", CheckState::Failure)
+        .unwrap();
+    let checks = adapter
+        .get_status_checks("local", "app", "feat/y")
+        .await
+        .unwrap();
+    assert_eq!(checks.state, CheckState::Failure);
+
+    let diff = adapter
+        .get_pull_request_diff("local", "app", 1)
+        .await
+        .unwrap();
+    assert!(diff.contains("+++ b/y.txt"), "diff: {}", diff);
+    assert!(diff.contains("+feature"));
+
+    let release = adapter
+        .create_release(
+            "local", "app", "v1.0.0", "v1.0.0", None, "main", false, false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(release.tag, "v1.0.0");
+    let output = std::process::Command::new("git")
+        .args(["tag", "--list"])
+        .current_dir(ws.remote_path("app"))
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).contains("v1.0.0"));
+}
+
+#[tokio::test]
+async fn test_local_pr_comments_across_repos() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .build();
+    let manifest = local_manifest(&ws);
+    let adapter = adapter(&ws);
+    for repo in ["app", "lib"] {
+        push_feature(&ws, repo, "feat/talk", "talk.txt");
+        adapter
+            .create_pull_request("local", repo, "feat/talk", "main", "Talk", None, false)
+            .await
+            .unwrap();
+    }
+
+    gitgrip::cli::commands::pr::run_pr_comment(
+        &ws.workspace_root,
+        &manifest,
+        "Rebased on the new API",
+        None,
+    )
+    .await
+    .unwrap();
+
+    for repo in ["app", "lib"] {
+        let comments = adapter
+            .get_pull_request_comments("local", repo, 1)
+            .await
+            .unwrap();
+        assert_eq!(comments.len(), 1, "{} should have one comment", repo);
+        assert_eq!(comments[0].body, "Rebased on the new API");
+    }
+
+    adapter
+        .add_review_thread("app", 1, "alice", "talk.txt", 1, "Typo here")
+        .unwrap();
+    adapter
+        .add_review_thread("app", 1, "bob", "talk.txt", 1, "Rename this")
+        .unwrap();
+    adapter.resolve_review_thread("app", 1, 0).unwrap();
+
+    let threads = adapter.get_review_threads("local", "app", 1).await.unwrap();
+    assert_eq!(threads.len(), 2);
+    assert!(threads[0].resolved);
+    assert!(!threads[1].resolved);
+    assert_eq!(threads[1].path.as_deref(), Some("talk.txt"));
+    assert_eq!(threads[1].comments[0].author, "bob");
+
+    gitgrip::cli::commands::pr::run_pr_comments(&ws.workspace_root, &manifest, true, true)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_local_pr_create_applies_reviewers_and_labels() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("app")
+        .add_repo("lib")
+        .build();
+    let mut manifest = local_manifest(&ws);
+    manifest.settings.pr = PrDefaults {
+        reviewers: Some(vec!["alice".to_string(), "bob".to_string()]),
+        labels: Some(vec!["cross-repo".to_string()]),
+        ..Default::default()
+    };
+    manifest.repos.get_mut("lib").unwrap().pr = Some(PrDefaults {
+        reviewers: Some(vec!["carol".to_string()]),
+        ..Default::default()
+    });
+    for repo in ["app", "lib"] {
+        git_helpers::create_branch(&ws.repo_path(repo), "feat/meta");
+        git_helpers::commit_file(&ws.repo_path(repo), "meta.txt", "x", "Add meta");
+    }
+
+    let cli = PrDefaults {
+        labels: Some(vec!["urgent".to_string()]),
+        milestone: Some("v2".to_string()),
+        ..Default::default()
+    };
+    gitgrip::cli::commands::pr::run_pr_create(
+        &ws.workspace_root,
+        &manifest,
+        Some("Metadata"),
+        None,
+        &cli,
+        false,
+        true, // push first
+        false,
+        true,
+    )
+    .await
+    .unwrap();
+
+    let adapter = adapter(&ws);
+    let app = adapter.pull_request_options("app", 1).unwrap();
+    assert_eq!(app.reviewers, vec!["alice", "bob"]);
+    assert_eq!(app.labels, vec!["cross-repo", "urgent"]);
+    assert_eq!(app.milestone.as_deref(), Some("v2"));
+
+    let lib = adapter.pull_request_options("lib", 1).unwrap();
+    assert_eq!(
+        lib.reviewers,
+        vec!["carol"],
+        "repo override replaces reviewers"
+    );
+    assert_eq!(lib.labels, vec!["cross-repo", "urgent"]);
+    assert!(lib.assignees.is_empty());
+}
@@ -298,3 +298,38 @@ fn test_repo_remove_preserves_files_by_default() {
         "backend files should be preserved when delete_files=false"
     );
 }
+
+#[test]
+fn test_repo_add_and_remove_preserve_comments() {
+    let ws = WorkspaceBuilder::new().add_repo("frontend").build();
+    let manifest_path = workspace_manifest_path(&ws.workspace_root);
+    let content = fs::read_to_string(&manifest_path).unwrap().replacen(
+        "repos:\n",
+        "# Our repos\nrepos:\n  # Web frontend\n",
+        1,
+    );
+    fs::write(&manifest_path, &content).unwrap();
+
+    gitgrip::cli::commands::repo::run_repo_add(
+        &ws.workspace_root,
+        "git@github.com:owner/api.git",
+        None,
+        None,
+    )
+    .unwrap();
+    let updated = fs::read_to_string(&manifest_path).unwrap();
+    assert!(updated.contains("  # Web frontend\n  frontend:\n"));
+    assert!(updated.contains("  api:\n    url: git@github.com:owner/api.git\n"));
+
+    let err = gitgrip::cli::commands::repo::run_repo_add(
+        &ws.workspace_root,
+        "git@github.com:owner/api.git",
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("already exists"));
+
+    gitgrip::cli::commands::repo::run_repo_remove(&ws.workspace_root, "api", false).unwrap();
+    assert_eq!(fs::read_to_string(&manifest_path).unwrap(), content);
+}
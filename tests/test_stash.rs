@@ -0,0 +1,115 @@
+//! Integration tests for workspace stashes (`gr stash`).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use predicates::str::contains;
+
+use common::fixtures::WorkspaceBuilder;
+use common::git_helpers;
+
+#[test]
+fn test_stash_push_and_pop_all_repos() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .add_repo("shared")
+        .build();
+
+    std::fs::write(ws.repo_path("frontend").join("README.md"), "# Changed").unwrap();
+    std::fs::write(ws.repo_path("backend").join("new.txt"), "untracked").unwrap();
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["stash", "push", "-m", "wip"])
+        .assert()
+        .success();
+    assert!(git_helpers::is_clean(&ws.repo_path("frontend")));
+    assert!(git_helpers::is_clean(&ws.repo_path("backend")));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["stash", "list"])
+        .assert()
+        .success()
+        .stdout(contains("stash-1"))
+        .stdout(contains("wip"))
+        .stdout(contains("backend, frontend").or(contains("frontend, backend")));
+
+    // A dirty repo blocks the pop before anything is restored
+    std::fs::write(ws.repo_path("backend").join("other.txt"), "dirty").unwrap();
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["stash", "pop"])
+        .assert()
+        .failure()
+        .stderr(contains("backend: has uncommitted changes"));
+    assert!(git_helpers::is_clean(&ws.repo_path("frontend")));
+    std::fs::remove_file(ws.repo_path("backend").join("other.txt")).unwrap();
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["stash", "pop", "stash-1"])
+        .assert()
+        .success();
+    assert_eq!(
+        std::fs::read_to_string(ws.repo_path("frontend").join("README.md")).unwrap(),
+        "# Changed"
+    );
+    assert!(ws.repo_path("backend").join("new.txt").exists());
+    assert!(git_helpers::is_clean(&ws.repo_path("shared")));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["stash", "list", "--json"])
+        .assert()
+        .success()
+        .stdout(contains("[]"));
+}
+
+#[test]
+fn test_stash_pop_rolls_back_on_conflict() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    std::fs::write(ws.repo_path("frontend").join("README.md"), "# Frontend").unwrap();
+    std::fs::write(ws.repo_path("backend").join("README.md"), "# Backend").unwrap();
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["stash", "push"])
+        .assert()
+        .success();
+
+    // Conflicting commit in one repo
+    git_helpers::commit_file(
+        &ws.repo_path("backend"),
+        "README.md",
+        "# Conflict",
+        "Conflict",
+    );
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["stash", "pop"])
+        .assert()
+        .failure()
+        .stderr(contains("Pop aborted"));
+    assert!(git_helpers::is_clean(&ws.repo_path("frontend")));
+    assert!(git_helpers::is_clean(&ws.repo_path("backend")));
+
+    // The stash is kept and can be dropped
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["stash", "drop", "stash-1"])
+        .assert()
+        .success();
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["stash", "list"])
+        .assert()
+        .success()
+        .stdout(contains("stash-1").not());
+}
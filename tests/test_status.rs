@@ -2,9 +2,7 @@
 
 mod common;
 
-use common::assertions;
 use common::fixtures::WorkspaceBuilder;
-use common::git_helpers;
 
 #[test]
 fn test_status_clean_workspace() {
@@ -152,3 +150,28 @@ fn test_status_quiet_mode_with_changes() {
         result.err()
     );
 }
+
+#[test]
+fn test_status_pinned_repo_drifted() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let repo = ws.repo_path("app");
+
+    let mut manifest = ws.load_manifest();
+    let pinned = common::git_helpers::get_head_sha(&repo);
+    manifest.repos.get_mut("app").unwrap().revision = Some(pinned);
+
+    // Move HEAD away from the pin
+    common::git_helpers::commit_file(&repo, "drift.txt", "drift", "Drift from pin");
+
+    for (quiet, json) in [(false, false), (true, false), (false, true)] {
+        let result = gitgrip::cli::commands::status::run_status(
+            &ws.workspace_root,
+            &manifest,
+            false,
+            quiet,
+            None,
+            json,
+        );
+        assert!(result.is_ok(), "status should succeed: {:?}", result.err());
+    }
+}
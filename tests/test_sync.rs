@@ -37,18 +37,23 @@ async fn test_sync_clones_missing_repos() {
 
     let manifest = ws.load_manifest();
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
 
     // backend should now be cloned
@@ -68,18 +73,23 @@ async fn test_sync_pulls_existing_repos() {
 
     let manifest = ws.load_manifest();
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
 
     // The new file should now exist in the workspace repo
@@ -101,18 +111,23 @@ async fn test_sync_uses_griptree_upstream_mapping() {
     write_griptree_config(&ws.workspace_root, "feat/griptree", "app", "origin/dev");
     let manifest = ws.load_manifest();
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
 
     assert_file_exists(&ws.repo_path("app").join("dev-only.txt"));
@@ -131,18 +146,23 @@ async fn test_sync_sets_tracking_upstream_for_griptree_base_branch() {
     write_griptree_config(&ws.workspace_root, "feat/griptree", "app", "origin/main");
     let manifest = ws.load_manifest();
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
 
     assert_eq!(
@@ -158,18 +178,23 @@ async fn test_sync_handles_up_to_date() {
     let manifest = ws.load_manifest();
 
     // Sync when already up to date
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(
         result.is_ok(),
         "sync should succeed when up to date: {:?}",
@@ -197,18 +222,23 @@ async fn test_sync_skips_griptree_base_with_local_commits_ahead() {
     write_griptree_config(&ws.workspace_root, "feat/griptree", "app", "origin/main");
     let manifest = ws.load_manifest();
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
 
     assert_file_exists(&ws.repo_path("app").join("local-only.txt"));
@@ -236,18 +266,23 @@ async fn test_sync_reset_refs_hard_resets_reference_repo() {
     assert_ne!(local_sha, remote_sha);
 
     let manifest = ws.load_manifest();
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        true,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: true,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
 
     let synced_sha = git_helpers::get_head_sha(&ws.repo_path("ref"));
@@ -274,18 +309,23 @@ async fn test_sync_reset_refs_checks_out_upstream_branch() {
     write_griptree_config(&ws.workspace_root, "feat/griptree", "ref", "origin/dev");
     let manifest = ws.load_manifest();
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        true,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: true,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
 
     assert_on_branch(&ws.repo_path("ref"), "dev");
@@ -316,18 +356,23 @@ async fn test_sync_reset_refs_falls_back_to_detached_when_branch_locked_in_workt
     write_griptree_config(&ws.workspace_root, "feat/griptree", "ref", "origin/dev");
     let manifest = ws.load_manifest();
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        true,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: true,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
 
     assert_file_exists(&ref_repo.join("dev-only.txt"));
@@ -369,18 +414,23 @@ async fn test_sync_multiple_repos() {
 
     let manifest = ws.load_manifest();
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
 
     // All should now be cloned
@@ -399,18 +449,23 @@ async fn test_sync_quiet_mode() {
     let manifest = ws.load_manifest();
 
     // Quiet sync on already-synced repos should succeed (suppresses "up to date" messages)
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        true,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: true,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(
         result.is_ok(),
         "quiet sync should succeed: {:?}",
@@ -428,18 +483,23 @@ async fn test_sync_sequential_mode() {
     let manifest = ws.load_manifest();
 
     // Sequential sync (--sequential flag)
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        true,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: true,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(
         result.is_ok(),
         "sequential sync should succeed: {:?}",
@@ -458,18 +518,23 @@ async fn test_sync_clone_failure_invalid_url() {
     manifest.repos.get_mut("app").expect("app repo config").url =
         "file:///does-not-exist/repo.git".to_string();
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should not crash: {:?}", result.err());
 
     // Clone should fail, leaving no git metadata
@@ -488,18 +553,23 @@ async fn test_sync_existing_repo_missing_git_dir() {
     fs::remove_dir_all(ws.repo_path("app").join(".git")).unwrap();
     assert!(!ws.repo_path("app").join(".git").exists());
 
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        false,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: false,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should not crash: {:?}", result.err());
 
     // Sync should report error and leave repo unchanged (still missing .git)
@@ -541,18 +611,23 @@ workspace:
     append_hooks_to_manifest(&ws.workspace_root, &hooks_yaml);
 
     let manifest = ws.load_manifest();
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        true,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: true,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
     assert!(marker.exists(), "hook marker file should have been created");
 }
@@ -573,18 +648,23 @@ workspace:
     append_hooks_to_manifest(&ws.workspace_root, hooks_yaml);
 
     let manifest = ws.load_manifest();
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        true,
-        None,
-        false,
-        false,
-        false,
-        false,
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: true,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     // Sync should still succeed even though hook failed
     assert!(
         result.is_ok(),
@@ -613,21 +693,354 @@ workspace:
     append_hooks_to_manifest(&ws.workspace_root, &hooks_yaml);
 
     let manifest = ws.load_manifest();
-    let result = gitgrip::cli::commands::sync::run_sync(
-        &ws.workspace_root,
-        &manifest,
-        false,
-        true,
-        None,
-        false,
-        false,
-        false,
-        true, // no_hooks = true
-    )
-    .await;
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: true,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: true,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
     assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
     assert!(
         !marker.exists(),
         "hook marker file should NOT exist when --no-hooks is set"
     );
 }
+
+fn pinned_sync_options<'a>(
+    workspace_root: &'a std::path::PathBuf,
+    manifest: &'a gitgrip::core::manifest::Manifest,
+) -> gitgrip::cli::commands::sync::SyncOptions<'a> {
+    gitgrip::cli::commands::sync::SyncOptions {
+        workspace_root,
+        manifest,
+        force: false,
+        quiet: true,
+        group_filter: None,
+        sequential: true,
+        reset_refs: false,
+        json: false,
+        no_hooks: true,
+        locked: false,
+        depth: None,
+        filter: None,
+        unshallow: false,
+    }
+}
+
+#[tokio::test]
+async fn test_sync_checks_out_pinned_tag_detached() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let repo = ws.repo_path("app");
+
+    let tagged = git_helpers::get_head_sha(&repo);
+    git(&repo, &["tag", "v1.0"]);
+    git(&repo, &["push", "origin", "v1.0"]);
+    git_helpers::commit_file(&repo, "later.txt", "later", "After the tag");
+    git_helpers::push_branch(&repo, "origin", "main");
+
+    let mut manifest = ws.load_manifest();
+    manifest.repos.get_mut("app").unwrap().revision = Some("v1.0".to_string());
+
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+
+    assert_eq!(git_helpers::get_head_sha(&repo), tagged);
+    assert_eq!(git_helpers::current_branch(&repo), "HEAD");
+}
+
+#[tokio::test]
+async fn test_sync_clones_pinned_sha() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let repo = ws.repo_path("app");
+
+    let pinned = git_helpers::get_head_sha(&repo);
+    git_helpers::commit_file(&repo, "later.txt", "later", "After the pin");
+    git_helpers::push_branch(&repo, "origin", "main");
+    fs::remove_dir_all(&repo).unwrap();
+
+    let mut manifest = ws.load_manifest();
+    manifest.repos.get_mut("app").unwrap().revision = Some(pinned.clone());
+
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+
+    assert_eq!(git_helpers::get_head_sha(&repo), pinned);
+}
+
+#[tokio::test]
+async fn test_sync_checks_out_pinned_branch_with_tracking() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let repo = ws.repo_path("app");
+
+    git_helpers::create_branch(&repo, "release");
+    let release_sha = git_helpers::commit_file(&repo, "release.txt", "r", "Release work");
+    git_helpers::push_branch(&repo, "origin", "release");
+    git_helpers::checkout(&repo, "main");
+
+    let mut manifest = ws.load_manifest();
+    manifest.repos.get_mut("app").unwrap().revision = Some("release".to_string());
+
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+
+    assert_on_branch(&repo, "release");
+    assert_eq!(git_helpers::get_head_sha(&repo), release_sha);
+    assert_eq!(
+        git_helpers::branch_upstream(&repo, "release").as_deref(),
+        Some("origin/release")
+    );
+}
+
+#[tokio::test]
+async fn test_sync_pinned_repo_keeps_feature_branch() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let repo = ws.repo_path("app");
+
+    let pinned = git_helpers::get_head_sha(&repo);
+    git_helpers::create_branch(&repo, "feat/work");
+    let feature_sha = git_helpers::commit_file(&repo, "feat.txt", "f", "Feature work");
+
+    let mut manifest = ws.load_manifest();
+    manifest.repos.get_mut("app").unwrap().revision = Some(pinned);
+
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+
+    assert_on_branch(&repo, "feat/work");
+    assert_eq!(git_helpers::get_head_sha(&repo), feature_sha);
+}
+
+#[tokio::test]
+async fn test_sync_clones_repo_from_named_remote() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let repo = ws.repo_path("app");
+    fs::remove_dir_all(&repo).unwrap();
+
+    let mut manifest = ws.load_manifest();
+    let fetch_base = format!("file://{}", ws.remotes_dir.display());
+    manifest.remotes = Some(
+        [(
+            "local".to_string(),
+            gitgrip::core::manifest::RemoteConfig {
+                fetch: fetch_base.clone(),
+                push: Some("git@example.com:mirror".to_string()),
+                platform: None,
+            },
+        )]
+        .into_iter()
+        .collect(),
+    );
+    let config = manifest.repos.get_mut("app").unwrap();
+    config.url = String::new();
+    config.remote = Some("local".to_string());
+    config.name = Some("app.git".to_string());
+    manifest.validate().unwrap();
+
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+
+    assert!(repo.join(".git").exists());
+    let remote_url = |extra: &[&str]| {
+        let mut args = vec!["remote", "get-url"];
+        args.extend_from_slice(extra);
+        args.push("origin");
+        let output = Command::new("git")
+            .current_dir(&repo)
+            .args(&args)
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+    assert_eq!(remote_url(&[]), format!("{}/app.git", fetch_base));
+    assert_eq!(remote_url(&["--push"]), "git@example.com:mirror/app.git");
+}
+
+fn is_shallow(repo: &Path) -> bool {
+    git2::Repository::open(repo).unwrap().is_shallow()
+}
+
+#[tokio::test]
+async fn test_sync_shallow_clone_then_unshallow() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let repo = ws.repo_path("app");
+
+    git_helpers::commit_file(&repo, "later.txt", "later", "Second commit");
+    git_helpers::push_branch(&repo, "origin", "main");
+    fs::remove_dir_all(&repo).unwrap();
+
+    let mut manifest = ws.load_manifest();
+    manifest.repos.get_mut("app").unwrap().clone = Some(gitgrip::core::manifest::CloneConfig {
+        depth: Some(1),
+        filter: None,
+        single_branch: false,
+    });
+
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+    assert!(is_shallow(&repo), "clone should be shallow");
+
+    // A plain sync keeps the clone shallow
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+    assert!(is_shallow(&repo));
+
+    let mut opts = pinned_sync_options(&ws.workspace_root, &manifest);
+    opts.unshallow = true;
+    gitgrip::cli::commands::sync::run_sync(opts)
+        .await
+        .expect("sync --unshallow should succeed");
+    assert!(!is_shallow(&repo), "clone should have full history");
+}
+
+#[tokio::test]
+async fn test_sync_depth_flag_still_reaches_pinned_sha() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let repo = ws.repo_path("app");
+
+    let pinned = git_helpers::get_head_sha(&repo);
+    git_helpers::commit_file(&repo, "later.txt", "later", "After the pin");
+    git_helpers::push_branch(&repo, "origin", "main");
+    fs::remove_dir_all(&repo).unwrap();
+
+    let mut manifest = ws.load_manifest();
+    manifest.repos.get_mut("app").unwrap().revision = Some(pinned.clone());
+
+    let mut opts = pinned_sync_options(&ws.workspace_root, &manifest);
+    opts.depth = Some(1);
+    gitgrip::cli::commands::sync::run_sync(opts)
+        .await
+        .expect("sync should succeed");
+
+    assert_eq!(git_helpers::get_head_sha(&repo), pinned);
+}
+
+#[tokio::test]
+async fn test_sync_sparse_checkout_follows_manifest() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo_with_files(
+            "platform",
+            vec![
+                ("services/api/main.rs", "fn main() {}"),
+                ("services/web/index.js", "// web"),
+                ("docs/guide.md", "# Guide"),
+            ],
+        )
+        .build();
+    let repo = ws.repo_path("platform");
+    fs::remove_dir_all(&repo).unwrap();
+
+    let mut manifest = ws.load_manifest();
+    manifest.repos.get_mut("platform").unwrap().sparse = vec!["services/api".to_string()];
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+
+    assert!(repo.join("services/api/main.rs").exists());
+    assert!(!repo.join("services/web").exists());
+    assert!(!repo.join("docs").exists());
+
+    // Skipped paths are not reported as changes
+    let git_repo = gitgrip::git::open_repo(&repo).unwrap();
+    assert!(
+        gitgrip::git::status::get_status_info(&git_repo)
+            .unwrap()
+            .is_clean
+    );
+    assert!(
+        gitgrip::git::status::get_changed_files_since(&git_repo, "HEAD")
+            .unwrap()
+            .is_empty()
+    );
+
+    // Widening the list in the manifest updates the checkout
+    manifest.repos.get_mut("platform").unwrap().sparse =
+        vec!["services/api".to_string(), "docs".to_string()];
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+    assert!(repo.join("docs/guide.md").exists());
+    assert!(!repo.join("services/web").exists());
+
+    // Dropping it restores the full checkout
+    manifest.repos.get_mut("platform").unwrap().sparse = Vec::new();
+    gitgrip::cli::commands::sync::run_sync(pinned_sync_options(&ws.workspace_root, &manifest))
+        .await
+        .expect("sync should succeed");
+    assert!(repo.join("services/web/index.js").exists());
+}
+
+#[tokio::test]
+async fn test_sync_sets_push_url_on_existing_clone() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+
+    let manifest_path = ws
+        .workspace_root
+        .join(".gitgrip")
+        .join("spaces")
+        .join("main")
+        .join("gripspace.yml");
+    let yaml = format!(
+        r#"version: 1
+remotes:
+  origin:
+    fetch: file://{}
+    push: git@example.com:team
+repos:
+  app:
+    remote: origin
+    name: app.git
+    path: app
+    default_branch: main
+"#,
+        ws.remotes_dir.display()
+    );
+    fs::write(&manifest_path, yaml).unwrap();
+
+    let manifest = ws.load_manifest();
+    let result =
+        gitgrip::cli::commands::sync::run_sync(gitgrip::cli::commands::sync::SyncOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            force: false,
+            quiet: true,
+            group_filter: None,
+            sequential: false,
+            reset_refs: false,
+            json: false,
+            no_hooks: false,
+            locked: false,
+            depth: None,
+            filter: None,
+            unshallow: false,
+        })
+        .await;
+    assert!(result.is_ok(), "sync should succeed: {:?}", result.err());
+
+    let output = Command::new("git")
+        .args(["remote", "get-url", "--push", "origin"])
+        .current_dir(ws.repo_path("app"))
+        .output()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "git@example.com:team/app.git"
+    );
+}
@@ -0,0 +1,86 @@
+//! Integration tests for cross-repo tags (`gr tag`).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::str::contains;
+
+use common::fixtures::WorkspaceBuilder;
+use common::git_helpers;
+
+#[test]
+fn test_tag_create_list_and_delete() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["tag", "qa-1", "-m", "QA snapshot", "--push"])
+        .assert()
+        .success();
+    for repo in ["frontend", "backend"] {
+        assert!(git_helpers::has_tag(&ws.repo_path(repo), "qa-1"));
+        assert!(git_helpers::has_tag(&ws.remote_path(repo), "qa-1"));
+    }
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["tag", "qa-2", "--repo", "frontend"])
+        .assert()
+        .success();
+    assert!(!git_helpers::has_tag(&ws.repo_path("backend"), "qa-2"));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["tag"])
+        .assert()
+        .success()
+        .stdout(contains("qa-1 (all repos)"))
+        .stdout(contains("qa-2 (1/2 repos, missing in: backend)"));
+
+    // Creating an existing tag touches nothing
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["tag", "qa-2"])
+        .assert()
+        .failure()
+        .stderr(contains("already exists in: frontend"));
+    assert!(!git_helpers::has_tag(&ws.repo_path("backend"), "qa-2"));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["tag", "-d", "qa-1", "--push"])
+        .assert()
+        .success();
+    for repo in ["frontend", "backend"] {
+        assert!(!git_helpers::has_tag(&ws.repo_path(repo), "qa-1"));
+        assert!(!git_helpers::has_tag(&ws.remote_path(repo), "qa-1"));
+    }
+}
+
+#[test]
+fn test_tag_list_json() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["tag", "v1.0", "--repo", "backend"])
+        .assert()
+        .success();
+
+    let output = cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["tag", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let tags: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(tags[0]["name"], "v1.0");
+    assert_eq!(tags[0]["repos"], serde_json::json!(["backend"]));
+    assert_eq!(tags[0]["missing"], serde_json::json!(["frontend"]));
+}
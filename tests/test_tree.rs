@@ -8,7 +8,7 @@ mod common;
 use std::fs;
 use std::path::Path;
 
-use assert_cmd::Command;
+use assert_cmd::cargo::cargo_bin_cmd;
 use predicates::str::contains;
 use serde_yaml::Value;
 
@@ -246,7 +246,7 @@ fn test_tree_list_from_griptree_workspace_shows_registered_tree() {
 
     let tree_path = ws.workspace_root.parent().unwrap().join("feat-listed");
 
-    let mut cmd = Command::cargo_bin("gr").expect("gr binary should build");
+    let mut cmd = cargo_bin_cmd!("gr");
     cmd.current_dir(&tree_path)
         .args(["tree", "list"])
         .assert()
@@ -0,0 +1,208 @@
+//! Integration tests for the operation log (`gr op log`, `gr undo`).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use predicates::str::contains;
+
+use common::fixtures::WorkspaceBuilder;
+use common::git_helpers;
+
+#[test]
+fn test_undo_commit_across_repos() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    let frontend_head = git_helpers::get_head_sha(&ws.repo_path("frontend"));
+    let backend_head = git_helpers::get_head_sha(&ws.repo_path("backend"));
+
+    for repo in ["frontend", "backend"] {
+        std::fs::write(ws.repo_path(repo).join("change.txt"), "change").unwrap();
+    }
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["add", "."])
+        .assert()
+        .success();
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["commit", "-m", "Break things"])
+        .assert()
+        .success();
+    assert_ne!(
+        git_helpers::get_head_sha(&ws.repo_path("frontend")),
+        frontend_head
+    );
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["op", "log"])
+        .assert()
+        .success()
+        .stdout(contains("gr commit -m \"Break things\""))
+        .stdout(contains("frontend"));
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["undo"])
+        .assert()
+        .success();
+    assert_eq!(
+        git_helpers::get_head_sha(&ws.repo_path("frontend")),
+        frontend_head
+    );
+    assert_eq!(
+        git_helpers::get_head_sha(&ws.repo_path("backend")),
+        backend_head
+    );
+    assert_eq!(
+        git_helpers::current_branch(&ws.repo_path("backend")),
+        "main"
+    );
+    // The undone commit's changes stay in the worktree
+    assert!(ws.repo_path("frontend").join("change.txt").exists());
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["op", "log"])
+        .assert()
+        .success()
+        .stdout(contains("(undone)"));
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["undo"])
+        .assert()
+        .failure()
+        .stderr(contains("Nothing to undo"));
+}
+
+#[test]
+fn test_undo_multiple_operations() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["checkout", "-b", "feat/undo"])
+        .assert()
+        .success();
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["checkout", "main"])
+        .assert()
+        .success();
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["undo", "2"])
+        .assert()
+        .success();
+    for repo in ["frontend", "backend"] {
+        assert_eq!(git_helpers::current_branch(&ws.repo_path(repo)), "main");
+        assert!(!git_helpers::branch_exists(
+            &ws.repo_path(repo),
+            "feat/undo"
+        ));
+    }
+}
+
+#[test]
+fn test_undo_refuses_when_refs_moved() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["checkout", "-b", "feat/moved"])
+        .assert()
+        .success();
+    git_helpers::commit_file(&ws.repo_path("app"), "b.txt", "b", "Outside gr");
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["undo"])
+        .assert()
+        .failure()
+        .stderr(contains("refs have changed since"));
+    assert_eq!(
+        git_helpers::current_branch(&ws.repo_path("app")),
+        "feat/moved"
+    );
+}
+
+#[test]
+fn test_undo_retries_after_partial_failure() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["checkout", "-b", "feat/partial"])
+        .assert()
+        .success();
+
+    // A stale index lock makes the backend checkout fail
+    let lock = ws.repo_path("backend").join(".git/index.lock");
+    std::fs::write(&lock, "").unwrap();
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["undo"])
+        .assert()
+        .failure()
+        .stderr(contains("Failed to restore 1 repo(s)"));
+    assert_eq!(
+        git_helpers::current_branch(&ws.repo_path("frontend")),
+        "main"
+    );
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["op", "log"])
+        .assert()
+        .success()
+        .stdout(contains("(undone)").not());
+
+    std::fs::remove_file(&lock).unwrap();
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["undo"])
+        .assert()
+        .success();
+    for repo in ["frontend", "backend"] {
+        assert_eq!(git_helpers::current_branch(&ws.repo_path(repo)), "main");
+    }
+}
+
+#[test]
+fn test_undo_pull_restores_worktree() {
+    let ws = WorkspaceBuilder::new().add_repo("app").build();
+    let app = ws.repo_path("app");
+    let app_head = git_helpers::get_head_sha(&app);
+
+    let temp = tempfile::TempDir::new().unwrap();
+    let other = temp.path().join("app");
+    git_helpers::clone_repo(&ws.remote_url("app"), &other);
+    git_helpers::commit_file(&other, "pulled.txt", "pulled", "Upstream change");
+    git_helpers::push_branch(&other, "origin", "main");
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["pull"])
+        .assert()
+        .success();
+    assert!(app.join("pulled.txt").exists());
+
+    cargo_bin_cmd!("gr")
+        .current_dir(&ws.workspace_root)
+        .args(["undo"])
+        .assert()
+        .success();
+    assert_eq!(git_helpers::get_head_sha(&app), app_head);
+    assert!(!app.join("pulled.txt").exists());
+    assert!(git_helpers::is_clean(&app));
+}
@@ -25,6 +25,7 @@ fn test_verify_clean_passes_on_clean_workspace() {
             links: false,
             on_branch: None,
             synced: false,
+            locked: false,
         });
     assert!(
         result.is_ok(),
@@ -56,6 +57,7 @@ fn test_verify_clean_fails_with_changes() {
             links: false,
             on_branch: None,
             synced: false,
+            locked: false,
         });
     // JSON mode always returns Ok, but the output contains pass: false
     assert!(result.is_ok());
@@ -82,6 +84,7 @@ fn test_verify_on_branch_passes() {
             links: false,
             on_branch: Some("main"),
             synced: false,
+            locked: false,
         });
     assert!(result.is_ok(), "verify --on-branch main should pass");
 }
@@ -107,6 +110,7 @@ fn test_verify_on_branch_fails() {
             links: false,
             on_branch: Some("feat/test"),
             synced: false,
+            locked: false,
         });
     assert!(result.is_ok()); // JSON mode returns Ok
 }
@@ -129,6 +133,7 @@ fn test_verify_no_flags_returns_error() {
             links: false,
             on_branch: None,
             synced: false,
+            locked: false,
         });
     assert!(result.is_err(), "verify with no flags should error");
 }
@@ -151,6 +156,7 @@ fn test_verify_links_no_links_defined() {
             links: true,
             on_branch: None,
             synced: false,
+            locked: false,
         });
     assert!(
         result.is_ok(),
@@ -179,6 +185,7 @@ fn test_verify_multiple_checks_combined() {
             links: false,
             on_branch: Some("main"),
             synced: false,
+            locked: false,
         });
     assert!(
         result.is_ok(),
@@ -210,6 +217,37 @@ fn test_verify_on_branch_after_branch_switch() {
             links: false,
             on_branch: Some("feat/test"),
             synced: false,
+            locked: false,
+        });
+    assert!(result.is_ok()); // JSON mode always Ok
+}
+
+#[test]
+fn test_verify_locked_after_manifest_lock() {
+    let ws = WorkspaceBuilder::new()
+        .add_repo("frontend")
+        .add_repo("backend")
+        .build();
+
+    let manifest = ws.load_manifest();
+    gitgrip::cli::commands::manifest::run_manifest_lock(&ws.workspace_root, &manifest, true, false)
+        .unwrap();
+
+    // Drift one repo away from the lock
+    git_helpers::commit_file(&ws.repo_path("frontend"), "drift.txt", "drift", "Drift");
+
+    let result =
+        gitgrip::cli::commands::verify::run_verify(gitgrip::cli::commands::verify::VerifyOptions {
+            workspace_root: &ws.workspace_root,
+            manifest: &manifest,
+            group_filter: None,
+            json: true,
+            quiet: false,
+            clean: false,
+            links: false,
+            on_branch: None,
+            synced: false,
+            locked: true,
         });
     assert!(result.is_ok()); // JSON mode always Ok
 }